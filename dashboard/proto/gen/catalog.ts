@@ -63,6 +63,8 @@ export const HandleConflictBehavior = {
   NO_CHECK_UNSPECIFIED: "NO_CHECK_UNSPECIFIED",
   OVERWRITE: "OVERWRITE",
   IGNORE: "IGNORE",
+  ERROR: "ERROR",
+  DO_UPDATE_IF_NOT_NULL: "DO_UPDATE_IF_NOT_NULL",
   UNRECOGNIZED: "UNRECOGNIZED",
 } as const;
 
@@ -79,6 +81,12 @@ export function handleConflictBehaviorFromJSON(object: any): HandleConflictBehav
     case 2:
     case "IGNORE":
       return HandleConflictBehavior.IGNORE;
+    case 3:
+    case "ERROR":
+      return HandleConflictBehavior.ERROR;
+    case 4:
+    case "DO_UPDATE_IF_NOT_NULL":
+      return HandleConflictBehavior.DO_UPDATE_IF_NOT_NULL;
     case -1:
     case "UNRECOGNIZED":
     default:
@@ -94,6 +102,10 @@ export function handleConflictBehaviorToJSON(object: HandleConflictBehavior): st
       return "OVERWRITE";
     case HandleConflictBehavior.IGNORE:
       return "IGNORE";
+    case HandleConflictBehavior.ERROR:
+      return "ERROR";
+    case HandleConflictBehavior.DO_UPDATE_IF_NOT_NULL:
+      return "DO_UPDATE_IF_NOT_NULL";
     case HandleConflictBehavior.UNRECOGNIZED:
     default:
       return "UNRECOGNIZED";
@@ -237,6 +237,11 @@ export interface DdlProgress {
   id: number;
   statement: string;
   progress: string;
+  /**
+   * Estimated remaining time of the backfill, based on the backfill throughput so far and the
+   * remaining key count of the upstream tables. Empty if not yet measurable.
+   */
+  eta: string;
 }
 
 export interface GetDdlProgressResponse {
@@ -1440,7 +1445,7 @@ export const GetDdlProgressRequest = {
 };
 
 function createBaseDdlProgress(): DdlProgress {
-  return { id: 0, statement: "", progress: "" };
+  return { id: 0, statement: "", progress: "", eta: "" };
 }
 
 export const DdlProgress = {
@@ -1449,6 +1454,7 @@ export const DdlProgress = {
       id: isSet(object.id) ? Number(object.id) : 0,
       statement: isSet(object.statement) ? String(object.statement) : "",
       progress: isSet(object.progress) ? String(object.progress) : "",
+      eta: isSet(object.eta) ? String(object.eta) : "",
     };
   },
 
@@ -1457,6 +1463,7 @@ export const DdlProgress = {
     message.id !== undefined && (obj.id = Math.round(message.id));
     message.statement !== undefined && (obj.statement = message.statement);
     message.progress !== undefined && (obj.progress = message.progress);
+    message.eta !== undefined && (obj.eta = message.eta);
     return obj;
   },
 
@@ -1465,6 +1472,7 @@ export const DdlProgress = {
     message.id = object.id ?? 0;
     message.statement = object.statement ?? "";
     message.progress = object.progress ?? "";
+    message.eta = object.eta ?? "";
     return message;
   },
 };
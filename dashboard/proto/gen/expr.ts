@@ -107,6 +107,7 @@ export const ExprNode_Type = {
   REGEXP_MATCH: "REGEXP_MATCH",
   POW: "POW",
   EXP: "EXP",
+  WIDTH_BUCKET: "WIDTH_BUCKET",
   /** IS_TRUE - Boolean comparison */
   IS_TRUE: "IS_TRUE",
   IS_NOT_TRUE: "IS_NOT_TRUE",
@@ -354,6 +355,9 @@ export function exprNode_TypeFromJSON(object: any): ExprNode_Type {
     case 234:
     case "EXP":
       return ExprNode_Type.EXP;
+    case 235:
+    case "WIDTH_BUCKET":
+      return ExprNode_Type.WIDTH_BUCKET;
     case 301:
     case "IS_TRUE":
       return ExprNode_Type.IS_TRUE;
@@ -572,6 +576,8 @@ export function exprNode_TypeToJSON(object: ExprNode_Type): string {
       return "POW";
     case ExprNode_Type.EXP:
       return "EXP";
+    case ExprNode_Type.WIDTH_BUCKET:
+      return "WIDTH_BUCKET";
     case ExprNode_Type.IS_TRUE:
       return "IS_TRUE";
     case ExprNode_Type.IS_NOT_TRUE:
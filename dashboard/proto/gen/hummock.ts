@@ -59,6 +59,8 @@ export interface SstableInfo {
   minEpoch: number;
   maxEpoch: number;
   uncompressedFileSize: number;
+  /** Count of delete tombstones in the SST, including both point deletes and range tombstones. */
+  tombstoneKeyCount: number;
 }
 
 export interface OverlappingLevel {
@@ -407,6 +409,7 @@ export const CompactTask_TaskType = {
   MANUAL: "MANUAL",
   SHARED_BUFFER: "SHARED_BUFFER",
   TTL: "TTL",
+  TOMBSTONE: "TOMBSTONE",
   UNRECOGNIZED: "UNRECOGNIZED",
 } as const;
 
@@ -432,6 +435,9 @@ export function compactTask_TaskTypeFromJSON(object: any): CompactTask_TaskType
     case 5:
     case "TTL":
       return CompactTask_TaskType.TTL;
+    case 6:
+    case "TOMBSTONE":
+      return CompactTask_TaskType.TOMBSTONE;
     case -1:
     case "UNRECOGNIZED":
     default:
@@ -453,6 +459,8 @@ export function compactTask_TaskTypeToJSON(object: CompactTask_TaskType): string
       return "SHARED_BUFFER";
     case CompactTask_TaskType.TTL:
       return "TTL";
+    case CompactTask_TaskType.TOMBSTONE:
+      return "TOMBSTONE";
     case CompactTask_TaskType.UNRECOGNIZED:
     default:
       return "UNRECOGNIZED";
@@ -781,6 +789,11 @@ export interface CompactionConfig {
   compactionFilterMask: number;
   maxSubCompaction: number;
   maxSpaceReclaimBytes: number;
+  /**
+   * A tombstone-reclaim compaction is triggered for an SST when the percentage of its delete
+   * tombstones over its total keys reaches this ratio.
+   */
+  tombstoneReclaimRatio: number;
 }
 
 export const CompactionConfig_CompactionMode = {
@@ -848,6 +861,7 @@ function createBaseSstableInfo(): SstableInfo {
     minEpoch: 0,
     maxEpoch: 0,
     uncompressedFileSize: 0,
+    tombstoneKeyCount: 0,
   };
 }
 
@@ -865,6 +879,7 @@ export const SstableInfo = {
       minEpoch: isSet(object.minEpoch) ? Number(object.minEpoch) : 0,
       maxEpoch: isSet(object.maxEpoch) ? Number(object.maxEpoch) : 0,
       uncompressedFileSize: isSet(object.uncompressedFileSize) ? Number(object.uncompressedFileSize) : 0,
+      tombstoneKeyCount: isSet(object.tombstoneKeyCount) ? Number(object.tombstoneKeyCount) : 0,
     };
   },
 
@@ -885,6 +900,7 @@ export const SstableInfo = {
     message.minEpoch !== undefined && (obj.minEpoch = Math.round(message.minEpoch));
     message.maxEpoch !== undefined && (obj.maxEpoch = Math.round(message.maxEpoch));
     message.uncompressedFileSize !== undefined && (obj.uncompressedFileSize = Math.round(message.uncompressedFileSize));
+    message.tombstoneKeyCount !== undefined && (obj.tombstoneKeyCount = Math.round(message.tombstoneKeyCount));
     return obj;
   },
 
@@ -903,6 +919,7 @@ export const SstableInfo = {
     message.minEpoch = object.minEpoch ?? 0;
     message.maxEpoch = object.maxEpoch ?? 0;
     message.uncompressedFileSize = object.uncompressedFileSize ?? 0;
+    message.tombstoneKeyCount = object.tombstoneKeyCount ?? 0;
     return message;
   },
 };
@@ -4247,6 +4264,7 @@ function createBaseCompactionConfig(): CompactionConfig {
     compactionFilterMask: 0,
     maxSubCompaction: 0,
     maxSpaceReclaimBytes: 0,
+    tombstoneReclaimRatio: 0,
   };
 }
 
@@ -4275,6 +4293,7 @@ export const CompactionConfig = {
       compactionFilterMask: isSet(object.compactionFilterMask) ? Number(object.compactionFilterMask) : 0,
       maxSubCompaction: isSet(object.maxSubCompaction) ? Number(object.maxSubCompaction) : 0,
       maxSpaceReclaimBytes: isSet(object.maxSpaceReclaimBytes) ? Number(object.maxSpaceReclaimBytes) : 0,
+      tombstoneReclaimRatio: isSet(object.tombstoneReclaimRatio) ? Number(object.tombstoneReclaimRatio) : 0,
     };
   },
 
@@ -4300,6 +4319,8 @@ export const CompactionConfig = {
     message.compactionFilterMask !== undefined && (obj.compactionFilterMask = Math.round(message.compactionFilterMask));
     message.maxSubCompaction !== undefined && (obj.maxSubCompaction = Math.round(message.maxSubCompaction));
     message.maxSpaceReclaimBytes !== undefined && (obj.maxSpaceReclaimBytes = Math.round(message.maxSpaceReclaimBytes));
+    message.tombstoneReclaimRatio !== undefined &&
+      (obj.tombstoneReclaimRatio = Math.round(message.tombstoneReclaimRatio));
     return obj;
   },
 
@@ -4317,6 +4338,7 @@ export const CompactionConfig = {
     message.compactionFilterMask = object.compactionFilterMask ?? 0;
     message.maxSubCompaction = object.maxSubCompaction ?? 0;
     message.maxSpaceReclaimBytes = object.maxSpaceReclaimBytes ?? 0;
+    message.tombstoneReclaimRatio = object.tombstoneReclaimRatio ?? 0;
     return message;
   },
 };
@@ -222,7 +222,7 @@ mod tests {
     use risingwave_common::types::DataType;
     use risingwave_source::dml_manager::DmlManager;
     use risingwave_storage::memory::MemoryStateStore;
-    use risingwave_storage::store::{ReadOptions, StateStoreReadExt};
+    use risingwave_storage::store::{CachePolicy, ReadOptions, StateStoreReadExt};
 
     use super::*;
     use crate::executor::test_utils::MockExecutor;
@@ -353,9 +353,11 @@ mod tests {
                 ReadOptions {
                     prefix_hint: None,
                     ignore_range_tombstone: false,
+                    limit: None,
                     table_id: Default::default(),
                     retention_seconds: None,
                     read_version_from_backup: false,
+                    cache_policy: CachePolicy::Fill,
                 },
             )
             .await?;
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::future::Future;
+use std::path::PathBuf;
 
 use risingwave_common::array::DataChunk;
 use risingwave_common::error::Result;
@@ -29,6 +30,9 @@ use crate::task::fifo_channel::{new_fifo_channel, FifoReceiver, FifoSender};
 use crate::task::hash_shuffle_channel::{
     new_hash_shuffle_channel, HashShuffleReceiver, HashShuffleSender,
 };
+use crate::task::spill_channel::{
+    new_spillable_fifo_channel, SpillableFifoReceiver, SpillableFifoSender,
+};
 
 pub(super) trait ChanSender: Send {
     type SendFuture<'a>: Future<Output = BatchResult<()>> + Send
@@ -45,6 +49,7 @@ pub enum ChanSenderImpl {
     HashShuffle(HashShuffleSender),
     ConsistentHashShuffle(ConsistentHashShuffleSender),
     Fifo(FifoSender),
+    SpillableFifo(SpillableFifoSender),
     Broadcast(BroadcastSender),
 }
 
@@ -54,6 +59,7 @@ impl ChanSenderImpl {
             Self::HashShuffle(sender) => sender.send(chunk).await,
             Self::ConsistentHashShuffle(sender) => sender.send(chunk).await,
             Self::Fifo(sender) => sender.send(chunk).await,
+            Self::SpillableFifo(sender) => sender.send(chunk).await,
             Self::Broadcast(sender) => sender.send(chunk).await,
         }
     }
@@ -72,6 +78,7 @@ pub enum ChanReceiverImpl {
     HashShuffle(HashShuffleReceiver),
     ConsistentHashShuffle(ConsistentHashShuffleReceiver),
     Fifo(FifoReceiver),
+    SpillableFifo(SpillableFifoReceiver),
     Broadcast(BroadcastReceiver),
 }
 
@@ -82,6 +89,7 @@ impl ChanReceiverImpl {
             Self::ConsistentHashShuffle(receiver) => receiver.recv().await,
             Self::Broadcast(receiver) => receiver.recv().await,
             Self::Fifo(receiver) => receiver.recv().await,
+            Self::SpillableFifo(receiver) => receiver.recv().await,
         }
     }
 }
@@ -90,12 +98,20 @@ impl ChanReceiverImpl {
 /// The producer is the local task executor, the consumer is
 /// [`ExchangeService`](risingwave_pb::task_service::exchange_service_server::ExchangeService).
 /// The implementation depends on the shuffling strategy.
+///
+/// If `spill_path` is given, the single-distribution channel spills chunks exceeding
+/// `output_channel_size` to that path instead of blocking the producer. Shuffling channels do not
+/// support spilling yet and ignore it.
 pub fn create_output_channel(
     shuffle: &ExchangeInfo,
     output_channel_size: usize,
+    spill_path: Option<PathBuf>,
 ) -> Result<(ChanSenderImpl, Vec<ChanReceiverImpl>)> {
     match shuffle.get_mode()? {
-        ShuffleDistributionMode::Single => Ok(new_fifo_channel(output_channel_size)),
+        ShuffleDistributionMode::Single => Ok(match spill_path {
+            Some(spill_path) => new_spillable_fifo_channel(output_channel_size, spill_path),
+            None => new_fifo_channel(output_channel_size),
+        }),
         ShuffleDistributionMode::Hash => Ok(new_hash_shuffle_channel(shuffle, output_channel_size)),
         ShuffleDistributionMode::ConsistentHash => {
             Ok(new_consistent_shuffle_channel(shuffle, output_channel_size))
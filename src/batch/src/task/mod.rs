@@ -25,5 +25,6 @@ mod data_chunk_in_channel;
 mod env;
 mod fifo_channel;
 mod hash_shuffle_channel;
+mod spill_channel;
 mod task_execution;
 mod task_manager;
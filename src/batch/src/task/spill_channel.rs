@@ -0,0 +1,271 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use prost::Message;
+use risingwave_common::array::DataChunk;
+use risingwave_common::error::ErrorCode::InternalError;
+use risingwave_common::error::Result;
+use risingwave_pb::data::DataChunk as ProstDataChunk;
+use tokio::fs::File;
+use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader, BufWriter};
+use tokio::sync::{mpsc, Mutex};
+
+use crate::error::BatchError::{Internal, SenderError};
+use crate::error::Result as BatchResult;
+use crate::task::channel::{ChanReceiver, ChanReceiverImpl, ChanSender, ChanSenderImpl};
+use crate::task::data_chunk_in_channel::DataChunkInChannel;
+
+/// Tag of a spill file frame that carries a length-prefixed protobuf-encoded chunk.
+const FRAME_CHUNK: u8 = 1;
+/// Tag of a spill file frame that marks the end of the channel.
+const FRAME_END: u8 = 0;
+
+/// State shared between [`SpillableFifoSender`] and [`SpillableFifoReceiver`].
+///
+/// Chunks are delivered in order: everything buffered in the in-memory channel was sent before
+/// anything currently in the spill file, because the sender does not write to the in-memory
+/// channel again until the spill backlog is fully consumed.
+#[derive(Debug)]
+struct SpillState {
+    /// Path of the spill file. The file is created lazily by the first spilled chunk and removed
+    /// when the receiver is dropped.
+    path: PathBuf,
+    /// Number of frames written to the spill file.
+    spilled: AtomicUsize,
+    /// Number of frames consumed from the spill file.
+    consumed: AtomicUsize,
+}
+
+impl SpillState {
+    fn has_backlog(&self) -> bool {
+        self.consumed.load(Ordering::Acquire) < self.spilled.load(Ordering::Acquire)
+    }
+}
+
+/// A [`FifoSender`](super::fifo_channel::FifoSender) variant that spills chunks to local disk
+/// instead of blocking when the in-memory channel is full, so that an exchange producer can keep
+/// pipelining even if the consumer falls behind by more than `output_channel_size` chunks.
+#[derive(Debug, Clone)]
+pub struct SpillableFifoSender {
+    sender: mpsc::Sender<Option<DataChunkInChannel>>,
+    state: Arc<SpillState>,
+    writer: Arc<Mutex<Option<BufWriter<File>>>>,
+}
+
+pub struct SpillableFifoReceiver {
+    receiver: mpsc::Receiver<Option<DataChunkInChannel>>,
+    state: Arc<SpillState>,
+    reader: Option<BufReader<File>>,
+}
+
+impl SpillableFifoSender {
+    /// Appends `chunk` to the spill file. `None` is written as an end-of-channel marker.
+    async fn spill(&self, chunk: Option<DataChunk>) -> BatchResult<()> {
+        let mut writer = self.writer.lock().await;
+        if writer.is_none() {
+            let file = File::create(&self.state.path)
+                .await
+                .map_err(|e| Internal(e.into()))?;
+            *writer = Some(BufWriter::new(file));
+        }
+        let writer = writer.as_mut().unwrap();
+        let io_result = async {
+            match chunk {
+                Some(chunk) => {
+                    let buf = chunk.compact().to_protobuf().encode_to_vec();
+                    writer.write_u8(FRAME_CHUNK).await?;
+                    writer.write_u32_le(buf.len() as u32).await?;
+                    writer.write_all(&buf).await?;
+                }
+                None => writer.write_u8(FRAME_END).await?,
+            }
+            writer.flush().await
+        }
+        .await;
+        io_result.map_err(|e| Internal(e.into()))?;
+        self.state.spilled.fetch_add(1, Ordering::Release);
+        Ok(())
+    }
+}
+
+impl ChanSender for SpillableFifoSender {
+    type SendFuture<'a> = impl Future<Output = BatchResult<()>> + 'a;
+
+    fn send(&mut self, chunk: Option<DataChunk>) -> Self::SendFuture<'_> {
+        async move {
+            // Chunks must come back in order, so once spilling has started all subsequent chunks
+            // go to the spill file until the backlog is drained.
+            if self.state.has_backlog() {
+                return self.spill(chunk).await;
+            }
+            match self.sender.try_send(chunk.map(DataChunkInChannel::new)) {
+                Ok(()) => Ok(()),
+                Err(mpsc::error::TrySendError::Full(chunk)) => {
+                    self.spill(chunk.map(DataChunkInChannel::into_data_chunk))
+                        .await
+                }
+                Err(mpsc::error::TrySendError::Closed(_)) => Err(SenderError),
+            }
+        }
+    }
+}
+
+impl SpillableFifoReceiver {
+    /// Reads the next frame from the spill file.
+    async fn read_frame(&mut self) -> Result<Option<DataChunkInChannel>> {
+        if self.reader.is_none() {
+            // The file must have been created, as the sender has spilled at least one frame.
+            self.reader = Some(BufReader::new(File::open(&self.state.path).await?));
+        }
+        let reader = self.reader.as_mut().unwrap();
+        let chunk = match reader.read_u8().await? {
+            FRAME_END => None,
+            FRAME_CHUNK => {
+                let len = reader.read_u32_le().await? as usize;
+                let mut buf = vec![0; len];
+                reader.read_exact(&mut buf).await?;
+                let prost_chunk = ProstDataChunk::decode(buf.as_slice()).map_err(|e| {
+                    InternalError(format!("failed to decode spilled exchange chunk: {}", e))
+                })?;
+                Some(DataChunkInChannel::new(DataChunk::from_protobuf(
+                    &prost_chunk,
+                )?))
+            }
+            _ => return Err(InternalError("corrupted exchange spill file".to_string()).into()),
+        };
+        self.state.consumed.fetch_add(1, Ordering::Release);
+        Ok(chunk)
+    }
+}
+
+impl ChanReceiver for SpillableFifoReceiver {
+    type RecvFuture<'a> = impl Future<Output = Result<Option<DataChunkInChannel>>> + 'a;
+
+    fn recv(&mut self) -> Self::RecvFuture<'_> {
+        async move {
+            // Everything in the in-memory channel was sent before the spill backlog.
+            match self.receiver.try_recv() {
+                Ok(chunk) => Ok(chunk),
+                Err(mpsc::error::TryRecvError::Empty) => {
+                    if self.state.has_backlog() {
+                        self.read_frame().await
+                    } else {
+                        // The sender only spills when the in-memory channel is full, so it cannot
+                        // start a new backlog while we are waiting on the empty channel.
+                        match self.receiver.recv().await {
+                            Some(chunk) => Ok(chunk),
+                            // Early close should be treated as error.
+                            None => {
+                                Err(InternalError("broken spillable_fifo_channel".to_string())
+                                    .into())
+                            }
+                        }
+                    }
+                }
+                Err(mpsc::error::TryRecvError::Disconnected) => {
+                    // The sender is gone, but spilled chunks may remain to be consumed.
+                    if self.state.has_backlog() {
+                        self.read_frame().await
+                    } else {
+                        Err(InternalError("broken spillable_fifo_channel".to_string()).into())
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Drop for SpillableFifoReceiver {
+    fn drop(&mut self) {
+        if self.reader.is_some() || self.state.has_backlog() {
+            std::fs::remove_file(&self.state.path).ok();
+        }
+    }
+}
+
+pub fn new_spillable_fifo_channel(
+    output_channel_size: usize,
+    spill_path: PathBuf,
+) -> (ChanSenderImpl, Vec<ChanReceiverImpl>) {
+    let (s, r) = mpsc::channel(output_channel_size);
+    let state = Arc::new(SpillState {
+        path: spill_path,
+        spilled: AtomicUsize::new(0),
+        consumed: AtomicUsize::new(0),
+    });
+    (
+        ChanSenderImpl::SpillableFifo(SpillableFifoSender {
+            sender: s,
+            state: state.clone(),
+            writer: Arc::new(Mutex::new(None)),
+        }),
+        vec![ChanReceiverImpl::SpillableFifo(SpillableFifoReceiver {
+            receiver: r,
+            state,
+            reader: None,
+        })],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::test_prelude::DataChunkTestExt;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_spill_and_recv_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let (mut sender, mut receivers) =
+            new_spillable_fifo_channel(2, dir.path().join("spill.data"));
+        assert_eq!(receivers.len(), 1);
+        let receiver = receivers.get_mut(0).unwrap();
+
+        // The channel holds 2 chunks in memory, the rest are spilled to disk.
+        let chunks = (0..8)
+            .map(|i| DataChunk::from_pretty(&format!("i\n{}", i)))
+            .collect::<Vec<_>>();
+        for chunk in &chunks {
+            sender.send(Some(chunk.clone())).await.unwrap();
+        }
+        sender.send(None).await.unwrap();
+
+        for expected in &chunks {
+            let chunk = receiver.recv().await.unwrap().unwrap().into_data_chunk();
+            assert_eq!(&chunk, expected);
+        }
+        assert!(receiver.recv().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_no_spill_when_consumer_keeps_up() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("spill.data");
+        let (mut sender, mut receivers) = new_spillable_fifo_channel(2, path.clone());
+        let receiver = receivers.get_mut(0).unwrap();
+
+        for i in 0..8 {
+            let chunk = DataChunk::from_pretty(&format!("i\n{}", i));
+            sender.send(Some(chunk.clone())).await.unwrap();
+            let received = receiver.recv().await.unwrap().unwrap().into_data_chunk();
+            assert_eq!(received, chunk);
+        }
+        assert!(!path.exists());
+    }
+}
@@ -324,9 +324,17 @@ impl<C: BatchTaskContext> BatchTaskExecution<C> {
     ) -> Result<Self> {
         let task_id = TaskId::from(prost_tid);
 
+        let developer = &context.get_config().developer;
+        let spill_path = developer.batch_exchange_spill_enabled.then(|| {
+            std::env::temp_dir().join(format!(
+                "risingwave-exchange-spill-{}-{}-{}",
+                task_id.query_id, task_id.stage_id, task_id.task_id
+            ))
+        });
         let (sender, receivers) = create_output_channel(
             plan.get_exchange_info()?,
-            context.get_config().developer.batch_output_channel_size,
+            developer.batch_output_channel_size,
+            spill_path,
         )?;
 
         let mut rts = Vec::new();
@@ -17,7 +17,7 @@ use std::time::Duration;
 use risingwave_common::bail;
 use risingwave_common::error::Result;
 use risingwave_pb::meta::meta_snapshot::SnapshotVersion;
-use risingwave_pb::meta::subscribe_response::Info;
+use risingwave_pb::meta::subscribe_response::{Info, Operation};
 use risingwave_pb::meta::{SubscribeResponse, SubscribeType};
 use risingwave_rpc_client::error::RpcError;
 use risingwave_rpc_client::MetaClient;
@@ -56,6 +56,10 @@ pub struct ObserverManager<T: NotificationClient, S: ObserverState> {
     rx: T::Channel,
     client: T,
     observer_states: S,
+    /// The latest snapshot version this observer has fully applied. Sent to the meta service on
+    /// re-subscription, so that only the missed notifications are replayed instead of a full
+    /// snapshot.
+    resume_from: Option<SnapshotVersion>,
 }
 
 pub trait ObserverState: Send + 'static {
@@ -81,16 +85,39 @@ where
 {
     pub async fn new(client: T, observer_states: S) -> Self {
         let rx = client
-            .subscribe(S::SubscribeType::subscribe_type())
+            .subscribe(S::SubscribeType::subscribe_type(), None)
             .await
             .unwrap();
         Self {
             rx,
             client,
             observer_states,
+            resume_from: None,
         }
     }
 
+    /// Advance the resume version with a versioned notification that has been applied.
+    fn update_resume_version(resume_from: &mut SnapshotVersion, resp: &SubscribeResponse) {
+        let Some(info) = resp.info.as_ref() else {
+            return;
+        };
+        let observed = match info {
+            Info::Database(_)
+            | Info::Schema(_)
+            | Info::Table(_)
+            | Info::Source(_)
+            | Info::Sink(_)
+            | Info::Index(_)
+            | Info::View(_)
+            | Info::Function(_)
+            | Info::User(_) => &mut resume_from.catalog_version,
+            Info::ParallelUnitMapping(_) => &mut resume_from.parallel_unit_mapping_version,
+            Info::Node(_) => &mut resume_from.worker_node_version,
+            _ => return,
+        };
+        *observed = (*observed).max(resp.version);
+    }
+
     async fn wait_init_notification(&mut self) -> Result<()> {
         let mut notification_vec = Vec::new();
         let init_notification = loop {
@@ -98,22 +125,27 @@ where
             let Ok(Some(notification)) = self.rx.message().await else {
                 bail!("receives meta's notification err");
             };
-            if !matches!(notification.info.as_ref().unwrap(), &Info::Snapshot(_)) {
-                notification_vec.push(notification);
-            } else {
-                break notification;
+            match notification.info.as_ref().unwrap() {
+                Info::Snapshot(_) | Info::SnapshotDeltas(_) => break notification,
+                _ => notification_vec.push(notification),
             }
         };
 
-        let Info::Snapshot(info) = init_notification.info.as_ref().unwrap() else {
-            unreachable!();
+        let (version, hummock_version_id) = match init_notification.info.as_ref().unwrap() {
+            Info::Snapshot(info) => (
+                info.version.clone().unwrap(),
+                info.hummock_version.as_ref().map(|v| v.id),
+            ),
+            Info::SnapshotDeltas(deltas) => (deltas.version.clone().unwrap(), None),
+            _ => unreachable!(),
         };
+        self.resume_from = Some(version.clone());
 
         let SnapshotVersion {
             catalog_version,
             parallel_unit_mapping_version,
             worker_node_version,
-        } = info.version.clone().unwrap();
+        } = version;
 
         notification_vec.retain_mut(|notification| match notification.info.as_ref().unwrap() {
             Info::Database(_)
@@ -128,17 +160,38 @@ where
             Info::ParallelUnitMapping(_) => notification.version > parallel_unit_mapping_version,
             Info::Node(_) => notification.version > worker_node_version,
             Info::HummockVersionDeltas(version_delta) => {
-                version_delta.version_deltas[0].id > info.hummock_version.as_ref().unwrap().id
+                version_delta.version_deltas[0].id > hummock_version_id.unwrap()
             }
             Info::HummockSnapshot(_) => true,
             Info::MetaBackupManifestId(_) => true,
-            Info::Snapshot(_) => unreachable!(),
+            Info::Snapshot(_) | Info::SnapshotDeltas(_) => unreachable!(),
         });
 
-        self.observer_states
-            .handle_initialization_notification(init_notification);
+        match init_notification.info.clone().unwrap() {
+            Info::Snapshot(_) => {
+                self.observer_states
+                    .handle_initialization_notification(init_notification);
+            }
+            Info::SnapshotDeltas(deltas) => {
+                // The local states are retained across re-subscription: apply only the missed
+                // notifications replayed by the meta service, plus the latest hummock snapshot.
+                for delta in deltas.deltas {
+                    self.observer_states.handle_notification(delta);
+                }
+                if let Some(hummock_snapshot) = deltas.hummock_snapshot {
+                    self.observer_states.handle_notification(SubscribeResponse {
+                        status: None,
+                        operation: Operation::Update as i32,
+                        info: Some(Info::HummockSnapshot(hummock_snapshot)),
+                        version: 0,
+                    });
+                }
+            }
+            _ => unreachable!(),
+        }
 
         for notification in notification_vec {
+            Self::update_resume_version(self.resume_from.as_mut().unwrap(), &notification);
             self.observer_states.handle_notification(notification);
         }
 
@@ -161,7 +214,11 @@ where
                             self.re_subscribe().await;
                             continue;
                         }
-                        self.observer_states.handle_notification(resp.unwrap());
+                        let resp = resp.unwrap();
+                        if let Some(resume_from) = self.resume_from.as_mut() {
+                            Self::update_resume_version(resume_from, &resp);
+                        }
+                        self.observer_states.handle_notification(resp);
                     }
                     Err(e) => {
                         tracing::error!("Receives meta's notification err {:?}", e);
@@ -177,7 +234,7 @@ where
         loop {
             match self
                 .client
-                .subscribe(S::SubscribeType::subscribe_type())
+                .subscribe(S::SubscribeType::subscribe_type(), self.resume_from.clone())
                 .await
             {
                 Ok(rx) => {
@@ -214,7 +271,11 @@ impl<T: Send + 'static> Channel for Streaming<T> {
 #[async_trait::async_trait]
 pub trait NotificationClient: Send + Sync + 'static {
     type Channel: Channel<Item = SubscribeResponse>;
-    async fn subscribe(&self, subscribe_type: SubscribeType) -> Result<Self::Channel>;
+    async fn subscribe(
+        &self,
+        subscribe_type: SubscribeType,
+        resume_from: Option<SnapshotVersion>,
+    ) -> Result<Self::Channel>;
 }
 
 pub struct RpcNotificationClient {
@@ -231,9 +292,13 @@ impl RpcNotificationClient {
 impl NotificationClient for RpcNotificationClient {
     type Channel = Streaming<SubscribeResponse>;
 
-    async fn subscribe(&self, subscribe_type: SubscribeType) -> Result<Self::Channel> {
+    async fn subscribe(
+        &self,
+        subscribe_type: SubscribeType,
+        resume_from: Option<SnapshotVersion>,
+    ) -> Result<Self::Channel> {
         self.meta_client
-            .subscribe(subscribe_type)
+            .subscribe(subscribe_type, resume_from)
             .await
             .map_err(RpcError::into)
     }
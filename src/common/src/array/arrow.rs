@@ -18,6 +18,7 @@ use chrono::{NaiveDateTime, NaiveTime};
 
 use super::column::Column;
 use super::*;
+use crate::buffer::Bitmap;
 use crate::types::struct_type::StructType;
 use crate::util::iter_util::ZipEqFast;
 
@@ -220,12 +221,6 @@ macro_rules! converts {
         }
     };
 }
-converts!(BoolArray, arrow_array::BooleanArray);
-converts!(I16Array, arrow_array::Int16Array);
-converts!(I32Array, arrow_array::Int32Array);
-converts!(I64Array, arrow_array::Int64Array);
-converts!(F32Array, arrow_array::Float32Array, @map);
-converts!(F64Array, arrow_array::Float64Array, @map);
 converts!(DecimalArray, arrow_array::Decimal128Array, @map);
 converts!(BytesArray, arrow_array::BinaryArray);
 converts!(Utf8Array, arrow_array::StringArray);
@@ -234,6 +229,130 @@ converts!(NaiveTimeArray, arrow_array::Time64NanosecondArray, @map);
 converts!(NaiveDateTimeArray, arrow_array::TimestampNanosecondArray, @map);
 converts!(IntervalArray, arrow_array::IntervalMonthDayNanoArray, @map);
 
+/// Implement bi-directional `From` between fixed-width array types, converting the value buffer
+/// and the null bitmap wholesale instead of going through each value.
+macro_rules! converts_primitive {
+    ($ArrayType:ty, $ArrowType:ty, $ArrowDataType:expr) => {
+        impl From<&$ArrayType> for $ArrowType {
+            fn from(array: &$ArrayType) -> Self {
+                let values = arrow_buffer::Buffer::from_slice_ref(array.as_slice());
+                Self::from(fixed_width_array_data(
+                    $ArrowDataType,
+                    array.len(),
+                    values,
+                    array.null_bitmap(),
+                ))
+            }
+        }
+        impl From<&$ArrowType> for $ArrayType {
+            fn from(array: &$ArrowType) -> Self {
+                <$ArrayType>::from_iter_bitmap(
+                    array.values().iter().copied(),
+                    arrow_bitmap(array),
+                )
+            }
+        }
+    };
+    // convert values using FromIntoArrow
+    ($ArrayType:ty, $ArrowType:ty, $ArrowDataType:expr, @map) => {
+        impl From<&$ArrayType> for $ArrowType {
+            fn from(array: &$ArrayType) -> Self {
+                let values: Vec<_> = array.as_slice().iter().map(|v| v.into_arrow()).collect();
+                Self::from(fixed_width_array_data(
+                    $ArrowDataType,
+                    array.len(),
+                    arrow_buffer::Buffer::from_slice_ref(&values),
+                    array.null_bitmap(),
+                ))
+            }
+        }
+        impl From<&$ArrowType> for $ArrayType {
+            fn from(array: &$ArrowType) -> Self {
+                let values = array.values().iter().map(|&v| {
+                    <<$ArrayType as Array>::RefItem<'_> as FromIntoArrow>::from_arrow(v)
+                });
+                <$ArrayType>::from_iter_bitmap(values, arrow_bitmap(array))
+            }
+        }
+    };
+}
+converts_primitive!(I16Array, arrow_array::Int16Array, arrow_schema::DataType::Int16);
+converts_primitive!(I32Array, arrow_array::Int32Array, arrow_schema::DataType::Int32);
+converts_primitive!(I64Array, arrow_array::Int64Array, arrow_schema::DataType::Int64);
+converts_primitive!(F32Array, arrow_array::Float32Array, arrow_schema::DataType::Float32, @map);
+converts_primitive!(F64Array, arrow_array::Float64Array, arrow_schema::DataType::Float64, @map);
+
+impl From<&BoolArray> for arrow_array::BooleanArray {
+    fn from(array: &BoolArray) -> Self {
+        // The values of a boolean array are a bit buffer in the same layout as the bitmap.
+        let values = arrow_buffer::Buffer::from(array.data().to_bytes());
+        Self::from(fixed_width_array_data(
+            arrow_schema::DataType::Boolean,
+            array.len(),
+            values,
+            array.null_bitmap(),
+        ))
+    }
+}
+
+impl From<&arrow_array::BooleanArray> for BoolArray {
+    fn from(array: &arrow_array::BooleanArray) -> Self {
+        let data = arrow_array::Array::data(array);
+        BoolArray::new(
+            arrow_bit_buffer_to_bitmap(&data.buffers()[0], data.offset(), data.len()),
+            arrow_bitmap(array),
+        )
+    }
+}
+
+/// Builds the [`arrow_data::ArrayData`] of a fixed-width array from its value buffer and null
+/// bitmap.
+fn fixed_width_array_data(
+    data_type: arrow_schema::DataType,
+    len: usize,
+    values: arrow_buffer::Buffer,
+    bitmap: &Bitmap,
+) -> arrow_data::ArrayData {
+    arrow_data::ArrayData::builder(data_type)
+        .len(len)
+        .add_buffer(values)
+        .null_bit_buffer(bitmap_to_null_buffer(bitmap))
+        .build()
+        .unwrap()
+}
+
+/// Converts a null bitmap to an Arrow validity buffer, or `None` if all values are valid, which
+/// allows Arrow to skip the null checks entirely.
+fn bitmap_to_null_buffer(bitmap: &Bitmap) -> Option<arrow_buffer::Buffer> {
+    (!bitmap.all()).then(|| arrow_buffer::Buffer::from(bitmap.to_bytes()))
+}
+
+/// Converts the validity of an Arrow array to a null bitmap, copying the buffer directly instead
+/// of checking each value.
+fn arrow_bitmap(array: &dyn arrow_array::Array) -> Bitmap {
+    let data = arrow_array::Array::data(array);
+    match data.null_buffer() {
+        Some(buffer) => arrow_bit_buffer_to_bitmap(buffer, data.offset(), data.len()),
+        None => Bitmap::ones(data.len()),
+    }
+}
+
+/// Converts `num_bits` bits of an Arrow bit buffer starting at `offset` to a bitmap. The bytes are
+/// copied directly unless the range is not byte-aligned.
+fn arrow_bit_buffer_to_bitmap(
+    buffer: &arrow_buffer::Buffer,
+    offset: usize,
+    num_bits: usize,
+) -> Bitmap {
+    if offset % 8 == 0 {
+        Bitmap::from_bytes_with_num_bits(&buffer.as_slice()[offset / 8..], num_bits)
+    } else {
+        (0..num_bits)
+            .map(|i| arrow_buffer::bit_util::get_bit(buffer.as_slice(), offset + i))
+            .collect()
+    }
+}
+
 /// Converts RisingWave value from and into Arrow value.
 trait FromIntoArrow {
     /// The corresponding element type in the Arrow array.
@@ -528,6 +647,26 @@ mod tests {
         assert_eq!(I16Array::from(&arrow), array);
     }
 
+    #[test]
+    fn i32_large() {
+        // Cross the word boundary of the null bitmap.
+        let array = I32Array::from_iter((0..100).map(|i| (i % 7 != 0).then_some(i)));
+        let arrow = arrow_array::Int32Array::from(&array);
+        assert_eq!(I32Array::from(&arrow), array);
+    }
+
+    #[test]
+    fn bool_large() {
+        // Cross the byte and word boundaries of the bit buffers.
+        let array = BoolArray::from_iter((0..1000).map(|i| match i % 3 {
+            0 => None,
+            1 => Some(i % 2 == 0),
+            _ => Some(i % 5 == 0),
+        }));
+        let arrow = arrow_array::BooleanArray::from(&array);
+        assert_eq!(BoolArray::from(&arrow), array);
+    }
+
     #[test]
     fn f32() {
         let array = F32Array::from_iter([
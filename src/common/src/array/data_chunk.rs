@@ -32,7 +32,7 @@ use crate::types::to_text::ToText;
 use crate::types::{DataType, Datum, NaiveDateTimeWrapper, ToOwnedDatum};
 use crate::util::hash_util::finalize_hashers;
 use crate::util::iter_util::{ZipEqDebug, ZipEqFast};
-use crate::util::value_encoding::serialize_datum_into;
+use crate::util::value_encoding::{serialize_datum_into, ValueRowSerializer};
 
 /// `DataChunk` is a collection of arrays with visibility mask.
 #[derive(Clone, PartialEq)]
@@ -416,6 +416,23 @@ impl DataChunk {
             }
         }
     }
+
+    /// Serialize each row into bytes with the given serializer.
+    ///
+    /// Unlike [`DataChunk::serialize`], the returned bytes are not guaranteed to be in the basic
+    /// value encoding format, so they can only be deserialized with the corresponding
+    /// deserializer.
+    ///
+    /// The returned vector's size is `self.capacity()` and for the invisible row will give empty
+    /// bytes.
+    pub fn serialize_with(&self, serializer: &impl ValueRowSerializer) -> Vec<Bytes> {
+        self.rows_with_holes()
+            .map(|row| match row {
+                Some(row) => serializer.serialize(row).into(),
+                None => Bytes::new(),
+            })
+            .collect()
+    }
 }
 
 impl fmt::Debug for DataChunk {
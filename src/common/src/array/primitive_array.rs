@@ -168,6 +168,12 @@ impl<T: PrimitiveArrayItemType> PrimitiveArray<T> {
         assert_eq!(data.len(), bitmap.len());
         PrimitiveArray { bitmap, data }
     }
+
+    /// Returns a slice containing the entire underlying values, disregarding the null bitmap. The
+    /// values at null positions are unspecified.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
 }
 
 impl<T: PrimitiveArrayItemType> Array for PrimitiveArray<T> {
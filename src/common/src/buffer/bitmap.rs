@@ -226,17 +226,41 @@ impl Bitmap {
 
     /// Creates a new bitmap from bytes.
     pub fn from_bytes(buf: &[u8]) -> Self {
-        let num_bits = buf.len() * 8;
+        Self::from_bytes_with_num_bits(buf, buf.len() * 8)
+    }
+
+    /// Creates a new bitmap from the first `num_bits` bits of `buf`. Bits are ordered LSB first
+    /// within each byte, which is also the layout of Arrow validity buffers. Bits in `buf` beyond
+    /// `num_bits` are ignored.
+    pub fn from_bytes_with_num_bits(buf: &[u8], num_bits: usize) -> Self {
+        assert!(num_bits <= buf.len() * 8);
+        let num_bytes = (num_bits + 7) / 8;
         let mut bits = Vec::with_capacity(Self::vec_len(num_bits));
         let slice = unsafe {
             bits.set_len(bits.capacity());
             std::slice::from_raw_parts_mut(bits.as_ptr() as *mut u8, bits.len() * (BITS / 8))
         };
-        slice[..buf.len()].copy_from_slice(buf);
-        slice[buf.len()..].fill(0);
+        slice[..num_bytes].copy_from_slice(&buf[..num_bytes]);
+        slice[num_bytes..].fill(0);
+        if num_bits % BITS != 0 {
+            // The padding bits of `buf` are not guaranteed to be zero.
+            let last = bits.len() - 1;
+            bits[last] &= (1 << (num_bits % BITS)) - 1;
+        }
         Self::from_vec_with_len(bits, num_bits)
     }
 
+    /// Returns the bitmap content as a byte buffer, in the same layout as [`Bitmap::from_bytes`].
+    /// The padding bits in the last byte, if any, are set to zero.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.bits.len() * (BITS / 8));
+        for bits in self.bits.iter() {
+            buf.extend_from_slice(&bits.to_le_bytes());
+        }
+        buf.truncate((self.num_bits + 7) / 8);
+        buf
+    }
+
     /// Creates a new bitmap from a slice of `bool`.
     pub fn from_bool_slice(bools: &[bool]) -> Self {
         // use SIMD to speed up
@@ -788,6 +812,22 @@ mod tests {
         assert_eq!(expected.count_ones(), count_ones);
     }
 
+    #[test]
+    fn test_bitmap_to_bytes() {
+        // The padding bits of the input are dropped on the way in and zeroed on the way out.
+        let bitmap = Bitmap::from_bytes_with_num_bits(&[0b0110_1010, 0b1011_0101], 12);
+        assert_eq!(bitmap.count_ones(), 6);
+        assert_eq!(bitmap.to_bytes(), vec![0b0110_1010, 0b0000_0101]);
+        assert_eq!(Bitmap::from_bytes(&bitmap.to_bytes()).to_bytes(), bitmap.to_bytes());
+
+        // A bitmap spanning multiple `usize` words round-trips losslessly.
+        let bitmap: Bitmap = [true, false, true].iter().cycle().cloned().take(200).collect();
+        assert_eq!(
+            Bitmap::from_bytes_with_num_bits(&bitmap.to_bytes(), bitmap.len()),
+            bitmap
+        );
+    }
+
     #[test]
     fn test_bitmap_eq() {
         let b1: Bitmap = Bitmap::zeros(3);
@@ -367,10 +367,20 @@ impl From<UserId> for u32 {
     }
 }
 
-#[derive(Clone, Debug)]
-#[cfg_attr(test, derive(PartialEq))]
+/// How the materialized state of a table reacts to a write whose primary key already exists. See
+/// the `ON CONFLICT` clause of `CREATE TABLE`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ConflictBehavior {
+    /// The input is trusted to never conflict, e.g. the output of an operator that preserves the
+    /// primary key. Writes are applied blindly.
     NoCheck,
+    /// The new row completely replaces the old one.
     OverWrite,
+    /// The new row is discarded and the old one is kept.
     IgnoreConflict,
+    /// A conflicting write fails the streaming job with an error.
+    ReportError,
+    /// The non-null columns of the new row overwrite the old one; its null columns keep the old
+    /// values.
+    DoUpdateIfNotNull,
 }
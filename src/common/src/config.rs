@@ -163,6 +163,10 @@ pub struct MetaConfig {
     /// Schedule ttl_reclaim compaction for all compaction groups with this interval.
     #[serde(default = "default::meta::periodic_ttl_reclaim_compaction_interval_sec")]
     pub periodic_ttl_reclaim_compaction_interval_sec: u64,
+
+    /// Schedule tombstone_reclaim compaction for all compaction groups with this interval.
+    #[serde(default = "default::meta::periodic_tombstone_reclaim_compaction_interval_sec")]
+    pub periodic_tombstone_reclaim_compaction_interval_sec: u64,
 }
 
 impl Default for MetaConfig {
@@ -256,6 +260,12 @@ pub struct StreamingConfig {
     /// Max unique user stream errors per actor
     #[serde(default = "default::streaming::unique_user_stream_errors")]
     pub unique_user_stream_errors: usize,
+
+    /// The freshness SLO threshold of materialized views in milliseconds. A warning event is
+    /// reported when the freshness lag of a materialized view exceeds this threshold. Disabled if
+    /// not specified.
+    #[serde(default)]
+    pub mview_freshness_slo_threshold_ms: Option<u64>,
 }
 
 impl Default for StreamingConfig {
@@ -314,6 +324,12 @@ pub struct StorageConfig {
     #[serde(default = "default::storage::block_cache_capacity_mb")]
     pub block_cache_capacity_mb: usize,
 
+    /// Maximum share of the block cache a single table may occupy. Blocks of a table at its cap
+    /// are served without being cached, so that one huge scan cannot evict every other table's
+    /// blocks. `0` disables the cap.
+    #[serde(default = "default::storage::block_cache_per_table_capacity_mb")]
+    pub block_cache_per_table_capacity_mb: usize,
+
     /// Capacity of sstable meta cache.
     #[serde(default = "default::storage::meta_cache_capacity_mb")]
     pub meta_cache_capacity_mb: usize,
@@ -332,6 +348,26 @@ pub struct StorageConfig {
     #[serde(default = "default::storage::share_buffer_upload_concurrency")]
     pub share_buffer_upload_concurrency: usize,
 
+    /// Number of upload tasks that may run in parallel for a single table. `0` disables the
+    /// per-table limit.
+    #[serde(default = "default::storage::share_buffer_upload_per_table_concurrency")]
+    pub share_buffer_upload_per_table_concurrency: usize,
+
+    /// Target size of a single shared buffer upload task in MB. When a checkpoint is flushed,
+    /// the data of different tables is packed into upload tasks of roughly this size and
+    /// uploaded in parallel, instead of in one task whose duration scales with the table count.
+    #[serde(default = "default::storage::share_buffer_upload_task_size_mb")]
+    pub share_buffer_upload_task_size_mb: usize,
+
+    /// Whether to spill shared buffer flushes to the local disk under the file cache directory
+    /// and upload the resulting SSTs to the object store in the background, so that barriers
+    /// are bounded by the local disk bandwidth instead of the object store throughput. Until
+    /// the background upload finishes, the spilled SSTs can only be read on the node that
+    /// produced them, and they are lost if the node crashes. Requires `file_cache.dir` to be
+    /// set.
+    #[serde(default = "default::storage::shared_buffer_spill_enabled")]
+    pub shared_buffer_spill_enabled: bool,
+
     /// Capacity of sstable meta cache.
     #[serde(default = "default::storage::compactor_memory_limit_mb")]
     pub compactor_memory_limit_mb: usize,
@@ -353,6 +389,89 @@ pub struct StorageConfig {
 
     #[serde(default = "default::storage::max_concurrent_compaction_task_number")]
     pub max_concurrent_compaction_task_number: u64,
+
+    /// Ratio of compaction tasks whose results are verified against their inputs before being
+    /// reported as successful. 0.0 disables verification and 1.0 verifies every task.
+    #[serde(default = "default::storage::compaction_result_verification_ratio")]
+    pub compaction_result_verification_ratio: f64,
+
+    /// Ratio of state store reads that are verified against a shadow state store. 0.0 disables
+    /// verification and 1.0 verifies every read. Writes are always mirrored into the shadow
+    /// store while verification is enabled. Only effective in debug builds.
+    #[serde(default = "default::storage::state_store_verify_sample_ratio")]
+    pub state_store_verify_sample_ratio: f64,
+
+    /// In-memory cache capacity of the shadow state store used for verification.
+    #[serde(default = "default::storage::state_store_verify_cache_capacity_mb")]
+    pub state_store_verify_cache_capacity_mb: usize,
+
+    /// Run the state store in read-only replica mode: local writes, epoch syncs and sealing are
+    /// rejected, while `get` and `iter` are served from pinned versions. Intended for
+    /// serving-only compute nodes that scale out batch reads on materialized views.
+    #[serde(default = "default::storage::read_only")]
+    pub read_only: bool,
+
+    /// Compression algorithm for blocks of newly written SSTs: "none", "lz4" or "zstd".
+    #[serde(default = "default::storage::sstable_compression_algorithm")]
+    pub sstable_compression_algorithm: String,
+
+    /// Compression level of the chosen compression algorithm.
+    #[serde(default = "default::storage::sstable_compression_level")]
+    pub sstable_compression_level: u32,
+
+    /// Number of entries between restart points inside an SST block. Keys between two restart
+    /// points are delta-encoded against their predecessor.
+    #[serde(default = "default::storage::block_restart_interval")]
+    pub block_restart_interval: usize,
+
+    /// Number of attempts for a remote object store operation before the error is returned to
+    /// the caller. Only transient errors are retried. `1` disables retrying.
+    #[serde(default = "default::storage::object_store_retry_attempts")]
+    pub object_store_retry_attempts: usize,
+
+    /// The backoff before the first retry of a remote object store operation in milliseconds.
+    /// The backoff doubles after each failed attempt, capped at
+    /// `object_store_retry_max_backoff_ms`.
+    #[serde(default = "default::storage::object_store_retry_initial_backoff_ms")]
+    pub object_store_retry_initial_backoff_ms: u64,
+
+    /// The maximum backoff between two attempts of a remote object store operation in
+    /// milliseconds.
+    #[serde(default = "default::storage::object_store_retry_max_backoff_ms")]
+    pub object_store_retry_max_backoff_ms: u64,
+
+    /// Admission policy of the block cache: "lru" admits every fetched block, "tinylfu" only
+    /// admits blocks that have been accessed more than once recently, so that one-off scans do
+    /// not wash hot blocks out of the cache.
+    #[serde(default = "default::storage::block_cache_policy")]
+    pub block_cache_policy: String,
+
+    /// Bandwidth quota for object store requests issued by compaction, in MB/s. `0` disables the
+    /// quota.
+    #[serde(default = "default::storage::object_store_compaction_bandwidth_mb_per_sec")]
+    pub object_store_compaction_bandwidth_mb_per_sec: u64,
+
+    /// Bandwidth quota for object store writes flushing the shared buffer, in MB/s. `0` disables
+    /// the quota.
+    #[serde(default = "default::storage::object_store_flush_bandwidth_mb_per_sec")]
+    pub object_store_flush_bandwidth_mb_per_sec: u64,
+
+    /// Bandwidth quota for object store reads serving `get` and `iter`, in MB/s. `0` disables the
+    /// quota.
+    #[serde(default = "default::storage::object_store_read_bandwidth_mb_per_sec")]
+    pub object_store_read_bandwidth_mb_per_sec: u64,
+
+    /// Bandwidth quota shared by all object store writes, i.e. checkpoint uploads and compaction
+    /// output, in MB/s. Checkpoint uploads take priority over compaction output when the quota is
+    /// exhausted. `0` disables the quota.
+    #[serde(default = "default::storage::object_store_write_bandwidth_mb_per_sec")]
+    pub object_store_write_bandwidth_mb_per_sec: u64,
+
+    /// Path to a local file holding the AES-256-GCM keys used to encrypt SST blocks at rest, one
+    /// hex-encoded 32-byte key per line. Newly written SSTs are encrypted with the key on the
+    /// last line, so appending a line rotates the key. Empty disables encryption.
+    #[serde(default = "default::storage::sstable_encryption_key_file")]
+    pub sstable_encryption_key_file: String,
 }
 
 impl Default for StorageConfig {
@@ -410,6 +529,11 @@ pub struct DeveloperConfig {
     #[serde(default = "default::developer::batch_output_channel_size")]
     pub batch_output_channel_size: usize,
 
+    /// Set to true to spill the output of a single-distribution exchange to local disk when it
+    /// exceeds `batch_output_channel_size` chunks, instead of blocking the producer.
+    #[serde(default = "default::developer::batch_exchange_spill_enabled")]
+    pub batch_exchange_spill_enabled: bool,
+
     /// The size of a chunk produced by `RowSeqScanExecutor`
     #[serde(default = "default::developer::batch_chunk_size")]
     pub batch_chunk_size: usize,
@@ -515,6 +639,10 @@ mod default {
         pub fn periodic_ttl_reclaim_compaction_interval_sec() -> u64 {
             1800 // 30mi
         }
+
+        pub fn periodic_tombstone_reclaim_compaction_interval_sec() -> u64 {
+            600 // 10min
+        }
     }
 
     pub mod server {
@@ -574,6 +702,10 @@ mod default {
             512
         }
 
+        pub fn block_cache_per_table_capacity_mb() -> usize {
+            0
+        }
+
         pub fn meta_cache_capacity_mb() -> usize {
             128
         }
@@ -594,6 +726,18 @@ mod default {
             8
         }
 
+        pub fn share_buffer_upload_per_table_concurrency() -> usize {
+            2
+        }
+
+        pub fn share_buffer_upload_task_size_mb() -> usize {
+            64
+        }
+
+        pub fn shared_buffer_spill_enabled() -> bool {
+            false
+        }
+
         pub fn compactor_memory_limit_mb() -> usize {
             512
         }
@@ -614,6 +758,70 @@ mod default {
         pub fn max_concurrent_compaction_task_number() -> u64 {
             16
         }
+
+        pub fn compaction_result_verification_ratio() -> f64 {
+            0.0
+        }
+
+        pub fn state_store_verify_sample_ratio() -> f64 {
+            0.0
+        }
+
+        pub fn state_store_verify_cache_capacity_mb() -> usize {
+            64
+        }
+
+        pub fn read_only() -> bool {
+            false
+        }
+
+        pub fn sstable_compression_algorithm() -> String {
+            "none".to_string()
+        }
+
+        pub fn sstable_compression_level() -> u32 {
+            4
+        }
+
+        pub fn block_restart_interval() -> usize {
+            16
+        }
+
+        pub fn object_store_retry_attempts() -> usize {
+            3
+        }
+
+        pub fn object_store_retry_initial_backoff_ms() -> u64 {
+            100
+        }
+
+        pub fn object_store_retry_max_backoff_ms() -> u64 {
+            10000
+        }
+
+        pub fn block_cache_policy() -> String {
+            "lru".to_string()
+        }
+
+        pub fn object_store_compaction_bandwidth_mb_per_sec() -> u64 {
+            0
+        }
+
+        pub fn object_store_flush_bandwidth_mb_per_sec() -> u64 {
+            0
+        }
+
+        pub fn object_store_read_bandwidth_mb_per_sec() -> u64 {
+            0
+        }
+
+        pub fn object_store_write_bandwidth_mb_per_sec() -> u64 {
+            0
+        }
+
+        pub fn sstable_encryption_key_file() -> String {
+            "".to_string()
+        }
     }
 
     pub mod streaming {
@@ -679,6 +887,10 @@ mod default {
             64
         }
 
+        pub fn batch_exchange_spill_enabled() -> bool {
+            false
+        }
+
         pub fn batch_chunk_size() -> usize {
             1024
         }
@@ -66,3 +66,11 @@ pub mod test_prelude {
 pub const RW_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 pub const GIT_SHA: &str = option_env!("GIT_SHA").unwrap_or("unknown");
+
+/// The PostgreSQL version RisingWave claims to be compatible with, as reported by `version()`,
+/// `SHOW server_version` and the `server_version` startup parameter. pg_dump and migration tools
+/// parse it to decide which catalog queries to issue.
+pub const PG_VERSION: &str = "13.9";
+
+/// The numeric form of [`PG_VERSION`], as reported by `SHOW server_version_num`.
+pub const PG_VERSION_NUM: i32 = 130009;
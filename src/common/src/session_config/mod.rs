@@ -32,7 +32,7 @@ use crate::util::epoch::Epoch;
 
 // This is a hack, &'static str is not allowed as a const generics argument.
 // TODO: refine this using the adt_const_params feature.
-const CONFIG_KEYS: [&str; 19] = [
+const CONFIG_KEYS: [&str; 23] = [
     "RW_IMPLICIT_FLUSH",
     "CREATE_COMPACTION_GROUP_FOR_MV",
     "QUERY_MODE",
@@ -52,6 +52,10 @@ const CONFIG_KEYS: [&str; 19] = [
     "RW_ENABLE_TWO_PHASE_AGG",
     "RW_FORCE_TWO_PHASE_AGG",
     "RW_ENABLE_SHARE_PLAN",
+    "RW_FORCE_SPLIT_DISTINCT_AGG",
+    "RW_AUTO_TS_INDEX",
+    "SERVER_VERSION",
+    "SERVER_VERSION_NUM",
 ];
 
 // MUST HAVE 1v1 relationship to CONFIG_KEYS. e.g. CONFIG_KEYS[IMPLICIT_FLUSH] =
@@ -75,6 +79,23 @@ const STREAMING_ENABLE_DELTA_JOIN: usize = 15;
 const ENABLE_TWO_PHASE_AGG: usize = 16;
 const FORCE_TWO_PHASE_AGG: usize = 17;
 const RW_ENABLE_SHARE_PLAN: usize = 18;
+const FORCE_SPLIT_DISTINCT_AGG: usize = 19;
+const AUTO_TS_INDEX: usize = 20;
+const SERVER_VERSION: usize = 21;
+const SERVER_VERSION_NUM: usize = 22;
+
+/// Parameters that pg_dump and migration tools set unconditionally on connecting, but that have
+/// no meaning in RisingWave. `SET`s of them are accepted and ignored so that those tools can run.
+const IGNORED_CONFIG_KEYS: [&str; 8] = [
+    "STATEMENT_TIMEOUT",
+    "LOCK_TIMEOUT",
+    "IDLE_IN_TRANSACTION_SESSION_TIMEOUT",
+    "ROW_SECURITY",
+    "SYNCHRONIZE_SEQSCANS",
+    "CLIENT_ENCODING",
+    "CLIENT_MIN_MESSAGES",
+    "STANDARD_CONFORMING_STRINGS",
+];
 
 trait ConfigEntry: Default + for<'a> TryFrom<&'a [&'a str], Error = RwError> {
     fn entry_name() -> &'static str;
@@ -274,6 +295,10 @@ type StreamingEnableDeltaJoin = ConfigBool<STREAMING_ENABLE_DELTA_JOIN, false>;
 type EnableTwoPhaseAgg = ConfigBool<ENABLE_TWO_PHASE_AGG, true>;
 type ForceTwoPhaseAgg = ConfigBool<FORCE_TWO_PHASE_AGG, false>;
 type EnableSharePlan = ConfigBool<RW_ENABLE_SHARE_PLAN, true>;
+type ForceSplitDistinctAgg = ConfigBool<FORCE_SPLIT_DISTINCT_AGG, false>;
+type AutoTsIndex = ConfigBool<AUTO_TS_INDEX, false>;
+type ServerVersion = ConfigString<SERVER_VERSION>;
+type ServerVersionNum = ConfigI32<SERVER_VERSION_NUM, { crate::PG_VERSION_NUM }>;
 
 #[derive(Derivative)]
 #[derivative(Default)]
@@ -346,6 +371,26 @@ pub struct ConfigMap {
     /// This means that DAG structured query plans can be constructed,
     /// rather than only tree structured query plans.
     enable_share_plan: EnableSharePlan,
+
+    /// Force splitting distinct aggregations into a two-phase plan that shards the distinct keys
+    /// across vnodes, even for streaming queries with group keys, where it's disabled by default
+    /// for performance reasons. Useful when the cardinality of the distinct keys is very high.
+    force_split_distinct_agg: ForceSplitDistinctAgg,
+
+    /// Automatically create an index on the first timestamp column of an append-only table when
+    /// it's created, so that range filters on that column can be served by an index range scan
+    /// plus point lookups instead of a full table scan.
+    auto_ts_index: AutoTsIndex,
+
+    /// The PostgreSQL version RisingWave claims to be compatible with. Read-only; pg_dump and
+    /// migration tools read it to decide which catalog queries to issue.
+    #[derivative(Default(
+        value = "ConfigString::<SERVER_VERSION>(String::from(crate::PG_VERSION))"
+    ))]
+    server_version: ServerVersion,
+
+    /// The numeric form of `SERVER_VERSION`. Read-only.
+    server_version_num: ServerVersionNum,
 }
 
 impl ConfigMap {
@@ -399,6 +444,23 @@ impl ConfigMap {
             }
         } else if key.eq_ignore_ascii_case(EnableSharePlan::entry_name()) {
             self.enable_share_plan = val.as_slice().try_into()?;
+        } else if key.eq_ignore_ascii_case(ForceSplitDistinctAgg::entry_name()) {
+            self.force_split_distinct_agg = val.as_slice().try_into()?;
+        } else if key.eq_ignore_ascii_case(AutoTsIndex::entry_name()) {
+            self.auto_ts_index = val.as_slice().try_into()?;
+        } else if key.eq_ignore_ascii_case(ServerVersion::entry_name())
+            || key.eq_ignore_ascii_case(ServerVersionNum::entry_name())
+        {
+            return Err(ErrorCode::InternalError(format!(
+                "parameter \"{}\" cannot be changed",
+                key.to_lowercase()
+            ))
+            .into());
+        } else if IGNORED_CONFIG_KEYS
+            .iter()
+            .any(|ignored| key.eq_ignore_ascii_case(ignored))
+        {
+            // Accepted and ignored for PostgreSQL tool compatibility.
         } else {
             return Err(ErrorCode::UnrecognizedConfigurationParameter(key.to_string()).into());
         }
@@ -445,6 +507,14 @@ impl ConfigMap {
             Ok(self.force_two_phase_agg.to_string())
         } else if key.eq_ignore_ascii_case(EnableSharePlan::entry_name()) {
             Ok(self.enable_share_plan.to_string())
+        } else if key.eq_ignore_ascii_case(ForceSplitDistinctAgg::entry_name()) {
+            Ok(self.force_split_distinct_agg.to_string())
+        } else if key.eq_ignore_ascii_case(AutoTsIndex::entry_name()) {
+            Ok(self.auto_ts_index.to_string())
+        } else if key.eq_ignore_ascii_case(ServerVersion::entry_name()) {
+            Ok(self.server_version.to_string())
+        } else if key.eq_ignore_ascii_case(ServerVersionNum::entry_name()) {
+            Ok(self.server_version_num.to_string())
         } else {
             Err(ErrorCode::UnrecognizedConfigurationParameter(key.to_string()).into())
         }
@@ -542,6 +612,26 @@ impl ConfigMap {
                 setting : self.enable_share_plan.to_string(),
                 description: String::from("Enable sharing of common sub-plans. This means that DAG structured query plans can be constructed, rather than only tree structured query plans.")
             },
+            VariableInfo{
+                name : ForceSplitDistinctAgg::entry_name().to_lowercase(),
+                setting : self.force_split_distinct_agg.to_string(),
+                description: String::from("Force splitting distinct aggregations into a two-phase plan that shards the distinct keys across vnodes, even for streaming queries with group keys.")
+            },
+            VariableInfo{
+                name : AutoTsIndex::entry_name().to_lowercase(),
+                setting : self.auto_ts_index.to_string(),
+                description: String::from("Automatically create an index on the first timestamp column of an append-only table when it's created.")
+            },
+            VariableInfo{
+                name : ServerVersion::entry_name().to_lowercase(),
+                setting : self.server_version.to_string(),
+                description: String::from("The PostgreSQL version RisingWave claims to be compatible with. Read-only.")
+            },
+            VariableInfo{
+                name : ServerVersionNum::entry_name().to_lowercase(),
+                setting : self.server_version_num.to_string(),
+                description: String::from("The numeric form of `server_version`. Read-only.")
+            },
         ]
     }
 
@@ -626,4 +716,12 @@ impl ConfigMap {
     pub fn get_enable_share_plan(&self) -> bool {
         *self.enable_share_plan
     }
+
+    pub fn get_force_split_distinct_agg(&self) -> bool {
+        *self.force_split_distinct_agg
+    }
+
+    pub fn get_auto_ts_index(&self) -> bool {
+        *self.auto_ts_index
+    }
 }
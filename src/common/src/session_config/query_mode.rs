@@ -22,7 +22,11 @@ use crate::error::RwError;
 
 #[derive(Copy, Default, Debug, Clone, PartialEq, Eq)]
 pub enum QueryMode {
+    /// Choose between local and distributed mode automatically: queries whose cost is bounded,
+    /// e.g. point gets, run in local mode, while the rest run in distributed mode.
     #[default]
+    Auto,
+
     Local,
 
     Distributed,
@@ -47,7 +51,9 @@ impl TryFrom<&[&str]> for QueryMode {
         }
 
         let s = value[0];
-        if s.eq_ignore_ascii_case("local") {
+        if s.eq_ignore_ascii_case("auto") {
+            Ok(Self::Auto)
+        } else if s.eq_ignore_ascii_case("local") {
             Ok(Self::Local)
         } else if s.eq_ignore_ascii_case("distributed") {
             Ok(Self::Distributed)
@@ -63,6 +69,7 @@ impl TryFrom<&[&str]> for QueryMode {
 impl std::fmt::Display for QueryMode {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::Auto => write!(f, "auto"),
             Self::Local => write!(f, "local"),
             Self::Distributed => write!(f, "distributed"),
         }
@@ -75,6 +82,14 @@ mod tests {
 
     #[test]
     fn parse_query_mode() {
+        assert_eq!(
+            QueryMode::try_from(["auto"].as_slice()).unwrap(),
+            QueryMode::Auto
+        );
+        assert_eq!(
+            QueryMode::try_from(["Auto"].as_slice()).unwrap(),
+            QueryMode::Auto
+        );
         assert_eq!(
             QueryMode::try_from(["local"].as_slice()).unwrap(),
             QueryMode::Local
@@ -17,7 +17,7 @@ use chrono::{TimeZone, Utc};
 use postgres_types::{ToSql, Type};
 
 use super::{DataType, DatumRef, ScalarRefImpl};
-use crate::error::Result;
+use crate::error::{ErrorCode, Result};
 // Used to convert ScalarRef to text format
 pub trait ToBinary {
     fn to_binary_with_type(&self, ty: &DataType) -> Result<Option<Bytes>>;
@@ -94,8 +94,11 @@ impl ToBinary for ScalarRefImpl<'_> {
             ScalarRefImpl::NaiveTime(v) => v.to_binary_with_type(ty),
             ScalarRefImpl::Bytea(v) => v.to_binary_with_type(ty),
             ScalarRefImpl::Jsonb(v) => v.to_binary_with_type(ty),
-            ScalarRefImpl::Struct(_) => todo!(),
-            ScalarRefImpl::List(_) => todo!(),
+            ScalarRefImpl::Struct(_) | ScalarRefImpl::List(_) => Err(ErrorCode::NotImplemented(
+                format!("binary format for {} is not supported", ty),
+                None.into(),
+            )
+            .into()),
         }
     }
 }
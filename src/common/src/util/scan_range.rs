@@ -68,6 +68,10 @@ impl ScanRange {
         self.eq_conds.is_empty() && self.range == full_range()
     }
 
+    pub fn has_eq_conds(&self) -> bool {
+        !self.eq_conds.is_empty()
+    }
+
     pub const fn full_table_scan() -> Self {
         Self {
             eq_conds: vec![],
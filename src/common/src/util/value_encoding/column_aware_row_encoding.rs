@@ -20,6 +20,7 @@
 //! until schema changes
 
 use std::collections::BTreeMap;
+use std::sync::Arc;
 
 use bitflags::bitflags;
 
@@ -107,6 +108,7 @@ impl RowEncoding {
 
 /// Column-Aware `Serializer` holds schema related information, and shall be
 /// created again once the schema changes
+#[derive(Clone)]
 pub struct Serializer {
     encoded_column_ids: Vec<u8>,
     datum_num: u32,
@@ -149,15 +151,22 @@ impl Serializer {
     }
 }
 
+impl ValueRowSerializer for Serializer {
+    fn serialize(&self, row: impl Row) -> Vec<u8> {
+        self.serialize_row_column_aware(row)
+    }
+}
+
 /// Column-Aware `Deserializer` holds needed `ColumnIds` and their corresponding schema
 /// Should non-null default values be specified, a new field could be added to Deserializer
-pub struct Deserializer<'a> {
+#[derive(Clone)]
+pub struct Deserializer {
     needed_column_ids: BTreeMap<i32, usize>,
-    schema: &'a [DataType],
+    schema: Arc<[DataType]>,
 }
 
-impl<'a> Deserializer<'a> {
-    pub fn new(column_ids: &'a [ColumnId], schema: &'a [DataType]) -> Self {
+impl Deserializer {
+    pub fn new(column_ids: &[ColumnId], schema: Arc<[DataType]>) -> Self {
         assert_eq!(column_ids.len(), schema.len());
         Self {
             needed_column_ids: column_ids
@@ -169,6 +178,38 @@ impl<'a> Deserializer<'a> {
         }
     }
 
+    /// Create a `Deserializer` that only decodes the columns at `projection`. The decoded row
+    /// is still of the full width of `schema`, with the unneeded columns left as `None`, so
+    /// that decoding can be skipped for the columns not accessed by the reader.
+    pub fn with_projection(
+        column_ids: &[ColumnId],
+        schema: Arc<[DataType]>,
+        projection: &[usize],
+    ) -> Self {
+        assert_eq!(column_ids.len(), schema.len());
+        Self {
+            needed_column_ids: projection
+                .iter()
+                .map(|&i| (column_ids[i].get_id(), i))
+                .collect::<BTreeMap<_, _>>(),
+            schema,
+        }
+    }
+
+    /// Create a `Deserializer` that only decodes the columns at `projection`, out of the columns
+    /// this deserializer decodes.
+    pub fn project(&self, projection: &[usize]) -> Self {
+        Self {
+            needed_column_ids: self
+                .needed_column_ids
+                .iter()
+                .filter(|(_, idx)| projection.contains(idx))
+                .map(|(&id, &idx)| (id, idx))
+                .collect::<BTreeMap<_, _>>(),
+            schema: self.schema.clone(),
+        }
+    }
+
     pub fn decode(&self, mut encoded_bytes: &[u8]) -> Result<Vec<Datum>> {
         let flag = Flag::from_bits(encoded_bytes.get_u8()).expect("should be a valid flag");
         let offset_bytes = match flag - Flag::EMPTY {
@@ -219,6 +260,12 @@ impl<'a> Deserializer<'a> {
     }
 }
 
+impl ValueRowDeserializer for Deserializer {
+    fn deserialize(&self, encoded_bytes: &[u8]) -> Result<Vec<Datum>> {
+        self.decode(encoded_bytes)
+    }
+}
+
 fn deserialize_width(len: usize, data: &mut impl Buf) -> usize {
     match len {
         1 => data.get_u8() as usize,
@@ -291,11 +338,32 @@ mod tests {
         let row_bytes = serializer.serialize_row_column_aware(row1);
         let data_types = vec![DataType::Int16, DataType::Varchar];
         let deserializer =
-            column_aware_row_encoding::Deserializer::new(&column_ids[..], &data_types[..]);
+            column_aware_row_encoding::Deserializer::new(&column_ids[..], data_types.into());
         let decoded = deserializer.decode(&row_bytes[..]);
         assert_eq!(
             decoded.unwrap(),
             vec![Some(Int16(5)), Some(Utf8("abc".into()))]
         );
     }
+
+    #[test]
+    fn test_row_decoding_with_projection() {
+        let column_ids = vec![ColumnId::new(0), ColumnId::new(1), ColumnId::new(2)];
+        let row = OwnedRow::new(vec![
+            Some(Int16(5)),
+            Some(Utf8("abc".into())),
+            Some(Int16(6)),
+        ]);
+        let serializer = column_aware_row_encoding::Serializer::new(&column_ids);
+        let row_bytes = serializer.serialize_row_column_aware(row);
+        let data_types = vec![DataType::Int16, DataType::Varchar, DataType::Int16];
+        let deserializer = column_aware_row_encoding::Deserializer::with_projection(
+            &column_ids[..],
+            data_types.into(),
+            &[0, 2],
+        );
+        // The decoded row is of the full width, but only the projected columns are decoded.
+        let decoded = deserializer.decode(&row_bytes[..]);
+        assert_eq!(decoded.unwrap(), vec![Some(Int16(5)), None, Some(Int16(6))]);
+    }
 }
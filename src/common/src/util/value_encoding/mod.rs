@@ -20,6 +20,7 @@ use chrono::{Datelike, Timelike};
 use itertools::Itertools;
 
 use crate::array::{JsonbVal, ListRef, ListValue, StructRef, StructValue};
+use crate::row::{Row, RowDeserializer};
 use crate::types::struct_type::StructType;
 use crate::types::{
     DataType, Datum, Decimal, IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper,
@@ -32,6 +33,68 @@ pub mod column_aware_row_encoding;
 
 pub type Result<T> = std::result::Result<T, ValueEncodingError>;
 
+/// `ValueRowSerializer` serializes a row of datums into value bytes.
+pub trait ValueRowSerializer: Clone {
+    fn serialize(&self, row: impl Row) -> Vec<u8>;
+}
+
+/// `ValueRowDeserializer` deserializes value bytes into a row of datums.
+pub trait ValueRowDeserializer: Clone {
+    fn deserialize(&self, encoded_bytes: &[u8]) -> Result<Vec<Datum>>;
+}
+
+/// `ValueRowSerde` provides the ability to encode and decode rows to and from value bytes.
+pub trait ValueRowSerde: ValueRowSerializer + ValueRowDeserializer + Send + Sync + 'static {}
+
+impl<T: ValueRowSerializer + ValueRowDeserializer + Send + Sync + 'static> ValueRowSerde for T {}
+
+/// Serializer of the basic value encoding, which simply concatenates the value-encoded datums of
+/// the row.
+#[derive(Clone, Copy, Default)]
+pub struct BasicSerializer;
+
+impl ValueRowSerializer for BasicSerializer {
+    fn serialize(&self, row: impl Row) -> Vec<u8> {
+        row.value_serialize()
+    }
+}
+
+pub type BasicDeserializer = RowDeserializer;
+
+impl ValueRowDeserializer for BasicDeserializer {
+    fn deserialize(&self, encoded_bytes: &[u8]) -> Result<Vec<Datum>> {
+        Ok(self.deserialize(encoded_bytes)?.into_inner())
+    }
+}
+
+/// Serde of the basic value encoding.
+#[derive(Clone)]
+pub struct BasicSerde {
+    pub serializer: BasicSerializer,
+    pub deserializer: BasicDeserializer,
+}
+
+impl BasicSerde {
+    pub fn new(data_types: Vec<DataType>) -> Self {
+        Self {
+            serializer: BasicSerializer,
+            deserializer: BasicDeserializer::new(data_types),
+        }
+    }
+}
+
+impl ValueRowSerializer for BasicSerde {
+    fn serialize(&self, row: impl Row) -> Vec<u8> {
+        self.serializer.serialize(row)
+    }
+}
+
+impl ValueRowDeserializer for BasicSerde {
+    fn deserialize(&self, encoded_bytes: &[u8]) -> Result<Vec<Datum>> {
+        self.deserializer.deserialize(encoded_bytes)
+    }
+}
+
 /// Serialize a datum into bytes and return (Not order guarantee, used in value encoding).
 pub fn serialize_datum(cell: impl ToDatumRef) -> Vec<u8> {
     let mut buf: Vec<u8> = vec![];
@@ -13,11 +13,16 @@
 // limitations under the License.
 
 use prometheus::core::{AtomicU64, GenericCounterVec};
-use prometheus::{register_int_counter_vec_with_registry, Registry};
+use prometheus::{
+    register_int_counter_vec_with_registry, register_int_gauge_vec_with_registry, IntGaugeVec,
+    Registry,
+};
 
 pub struct ExchangeServiceMetrics {
     pub registry: Registry,
     pub stream_fragment_exchange_bytes: GenericCounterVec<AtomicU64>,
+    pub stream_exchange_channel_permits: IntGaugeVec,
+    pub stream_exchange_channel_in_flight_permits: IntGaugeVec,
     pub actor_sampled_serialize_duration_ns: GenericCounterVec<AtomicU64>,
 }
 
@@ -31,6 +36,23 @@ impl ExchangeServiceMetrics {
         )
         .unwrap();
 
+        let stream_exchange_channel_permits = register_int_gauge_vec_with_registry!(
+            "stream_exchange_channel_permits",
+            "Credits (permits) currently available to the upstream actor of the channel",
+            &["up_actor_id", "down_actor_id"],
+            registry
+        )
+        .unwrap();
+
+        let stream_exchange_channel_in_flight_permits = register_int_gauge_vec_with_registry!(
+            "stream_exchange_channel_in_flight_permits",
+            "Credits (permits) sent to the downstream actor but not yet granted back, i.e. the \
+             backlog of the channel",
+            &["up_actor_id", "down_actor_id"],
+            registry
+        )
+        .unwrap();
+
         let actor_sampled_serialize_duration_ns = register_int_counter_vec_with_registry!(
             "actor_sampled_serialize_duration_ns",
             "Duration (ns) of sampled chunk serialization",
@@ -42,6 +64,8 @@ impl ExchangeServiceMetrics {
         Self {
             registry,
             stream_fragment_exchange_bytes,
+            stream_exchange_channel_permits,
+            stream_exchange_channel_in_flight_permits,
             actor_sampled_serialize_duration_ns,
         }
     }
@@ -140,11 +140,19 @@ impl ExchangeServiceImpl {
     ) {
         tracing::trace!(target: "events::compute::exchange", peer_addr = %peer_addr, "serve stream exchange RPC");
         let up_actor_id = up_down_actor_ids.0.to_string();
+        let down_actor_id = up_down_actor_ids.1.to_string();
         let up_fragment_id = up_down_fragment_ids.0.to_string();
         let down_fragment_id = up_down_fragment_ids.1.to_string();
 
         let permits = receiver.permits();
 
+        let channel_permits = metrics
+            .stream_exchange_channel_permits
+            .with_label_values(&[&up_actor_id, &down_actor_id]);
+        let in_flight_permits = metrics
+            .stream_exchange_channel_in_flight_permits
+            .with_label_values(&[&up_actor_id, &down_actor_id]);
+
         // Select from the permits back from the downstream and the upstream receiver.
         let select_stream = futures::stream::select(
             add_permits_stream.map_ok(Either::Left),
@@ -164,6 +172,8 @@ impl ExchangeServiceImpl {
             match r {
                 Either::Left(permits_to_add) => {
                     permits.add_permits(permits_to_add as usize);
+                    in_flight_permits.sub(permits_to_add as i64);
+                    channel_permits.set(permits.available_permits() as i64);
                 }
                 Either::Right(MessageWithPermits { message, permits }) => {
                     // add serialization duration metric with given sampling frequency
@@ -185,6 +195,8 @@ impl ExchangeServiceImpl {
                         permits, // forward the acquired permit to the downstream
                     };
                     let bytes = Message::get_encoded_len(&response);
+                    in_flight_permits.add(response.permits as i64);
+                    channel_permits.set(permits.available_permits() as i64);
 
                     yield response;
 
@@ -195,5 +207,13 @@ impl ExchangeServiceImpl {
                 }
             }
         }
+
+        // The channel is closed, clean up the per-channel gauges.
+        let _ = metrics
+            .stream_exchange_channel_permits
+            .remove_label_values(&[&up_actor_id, &down_actor_id]);
+        let _ = metrics
+            .stream_exchange_channel_in_flight_permits
+            .remove_label_values(&[&up_actor_id, &down_actor_id]);
     }
 }
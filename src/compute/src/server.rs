@@ -199,6 +199,17 @@ pub async fn compute_node_serve(
             memory_limiter,
         ));
         monitor_cache(memory_collector, &registry).unwrap();
+
+        if let Some(hummock) = storage.as_hummock() {
+            // Warm up the caches with the pinned version in the background, so that the
+            // recovered actors are less likely to be served entirely from cold caches.
+            let hummock = hummock.clone();
+            tokio::spawn(async move {
+                let version = hummock.get_pinned_version();
+                let table_ids = version.table_ids().collect::<Vec<_>>();
+                hummock.warm_up(&table_ids).await;
+            });
+        }
     }
 
     sub_tasks.push(MetaClient::start_heartbeat_loop(
@@ -0,0 +1,697 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Payload encoders for sinks.
+//!
+//! An encoder turns a single row into one payload, independently of the transport that delivers
+//! it, so that any sink can select its payload format via `WITH` options instead of hard-coding
+//! one format per connector. Null handling and timestamp rendering are configurable as well, and
+//! encoding a projection of the columns (e.g. the primary key columns) yields the message key of
+//! a key/value transport.
+
+use std::collections::HashMap;
+
+use anyhow::anyhow;
+use prost::Message;
+use prost_reflect::{DescriptorPool, DynamicMessage, Kind, MessageDescriptor};
+use risingwave_common::array::{ArrayError, ArrayResult, RowRef};
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::row::Row;
+use risingwave_common::types::to_text::ToText;
+use risingwave_common::types::{DataType, DatumRef, ScalarRefImpl};
+use risingwave_common::util::iter_util::ZipEqFast;
+use serde_json::{json, Map, Value};
+
+use crate::sink::{Result, SinkError};
+
+pub const SINK_PAYLOAD_FORMAT_OPTION: &str = "payload.format";
+pub const SINK_PAYLOAD_FORMAT_JSON: &str = "json";
+pub const SINK_PAYLOAD_FORMAT_CSV: &str = "csv";
+pub const SINK_PAYLOAD_FORMAT_AVRO: &str = "avro";
+pub const SINK_PAYLOAD_FORMAT_PROTOBUF: &str = "protobuf";
+
+pub const SINK_PAYLOAD_NULL_OPTION: &str = "payload.null";
+pub const SINK_PAYLOAD_NULL_LITERAL: &str = "literal";
+pub const SINK_PAYLOAD_NULL_OMIT: &str = "omit";
+
+pub const SINK_PAYLOAD_TIMESTAMP_FORMAT_OPTION: &str = "payload.timestamp.format";
+pub const SINK_PAYLOAD_TIMESTAMP_FORMAT_STRING: &str = "string";
+pub const SINK_PAYLOAD_TIMESTAMP_FORMAT_EPOCH_MS: &str = "epoch_ms";
+
+pub const SINK_PAYLOAD_CSV_DELIMITER_OPTION: &str = "payload.csv.delimiter";
+pub const SINK_PAYLOAD_PROTOBUF_MESSAGE_OPTION: &str = "payload.protobuf.message";
+pub const SINK_PAYLOAD_PROTOBUF_SCHEMA_LOCATION_OPTION: &str = "payload.protobuf.schema.location";
+
+/// The payload format of a sink, selected by the `payload.format` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayloadFormat {
+    Json,
+    Csv,
+    Avro,
+    Protobuf,
+}
+
+/// How an encoder renders `NULL` values: as an explicit null of the format, or by omitting the
+/// field from the payload entirely (where the format allows it).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NullHandling {
+    Literal,
+    Omit,
+}
+
+/// How an encoder renders timestamp values: as a human-readable string, or as milliseconds since
+/// the UNIX epoch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampFormat {
+    String,
+    EpochMillis,
+}
+
+/// Encoder options shared by all sinks, parsed from the `WITH` options of `CREATE SINK`.
+#[derive(Debug, Clone)]
+pub struct PayloadFormatConfig {
+    pub format: PayloadFormat,
+    pub null_handling: NullHandling,
+    pub timestamp_format: TimestampFormat,
+    pub csv_delimiter: u8,
+    pub protobuf_message: Option<String>,
+    pub protobuf_schema_location: Option<String>,
+}
+
+impl Default for PayloadFormatConfig {
+    fn default() -> Self {
+        Self {
+            format: PayloadFormat::Json,
+            null_handling: NullHandling::Literal,
+            timestamp_format: TimestampFormat::String,
+            csv_delimiter: b',',
+            protobuf_message: None,
+            protobuf_schema_location: None,
+        }
+    }
+}
+
+impl PayloadFormatConfig {
+    pub fn from_options(options: &HashMap<String, String>) -> Result<Self> {
+        let format = match options.get(SINK_PAYLOAD_FORMAT_OPTION).map(|s| s.as_str()) {
+            None | Some(SINK_PAYLOAD_FORMAT_JSON) => PayloadFormat::Json,
+            Some(SINK_PAYLOAD_FORMAT_CSV) => PayloadFormat::Csv,
+            Some(SINK_PAYLOAD_FORMAT_AVRO) => PayloadFormat::Avro,
+            Some(SINK_PAYLOAD_FORMAT_PROTOBUF) => PayloadFormat::Protobuf,
+            Some(other) => {
+                return Err(SinkError::Config(anyhow!(
+                    "{} must be json, csv, avro, or protobuf, got {}",
+                    SINK_PAYLOAD_FORMAT_OPTION,
+                    other
+                )))
+            }
+        };
+        let null_handling = match options.get(SINK_PAYLOAD_NULL_OPTION).map(|s| s.as_str()) {
+            None | Some(SINK_PAYLOAD_NULL_LITERAL) => NullHandling::Literal,
+            Some(SINK_PAYLOAD_NULL_OMIT) => NullHandling::Omit,
+            Some(other) => {
+                return Err(SinkError::Config(anyhow!(
+                    "{} must be literal or omit, got {}",
+                    SINK_PAYLOAD_NULL_OPTION,
+                    other
+                )))
+            }
+        };
+        let timestamp_format = match options
+            .get(SINK_PAYLOAD_TIMESTAMP_FORMAT_OPTION)
+            .map(|s| s.as_str())
+        {
+            None | Some(SINK_PAYLOAD_TIMESTAMP_FORMAT_STRING) => TimestampFormat::String,
+            Some(SINK_PAYLOAD_TIMESTAMP_FORMAT_EPOCH_MS) => TimestampFormat::EpochMillis,
+            Some(other) => {
+                return Err(SinkError::Config(anyhow!(
+                    "{} must be string or epoch_ms, got {}",
+                    SINK_PAYLOAD_TIMESTAMP_FORMAT_OPTION,
+                    other
+                )))
+            }
+        };
+        let csv_delimiter = match options
+            .get(SINK_PAYLOAD_CSV_DELIMITER_OPTION)
+            .map(|s| s.as_str())
+        {
+            None => b',',
+            Some(s) if s.len() == 1 && s.is_ascii() => s.as_bytes()[0],
+            Some(other) => {
+                return Err(SinkError::Config(anyhow!(
+                    "{} must be a single ascii character, got {}",
+                    SINK_PAYLOAD_CSV_DELIMITER_OPTION,
+                    other
+                )))
+            }
+        };
+        Ok(Self {
+            format,
+            null_handling,
+            timestamp_format,
+            csv_delimiter,
+            protobuf_message: options.get(SINK_PAYLOAD_PROTOBUF_MESSAGE_OPTION).cloned(),
+            protobuf_schema_location: options
+                .get(SINK_PAYLOAD_PROTOBUF_SCHEMA_LOCATION_OPTION)
+                .cloned(),
+        })
+    }
+}
+
+/// Encodes single rows into sink payloads, independently of the transport that delivers them.
+pub trait RowEncoder: Send {
+    /// Encodes the columns of `row` selected by `col_indices` into one payload. Passing the
+    /// primary key indices yields the message key of a key/value transport, passing all indices
+    /// yields the message value.
+    fn encode(&self, row: RowRef<'_>, col_indices: &[usize]) -> Result<Vec<u8>>;
+}
+
+/// Builds the [`RowEncoder`] selected by `config` for the given sink schema. Unsupported
+/// combinations of format and data types are rejected here, so that `encode` only fails on
+/// malformed data.
+pub fn build_row_encoder(
+    config: &PayloadFormatConfig,
+    schema: Schema,
+) -> Result<Box<dyn RowEncoder>> {
+    Ok(match config.format {
+        PayloadFormat::Json => Box::new(JsonEncoder::new(
+            schema,
+            config.null_handling,
+            config.timestamp_format,
+        )),
+        PayloadFormat::Csv => Box::new(CsvEncoder::new(schema, config.csv_delimiter)),
+        PayloadFormat::Avro => Box::new(AvroEncoder::new(schema, config.timestamp_format)?),
+        PayloadFormat::Protobuf => {
+            let message = config.protobuf_message.as_ref().ok_or_else(|| {
+                SinkError::Config(anyhow!(
+                    "protobuf payloads require {}",
+                    SINK_PAYLOAD_PROTOBUF_MESSAGE_OPTION
+                ))
+            })?;
+            let location = config.protobuf_schema_location.as_ref().ok_or_else(|| {
+                SinkError::Config(anyhow!(
+                    "protobuf payloads require {}",
+                    SINK_PAYLOAD_PROTOBUF_SCHEMA_LOCATION_OPTION
+                ))
+            })?;
+            Box::new(ProtobufEncoder::new(schema, message, location)?)
+        }
+    })
+}
+
+/// Encodes a row as one JSON object, with the field names as keys.
+pub struct JsonEncoder {
+    schema: Schema,
+    null_handling: NullHandling,
+    timestamp_format: TimestampFormat,
+}
+
+impl JsonEncoder {
+    pub fn new(
+        schema: Schema,
+        null_handling: NullHandling,
+        timestamp_format: TimestampFormat,
+    ) -> Self {
+        Self {
+            schema,
+            null_handling,
+            timestamp_format,
+        }
+    }
+
+    pub fn record_to_json(
+        &self,
+        row: RowRef<'_>,
+        col_indices: &[usize],
+    ) -> Result<Map<String, Value>> {
+        let mut mappings = Map::with_capacity(col_indices.len());
+        for &idx in col_indices {
+            let field = &self.schema.fields[idx];
+            let value = datum_to_json_object(field, row.datum_at(idx), self.timestamp_format)
+                .map_err(|e| SinkError::JsonParse(e.to_string()))?;
+            if value.is_null() && self.null_handling == NullHandling::Omit {
+                continue;
+            }
+            mappings.insert(field.name.clone(), value);
+        }
+        Ok(mappings)
+    }
+}
+
+impl RowEncoder for JsonEncoder {
+    fn encode(&self, row: RowRef<'_>, col_indices: &[usize]) -> Result<Vec<u8>> {
+        Ok(Value::Object(self.record_to_json(row, col_indices)?)
+            .to_string()
+            .into_bytes())
+    }
+}
+
+/// Encodes a row as one CSV record without a trailing line terminator. `NULL` values become empty
+/// fields, and fields containing the delimiter, a quote or a newline are quoted.
+pub struct CsvEncoder {
+    schema: Schema,
+    delimiter: u8,
+}
+
+impl CsvEncoder {
+    pub fn new(schema: Schema, delimiter: u8) -> Self {
+        Self { schema, delimiter }
+    }
+}
+
+impl RowEncoder for CsvEncoder {
+    fn encode(&self, row: RowRef<'_>, col_indices: &[usize]) -> Result<Vec<u8>> {
+        let delimiter = self.delimiter as char;
+        let mut record = String::new();
+        for (i, &idx) in col_indices.iter().enumerate() {
+            if i != 0 {
+                record.push(delimiter);
+            }
+            let text = match row.datum_at(idx) {
+                None => continue,
+                Some(scalar) => scalar.to_text_with_type(&self.schema.fields[idx].data_type),
+            };
+            if text.contains(|c: char| c == delimiter || c == '"' || c == '\n' || c == '\r') {
+                record.push('"');
+                record.push_str(&text.replace('"', "\"\""));
+                record.push('"');
+            } else {
+                record.push_str(&text);
+            }
+        }
+        Ok(record.into_bytes())
+    }
+}
+
+/// Encodes a row as one Avro record serialized without the object container framing, suitable for
+/// per-message transports. Every field is a union with `null`, so `NULL` values are representable
+/// regardless of the null handling option.
+pub struct AvroEncoder {
+    schema: Schema,
+    avro_schema: apache_avro::Schema,
+    timestamp_format: TimestampFormat,
+}
+
+impl AvroEncoder {
+    pub fn new(schema: Schema, timestamp_format: TimestampFormat) -> Result<Self> {
+        let fields = schema
+            .fields
+            .iter()
+            .map(|field| {
+                let avro_type = avro_field_type(&field.data_type, timestamp_format)?;
+                Ok(json!({
+                    "name": field.name,
+                    "type": ["null", avro_type],
+                    "default": null,
+                }))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let avro_schema = apache_avro::Schema::parse(&json!({
+            "type": "record",
+            "name": "RisingWaveSinkRecord",
+            "fields": fields,
+        }))
+        .map_err(|e| SinkError::Config(anyhow!("failed to build avro schema: {}", e)))?;
+        Ok(Self {
+            schema,
+            avro_schema,
+            timestamp_format,
+        })
+    }
+}
+
+impl RowEncoder for AvroEncoder {
+    fn encode(&self, row: RowRef<'_>, col_indices: &[usize]) -> Result<Vec<u8>> {
+        if col_indices.len() != self.schema.len() {
+            return Err(SinkError::Encode(
+                "avro payloads do not support column projection".to_string(),
+            ));
+        }
+        let mut record = Vec::with_capacity(col_indices.len());
+        for &idx in col_indices {
+            let field = &self.schema.fields[idx];
+            let value = match row.datum_at(idx) {
+                None => apache_avro::types::Value::Null,
+                Some(scalar) => datum_to_avro_value(field, scalar, self.timestamp_format)?,
+            };
+            record.push((field.name.clone(), value));
+        }
+        apache_avro::to_avro_datum(&self.avro_schema, apache_avro::types::Value::Record(record))
+            .map_err(|e| SinkError::Encode(format!("failed to encode avro record: {}", e)))
+    }
+}
+
+/// Encodes a row as one protobuf message of the message type selected by
+/// `payload.protobuf.message`, resolved from a compiled file descriptor set at
+/// `payload.protobuf.schema.location`. `NULL` values are omitted from the message.
+pub struct ProtobufEncoder {
+    schema: Schema,
+    message_descriptor: MessageDescriptor,
+}
+
+impl ProtobufEncoder {
+    pub fn new(schema: Schema, message: &str, location: &str) -> Result<Self> {
+        let path = location.strip_prefix("file://").ok_or_else(|| {
+            SinkError::Config(anyhow!(
+                "{} only supports file:// locations, got {}",
+                SINK_PAYLOAD_PROTOBUF_SCHEMA_LOCATION_OPTION,
+                location
+            ))
+        })?;
+        let bytes = std::fs::read(path)
+            .map_err(|e| SinkError::Config(anyhow!("failed to read {}: {}", path, e)))?;
+        let pool = DescriptorPool::decode(bytes.as_slice()).map_err(|e| {
+            SinkError::Config(anyhow!("failed to decode file descriptor set: {}", e))
+        })?;
+        let message_descriptor = pool.get_message_by_name(message).ok_or_else(|| {
+            SinkError::Config(anyhow!("message {} not found in {}", message, location))
+        })?;
+        for field in &schema.fields {
+            let field_descriptor = message_descriptor
+                .get_field_by_name(&field.name)
+                .ok_or_else(|| {
+                    SinkError::Config(anyhow!(
+                        "field {} not found in message {}",
+                        field.name,
+                        message
+                    ))
+                })?;
+            check_protobuf_type_mapping(&field.data_type, &field_descriptor.kind(), &field.name)?;
+        }
+        Ok(Self {
+            schema,
+            message_descriptor,
+        })
+    }
+}
+
+impl RowEncoder for ProtobufEncoder {
+    fn encode(&self, row: RowRef<'_>, col_indices: &[usize]) -> Result<Vec<u8>> {
+        let mut message = DynamicMessage::new(self.message_descriptor.clone());
+        for &idx in col_indices {
+            let field = &self.schema.fields[idx];
+            let scalar = match row.datum_at(idx) {
+                None => continue,
+                Some(scalar) => scalar,
+            };
+            let value = match scalar {
+                ScalarRefImpl::Bool(v) => prost_reflect::Value::Bool(v),
+                ScalarRefImpl::Int16(v) => prost_reflect::Value::I32(v as i32),
+                ScalarRefImpl::Int32(v) => prost_reflect::Value::I32(v),
+                ScalarRefImpl::Int64(v) => prost_reflect::Value::I64(v),
+                ScalarRefImpl::Float32(v) => prost_reflect::Value::F32(f32::from(v)),
+                ScalarRefImpl::Float64(v) => prost_reflect::Value::F64(f64::from(v)),
+                ScalarRefImpl::Utf8(v) => prost_reflect::Value::String(v.to_string()),
+                _ => prost_reflect::Value::String(scalar.to_text_with_type(&field.data_type)),
+            };
+            message.set_field_by_name(&field.name, value);
+        }
+        Ok(message.encode_to_vec())
+    }
+}
+
+fn avro_field_type(data_type: &DataType, timestamp_format: TimestampFormat) -> Result<Value> {
+    let avro_type = match data_type {
+        DataType::Boolean => "boolean",
+        DataType::Int16 | DataType::Int32 => "int",
+        DataType::Int64 => "long",
+        DataType::Float32 => "float",
+        DataType::Float64 => "double",
+        DataType::Varchar => "string",
+        DataType::Timestamp | DataType::Timestamptz => match timestamp_format {
+            TimestampFormat::String => "string",
+            TimestampFormat::EpochMillis => "long",
+        },
+        // Rendered as their textual representation, like the json encoder does.
+        DataType::Decimal
+        | DataType::Date
+        | DataType::Time
+        | DataType::Interval
+        | DataType::Bytea => "string",
+        DataType::List { .. } | DataType::Struct(_) | DataType::Jsonb => {
+            return Err(SinkError::Config(anyhow!(
+                "data type {:?} is not supported by avro payloads",
+                data_type
+            )))
+        }
+    };
+    Ok(json!(avro_type))
+}
+
+fn datum_to_avro_value(
+    field: &Field,
+    scalar: ScalarRefImpl<'_>,
+    timestamp_format: TimestampFormat,
+) -> Result<apache_avro::types::Value> {
+    use apache_avro::types::Value as AvroValue;
+    let value = match (field.data_type(), scalar) {
+        (DataType::Boolean, ScalarRefImpl::Bool(v)) => AvroValue::Boolean(v),
+        (DataType::Int16, ScalarRefImpl::Int16(v)) => AvroValue::Int(v as i32),
+        (DataType::Int32, ScalarRefImpl::Int32(v)) => AvroValue::Int(v),
+        (DataType::Int64, ScalarRefImpl::Int64(v)) => AvroValue::Long(v),
+        (DataType::Float32, ScalarRefImpl::Float32(v)) => AvroValue::Float(f32::from(v)),
+        (DataType::Float64, ScalarRefImpl::Float64(v)) => AvroValue::Double(f64::from(v)),
+        (DataType::Varchar, ScalarRefImpl::Utf8(v)) => AvroValue::String(v.to_string()),
+        (DataType::Timestamp, ScalarRefImpl::NaiveDateTime(v))
+            if timestamp_format == TimestampFormat::EpochMillis =>
+        {
+            AvroValue::Long(v.0.timestamp_millis())
+        }
+        (DataType::Timestamptz, ScalarRefImpl::Int64(v))
+            if timestamp_format == TimestampFormat::EpochMillis =>
+        {
+            // Timestamptz is physically microseconds since the UNIX epoch.
+            AvroValue::Long(v / 1000)
+        }
+        (data_type, scalar) => AvroValue::String(scalar.to_text_with_type(&data_type)),
+    };
+    Ok(value)
+}
+
+fn check_protobuf_type_mapping(data_type: &DataType, kind: &Kind, name: &str) -> Result<()> {
+    let compatible = match data_type {
+        DataType::Boolean => matches!(kind, Kind::Bool),
+        DataType::Int16 | DataType::Int32 => {
+            matches!(kind, Kind::Int32 | Kind::Sint32 | Kind::Sfixed32)
+        }
+        DataType::Int64 => matches!(kind, Kind::Int64 | Kind::Sint64 | Kind::Sfixed64),
+        DataType::Float32 => matches!(kind, Kind::Float),
+        DataType::Float64 => matches!(kind, Kind::Double),
+        // The remaining types are rendered as their textual representation.
+        _ => matches!(kind, Kind::String),
+    };
+    if compatible {
+        Ok(())
+    } else {
+        Err(SinkError::Config(anyhow!(
+            "field {} of type {:?} cannot be encoded as protobuf {:?}",
+            name,
+            data_type,
+            kind
+        )))
+    }
+}
+
+/// Converts one datum into a json value, rendering timestamps according to `timestamp_format`.
+pub fn datum_to_json_object(
+    field: &Field,
+    datum: DatumRef<'_>,
+    timestamp_format: TimestampFormat,
+) -> ArrayResult<Value> {
+    let scalar_ref = match datum {
+        None => return Ok(Value::Null),
+        Some(datum) => datum,
+    };
+
+    let data_type = field.data_type();
+
+    tracing::debug!("datum_to_json_object: {:?}, {:?}", data_type, scalar_ref);
+
+    let value = match (data_type, scalar_ref) {
+        (DataType::Boolean, ScalarRefImpl::Bool(v)) => {
+            json!(v)
+        }
+        (DataType::Int16, ScalarRefImpl::Int16(v)) => {
+            json!(v)
+        }
+        (DataType::Int32, ScalarRefImpl::Int32(v)) => {
+            json!(v)
+        }
+        (DataType::Int64, ScalarRefImpl::Int64(v)) => {
+            json!(v)
+        }
+        (DataType::Float32, ScalarRefImpl::Float32(v)) => {
+            json!(f32::from(v))
+        }
+        (DataType::Float64, ScalarRefImpl::Float64(v)) => {
+            json!(f64::from(v))
+        }
+        (DataType::Varchar, ScalarRefImpl::Utf8(v)) => {
+            json!(v)
+        }
+        (DataType::Decimal, ScalarRefImpl::Decimal(v)) => {
+            // fixme
+            json!(v.to_text())
+        }
+        (DataType::Timestamp, ScalarRefImpl::NaiveDateTime(v))
+            if timestamp_format == TimestampFormat::EpochMillis =>
+        {
+            json!(v.0.timestamp_millis())
+        }
+        (DataType::Timestamptz, ScalarRefImpl::Int64(v))
+            if timestamp_format == TimestampFormat::EpochMillis =>
+        {
+            // Timestamptz is physically microseconds since the UNIX epoch.
+            json!(v / 1000)
+        }
+        (
+            dt @ DataType::Date
+            | dt @ DataType::Time
+            | dt @ DataType::Timestamp
+            | dt @ DataType::Timestamptz
+            | dt @ DataType::Interval
+            | dt @ DataType::Bytea,
+            scalar,
+        ) => {
+            json!(scalar.to_text_with_type(&dt))
+        }
+        (DataType::List { datatype }, ScalarRefImpl::List(list_ref)) => {
+            let mut vec = Vec::with_capacity(list_ref.values_ref().len());
+            let inner_field = Field::unnamed(Box::<DataType>::into_inner(datatype));
+            for sub_datum_ref in list_ref.values_ref() {
+                let value = datum_to_json_object(&inner_field, sub_datum_ref, timestamp_format)?;
+                vec.push(value);
+            }
+            json!(vec)
+        }
+        (DataType::Struct(st), ScalarRefImpl::Struct(struct_ref)) => {
+            let mut map = Map::with_capacity(st.fields.len());
+            for (sub_datum_ref, sub_field) in struct_ref.fields_ref().into_iter().zip_eq_fast(
+                st.fields
+                    .iter()
+                    .zip_eq_fast(st.field_names.iter())
+                    .map(|(dt, name)| Field::with_name(dt.clone(), name)),
+            ) {
+                let value = datum_to_json_object(&sub_field, sub_datum_ref, timestamp_format)?;
+                map.insert(sub_field.name.clone(), value);
+            }
+            json!(map)
+        }
+        _ => {
+            return Err(ArrayError::internal(
+                "datum_to_json_object: unsupported data type".to_string(),
+            ));
+        }
+    };
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod test {
+    use maplit::hashmap;
+    use risingwave_common::test_prelude::StreamChunkTestExt;
+
+    use super::*;
+
+    fn test_schema() -> Schema {
+        Schema::new(vec![
+            Field {
+                data_type: DataType::Int32,
+                name: "id".into(),
+                sub_fields: vec![],
+                type_name: "".into(),
+            },
+            Field {
+                data_type: DataType::Varchar,
+                name: "name".into(),
+                sub_fields: vec![],
+                type_name: "".into(),
+            },
+        ])
+    }
+
+    #[test]
+    fn test_parse_payload_format_config() {
+        let config = PayloadFormatConfig::from_options(&hashmap! {
+            "payload.format".to_string() => "csv".to_string(),
+            "payload.csv.delimiter".to_string() => "|".to_string(),
+            "payload.timestamp.format".to_string() => "epoch_ms".to_string(),
+        })
+        .unwrap();
+        assert_eq!(config.format, PayloadFormat::Csv);
+        assert_eq!(config.csv_delimiter, b'|');
+        assert_eq!(config.timestamp_format, TimestampFormat::EpochMillis);
+
+        assert!(PayloadFormatConfig::from_options(&hashmap! {
+            "payload.format".to_string() => "orc".to_string(),
+        })
+        .is_err());
+        assert!(PayloadFormatConfig::from_options(&hashmap! {
+            "payload.null".to_string() => "skip".to_string(),
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_json_encoder_null_handling() {
+        let chunk = risingwave_common::array::StreamChunk::from_pretty(
+            " i T
+            + 1 foo
+            + 2 .",
+        );
+        let schema = test_schema();
+
+        let literal = JsonEncoder::new(
+            schema.clone(),
+            NullHandling::Literal,
+            TimestampFormat::String,
+        );
+        let omit = JsonEncoder::new(schema, NullHandling::Omit, TimestampFormat::String);
+
+        let rows = chunk.rows().collect::<Vec<_>>();
+        assert_eq!(
+            String::from_utf8(literal.encode(rows[1].1, &[0, 1]).unwrap()).unwrap(),
+            "{\"id\":2,\"name\":null}"
+        );
+        assert_eq!(
+            String::from_utf8(omit.encode(rows[1].1, &[0, 1]).unwrap()).unwrap(),
+            "{\"id\":2}"
+        );
+        // Projecting the key columns yields the message key.
+        assert_eq!(
+            String::from_utf8(literal.encode(rows[0].1, &[0]).unwrap()).unwrap(),
+            "{\"id\":1}"
+        );
+    }
+
+    #[test]
+    fn test_csv_encoder() {
+        let chunk = risingwave_common::array::StreamChunk::from_pretty(
+            " i T
+            + 1 foo
+            + 2 .",
+        );
+        let encoder = CsvEncoder::new(test_schema(), b',');
+        let rows = chunk.rows().collect::<Vec<_>>();
+        assert_eq!(
+            String::from_utf8(encoder.encode(rows[0].1, &[0, 1]).unwrap()).unwrap(),
+            "1,foo"
+        );
+        assert_eq!(
+            String::from_utf8(encoder.encode(rows[1].1, &[0, 1]).unwrap()).unwrap(),
+            "2,"
+        );
+    }
+}
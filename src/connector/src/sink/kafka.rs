@@ -23,11 +23,9 @@ use rdkafka::message::ToBytes;
 use rdkafka::producer::{BaseRecord, DefaultProducerContext, Producer, ThreadedProducer};
 use rdkafka::types::RDKafkaErrorCode;
 use rdkafka::ClientConfig;
-use risingwave_common::array::{ArrayError, ArrayResult, Op, RowRef, StreamChunk};
+use risingwave_common::array::{Op, RowRef, StreamChunk};
 use risingwave_common::catalog::{Field, Schema};
 use risingwave_common::row::Row;
-use risingwave_common::types::to_text::ToText;
-use risingwave_common::types::{DataType, DatumRef, ScalarRefImpl};
 use risingwave_common::util::iter_util::ZipEqFast;
 use serde_derive::Deserialize;
 use serde_json::{json, Map, Value};
@@ -35,6 +33,10 @@ use tracing::warn;
 
 use super::{Sink, SinkError, SINK_FORMAT_APPEND_ONLY, SINK_FORMAT_DEBEZIUM, SINK_FORMAT_UPSERT};
 use crate::common::KafkaCommon;
+use crate::sink::encoder::{
+    build_row_encoder, datum_to_json_object, PayloadFormat, PayloadFormatConfig, RowEncoder,
+    TimestampFormat,
+};
 use crate::sink::Result;
 use crate::{deserialize_bool_from_string, deserialize_duration_from_string};
 
@@ -87,12 +89,18 @@ pub struct KafkaConfig {
         default = "_default_use_transaction"
     )]
     pub use_transaction: bool,
+
+    /// The payload format of the sink, parsed from the `payload.*` options.
+    #[serde(skip)]
+    pub payload: PayloadFormatConfig,
 }
 
 impl KafkaConfig {
     pub fn from_hashmap(values: HashMap<String, String>) -> Result<Self> {
-        let config = serde_json::from_value::<KafkaConfig>(serde_json::to_value(values).unwrap())
-            .map_err(|e| SinkError::Config(anyhow!(e)))?;
+        let payload = PayloadFormatConfig::from_options(&values)?;
+        let mut config =
+            serde_json::from_value::<KafkaConfig>(serde_json::to_value(values).unwrap())
+                .map_err(|e| SinkError::Config(anyhow!(e)))?;
 
         if config.format != SINK_FORMAT_APPEND_ONLY
             && config.format != SINK_FORMAT_DEBEZIUM
@@ -102,6 +110,14 @@ impl KafkaConfig {
                 "format must be append_only, debezium, or upsert"
             )));
         }
+        // The debezium and upsert formats are json envelopes, so the payload format only applies
+        // to append-only sinks.
+        if config.format != SINK_FORMAT_APPEND_ONLY && payload.format != PayloadFormat::Json {
+            return Err(SinkError::Config(anyhow!(
+                "debezium and upsert sinks only support json payloads"
+            )));
+        }
+        config.payload = payload;
         Ok(config)
     }
 }
@@ -119,11 +135,13 @@ pub struct KafkaSink<const APPEND_ONLY: bool> {
     state: KafkaSinkState,
     schema: Schema,
     pk_indices: Vec<usize>,
+    encoder: Box<dyn RowEncoder>,
     in_transaction_epoch: Option<u64>,
 }
 
 impl<const APPEND_ONLY: bool> KafkaSink<APPEND_ONLY> {
     pub async fn new(config: KafkaConfig, schema: Schema, pk_indices: Vec<usize>) -> Result<Self> {
+        let encoder = build_row_encoder(&config.payload, schema.clone())?;
         Ok(KafkaSink {
             config: config.clone(),
             conductor: KafkaTransactionConductor::new(config).await?,
@@ -131,6 +149,7 @@ impl<const APPEND_ONLY: bool> KafkaSink<APPEND_ONLY> {
             state: KafkaSinkState::Init,
             schema,
             pk_indices,
+            encoder,
         })
     }
 
@@ -282,13 +301,14 @@ impl<const APPEND_ONLY: bool> KafkaSink<APPEND_ONLY> {
     }
 
     async fn append_only(&self, chunk: StreamChunk) -> Result<()> {
+        let all_indices = (0..self.schema.len()).collect::<Vec<_>>();
         for (op, row) in chunk.rows() {
             if op == Op::Insert {
-                let record = Value::Object(record_to_json(row, &self.schema.fields)?).to_string();
+                let record = self.encoder.encode(row, &all_indices)?;
                 self.send(
                     BaseRecord::to(self.config.common.topic.as_str())
                         .key(self.gen_message_key().as_bytes())
-                        .payload(record.as_bytes()),
+                        .payload(record.as_slice()),
                 )
                 .await?;
             }
@@ -365,90 +385,11 @@ impl<const APPEND_ONLY: bool> Debug for KafkaSink<APPEND_ONLY> {
     }
 }
 
-fn datum_to_json_object(field: &Field, datum: DatumRef<'_>) -> ArrayResult<Value> {
-    let scalar_ref = match datum {
-        None => return Ok(Value::Null),
-        Some(datum) => datum,
-    };
-
-    let data_type = field.data_type();
-
-    tracing::debug!("datum_to_json_object: {:?}, {:?}", data_type, scalar_ref);
-
-    let value = match (data_type, scalar_ref) {
-        (DataType::Boolean, ScalarRefImpl::Bool(v)) => {
-            json!(v)
-        }
-        (DataType::Int16, ScalarRefImpl::Int16(v)) => {
-            json!(v)
-        }
-        (DataType::Int32, ScalarRefImpl::Int32(v)) => {
-            json!(v)
-        }
-        (DataType::Int64, ScalarRefImpl::Int64(v)) => {
-            json!(v)
-        }
-        (DataType::Float32, ScalarRefImpl::Float32(v)) => {
-            json!(f32::from(v))
-        }
-        (DataType::Float64, ScalarRefImpl::Float64(v)) => {
-            json!(f64::from(v))
-        }
-        (DataType::Varchar, ScalarRefImpl::Utf8(v)) => {
-            json!(v)
-        }
-        (DataType::Decimal, ScalarRefImpl::Decimal(v)) => {
-            // fixme
-            json!(v.to_text())
-        }
-        (
-            dt @ DataType::Date
-            | dt @ DataType::Time
-            | dt @ DataType::Timestamp
-            | dt @ DataType::Timestamptz
-            | dt @ DataType::Interval
-            | dt @ DataType::Bytea,
-            scalar,
-        ) => {
-            json!(scalar.to_text_with_type(&dt))
-        }
-        (DataType::List { datatype }, ScalarRefImpl::List(list_ref)) => {
-            let mut vec = Vec::with_capacity(list_ref.values_ref().len());
-            let inner_field = Field::unnamed(Box::<DataType>::into_inner(datatype));
-            for sub_datum_ref in list_ref.values_ref() {
-                let value = datum_to_json_object(&inner_field, sub_datum_ref)?;
-                vec.push(value);
-            }
-            json!(vec)
-        }
-        (DataType::Struct(st), ScalarRefImpl::Struct(struct_ref)) => {
-            let mut map = Map::with_capacity(st.fields.len());
-            for (sub_datum_ref, sub_field) in struct_ref.fields_ref().into_iter().zip_eq_fast(
-                st.fields
-                    .iter()
-                    .zip_eq_fast(st.field_names.iter())
-                    .map(|(dt, name)| Field::with_name(dt.clone(), name)),
-            ) {
-                let value = datum_to_json_object(&sub_field, sub_datum_ref)?;
-                map.insert(sub_field.name.clone(), value);
-            }
-            json!(map)
-        }
-        _ => {
-            return Err(ArrayError::internal(
-                "datum_to_json_object: unsupported data type".to_string(),
-            ));
-        }
-    };
-
-    Ok(value)
-}
-
 fn record_to_json(row: RowRef<'_>, schema: &[Field]) -> Result<Map<String, Value>> {
     let mut mappings = Map::with_capacity(schema.len());
     for (field, datum_ref) in schema.iter().zip_eq_fast(row.iter()) {
         let key = field.name.clone();
-        let value = datum_to_json_object(field, datum_ref)
+        let value = datum_to_json_object(field, datum_ref, TimestampFormat::String)
             .map_err(|e| SinkError::JsonParse(e.to_string()))?;
         mappings.insert(key, value);
     }
@@ -464,7 +405,7 @@ fn pk_to_json(
     for idx in pk_indices {
         let field = &schema[*idx];
         let key = field.name.clone();
-        let value = datum_to_json_object(field, row.datum_at(*idx))
+        let value = datum_to_json_object(field, row.datum_at(*idx), TimestampFormat::String)
             .map_err(|e| SinkError::JsonParse(e.to_string()))?;
         mappings.insert(key, value);
     }
@@ -590,6 +531,7 @@ impl KafkaTransactionConductor {
 mod test {
     use maplit::hashmap;
     use risingwave_common::test_prelude::StreamChunkTestExt;
+    use risingwave_common::types::DataType;
 
     use super::*;
 
@@ -14,6 +14,7 @@
 
 pub mod catalog;
 pub mod console;
+pub mod encoder;
 pub mod kafka;
 pub mod redis;
 pub mod remote;
@@ -80,6 +81,15 @@ pub enum SinkState {
 
 pub const BLACKHOLE_SINK: &str = "blackhole";
 
+/// A `table` sink writes changes into another table in the same cluster through the DML path,
+/// instead of an external system. It is planned for `CREATE SINK ... INTO table` and handled by
+/// a dedicated executor on the compute node, so it never reaches the connectors here. The
+/// options below are injected by the frontend and not meant to be specified by users.
+pub const TABLE_SINK: &str = "table";
+pub const TABLE_SINK_ID_OPTION: &str = "table.id";
+pub const TABLE_SINK_VERSION_ID_OPTION: &str = "table.version.id";
+pub const TABLE_SINK_ROW_ID_INDEX_OPTION: &str = "table.row.id.index";
+
 impl SinkConfig {
     pub fn from_hashmap(properties: HashMap<String, String>) -> Result<Self> {
         const SINK_TYPE_KEY: &str = "connector";
@@ -94,6 +104,9 @@ impl SinkConfig {
                 properties,
             )?)),
             BLACKHOLE_SINK => Ok(SinkConfig::BlackHole),
+            TABLE_SINK => Err(SinkError::Config(anyhow!(
+                "table sink should have been handled by the table sink executor"
+            ))),
             _ => Ok(SinkConfig::Remote(RemoteConfig::from_hashmap(properties)?)),
         }
     }
@@ -216,6 +229,8 @@ pub enum SinkError {
     Remote(String),
     #[error("Json parse error: {0}")]
     JsonParse(String),
+    #[error("Payload encode error: {0}")]
+    Encode(String),
     #[error("config error: {0}")]
     Config(#[from] anyhow::Error),
 }
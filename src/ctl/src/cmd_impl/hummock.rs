@@ -20,12 +20,14 @@ mod sst_dump;
 pub use sst_dump::*;
 mod compaction_group;
 mod disable_commit_epoch;
+mod fsck;
 mod list_version_deltas;
 mod trigger_full_gc;
 mod trigger_manual_compaction;
 
 pub use compaction_group::*;
 pub use disable_commit_epoch::*;
+pub use fsck::*;
 pub use list_version_deltas::*;
 pub use trigger_full_gc::*;
 pub use trigger_manual_compaction::*;
@@ -0,0 +1,62 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use risingwave_hummock_sdk::fsck::{check_version, FsckIssue};
+use serde_json::json;
+
+use crate::CtlContext;
+
+/// Validates the current Hummock version manifest against the object store and
+/// prints a machine-readable (JSON) report.
+pub async fn fsck(context: &CtlContext) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let version = meta_client.get_current_version().await?;
+
+    let hummock = context.hummock_store().await?;
+    let sstable_store = hummock.sstable_store();
+    let existing_sst_ids: HashSet<_> = sstable_store
+        .list_ssts_from_object_store()
+        .await?
+        .into_iter()
+        .map(|object| sstable_store.get_sst_id_from_path(&object.key))
+        .collect();
+
+    let report = check_version(&version, Some(&existing_sst_ids));
+    let issues = report
+        .issues
+        .iter()
+        .map(|issue| {
+            json!({
+                "kind": issue.kind(),
+                "detail": issue.to_string(),
+            })
+        })
+        .collect::<Vec<_>>();
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&json!({
+            "version_id": report.version_id,
+            "max_committed_epoch": version.max_committed_epoch,
+            "object_count": existing_sst_ids.len(),
+            "consistent": report.is_consistent(),
+            "issues": issues,
+        }))?
+    );
+    if !report.is_consistent() {
+        anyhow::bail!("{} issue(s) found in version {}", report.issues.len(), report.version_id);
+    }
+    Ok(())
+}
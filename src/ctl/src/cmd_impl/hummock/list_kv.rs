@@ -15,7 +15,7 @@
 use core::ops::Bound::Unbounded;
 
 use risingwave_common::catalog::TableId;
-use risingwave_storage::store::{ReadOptions, StateStoreReadExt};
+use risingwave_storage::store::{CachePolicy, ReadOptions, StateStoreReadExt};
 
 use crate::CtlContext;
 
@@ -33,10 +33,12 @@ pub async fn list_kv(context: &CtlContext, epoch: u64, table_id: u32) -> anyhow:
                 None,
                 ReadOptions {
                     ignore_range_tombstone: false,
+                    limit: None,
                     prefix_hint: None,
                     table_id: TableId { table_id },
                     retention_seconds: None,
                     read_version_from_backup: false,
+                    cache_policy: CachePolicy::Fill,
                 },
             )
             .await?
@@ -24,7 +24,7 @@ pub async fn trigger_manual_compaction(
 ) -> anyhow::Result<()> {
     let meta_client = context.meta_client().await?;
     let result = meta_client
-        .trigger_manual_compaction(compaction_group_id, table_id, level)
+        .trigger_manual_compaction(compaction_group_id, table_id, level, None)
         .await;
     println!("{:#?}", result);
     Ok(())
@@ -0,0 +1,70 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use anyhow::Context;
+use prost::Message;
+use risingwave_pb::backup_service::CatalogBundle;
+
+use crate::CtlContext;
+
+/// Exports all catalog objects, users and privileges into a protobuf-encoded
+/// bundle file. The bundle only covers definitions; table data and streaming
+/// state are not included.
+pub async fn backup_catalog(context: &CtlContext, output: String) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let bundle = meta_client.backup_catalog().await?;
+    tracing::info!(
+        "exported catalog bundle: {} database(s), {} table(s), {} source(s), {} sink(s), {} user(s)",
+        bundle.databases.len(),
+        bundle.tables.len(),
+        bundle.sources.len(),
+        bundle.sinks.len(),
+        bundle.users.len(),
+    );
+    tokio::fs::write(&output, bundle.encode_to_vec())
+        .await
+        .with_context(|| format!("failed to write catalog bundle to {}", output))?;
+    tracing::info!("catalog bundle written to {}", output);
+    Ok(())
+}
+
+/// Imports a catalog bundle exported by [`backup_catalog`] into the cluster.
+/// `path_remap` entries of the form `from=to` rewrite connector property
+/// prefixes, e.g. object store paths, during import.
+pub async fn restore_catalog(
+    context: &CtlContext,
+    input: String,
+    path_remap: Vec<String>,
+) -> anyhow::Result<()> {
+    let path_remap = path_remap
+        .iter()
+        .map(|entry| {
+            entry
+                .split_once('=')
+                .map(|(from, to)| (from.to_string(), to.to_string()))
+                .with_context(|| format!("malformed path remap entry: {}", entry))
+        })
+        .collect::<anyhow::Result<HashMap<_, _>>>()?;
+    let raw = tokio::fs::read(&input)
+        .await
+        .with_context(|| format!("failed to read catalog bundle from {}", input))?;
+    let bundle = CatalogBundle::decode(raw.as_slice())
+        .context("failed to decode catalog bundle")?;
+    let meta_client = context.meta_client().await?;
+    meta_client.restore_catalog(bundle, path_remap).await?;
+    tracing::info!("catalog bundle from {} restored", input);
+    Ok(())
+}
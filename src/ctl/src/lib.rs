@@ -140,6 +140,9 @@ enum HummockCommands {
         #[clap(long)]
         max_sub_compaction: Option<u32>,
     },
+    /// Validate the current version manifest against the object store and
+    /// print a JSON report.
+    Fsck,
 }
 
 #[derive(Subcommand)]
@@ -193,6 +196,22 @@ enum MetaCommands {
     BackupMeta,
     /// delete meta snapshots
     DeleteMetaSnapshots { snapshot_ids: Vec<u64> },
+    /// export all catalog objects, users and privileges into a bundle file
+    BackupCatalog {
+        /// path of the output bundle file
+        #[clap(long, default_value = "catalog.bundle")]
+        output: String,
+    },
+    /// import a catalog bundle exported by backup-catalog
+    RestoreCatalog {
+        /// path of the bundle file to import
+        #[clap(long)]
+        input: String,
+        /// `from=to` prefix replacements applied to connector property values,
+        /// e.g. to remap object store paths
+        #[clap(long)]
+        path_remap: Vec<String>,
+    },
 }
 
 pub async fn start(opts: CliOpts) -> Result<()> {
@@ -277,6 +296,7 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
             )
             .await?
         }
+        Commands::Hummock(HummockCommands::Fsck) => cmd_impl::hummock::fsck(context).await?,
         Commands::Table(TableCommands::Scan { mv_name }) => {
             cmd_impl::table::scan(context, mv_name).await?
         }
@@ -295,6 +315,12 @@ pub async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
         Commands::Meta(MetaCommands::DeleteMetaSnapshots { snapshot_ids }) => {
             cmd_impl::meta::delete_meta_snapshots(context, &snapshot_ids).await?
         }
+        Commands::Meta(MetaCommands::BackupCatalog { output }) => {
+            cmd_impl::meta::backup_catalog(context, output).await?
+        }
+        Commands::Meta(MetaCommands::RestoreCatalog { input, path_remap }) => {
+            cmd_impl::meta::restore_catalog(context, input, path_remap).await?
+        }
         Commands::Trace => cmd_impl::trace::trace(context).await?,
         Commands::Profile { sleep } => cmd_impl::profile::profile(context, sleep).await?,
     }
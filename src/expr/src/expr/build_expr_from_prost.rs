@@ -33,6 +33,7 @@ use super::expr_concat_ws::ConcatWsExpression;
 use super::expr_field::FieldExpression;
 use super::expr_in::InExpression;
 use super::expr_nested_construct::NestedConstructExpression;
+use super::expr_quaternary::new_width_bucket;
 use super::expr_quaternary_bytes::new_overlay_for_exp;
 use super::expr_regexp::RegexpMatchExpression;
 use super::expr_some_all::SomeAllExpression;
@@ -84,6 +85,7 @@ pub fn build_from_prost(prost: &ExprNode) -> Result<BoxedExpression> {
         Repeat => build_repeat_expr(prost),
         SplitPart => build_split_part_expr(prost),
         Translate => build_translate_expr(prost),
+        WidthBucket => build_width_bucket_expr(prost),
 
         // Variable number of arguments and based on `Unary/Binary/Ternary/...Expression`
         Substr => build_substr_expr(prost),
@@ -309,6 +311,16 @@ fn build_split_part_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     ))
 }
 
+fn build_width_bucket_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_children_and_return_type(prost)?;
+    ensure!(children.len() == 4);
+    let operand = expr_build_from_prost(&children[0])?;
+    let low = expr_build_from_prost(&children[1])?;
+    let high = expr_build_from_prost(&children[2])?;
+    let count = expr_build_from_prost(&children[3])?;
+    Ok(new_width_bucket(operand, low, high, count, ret_type))
+}
+
 fn build_to_char_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     let (children, ret_type) = get_children_and_return_type(prost)?;
     ensure!(children.len() == 2);
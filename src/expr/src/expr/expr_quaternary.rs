@@ -0,0 +1,78 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! For expression that accept 4 arguments.
+
+use risingwave_common::array::{F64Array, I32Array};
+use risingwave_common::types::DataType;
+
+use crate::expr::template::QuaternaryExpression;
+use crate::expr::BoxedExpression;
+use crate::vector_op::width_bucket::width_bucket;
+
+pub fn new_width_bucket(
+    operand: BoxedExpression,
+    low: BoxedExpression,
+    high: BoxedExpression,
+    count: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(QuaternaryExpression::<
+        F64Array,
+        F64Array,
+        F64Array,
+        I32Array,
+        I32Array,
+        _,
+    >::new(
+        operand, low, high, count, return_type, width_bucket
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::DataChunk;
+    use risingwave_common::row::OwnedRow;
+    use risingwave_common::types::{OrderedF64, ScalarImpl};
+
+    use super::*;
+    use crate::expr::LiteralExpression;
+
+    #[test]
+    fn test_width_bucket() {
+        let expr = new_width_bucket(
+            Box::new(LiteralExpression::new(
+                DataType::Float64,
+                Some(ScalarImpl::Float64(OrderedF64::from(5.35))),
+            )),
+            Box::new(LiteralExpression::new(
+                DataType::Float64,
+                Some(ScalarImpl::Float64(OrderedF64::from(0.024))),
+            )),
+            Box::new(LiteralExpression::new(
+                DataType::Float64,
+                Some(ScalarImpl::Float64(OrderedF64::from(10.06))),
+            )),
+            Box::new(LiteralExpression::new(
+                DataType::Int32,
+                Some(ScalarImpl::Int32(5)),
+            )),
+            DataType::Int32,
+        );
+
+        let expected = Some(ScalarImpl::Int32(3));
+        assert_eq!(expr.eval(&DataChunk::new_dummy(1)).unwrap().to_datum(), expected);
+        assert_eq!(expr.eval_row(&OwnedRow::new(vec![])).unwrap(), expected);
+    }
+}
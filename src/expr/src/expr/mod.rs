@@ -47,6 +47,7 @@ mod expr_is_null;
 mod expr_jsonb_access;
 mod expr_literal;
 mod expr_nested_construct;
+mod expr_quaternary;
 mod expr_quaternary_bytes;
 pub mod expr_regexp;
 mod expr_some_all;
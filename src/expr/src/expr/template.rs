@@ -326,6 +326,7 @@ macro_rules! gen_expr_nullable {
 gen_expr_normal!(UnaryExpression, { IA1 });
 gen_expr_normal!(BinaryExpression, { IA1, IA2 });
 gen_expr_normal!(TernaryExpression, { IA1, IA2, IA3 });
+gen_expr_normal!(QuaternaryExpression, { IA1, IA2, IA3, IA4 });
 
 gen_expr_bytes!(UnaryBytesExpression, { IA1 });
 gen_expr_bytes!(BinaryBytesExpression, { IA1, IA2 });
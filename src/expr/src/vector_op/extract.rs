@@ -25,6 +25,8 @@ where
         "HOUR" => Ok(time.hour().into()),
         "MINUTE" => Ok(time.minute().into()),
         "SECOND" => Ok(time.second().into()),
+        "MILLISECONDS" => Ok((time.second() * 1_000 + time.nanosecond() / 1_000_000).into()),
+        "MICROSECONDS" => Ok((time.second() * 1_000_000 + time.nanosecond() / 1_000).into()),
         _ => bail!("Unsupported time unit {} in extract function", time_unit),
     }
 }
@@ -34,21 +36,35 @@ where
     T: Datelike,
 {
     match time_unit {
-        "DAY" => Ok(date.day().into()),
-        "MONTH" => Ok(date.month().into()),
+        "MILLENNIUM" => Ok(((date.year() - 1) / 1000 + 1).into()),
+        "CENTURY" => Ok(((date.year() - 1) / 100 + 1).into()),
+        "DECADE" => Ok((date.year() / 10).into()),
         "YEAR" => Ok(date.year().into()),
+        "ISOYEAR" => Ok(date.iso_week().year().into()),
+        "QUARTER" => Ok(((date.month() - 1) / 3 + 1).into()),
+        "MONTH" => Ok(date.month().into()),
+        "WEEK" => Ok(date.iso_week().week().into()),
+        "DAY" => Ok(date.day().into()),
         // Sun = 0 and Sat = 6
         "DOW" => Ok(date.weekday().num_days_from_sunday().into()),
+        // Mon = 1 and Sun = 7
+        "ISODOW" => Ok(date.weekday().number_from_monday().into()),
         "DOY" => Ok(date.ordinal().into()),
         _ => bail!("Unsupported time unit {} in extract function", time_unit),
     }
 }
 
 pub fn extract_from_date(time_unit: &str, date: NaiveDateWrapper) -> Result<Decimal> {
-    extract_date(date.0, time_unit)
+    match time_unit {
+        "EPOCH" => extract_from_timestamp(time_unit, date.and_hms_uncheck(0, 0, 0)),
+        _ => extract_date(date.0, time_unit),
+    }
 }
 
 pub fn extract_from_timestamp(time_unit: &str, timestamp: NaiveDateTimeWrapper) -> Result<Decimal> {
+    if time_unit == "EPOCH" {
+        return Ok(Decimal::from(timestamp.0.timestamp_micros()) / 1_000_000.into());
+    }
     let time = timestamp.0;
     let mut res = extract_date(time, time_unit);
     if res.is_err() {
@@ -80,9 +96,17 @@ mod tests {
             NaiveDateWrapper::new(NaiveDate::parse_from_str("2021-11-22", "%Y-%m-%d").unwrap());
         assert_eq!(extract_from_date("DAY", date).unwrap(), 22.into());
         assert_eq!(extract_from_date("MONTH", date).unwrap(), 11.into());
+        assert_eq!(extract_from_date("QUARTER", date).unwrap(), 4.into());
+        assert_eq!(extract_from_date("WEEK", date).unwrap(), 47.into());
         assert_eq!(extract_from_date("YEAR", date).unwrap(), 2021.into());
+        assert_eq!(extract_from_date("ISOYEAR", date).unwrap(), 2021.into());
+        assert_eq!(extract_from_date("DECADE", date).unwrap(), 202.into());
+        assert_eq!(extract_from_date("CENTURY", date).unwrap(), 21.into());
+        assert_eq!(extract_from_date("MILLENNIUM", date).unwrap(), 3.into());
         assert_eq!(extract_from_date("DOW", date).unwrap(), 1.into());
+        assert_eq!(extract_from_date("ISODOW", date).unwrap(), 1.into());
         assert_eq!(extract_from_date("DOY", date).unwrap(), 326.into());
+        assert_eq!(extract_from_date("EPOCH", date).unwrap(), 1637539200.into());
     }
 
     #[test]
@@ -93,5 +117,17 @@ mod tests {
         assert_eq!(extract_from_timestamp("HOUR", time).unwrap(), 12.into());
         assert_eq!(extract_from_timestamp("MINUTE", time).unwrap(), 4.into());
         assert_eq!(extract_from_timestamp("SECOND", time).unwrap(), 2.into());
+        assert_eq!(
+            extract_from_timestamp("MILLISECONDS", time).unwrap(),
+            2000.into()
+        );
+        assert_eq!(
+            extract_from_timestamp("MICROSECONDS", time).unwrap(),
+            2000000.into()
+        );
+        assert_eq!(
+            extract_from_timestamp("EPOCH", time).unwrap(),
+            1637582642.into()
+        );
     }
 }
@@ -47,6 +47,7 @@ pub mod trim;
 pub mod trim_characters;
 pub mod tumble;
 pub mod upper;
+pub mod width_bucket;
 
 #[cfg(test)]
 mod tests;
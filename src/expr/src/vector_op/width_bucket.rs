@@ -0,0 +1,103 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_traits::Float;
+use risingwave_common::types::OrderedF64;
+
+use crate::{ExprError, Result};
+
+/// Returns the number of the bucket in which `operand` falls in a histogram having `count`
+/// equal-width buckets spanning the range `low` to `high`. Returns `0` or `count + 1` for an
+/// operand outside that range, following PostgreSQL's `width_bucket`.
+pub fn width_bucket(
+    operand: OrderedF64,
+    low: OrderedF64,
+    high: OrderedF64,
+    count: i32,
+) -> Result<i32> {
+    if count <= 0 {
+        return Err(ExprError::InvalidParam {
+            name: "count",
+            reason: "count must be greater than zero".to_string(),
+        });
+    }
+    if operand.is_nan() || low.is_nan() || high.is_nan() {
+        return Err(ExprError::InvalidParam {
+            name: "operand",
+            reason: "operand, lower bound, and upper bound cannot be NaN".to_string(),
+        });
+    }
+    if low.is_infinite() || high.is_infinite() {
+        return Err(ExprError::InvalidParam {
+            name: "low",
+            reason: "lower and upper bounds must be finite".to_string(),
+        });
+    }
+    if low == high {
+        return Err(ExprError::InvalidParam {
+            name: "low",
+            reason: "lower bound cannot equal upper bound".to_string(),
+        });
+    }
+
+    let bucket = if low < high {
+        if operand < low {
+            return Ok(0);
+        } else if operand >= high {
+            count.checked_add(1).ok_or(ExprError::NumericOutOfRange)?
+        } else {
+            (((operand - low) / (high - low)).0 * count as f64) as i32 + 1
+        }
+    } else {
+        // The bounds are reversed, so the buckets count down from `high` to `low`.
+        if operand > low {
+            return Ok(0);
+        } else if operand <= high {
+            count.checked_add(1).ok_or(ExprError::NumericOutOfRange)?
+        } else {
+            (((low - operand) / (low - high)).0 * count as f64) as i32 + 1
+        }
+    };
+    Ok(bucket)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_width_bucket() {
+        assert_eq!(
+            width_bucket(5.35.into(), 0.024.into(), 10.06.into(), 5).unwrap(),
+            3
+        );
+        // Out-of-range operands fall into the two unbounded buckets.
+        assert_eq!(
+            width_bucket((-1.0).into(), 0.0.into(), 10.0.into(), 5).unwrap(),
+            0
+        );
+        assert_eq!(
+            width_bucket(11.0.into(), 0.0.into(), 10.0.into(), 5).unwrap(),
+            6
+        );
+        // Reversed bounds count down.
+        assert_eq!(
+            width_bucket(5.35.into(), 10.06.into(), 0.024.into(), 5).unwrap(),
+            3
+        );
+        assert!(width_bucket(5.0.into(), 0.0.into(), 10.0.into(), 0).is_err());
+        assert!(width_bucket(5.0.into(), 10.0.into(), 10.0.into(), 5).is_err());
+        assert!(width_bucket(f64::NAN.into(), 0.0.into(), 10.0.into(), 5).is_err());
+    }
+}
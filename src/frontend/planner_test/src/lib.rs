@@ -371,6 +371,7 @@ impl TestCase {
                     source_schema,
                     source_watermarks,
                     append_only,
+                    on_conflict,
                     ..
                 } => {
                     create_table::handle_create_table(
@@ -382,6 +383,7 @@ impl TestCase {
                         source_schema,
                         source_watermarks,
                         append_only,
+                        on_conflict,
                     )
                     .await?;
                 }
@@ -551,7 +553,10 @@ impl TestCase {
                 || self.batch_plan_proto.is_some()
                 || self.batch_error.is_some()
             {
-                let batch_plan = match logical_plan.gen_batch_distributed_plan() {
+                let batch_plan = match logical_plan
+                    .gen_batch_plan()
+                    .and_then(|batch_plan| logical_plan.gen_batch_distributed_plan(batch_plan))
+                {
                     Ok(batch_plan) => batch_plan,
                     Err(err) => {
                         ret.batch_error = Some(err.to_string());
@@ -575,7 +580,10 @@ impl TestCase {
 
         'local_batch: {
             if self.batch_local_plan.is_some() || self.batch_local_error.is_some() {
-                let batch_plan = match logical_plan.gen_batch_local_plan() {
+                let batch_plan = match logical_plan
+                    .gen_batch_plan()
+                    .and_then(|batch_plan| logical_plan.gen_batch_local_plan(batch_plan))
+                {
                     Ok(batch_plan) => batch_plan,
                     Err(err) => {
                         ret.batch_local_error = Some(err.to_string());
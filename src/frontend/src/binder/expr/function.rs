@@ -24,7 +24,7 @@ use risingwave_common::catalog::PG_CATALOG_SCHEMA_NAME;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::session_config::USER_NAME_WILD_CARD;
 use risingwave_common::types::{DataType, ScalarImpl};
-use risingwave_common::{GIT_SHA, RW_VERSION};
+use risingwave_common::{GIT_SHA, PG_VERSION, RW_VERSION};
 use risingwave_expr::expr::AggKind;
 use risingwave_sqlparser::ast::{Function, FunctionArg, FunctionArgExpr, WindowSpec};
 
@@ -335,6 +335,7 @@ impl Binder {
                 ("abs", raw_call(ExprType::Abs)),
                 ("exp", raw_call(ExprType::Exp)),
                 ("mod", raw_call(ExprType::Modulus)),
+                ("width_bucket", raw_call(ExprType::WidthBucket)),
                 (
                     "to_timestamp",
                     dispatch_by_len(vec![
@@ -512,9 +513,9 @@ impl Binder {
                 }))),
                 // internal
                 ("rw_vnode", raw_call(ExprType::Vnode)),
-                // TODO: choose which pg version we should return.
                 ("version", raw_literal(ExprImpl::literal_varchar(format!(
-                    "PostgreSQL 13.9-RisingWave-{} ({})",
+                    "PostgreSQL {}-RisingWave-{} ({})",
+                    PG_VERSION,
                     RW_VERSION,
                     GIT_SHA
                 )))),
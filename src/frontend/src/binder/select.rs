@@ -116,6 +116,19 @@ impl BoundDistinct {
 
 impl Binder {
     pub(super) fn bind_select(&mut self, select: Select) -> Result<BoundSelect> {
+        // `SELECT INTO` is handled as `CREATE TABLE AS` before binding, so reaching here means it
+        // appeared in a context where it is not allowed, e.g. in a subquery or in the definition
+        // of a materialized view. Note that a table created by `SELECT INTO` holds a one-time
+        // snapshot of the query result, unlike a materialized view, which is kept up to date.
+        if let Some(into) = &select.into {
+            return Err(ErrorCode::BindError(format!(
+                "SELECT INTO {} is only allowed as a top-level batch query. To maintain the \
+                 query result incrementally, use CREATE MATERIALIZED VIEW instead",
+                into
+            ))
+            .into());
+        }
+
         // Bind FROM clause.
         let from = self.bind_vec_table_with_joins(select.from)?;
 
@@ -14,11 +14,14 @@
 
 use std::collections::BTreeMap;
 
+use itertools::Itertools;
 use risingwave_common::catalog::ColumnCatalog;
 use risingwave_pb::catalog::{Source as ProstSource, StreamSourceInfo, WatermarkDesc};
+use risingwave_pb::plan_common::RowFormatType;
 
 use super::{ColumnId, RelationCatalog, SourceId};
 use crate::user::UserId;
+use crate::utils::{is_secret_option, SECRET_MASK};
 use crate::WithOptions;
 
 /// This struct `SourceCatalog` is used in frontend.
@@ -37,6 +40,97 @@ pub struct SourceCatalog {
     pub watermark_descs: Vec<WatermarkDesc>,
 }
 
+impl SourceCatalog {
+    /// Returns a SQL statement that can be used to re-create this source.
+    ///
+    /// Unlike tables, the original definition of a source is not persisted in
+    /// the catalog, so the statement is reconstructed from the catalog.
+    /// Secret values in the `WITH` clause are masked.
+    pub fn create_sql(&self) -> String {
+        let columns = self
+            .columns
+            .iter()
+            .filter(|c| !c.is_hidden())
+            .map(|c| format!("{} {}", c.name(), c.data_type()))
+            .join(", ");
+        let pk = if self.row_id_index.is_none() && !self.pk_col_ids.is_empty() {
+            let pk_names = self
+                .pk_col_ids
+                .iter()
+                .filter_map(|id| {
+                    self.columns
+                        .iter()
+                        .find(|c| c.column_id() == *id)
+                        .map(|c| c.name())
+                })
+                .join(", ");
+            format!(", PRIMARY KEY ({})", pk_names)
+        } else {
+            String::new()
+        };
+        let with_options = self
+            .properties
+            .iter()
+            .map(|(k, v)| {
+                if is_secret_option(k) {
+                    format!("{} = '{}'", k, SECRET_MASK)
+                } else {
+                    format!("{} = '{}'", k, v)
+                }
+            })
+            .join(", ");
+        format!(
+            "CREATE SOURCE {} ({}{}) WITH ({}) ROW FORMAT {}",
+            self.name,
+            columns,
+            pk,
+            with_options,
+            row_format_display(&self.info),
+        )
+    }
+}
+
+/// Formats the `ROW FORMAT` clause of a source from its catalog info,
+/// mirroring the `Display` of `SourceSchema` in the parser.
+fn row_format_display(info: &StreamSourceInfo) -> String {
+    let schema_location = || {
+        if info.use_schema_registry {
+            format!(
+                "ROW SCHEMA LOCATION CONFLUENT SCHEMA REGISTRY '{}'",
+                info.row_schema_location
+            )
+        } else {
+            format!("ROW SCHEMA LOCATION '{}'", info.row_schema_location)
+        }
+    };
+    match info.row_format() {
+        RowFormatType::Json => "JSON".to_string(),
+        RowFormatType::UpsertJson => "UPSERT JSON".to_string(),
+        RowFormatType::DebeziumJson => "DEBEZIUM JSON".to_string(),
+        RowFormatType::Maxwell => "MAXWELL".to_string(),
+        RowFormatType::CanalJson => "CANAL JSON".to_string(),
+        RowFormatType::Protobuf => format!(
+            "PROTOBUF MESSAGE '{}' {}",
+            info.proto_message_name,
+            schema_location()
+        ),
+        RowFormatType::Avro => format!("AVRO {}", schema_location()),
+        RowFormatType::UpsertAvro => format!("UPSERT AVRO {}", schema_location()),
+        RowFormatType::DebeziumAvro => format!("DEBEZIUM AVRO {}", schema_location()),
+        RowFormatType::Csv => format!(
+            "CSV {}DELIMITED BY '{}'",
+            if info.csv_has_header {
+                ""
+            } else {
+                "WITHOUT HEADER "
+            },
+            info.csv_delimiter as u8 as char,
+        ),
+        RowFormatType::Native => "NATIVE".to_string(),
+        RowFormatType::RowUnspecified => "UNSPECIFIED".to_string(),
+    }
+}
+
 impl From<&ProstSource> for SourceCatalog {
     fn from(prost: &ProstSource) -> Self {
         let id = prost.id;
@@ -35,7 +35,9 @@ use crate::catalog::system_catalog::pg_catalog::*;
 use crate::catalog::system_catalog::rw_catalog::*;
 use crate::meta_client::FrontendMetaClient;
 use crate::scheduler::worker_node_manager::WorkerNodeManagerRef;
+use crate::scheduler::QueryManager;
 use crate::session::AuthContext;
+use crate::user::user_query_stats::UserQueryStatsManagerRef;
 use crate::user::user_service::UserInfoReader;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -81,7 +83,6 @@ impl SystemCatalog {
     }
 }
 
-#[expect(dead_code)]
 pub struct SysCatalogReaderImpl {
     // Read catalog info: database/schema/source/table.
     catalog_reader: CatalogReader,
@@ -92,6 +93,10 @@ pub struct SysCatalogReaderImpl {
     // Read from meta.
     meta_client: Arc<dyn FrontendMetaClient>,
     auth_context: Arc<AuthContext>,
+    // Read the status of the distributed query scheduler on this frontend.
+    query_manager: QueryManager,
+    // Read the per-user query statistics collected on this frontend.
+    user_query_stats_manager: UserQueryStatsManagerRef,
 }
 
 impl SysCatalogReaderImpl {
@@ -101,6 +106,8 @@ impl SysCatalogReaderImpl {
         worker_node_manager: WorkerNodeManagerRef,
         meta_client: Arc<dyn FrontendMetaClient>,
         auth_context: Arc<AuthContext>,
+        query_manager: QueryManager,
+        user_query_stats_manager: UserQueryStatsManagerRef,
     ) -> Self {
         Self {
             catalog_reader,
@@ -108,6 +115,8 @@ impl SysCatalogReaderImpl {
             worker_node_manager,
             meta_client,
             auth_context,
+            query_manager,
+            user_query_stats_manager,
         }
     }
 }
@@ -207,4 +216,7 @@ prepare_sys_catalog! {
     { INFORMATION_SCHEMA, TABLES, vec![], read_tables_info },
     { RW_CATALOG, RW_META_SNAPSHOT, vec![], read_meta_snapshot await },
     { RW_CATALOG, RW_DDL_PROGRESS, vec![], read_ddl_progress await },
+    { RW_CATALOG, RW_DISTRIBUTED_QUERY_STATUS, vec![], read_distributed_query_status },
+    { RW_CATALOG, RW_USER_QUERY_STATS, vec![0], read_user_query_stats },
+    { RW_CATALOG, RW_WORKER_HEALTH, vec![0], read_worker_health },
 }
@@ -238,12 +238,63 @@ impl SysCatalogReaderImpl {
                     Some(ScalarImpl::Int64(s.id as i64)),
                     Some(ScalarImpl::Utf8(s.statement.into())),
                     Some(ScalarImpl::Utf8(s.progress.into())),
+                    Some(ScalarImpl::Utf8(s.eta.into())),
                 ])
             })
             .collect_vec();
         Ok(ddl_grogress)
     }
 
+    /// Note that each frontend node schedules its distributed queries independently, so the
+    /// numbers only reflect the frontend node serving this query.
+    pub(super) fn read_distributed_query_status(&self) -> Result<Vec<OwnedRow>> {
+        Ok(vec![OwnedRow::new(vec![
+            Some(ScalarImpl::Int64(self.query_manager.running_query_num())),
+            Some(ScalarImpl::Int64(self.query_manager.queued_query_num())),
+            self.query_manager
+                .disrtibuted_query_limit()
+                .map(|limit| ScalarImpl::Int64(limit as i64)),
+        ])])
+    }
+
+    /// Note that the statistics are collected by each frontend node independently, so the numbers
+    /// only reflect the statements served by the frontend node serving this query.
+    pub(super) fn read_user_query_stats(&self) -> Result<Vec<OwnedRow>> {
+        Ok(self
+            .user_query_stats_manager
+            .snapshot()
+            .into_iter()
+            .map(|(user_name, stats)| {
+                OwnedRow::new(vec![
+                    Some(ScalarImpl::Utf8(user_name.into())),
+                    Some(ScalarImpl::Int64(stats.query_count as i64)),
+                    Some(ScalarImpl::Int64(stats.failed_query_count as i64)),
+                    Some(ScalarImpl::Int64(stats.dml_row_count as i64)),
+                ])
+            })
+            .collect_vec())
+    }
+
+    /// Note that worker health is observed by each frontend node independently, so the rows only
+    /// reflect the RPCs issued by the frontend node serving this query.
+    pub(super) fn read_worker_health(&self) -> Result<Vec<OwnedRow>> {
+        Ok(self
+            .worker_node_manager
+            .worker_health()
+            .into_iter()
+            .map(|health| {
+                OwnedRow::new(vec![
+                    Some(ScalarImpl::Int32(health.worker_id as i32)),
+                    Some(ScalarImpl::Int64(health.recent_failure_count as i64)),
+                    health
+                        .rpc_latency_ms
+                        .map(|ms| ScalarImpl::Float64(ms.into())),
+                    Some(ScalarImpl::Bool(health.blacklisted)),
+                ])
+            })
+            .collect_vec())
+    }
+
     // FIXME(noel): Tracked by <https://github.com/risingwavelabs/risingwave/issues/3431#issuecomment-1164160988>
     pub(super) fn read_opclass_info(&self) -> Result<Vec<OwnedRow>> {
         Ok(vec![])
@@ -13,7 +13,13 @@
 // limitations under the License.
 
 mod rw_ddl_progress;
+mod rw_distributed_query_status;
 mod rw_meta_snapshot;
+mod rw_user_query_stats;
+mod rw_worker_health;
 
 pub use rw_ddl_progress::*;
+pub use rw_distributed_query_status::*;
 pub use rw_meta_snapshot::*;
+pub use rw_user_query_stats::*;
+pub use rw_worker_health::*;
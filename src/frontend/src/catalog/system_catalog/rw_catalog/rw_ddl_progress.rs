@@ -22,4 +22,5 @@ pub const RW_DDL_PROGRESS_COLUMNS: &[SystemCatalogColumnsDef<'_>] = &[
     (DataType::Int64, "ddl_id"),
     (DataType::Varchar, "ddl_statement"),
     (DataType::Varchar, "progress"),
+    (DataType::Varchar, "eta"),
 ];
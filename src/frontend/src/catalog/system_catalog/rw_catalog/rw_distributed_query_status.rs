@@ -0,0 +1,28 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::DataType;
+
+use crate::catalog::system_catalog::SystemCatalogColumnsDef;
+
+pub const RW_DISTRIBUTED_QUERY_STATUS_TABLE_NAME: &str = "rw_distributed_query_status";
+
+/// The status of the distributed query scheduler. `distributed_query_limit` is NULL when there's
+/// no limit on concurrently running queries. Note that each frontend node schedules its queries
+/// independently, so the numbers only reflect the frontend node serving this query.
+pub const RW_DISTRIBUTED_QUERY_STATUS_COLUMNS: &[SystemCatalogColumnsDef<'_>] = &[
+    (DataType::Int64, "running_query_num"),
+    (DataType::Int64, "queued_query_num"),
+    (DataType::Int64, "distributed_query_limit"),
+];
@@ -0,0 +1,31 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::DataType;
+
+use crate::catalog::system_catalog::SystemCatalogColumnsDef;
+
+pub const RW_WORKER_HEALTH_TABLE_NAME: &str = "rw_worker_health";
+
+/// The health of the compute nodes as observed by the batch RPCs of this frontend node.
+/// `rpc_latency_ms` is a moving average and is NULL until the first successful RPC. A
+/// `blacklisted` worker is temporarily excluded from batch scheduling because it failed
+/// repeatedly within a short window. Note that each frontend node observes worker health
+/// independently, so the rows only reflect the frontend node serving this query.
+pub const RW_WORKER_HEALTH_COLUMNS: &[SystemCatalogColumnsDef<'_>] = &[
+    (DataType::Int32, "worker_id"),
+    (DataType::Int64, "recent_failure_count"),
+    (DataType::Float64, "rpc_latency_ms"),
+    (DataType::Boolean, "blacklisted"),
+];
@@ -0,0 +1,251 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An Arrow Flight endpoint for high-throughput result retrieval.
+//!
+//! The service accepts a `SELECT` statement as the ticket of a `DoGet` call, executes it through
+//! the same binder, planner and scheduler as the pgwire path, and streams the result chunks back
+//! as Arrow record batches. This spares clients that consume large result sets the per-row pgwire
+//! encoding.
+//!
+//! Authentication is not supported yet: queries run against the default database as the default
+//! super user, and only read-only statements are accepted.
+
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_flight::encode::FlightDataEncoderBuilder;
+use arrow_flight::error::FlightError;
+use arrow_flight::flight_service_server::{FlightService, FlightServiceServer};
+use arrow_flight::{
+    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightInfo,
+    HandshakeRequest, HandshakeResponse, PutResult, SchemaResult, Ticket,
+};
+use arrow_schema::{ArrowError, Field, Schema as ArrowSchema, SchemaRef};
+use futures::stream::BoxStream;
+use futures::{stream, Stream, StreamExt, TryStreamExt};
+use pgwire::pg_server::SessionManager;
+use risingwave_common::array::DataChunk;
+use risingwave_common::catalog::{DEFAULT_DATABASE_NAME, DEFAULT_SUPER_USER};
+use risingwave_common::session_config::QueryMode;
+use risingwave_sqlparser::ast::Statement;
+use risingwave_sqlparser::parser::Parser;
+use tonic::{Request, Response, Status, Streaming};
+
+use crate::handler::query::{distribute_execute, gen_batch_query_plan, local_execute};
+use crate::handler::HandlerArgs;
+use crate::optimizer::OptimizerContext;
+use crate::scheduler::{BatchPlanFragmenter, PinnedHummockSnapshot};
+use crate::session::SessionManagerImpl;
+
+pub struct FlightServiceImpl {
+    session_mgr: Arc<SessionManagerImpl>,
+}
+
+impl FlightServiceImpl {
+    pub fn new(session_mgr: Arc<SessionManagerImpl>) -> Self {
+        Self { session_mgr }
+    }
+
+    pub fn into_server(self) -> FlightServiceServer<Self> {
+        FlightServiceServer::new(self)
+    }
+
+    /// Plan and execute `sql`, returning the result as a stream of Arrow record batches.
+    async fn execute(
+        &self,
+        sql: &str,
+    ) -> Result<impl Stream<Item = Result<RecordBatch, FlightError>> + Send + 'static, Status>
+    {
+        let session = self
+            .session_mgr
+            .connect(DEFAULT_DATABASE_NAME, DEFAULT_SUPER_USER)
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        let mut stmts = Parser::parse_sql(sql)
+            .map_err(|e| Status::invalid_argument(format!("failed to parse ticket: {}", e)))?;
+        if stmts.len() != 1 {
+            return Err(Status::invalid_argument(
+                "ticket must contain exactly one statement",
+            ));
+        }
+        let stmt = stmts.remove(0);
+        if !matches!(stmt, Statement::Query(_)) {
+            return Err(Status::unimplemented(
+                "only query statements are supported on the Flight endpoint",
+            ));
+        }
+
+        let handler_args = HandlerArgs::new(session.clone(), &stmt, sql)
+            .map_err(|e| Status::internal(e.to_string()))?;
+        // Subblock to make sure PlanRef (an Rc) is dropped before `await` below.
+        let (plan_fragmenter, query_mode, output_schema) = {
+            let context = OptimizerContext::from_handler_args(handler_args);
+            let (plan, query_mode, schema) = gen_batch_query_plan(&session, context.into(), stmt)
+                .map_err(|e| Status::internal(e.to_string()))?;
+            let plan_fragmenter = BatchPlanFragmenter::new(
+                session.env().worker_node_manager_ref(),
+                session.env().catalog_reader().clone(),
+                plan,
+            )
+            .map_err(|e| Status::internal(e.to_string()))?;
+            (plan_fragmenter, query_mode, schema)
+        };
+        let query = plan_fragmenter
+            .generate_complete_query()
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        let arrow_schema: SchemaRef = Arc::new(ArrowSchema::new(
+            output_schema
+                .fields()
+                .iter()
+                .map(|f| Field::new(&f.name, (&f.data_type()).into(), true))
+                .collect(),
+        ));
+
+        let query_snapshot = if let Some(query_epoch) = session.config().get_query_epoch() {
+            PinnedHummockSnapshot::Other(query_epoch)
+        } else {
+            let only_checkpoint_visible = session.config().only_checkpoint_visible();
+            let pinned_snapshot = session
+                .env()
+                .hummock_snapshot_manager()
+                .acquire(query.query_id())
+                .await
+                .map_err(|e| Status::internal(e.to_string()))?;
+            PinnedHummockSnapshot::FrontendPinned(pinned_snapshot, only_checkpoint_visible)
+        };
+        let chunk_stream = match query_mode {
+            QueryMode::Auto => unreachable!(),
+            QueryMode::Local => local_execute(session.clone(), query, query_snapshot)
+                .await
+                .map_err(|e| Status::internal(e.to_string()))?
+                .boxed(),
+            QueryMode::Distributed => distribute_execute(session.clone(), query, query_snapshot)
+                .await
+                .map_err(|e| Status::internal(e.to_string()))?
+                .boxed(),
+        };
+
+        // Always emit an empty head batch so that the schema reaches the client even when the
+        // result is empty.
+        let head_schema = arrow_schema.clone();
+        Ok(
+            stream::once(async move { Ok(RecordBatch::new_empty(head_schema)) }).chain(
+                chunk_stream.map(move |chunk| match chunk {
+                    Ok(chunk) => {
+                        to_record_batch(arrow_schema.clone(), &chunk).map_err(FlightError::Arrow)
+                    }
+                    Err(e) => Err(FlightError::ExternalError(e)),
+                }),
+            ),
+        )
+    }
+}
+
+fn to_record_batch(schema: SchemaRef, chunk: &DataChunk) -> Result<RecordBatch, ArrowError> {
+    let columns = chunk
+        .columns()
+        .iter()
+        .map(|column| column.array_ref().into())
+        .collect();
+    RecordBatch::try_new(schema, columns)
+}
+
+fn flight_error_to_status(e: FlightError) -> Status {
+    match e {
+        FlightError::Tonic(status) => status,
+        e => Status::internal(e.to_string()),
+    }
+}
+
+#[async_trait::async_trait]
+impl FlightService for FlightServiceImpl {
+    type DoActionStream = BoxStream<'static, Result<arrow_flight::Result, Status>>;
+    type DoExchangeStream = BoxStream<'static, Result<FlightData, Status>>;
+    type DoGetStream = BoxStream<'static, Result<FlightData, Status>>;
+    type DoPutStream = BoxStream<'static, Result<PutResult, Status>>;
+    type HandshakeStream = BoxStream<'static, Result<HandshakeResponse, Status>>;
+    type ListActionsStream = BoxStream<'static, Result<ActionType, Status>>;
+    type ListFlightsStream = BoxStream<'static, Result<FlightInfo, Status>>;
+
+    async fn handshake(
+        &self,
+        _request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<Self::HandshakeStream>, Status> {
+        Err(Status::unimplemented("handshake is not supported"))
+    }
+
+    async fn list_flights(
+        &self,
+        _request: Request<Criteria>,
+    ) -> Result<Response<Self::ListFlightsStream>, Status> {
+        Err(Status::unimplemented("list_flights is not supported"))
+    }
+
+    async fn get_flight_info(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(Status::unimplemented("get_flight_info is not supported"))
+    }
+
+    async fn get_schema(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<SchemaResult>, Status> {
+        Err(Status::unimplemented("get_schema is not supported"))
+    }
+
+    async fn do_get(&self, request: Request<Ticket>) -> Result<Response<Self::DoGetStream>, Status> {
+        let ticket = request.into_inner();
+        let sql = std::str::from_utf8(&ticket.ticket)
+            .map_err(|_| Status::invalid_argument("ticket must be a UTF-8 SQL string"))?
+            .to_string();
+        let batches = self.execute(&sql).await?;
+        let stream = FlightDataEncoderBuilder::new()
+            .build(batches)
+            .map_err(flight_error_to_status);
+        Ok(Response::new(stream.boxed()))
+    }
+
+    async fn do_put(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoPutStream>, Status> {
+        Err(Status::unimplemented("do_put is not supported"))
+    }
+
+    async fn do_exchange(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoExchangeStream>, Status> {
+        Err(Status::unimplemented("do_exchange is not supported"))
+    }
+
+    async fn do_action(
+        &self,
+        _request: Request<Action>,
+    ) -> Result<Response<Self::DoActionStream>, Status> {
+        Err(Status::unimplemented("do_action is not supported"))
+    }
+
+    async fn list_actions(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<Self::ListActionsStream>, Status> {
+        Err(Status::unimplemented("list_actions is not supported"))
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_sqlparser::ast::{ObjectName, SqlOption};
+
+use super::RwPgResponse;
+use crate::binder::Binder;
+use crate::catalog::root_catalog::SchemaPath;
+use crate::catalog::table_catalog::TableType;
+use crate::handler::HandlerArgs;
+
+/// Runtime parameters that can be tuned with `ALTER MATERIALIZED VIEW ... SET (...)`.
+const ALTERABLE_PARAMETERS: &[&str] = &["stream_chunk_size", "executor_cache_mb"];
+
+pub async fn handle_alter_mv(
+    handler_args: HandlerArgs,
+    table_name: ObjectName,
+    set_options: Vec<SqlOption>,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let db_name = session.database();
+    let (schema_name, real_table_name) =
+        Binder::resolve_schema_qualified_name(db_name, table_name.clone())?;
+    let search_path = session.config().get_search_path();
+    let user_name = &session.auth_context().user_name;
+
+    let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+    {
+        let reader = session.env().catalog_reader().read_guard();
+        let (table, schema_name) =
+            reader.get_table_by_name(db_name, schema_path, &real_table_name)?;
+
+        match table.table_type() {
+            TableType::MaterializedView => {}
+            _ => {
+                return Err(ErrorCode::InvalidInputSyntax(format!(
+                    "\"{table_name}\" is not a materialized view"
+                ))
+                .into())
+            }
+        }
+
+        session.check_privilege_for_drop_alter(schema_name, &**table)?;
+    }
+
+    for option in &set_options {
+        let param = option.name.real_value();
+        if !ALTERABLE_PARAMETERS.contains(&param.as_str()) {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "unrecognized parameter \"{}\", valid parameters are: {}",
+                param,
+                ALTERABLE_PARAMETERS.join(", ")
+            ))
+            .into());
+        }
+    }
+
+    // TODO: propagate the new parameters to the actors of this job with a config-change barrier,
+    // so that tuning does not require dropping and recreating the materialized view.
+    Err(ErrorCode::NotImplemented(
+        "ALTER MATERIALIZED VIEW ... SET: runtime propagation of job parameters is not supported \
+         yet"
+        .to_string(),
+        None.into(),
+    )
+    .into())
+}
@@ -20,6 +20,7 @@ use itertools::Itertools;
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::catalog::{IndexId, TableDesc, TableId};
 use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::DataType;
 use risingwave_common::util::sort_util::{OrderPair, OrderType};
 use risingwave_pb::catalog::{Index as ProstIndex, Table as ProstTable};
 use risingwave_pb::stream_plan::stream_fragment_graph::Parallelism;
@@ -371,6 +372,102 @@ fn check_columns(columns: Vec<OrderByExpr>) -> Result<Vec<(Ident, OrderType)>> {
         .try_collect::<_, Vec<_>, _>()
 }
 
+/// Automatically create an index on the first timestamp column of the given append-only table,
+/// so that range filters on the time column can be answered with an index range scan plus point
+/// lookups on the primary table instead of a full table scan. This is a no-op if the table has
+/// no timestamp column, if its primary key already leads with the timestamp column, or if the
+/// index name is already taken.
+pub(crate) async fn auto_create_ts_index(
+    mut handler_args: HandlerArgs,
+    table_name: ObjectName,
+) -> Result<()> {
+    let session = handler_args.session.clone();
+    let db_name = session.database();
+    let (schema_name, real_table_name) =
+        Binder::resolve_schema_qualified_name(db_name, table_name.clone())?;
+    let search_path = session.config().get_search_path();
+    let user_name = &session.auth_context().user_name;
+    let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+    let ts_column_name = {
+        let read_guard = session.env().catalog_reader().read_guard();
+        let (table, _) = read_guard.get_table_by_name(db_name, schema_path, &real_table_name)?;
+        let Some((ts_column_idx, ts_column)) = table.columns().iter().enumerate().find(|(_, c)| {
+            !c.is_hidden
+                && matches!(c.data_type(), DataType::Timestamp | DataType::Timestamptz)
+        }) else {
+            return Ok(());
+        };
+        // A time-leading primary key already supports time-range scans.
+        if table.pk().first().map(|field| field.index) == Some(ts_column_idx) {
+            return Ok(());
+        }
+        ts_column.name().to_owned()
+    };
+
+    let index_name = format!("{}_{}_idx", real_table_name, ts_column_name);
+    let index_name_obj = {
+        let mut idents = table_name.0.clone();
+        *idents.last_mut().unwrap() = Ident::new_unchecked(index_name.clone());
+        ObjectName(idents)
+    };
+    if session
+        .check_relation_name_duplicated(index_name_obj.clone())
+        .is_err()
+    {
+        return Ok(());
+    }
+
+    // The index is created on behalf of the user, so synthesize a definition for
+    // `SHOW CREATE INDEX`.
+    handler_args.normalized_sql = format!(
+        "CREATE INDEX {} ON {}({})",
+        index_name, real_table_name, ts_column_name
+    );
+    let columns = vec![OrderByExpr {
+        expr: risingwave_sqlparser::ast::Expr::Identifier(Ident::new_unchecked(ts_column_name)),
+        asc: None,
+        nulls_first: None,
+    }];
+
+    let (graph, index_table, index) = {
+        let context = OptimizerContext::from_handler_args(handler_args);
+        let (plan, index_table, index) = gen_create_index_plan(
+            &session,
+            context.into(),
+            index_name_obj,
+            table_name,
+            columns,
+            vec![],
+            vec![],
+        )?;
+        let mut graph = build_graph(plan);
+        graph.parallelism = session
+            .config()
+            .get_streaming_parallelism()
+            .map(|parallelism| Parallelism { parallelism });
+        (graph, index_table, index)
+    };
+
+    let _job_guard =
+        session
+            .env()
+            .creating_streaming_job_tracker()
+            .guard(CreatingStreamingJobInfo::new(
+                session.session_id(),
+                index.database_id,
+                index.schema_id,
+                index.name.clone(),
+            ));
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer
+        .create_index(index, index_table, graph)
+        .await?;
+
+    Ok(())
+}
+
 pub async fn handle_create_index(
     handler_args: HandlerArgs,
     if_not_exists: bool,
@@ -12,11 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
+use itertools::Itertools;
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::catalog::{DatabaseId, SchemaId, UserId};
-use risingwave_common::error::Result;
+use risingwave_common::error::{ErrorCode, Result};
 use risingwave_connector::sink::catalog::SinkCatalog;
+use risingwave_connector::sink::{
+    TABLE_SINK, TABLE_SINK_ID_OPTION, TABLE_SINK_ROW_ID_INDEX_OPTION,
+    TABLE_SINK_VERSION_ID_OPTION,
+};
 use risingwave_pb::stream_plan::stream_fragment_graph::Parallelism;
+use risingwave_pb::user::grant_privilege::{Action, Object};
 use risingwave_sqlparser::ast::{
     CreateSink, CreateSinkStatement, ObjectName, Query, Select, SelectItem, SetExpr, TableFactor,
     TableWithJoins,
@@ -25,12 +33,16 @@ use risingwave_sqlparser::ast::{
 use super::create_mv::get_column_names;
 use super::RwPgResponse;
 use crate::binder::Binder;
+use crate::catalog::root_catalog::SchemaPath;
+use crate::catalog::table_catalog::TableCatalog;
+use crate::handler::privilege::ObjectCheckItem;
 use crate::handler::HandlerArgs;
 use crate::optimizer::plan_node::Explain;
 use crate::optimizer::{OptimizerContext, OptimizerContextRef, PlanRef};
 use crate::scheduler::streaming_manager::CreatingStreamingJobInfo;
 use crate::session::SessionImpl;
 use crate::stream_fragmenter::build_graph;
+use crate::utils::WithOptions;
 use crate::Planner;
 
 pub fn gen_sink_query_from_name(from_name: ObjectName) -> Result<Query> {
@@ -77,6 +89,14 @@ pub fn gen_sink_plan(
 
     let definition = context.normalized_sql().to_owned();
 
+    let mut properties = context.with_options().clone();
+    // A sink with `INTO` writes into another table in the cluster through the DML path. Resolve
+    // the target table and turn the sink into an internal `table` sink.
+    let target_table = stmt
+        .into_table_name
+        .map(|into_table_name| resolve_sink_into_table(session, into_table_name, &mut properties))
+        .transpose()?;
+
     let bound = {
         let mut binder = Binder::new(session);
         binder.bind_query(*query)?
@@ -85,8 +105,6 @@ pub fn gen_sink_plan(
     // If colume names not specified, use the name in materialized view.
     let col_names = get_column_names(&bound, session, stmt.columns)?;
 
-    let properties = context.with_options().clone();
-
     let mut plan_root = Planner::new(context).plan_query(bound)?;
     if let Some(col_names) = col_names {
         plan_root.set_out_names(col_names)?;
@@ -99,9 +117,15 @@ pub fn gen_sink_plan(
         SchemaId::new(sink_schema_id),
         DatabaseId::new(sink_database_id),
         UserId::new(session.user_id()),
-        vec![],
+        // Record the target table as a dependency of the sink, so that it cannot be dropped
+        // while the sink is still writing into it.
+        target_table.iter().map(|table| table.id()).collect(),
     );
 
+    if let Some(table) = &target_table {
+        check_sink_into_table(&sink_catalog, table)?;
+    }
+
     let sink_plan: PlanRef = sink_plan.into();
 
     let ctx = sink_plan.ctx();
@@ -115,6 +139,112 @@ pub fn gen_sink_plan(
     Ok((sink_plan, sink_catalog))
 }
 
+/// Resolve and validate the target table of a `CREATE SINK ... INTO table` statement, and inject
+/// the internal `table` sink options for it.
+fn resolve_sink_into_table(
+    session: &SessionImpl,
+    into_table_name: ObjectName,
+    properties: &mut WithOptions,
+) -> Result<Arc<TableCatalog>> {
+    let db_name = session.database();
+    let (schema_name, table_name) =
+        Binder::resolve_schema_qualified_name(db_name, into_table_name)?;
+    let search_path = session.config().get_search_path();
+    let user_name = &session.auth_context().user_name;
+    let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+    let catalog_reader = session.env().catalog_reader();
+    let table = {
+        let read_guard = catalog_reader.read_guard();
+        let (table, _schema_name) =
+            read_guard.get_table_by_name(db_name, schema_path, &table_name)?;
+        table.clone()
+    };
+
+    if !table.is_table() {
+        return Err(ErrorCode::InvalidInputSyntax(format!(
+            "\"{}\" is not a user table",
+            table.name
+        ))
+        .into());
+    }
+    if table.has_associated_source() {
+        return Err(ErrorCode::InvalidInputSyntax(format!(
+            "cannot sink into table \"{}\" which is associated with a source",
+            table.name
+        ))
+        .into());
+    }
+    if properties.inner().contains_key("connector") {
+        return Err(ErrorCode::InvalidInputSyntax(
+            "a sink into a table must not specify a connector".to_string(),
+        )
+        .into());
+    }
+
+    // Sinking into a table is writing DML to it, so insert privilege is required.
+    session.check_privileges(&[ObjectCheckItem::new(
+        table.owner,
+        Action::Insert,
+        Object::TableId(table.id.table_id),
+    )])?;
+
+    let version_id = table
+        .version_id()
+        .expect("user table must have a version id");
+    let options = properties.inner_mut();
+    options.insert("connector".to_string(), TABLE_SINK.to_string());
+    options.insert(TABLE_SINK_ID_OPTION.to_string(), table.id.table_id.to_string());
+    options.insert(
+        TABLE_SINK_VERSION_ID_OPTION.to_string(),
+        version_id.to_string(),
+    );
+    if let Some(row_id_index) = table.row_id_index {
+        options.insert(
+            TABLE_SINK_ROW_ID_INDEX_OPTION.to_string(),
+            row_id_index.to_string(),
+        );
+    }
+
+    Ok(table)
+}
+
+/// Check that a sink into a table matches the schema of the target table and respects its
+/// primary key.
+fn check_sink_into_table(sink: &SinkCatalog, table: &TableCatalog) -> Result<()> {
+    let sink_types = sink
+        .columns
+        .iter()
+        .map(|c| c.column_desc.data_type.clone())
+        .collect_vec();
+    let table_types = table
+        .columns
+        .iter()
+        .filter(|c| !c.is_hidden())
+        .map(|c| c.data_type().clone())
+        .collect_vec();
+    if sink_types != table_types {
+        return Err(ErrorCode::InvalidInputSyntax(format!(
+            "the output of the sink query {:?} does not match the columns of table \"{}\" {:?}",
+            sink_types, table.name, table_types
+        ))
+        .into());
+    }
+
+    // Without a user-defined primary key, the target table distributes and identifies rows by
+    // the generated row id, so only append-only changes can be written to it.
+    if table.row_id_index.is_some() && !sink.sink_type.is_append_only() {
+        return Err(ErrorCode::InvalidInputSyntax(format!(
+            "the sink into table \"{}\" without a primary key must be append-only, please add \
+             \"format='append_only'\" in WITH options",
+            table.name
+        ))
+        .into());
+    }
+
+    Ok(())
+}
+
 pub async fn handle_create_sink(
     handle_args: HandlerArgs,
     stmt: CreateSinkStatement,
@@ -201,4 +331,38 @@ pub mod tests {
             .unwrap();
         assert_eq!(sink.name, "snk1");
     }
+
+    #[tokio::test]
+    async fn test_create_sink_as_query_handler() {
+        let proto_file = create_proto_file(PROTO_FILE_DATA);
+        let sql = format!(
+            r#"CREATE SOURCE t1
+    WITH (kafka.topic = 'abc', kafka.servers = 'localhost:1001')
+    ROW FORMAT PROTOBUF MESSAGE '.test.TestRecord' ROW SCHEMA LOCATION 'file://{}';"#,
+            proto_file.path().to_str().unwrap()
+        );
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend.run_sql(sql).await.unwrap();
+
+        let sql = r#"CREATE SINK snk1 AS SELECT t1.country FROM t1
+                    WITH (connector = 'mysql', mysql.endpoint = '127.0.0.1:3306', mysql.table =
+                        '<table_name>', mysql.database = '<database_name>', mysql.user = '<user_name>',
+                        mysql.password = '<password>', format = 'append_only', force_append_only = 'true');"#.to_string();
+        frontend.run_sql(sql).await.unwrap();
+
+        let session = frontend.session_ref();
+        let catalog_reader = session.env().catalog_reader().read_guard();
+        let schema_path = SchemaPath::Name(DEFAULT_SCHEMA_NAME);
+
+        // Check sink exists.
+        let (sink, _) = catalog_reader
+            .get_sink_by_name(DEFAULT_DATABASE_NAME, schema_path, "snk1")
+            .unwrap();
+        assert_eq!(sink.name, "snk1");
+
+        // An ad-hoc sink materializes no user-visible relation for its query.
+        assert!(catalog_reader
+            .get_table_by_name(DEFAULT_DATABASE_NAME, schema_path, "snk1")
+            .is_err());
+    }
 }
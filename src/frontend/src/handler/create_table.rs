@@ -19,8 +19,8 @@ use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::catalog::{
-    ColumnCatalog, ColumnDesc, TableId, TableVersionId, INITIAL_TABLE_VERSION_ID,
-    USER_COLUMN_ID_OFFSET,
+    ColumnCatalog, ColumnDesc, ConflictBehavior, TableId, TableVersionId,
+    INITIAL_TABLE_VERSION_ID, USER_COLUMN_ID_OFFSET,
 };
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_pb::catalog::{
@@ -29,10 +29,11 @@ use risingwave_pb::catalog::{
 };
 use risingwave_pb::stream_plan::stream_fragment_graph::Parallelism;
 use risingwave_sqlparser::ast::{
-    ColumnDef, ColumnOption, DataType as AstDataType, ObjectName, SourceSchema, SourceWatermark,
-    TableConstraint,
+    ColumnDef, ColumnOption, DataType as AstDataType, ObjectName, OnConflict, SourceSchema,
+    SourceWatermark, TableConstraint,
 };
 
+use super::create_index::auto_create_ts_index;
 use super::create_source::resolve_source_schema;
 use super::RwPgResponse;
 use crate::binder::{bind_data_type, bind_struct_field};
@@ -291,6 +292,7 @@ pub(crate) async fn gen_create_table_plan_with_source(
     source_watermarks: Vec<SourceWatermark>,
     mut col_id_gen: ColumnIdGenerator,
     append_only: bool,
+    on_conflict: Option<OnConflict>,
 ) -> Result<(PlanRef, Option<ProstSource>, ProstTable)> {
     let (column_descs, pk_column_id_from_columns) = bind_sql_columns(columns, &mut col_id_gen)?;
     let properties = context.with_options().inner().clone().into_iter().collect();
@@ -329,6 +331,7 @@ pub(crate) async fn gen_create_table_plan_with_source(
         definition,
         watermark_descs,
         append_only,
+        on_conflict,
         Some(col_id_gen.into_version()),
     )
 }
@@ -343,6 +346,7 @@ pub(crate) fn gen_create_table_plan(
     mut col_id_gen: ColumnIdGenerator,
     source_watermarks: Vec<SourceWatermark>,
     append_only: bool,
+    on_conflict: Option<OnConflict>,
 ) -> Result<(PlanRef, Option<ProstSource>, ProstTable)> {
     let definition = context.normalized_sql().to_owned();
     let (column_descs, pk_column_id_from_columns) = bind_sql_columns(columns, &mut col_id_gen)?;
@@ -356,6 +360,7 @@ pub(crate) fn gen_create_table_plan(
         definition,
         source_watermarks,
         append_only,
+        on_conflict,
         Some(col_id_gen.into_version()),
     )
 }
@@ -370,6 +375,7 @@ pub(crate) fn gen_create_table_plan_without_bind(
     definition: String,
     source_watermarks: Vec<SourceWatermark>,
     append_only: bool,
+    on_conflict: Option<OnConflict>,
     version: Option<TableVersion>,
 ) -> Result<(PlanRef, Option<ProstSource>, ProstTable)> {
     let (columns, pk_column_ids, row_id_index) =
@@ -392,6 +398,7 @@ pub(crate) fn gen_create_table_plan_without_bind(
         definition,
         watermark_descs,
         append_only,
+        on_conflict,
         version,
     )
 }
@@ -407,6 +414,7 @@ fn gen_table_plan_inner(
     definition: String,
     watermark_descs: Vec<WatermarkDesc>,
     append_only: bool,
+    on_conflict: Option<OnConflict>,
     version: Option<TableVersion>, /* TODO: this should always be `Some` if we support `ALTER
                                     * TABLE` for `CREATE TABLE AS`. */
 ) -> Result<(PlanRef, Option<ProstSource>, ProstTable)> {
@@ -479,12 +487,31 @@ fn gen_table_plan_inner(
         .into());
     }
 
+    let conflict_behavior = match append_only {
+        true => {
+            if on_conflict.is_some() {
+                return Err(ErrorCode::InvalidInputSyntax(
+                    "ON CONFLICT can not be applied on an append only table.".to_owned(),
+                )
+                .into());
+            }
+            ConflictBehavior::NoCheck
+        }
+        false => match on_conflict.unwrap_or(OnConflict::Overwrite) {
+            OnConflict::Overwrite => ConflictBehavior::OverWrite,
+            OnConflict::IgnoreNew => ConflictBehavior::IgnoreConflict,
+            OnConflict::ReportError => ConflictBehavior::ReportError,
+            OnConflict::DoUpdateIfNotNull => ConflictBehavior::DoUpdateIfNotNull,
+        },
+    };
+
     let materialize = plan_root.gen_table_plan(
         name,
         columns,
         definition,
         row_id_index,
         append_only,
+        conflict_behavior,
         watermark_descs,
         version,
     )?;
@@ -505,6 +532,7 @@ pub async fn handle_create_table(
     source_schema: Option<SourceSchema>,
     source_watermarks: Vec<SourceWatermark>,
     append_only: bool,
+    on_conflict: Option<OnConflict>,
 ) -> Result<RwPgResponse> {
     let session = handler_args.session.clone();
 
@@ -520,7 +548,7 @@ pub async fn handle_create_table(
     }
 
     let (graph, source, table) = {
-        let context = OptimizerContext::from_handler_args(handler_args);
+        let context = OptimizerContext::from_handler_args(handler_args.clone());
         let source_schema = check_create_table_with_source(context.with_options(), source_schema)?;
         let col_id_gen = ColumnIdGenerator::new_initial();
 
@@ -535,6 +563,7 @@ pub async fn handle_create_table(
                     source_watermarks,
                     col_id_gen,
                     append_only,
+                    on_conflict,
                 )
                 .await?
             }
@@ -546,6 +575,7 @@ pub async fn handle_create_table(
                 col_id_gen,
                 source_watermarks,
                 append_only,
+                on_conflict,
             )?,
         };
         let mut graph = build_graph(plan);
@@ -565,6 +595,13 @@ pub async fn handle_create_table(
     let catalog_writer = session.env().catalog_writer();
     catalog_writer.create_table(source, table, graph).await?;
 
+    // For append-only tables the primary key is the row id rather than the time column, so
+    // time-range queries degrade to full table scans. Optionally maintain a `(ts, pk)` index
+    // arrangement to serve them with an index range scan instead.
+    if append_only && session.config().get_auto_ts_index() {
+        auto_create_ts_index(handler_args, table_name).await?;
+    }
+
     Ok(PgResponse::empty_result(StatementType::CREATE_TABLE))
 }
 
@@ -16,7 +16,7 @@ use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::catalog::ColumnDesc;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_pb::stream_plan::stream_fragment_graph::Parallelism;
-use risingwave_sqlparser::ast::{ColumnDef, ObjectName, Query, Statement};
+use risingwave_sqlparser::ast::{ColumnDef, ObjectName, OnConflict, Query, Statement};
 
 use super::{HandlerArgs, RwPgResponse};
 use crate::binder::BoundStatement;
@@ -24,6 +24,12 @@ use crate::handler::create_table::{gen_create_table_plan_without_bind, ColumnIdG
 use crate::handler::query::handle_query;
 use crate::{build_graph, Binder, OptimizerContext};
 
+/// Handles `CREATE TABLE t AS SELECT ...` and its PostgreSQL-compatible spelling
+/// `SELECT ... INTO t`.
+///
+/// Unlike `CREATE MATERIALIZED VIEW`, the created table is a regular table populated with a
+/// one-time snapshot of the query result: it is writable and is not kept up to date when the
+/// queried relations change.
 pub async fn handle_create_as(
     handler_args: HandlerArgs,
     table_name: ObjectName,
@@ -31,6 +37,7 @@ pub async fn handle_create_as(
     query: Box<Query>,
     columns: Vec<ColumnDef>,
     append_only: bool,
+    on_conflict: Option<OnConflict>,
 ) -> Result<RwPgResponse> {
     if columns.iter().any(|column| column.data_type.is_some()) {
         return Err(ErrorCode::InvalidInputSyntax(
@@ -96,6 +103,7 @@ pub async fn handle_create_as(
             "".to_owned(), // TODO: support `SHOW CREATE TABLE` for `CREATE TABLE AS`
             vec![],        // No watermark should be defined in for `CREATE TABLE AS`
             append_only,
+            on_conflict,
             Some(col_id_gen.into_version()),
         )?;
         let mut graph = build_graph(plan);
@@ -70,6 +70,7 @@ pub async fn handle_explain(
                 source_schema,
                 source_watermarks,
                 append_only,
+                on_conflict,
                 ..
             } => match check_create_table_with_source(&handler_args.with_options, source_schema)? {
                 Some(s) => {
@@ -82,6 +83,7 @@ pub async fn handle_explain(
                         source_watermarks,
                         ColumnIdGenerator::new_initial(),
                         append_only,
+                        on_conflict,
                     )
                     .await?
                     .0
@@ -95,6 +97,7 @@ pub async fn handle_explain(
                         ColumnIdGenerator::new_initial(),
                         source_watermarks,
                         append_only,
+                        on_conflict,
                     )?
                     .0
                 }
@@ -30,6 +30,7 @@ use crate::scheduler::{DistributedQueryStream, LocalQueryStream};
 use crate::session::SessionImpl;
 use crate::utils::WithOptions;
 
+mod alter_mv;
 mod alter_system;
 mod alter_table;
 pub mod alter_user;
@@ -198,6 +199,7 @@ pub async fn handle(
             source_schema,
             source_watermarks,
             append_only,
+            on_conflict,
         } => {
             if or_replace {
                 return Err(ErrorCode::NotImplemented(
@@ -221,6 +223,7 @@ pub async fn handle(
                     query,
                     columns,
                     append_only,
+                    on_conflict,
                 )
                 .await;
             }
@@ -233,6 +236,7 @@ pub async fn handle(
                 source_schema,
                 source_watermarks,
                 append_only,
+                on_conflict,
             )
             .await
         }
@@ -253,7 +257,9 @@ pub async fn handle(
             handle_privilege::handle_revoke_privilege(handler_args, stmt).await
         }
         Statement::Describe { name } => describe::handle_describe(handler_args, name),
-        Statement::ShowObjects(show_object) => show::handle_show_object(handler_args, show_object),
+        Statement::ShowObjects(show_object) => {
+            show::handle_show_object(handler_args, show_object).await
+        }
         Statement::ShowCreateObject { create_type, name } => {
             show::handle_show_create_object(handler_args, create_type, name)
         }
@@ -309,6 +315,25 @@ pub async fn handle(
             func_desc,
             option,
         } => drop_function::handle_drop_function(handler_args, if_exists, func_desc, option).await,
+        // `SELECT ... INTO t` is PostgreSQL's equivalent of `CREATE TABLE t AS SELECT ...`.
+        Statement::Query(mut query)
+            if matches!(&query.body, SetExpr::Select(select) if select.into.is_some()) =>
+        {
+            let table_name = match &mut query.body {
+                SetExpr::Select(select) => select.into.take().unwrap(),
+                _ => unreachable!(),
+            };
+            create_table_as::handle_create_as(
+                handler_args,
+                table_name,
+                false,
+                query,
+                vec![],
+                false,
+                None,
+            )
+            .await
+        }
         Statement::Query(_)
         | Statement::Insert { .. }
         | Statement::Delete { .. }
@@ -380,6 +405,9 @@ pub async fn handle(
             name,
             operation: AlterTableOperation::AddColumn { column_def },
         } => alter_table::handle_add_column(handler_args, name, column_def).await,
+        Statement::AlterMaterializedView { name, set_options } => {
+            alter_mv::handle_alter_mv(handler_args, name, set_options).await
+        }
         Statement::AlterSystem { param, value } => {
             alter_system::handle_alter_system(handler_args, param, value).await
         }
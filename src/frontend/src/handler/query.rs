@@ -33,7 +33,7 @@ use crate::handler::privilege::resolve_privileges;
 use crate::handler::util::{to_pg_field, DataChunkToRowSetAdapter};
 use crate::handler::HandlerArgs;
 use crate::optimizer::plan_node::Explain;
-use crate::optimizer::{OptimizerContext, OptimizerContextRef};
+use crate::optimizer::{ExecutionModeDecider, OptimizerContext, OptimizerContextRef};
 use crate::planner::Planner;
 use crate::scheduler::plan_fragmenter::Query;
 use crate::scheduler::{
@@ -70,6 +70,10 @@ pub fn gen_batch_query_plan(
     }
     let must_dist = stmt_type.is_dml();
 
+    let mut logical = planner.plan(bound)?;
+    let schema = logical.schema();
+    let batch_plan = logical.gen_batch_plan()?;
+
     let query_mode = match (must_dist, must_local) {
         (true, true) => {
             return Err(ErrorCode::InternalError(
@@ -79,19 +83,29 @@ pub fn gen_batch_query_plan(
         }
         (true, false) => QueryMode::Distributed,
         (false, true) => QueryMode::Local,
-        (false, false) => session.config().get_query_mode(),
+        (false, false) => match session.config().get_query_mode() {
+            QueryMode::Auto => determine_query_mode(batch_plan.clone()),
+            QueryMode::Local => QueryMode::Local,
+            QueryMode::Distributed => QueryMode::Distributed,
+        },
     };
 
-    let mut logical = planner.plan(bound)?;
-    let schema = logical.schema();
-
     let physical = match query_mode {
-        QueryMode::Local => logical.gen_batch_local_plan()?,
-        QueryMode::Distributed => logical.gen_batch_distributed_plan()?,
+        QueryMode::Auto => unreachable!(),
+        QueryMode::Local => logical.gen_batch_local_plan(batch_plan)?,
+        QueryMode::Distributed => logical.gen_batch_distributed_plan(batch_plan)?,
     };
     Ok((physical, query_mode, schema))
 }
 
+fn determine_query_mode(batch_plan: PlanRef) -> QueryMode {
+    if ExecutionModeDecider::run_in_local_mode(batch_plan) {
+        QueryMode::Local
+    } else {
+        QueryMode::Distributed
+    }
+}
+
 pub async fn handle_query(
     handler_args: HandlerArgs,
     stmt: Statement,
@@ -152,6 +166,7 @@ pub async fn handle_query(
             PinnedHummockSnapshot::FrontendPinned(pinned_snapshot, only_checkpoint_visible)
         };
         match query_mode {
+            QueryMode::Auto => unreachable!(),
             QueryMode::Local => PgResponseStream::LocalQuery(DataChunkToRowSetAdapter::new(
                 local_execute(session.clone(), query, query_snapshot).await?,
                 column_types,
@@ -220,6 +235,7 @@ pub async fn handle_query(
 
         // update some metrics
         match query_mode {
+            QueryMode::Auto => unreachable!(),
             QueryMode::Local => {
                 session
                     .env()
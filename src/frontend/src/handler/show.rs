@@ -19,7 +19,10 @@ use pgwire::types::Row;
 use risingwave_common::catalog::{ColumnDesc, DEFAULT_SCHEMA_NAME};
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
-use risingwave_sqlparser::ast::{Ident, ObjectName, ShowCreateType, ShowObject};
+use risingwave_sqlparser::ast::{
+    Ident, ObjectName, ShowCreateType, ShowObject, SqlOption, Statement, Value,
+};
+use risingwave_sqlparser::parser::Parser;
 
 use super::RwPgResponse;
 use crate::binder::{Binder, Relation};
@@ -27,6 +30,7 @@ use crate::catalog::CatalogError;
 use crate::handler::util::col_descs_to_rows;
 use crate::handler::HandlerArgs;
 use crate::session::SessionImpl;
+use crate::utils::{is_secret_option, SECRET_MASK};
 
 pub fn get_columns_from_table(
     session: &SessionImpl,
@@ -56,8 +60,60 @@ fn schema_or_default(schema: &Option<Ident>) -> String {
         .map_or_else(|| DEFAULT_SCHEMA_NAME.to_string(), |s| s.real_value())
 }
 
-pub fn handle_show_object(handler_args: HandlerArgs, command: ShowObject) -> Result<RwPgResponse> {
+pub async fn handle_show_object(
+    handler_args: HandlerArgs,
+    command: ShowObject,
+) -> Result<RwPgResponse> {
     let session = handler_args.session;
+
+    // In-progress streaming DDL jobs are tracked by the meta node rather than the catalog, so
+    // handle them before taking the catalog read guard. A job that is rolled back by cluster
+    // recovery no longer shows up here, and its DDL statement returns an error.
+    if let ShowObject::Jobs = command {
+        let rows = session
+            .env()
+            .meta_client()
+            .list_ddl_progress()
+            .await?
+            .into_iter()
+            .map(|job| {
+                Row::new(vec![
+                    Some(job.id.to_string().into()),
+                    Some(job.statement.into()),
+                    Some(job.progress.into()),
+                    Some(job.eta.into()),
+                ])
+            })
+            .collect_vec();
+        return Ok(PgResponse::new_for_stream(
+            StatementType::SHOW_COMMAND,
+            None,
+            rows.into(),
+            vec![
+                PgFieldDescriptor::new(
+                    "Id".to_owned(),
+                    DataType::VARCHAR.to_oid(),
+                    DataType::VARCHAR.type_len(),
+                ),
+                PgFieldDescriptor::new(
+                    "Statement".to_owned(),
+                    DataType::VARCHAR.to_oid(),
+                    DataType::VARCHAR.type_len(),
+                ),
+                PgFieldDescriptor::new(
+                    "Progress".to_owned(),
+                    DataType::VARCHAR.to_oid(),
+                    DataType::VARCHAR.type_len(),
+                ),
+                PgFieldDescriptor::new(
+                    "Eta".to_owned(),
+                    DataType::VARCHAR.to_oid(),
+                    DataType::VARCHAR.type_len(),
+                ),
+            ],
+        ));
+    }
+
     let catalog_reader = session.env().catalog_reader().read_guard();
 
     let names = match command {
@@ -116,6 +172,7 @@ pub fn handle_show_object(handler_args: HandlerArgs, command: ShowObject) -> Res
                 ],
             ));
         }
+        ShowObject::Jobs => unreachable!("handled above"),
     };
 
     let rows = names
@@ -135,6 +192,32 @@ pub fn handle_show_object(handler_args: HandlerArgs, command: ShowObject) -> Res
     ))
 }
 
+fn redact_sql_options(options: &mut [SqlOption]) {
+    for option in options {
+        if is_secret_option(&option.name.real_value()) {
+            option.value = Value::SingleQuotedString(SECRET_MASK.to_string());
+        }
+    }
+}
+
+/// Masks secret values in the `WITH` clause of a persisted definition. If the
+/// definition cannot be parsed, it is returned unchanged.
+fn redact_definition(sql: &str) -> String {
+    let Ok(mut statements) = Parser::parse_sql(sql) else {
+        return sql.to_string();
+    };
+    let [statement] = &mut statements[..] else {
+        return sql.to_string();
+    };
+    match statement {
+        Statement::CreateTable { with_options, .. } => redact_sql_options(with_options),
+        Statement::CreateSource { stmt } => redact_sql_options(&mut stmt.with_properties.0),
+        Statement::CreateSink { stmt } => redact_sql_options(&mut stmt.with_properties.0),
+        _ => {}
+    }
+    statement.to_string()
+}
+
 pub fn handle_show_create_object(
     handle_args: HandlerArgs,
     show_create_type: ShowCreateType,
@@ -167,7 +250,25 @@ pub fn handle_show_create_object(
                 .ok_or_else(|| CatalogError::NotFound("table", name.to_string()))?;
             table.create_sql()
         }
-        _ => {
+        ShowCreateType::Index => {
+            let index = schema
+                .get_index_by_name(&object_name)
+                .ok_or_else(|| CatalogError::NotFound("index", name.to_string()))?;
+            index.index_table.create_sql()
+        }
+        ShowCreateType::Source => {
+            let source = schema
+                .get_source_by_name(&object_name)
+                .ok_or_else(|| CatalogError::NotFound("source", name.to_string()))?;
+            source.create_sql()
+        }
+        ShowCreateType::Sink => {
+            let sink = schema
+                .get_sink_by_name(&object_name)
+                .ok_or_else(|| CatalogError::NotFound("sink", name.to_string()))?;
+            sink.definition.clone()
+        }
+        ShowCreateType::Function => {
             return Err(ErrorCode::NotImplemented(
                 format!("show create on: {}", show_create_type),
                 None.into(),
@@ -175,6 +276,8 @@ pub fn handle_show_create_object(
             .into());
         }
     };
+    // Connector WITH options may contain credentials.
+    let sql = redact_definition(&sql);
     let name = format!("{}.{}", schema_name, object_name);
 
     Ok(PgResponse::new_for_stream(
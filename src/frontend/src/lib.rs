@@ -58,6 +58,8 @@ mod meta_client;
 pub mod test_utils;
 mod user;
 
+#[cfg(not(madsim))]
+pub mod flight_service;
 pub mod health_service;
 mod monitor;
 
@@ -112,6 +114,11 @@ pub struct FrontendOpts {
     )]
     pub health_check_listener_addr: String,
 
+    /// The address that the Arrow Flight service listens to, serving query results as Arrow
+    /// record batches. The service is disabled if not specified.
+    #[clap(long, env = "RW_FLIGHT_SQL_LISTENER_ADDR")]
+    pub flight_sql_listener_addr: Option<String>,
+
     /// The path of `risingwave.toml` configuration file.
     ///
     /// If empty, default configuration values will be used.
@@ -153,7 +160,25 @@ pub fn start(opts: FrontendOpts) -> Pin<Box<dyn Future<Output = ()> + Send>> {
     // slow compile in release mode.
     Box::pin(async move {
         let listen_addr = opts.listen_addr.clone();
+        #[cfg(not(madsim))]
+        let flight_sql_listener_addr = opts.flight_sql_listener_addr.clone();
         let session_mgr = Arc::new(SessionManagerImpl::new(opts).await.unwrap());
+        #[cfg(not(madsim))]
+        if let Some(flight_sql_listener_addr) = flight_sql_listener_addr {
+            let flight_srv = flight_service::FlightServiceImpl::new(session_mgr.clone());
+            let host = flight_sql_listener_addr.clone();
+            tokio::spawn(async move {
+                tonic::transport::Server::builder()
+                    .add_service(flight_srv.into_server())
+                    .serve(host.parse().unwrap())
+                    .await
+                    .unwrap();
+            });
+            tracing::info!(
+                "Arrow Flight listener is set up on {}",
+                flight_sql_listener_addr
+            );
+        }
         pg_serve(&listen_addr, session_mgr, Some(TlsConfig::new_default()))
             .await
             .unwrap();
@@ -64,7 +64,7 @@ impl ObserverState for FrontendObserverNode {
                 self.handle_user_notification(resp);
             }
             Info::ParallelUnitMapping(_) => self.handle_fragment_mapping_notification(resp),
-            Info::Snapshot(_) => {
+            Info::Snapshot(_) | Info::SnapshotDeltas(_) => {
                 panic!(
                     "receiving a snapshot in the middle is unsupported now {:?}",
                     resp
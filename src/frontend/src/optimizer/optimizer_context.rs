@@ -130,6 +130,10 @@ impl OptimizerContext {
         self.explain_options.trace
     }
 
+    pub fn is_explain_storage(&self) -> bool {
+        self.explain_options.storage
+    }
+
     pub fn is_explain_logical(&self) -> bool {
         self.explain_options.explain_type == ExplainType::Logical
     }
@@ -204,6 +204,28 @@ impl fmt::Display for BatchSeqScan {
             )?;
         }
 
+        if self.base.ctx.is_explain_storage() {
+            let order_len = self.logical.table_desc().order_column_indices().len();
+            let (access, cache) = if self.scan_ranges.is_empty() {
+                ("full table scan".to_string(), "sequential block cache fill")
+            } else if self
+                .scan_ranges
+                .iter()
+                .all(|scan_range| scan_range.eq_conds.len() == order_len)
+            {
+                (
+                    format!("{} point get(s)", self.scan_ranges.len()),
+                    "block cache with bloom filter",
+                )
+            } else {
+                (
+                    format!("{} range scan(s)", self.scan_ranges.len()),
+                    "sequential block cache fill",
+                )
+            };
+            write!(f, ", storage: {{ access: {}, cache: {} }}", access, cache)?;
+        }
+
         write!(f, " }}")
     }
 }
@@ -144,6 +144,18 @@ impl LogicalHopWindow {
         self.window_start_col_idx() + 1
     }
 
+    /// Returns the index of `window_start` in the output schema, if it's not pruned.
+    pub fn output_window_start_col_idx(&self) -> Option<usize> {
+        self.internal2output_col_mapping()
+            .try_map(self.window_start_col_idx())
+    }
+
+    /// Returns the index of `window_end` in the output schema, if it's not pruned.
+    pub fn output_window_end_col_idx(&self) -> Option<usize> {
+        self.internal2output_col_mapping()
+            .try_map(self.window_end_col_idx())
+    }
+
     pub fn o2i_col_mapping(&self) -> ColIndexMapping {
         self.output2internal_col_mapping()
             .composite(&self.internal2input_col_mapping())
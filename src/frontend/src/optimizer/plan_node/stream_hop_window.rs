@@ -39,10 +39,18 @@ impl StreamHopWindow {
         let dist = i2o.rewrite_provided_distribution(input.distribution());
 
         let mut watermark_columns = i2o.rewrite_bitset(input.watermark_columns());
-        if watermark_columns.contains(logical.core.time_col.index) {
+        if input
+            .watermark_columns()
+            .contains(logical.core.time_col.index)
+        {
             // Watermark on `time_col` indicates watermark on both `window_start` and `window_end`.
-            watermark_columns.insert(schema.len() - 2); // window_start
-            watermark_columns.insert(schema.len() - 1); // window_end
+            // Note that either of them may be pruned or reordered by `output_indices`.
+            if let Some(start_idx) = logical.output_window_start_col_idx() {
+                watermark_columns.insert(start_idx);
+            }
+            if let Some(end_idx) = logical.output_window_end_col_idx() {
+                watermark_columns.insert(end_idx);
+            }
         }
 
         let base = PlanBase::new_stream(
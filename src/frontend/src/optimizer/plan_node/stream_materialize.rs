@@ -173,6 +173,8 @@ impl StreamMaterialize {
             ConflictBehavior::NoCheck => 0,
             ConflictBehavior::OverWrite => 1,
             ConflictBehavior::IgnoreConflict => 2,
+            ConflictBehavior::ReportError => 3,
+            ConflictBehavior::DoUpdateIfNotNull => 4,
         };
         Ok(TableCatalog {
             id: TableId::placeholder(),
@@ -246,6 +248,10 @@ impl fmt::Display for StreamMaterialize {
             pk_conflict_behavior = "no check";
         } else if self.table.conflict_behavior_type() == 1 {
             pk_conflict_behavior = "overwrite";
+        } else if self.table.conflict_behavior_type() == 3 {
+            pk_conflict_behavior = "report error";
+        } else if self.table.conflict_behavior_type() == 4 {
+            pk_conflict_behavior = "do update if not null";
         } else {
             pk_conflict_behavior = "ignore conflict";
         }
@@ -28,6 +28,9 @@ use crate::utils::ColIndexMappingRewriteExt;
 pub struct StreamProjectSet {
     pub base: PlanBase,
     logical: LogicalProjectSet,
+    /// All the watermark derivations, (input_column_index, expr_index). And the
+    /// derived expression is the project set's expression itself.
+    watermark_derivations: Vec<(usize, usize)>,
 }
 
 impl StreamProjectSet {
@@ -40,10 +43,12 @@ impl StreamProjectSet {
             .i2o_col_mapping()
             .rewrite_provided_distribution(input.distribution());
 
+        let mut watermark_derivations = vec![];
         let mut watermark_columns = FixedBitSet::with_capacity(schema.len());
         for (expr_idx, expr) in logical.select_list().iter().enumerate() {
             if let Some(input_idx) = try_derive_watermark(expr) {
                 if input.watermark_columns().contains(input_idx) {
+                    watermark_derivations.push((input_idx, expr_idx));
                     // The first column of ProjectSet is `projected_row_id`.
                     watermark_columns.insert(expr_idx + 1);
                 }
@@ -61,7 +66,11 @@ impl StreamProjectSet {
             logical.input().append_only(),
             watermark_columns,
         );
-        StreamProjectSet { base, logical }
+        StreamProjectSet {
+            base,
+            logical,
+            watermark_derivations,
+        }
     }
 }
 
@@ -92,6 +101,16 @@ impl StreamNode for StreamProjectSet {
                 .iter()
                 .map(|select_item| select_item.to_project_set_select_item_proto())
                 .collect_vec(),
+            watermark_input_key: self
+                .watermark_derivations
+                .iter()
+                .map(|(x, _)| *x as u32)
+                .collect_vec(),
+            watermark_output_key: self
+                .watermark_derivations
+                .iter()
+                .map(|(_, y)| *y as u32)
+                .collect_vec(),
         })
     }
 }
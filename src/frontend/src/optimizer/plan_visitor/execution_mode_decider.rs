@@ -0,0 +1,61 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::optimizer::plan_node::{BatchLimit, BatchSeqScan, BatchValues};
+use crate::optimizer::plan_visitor::PlanVisitor;
+use crate::PlanRef;
+
+/// Decide whether a batch query is cheap enough to run in local execution mode, where the whole
+/// plan runs in the frontend without the scheduling cost of distributed tasks.
+///
+/// The decision is intentionally conservative: only queries whose cost is bounded regardless of
+/// the table size are considered cheap, i.e. all scans are point gets on a key prefix, or the
+/// result set is cut by a small `LIMIT`. Everything else runs in distributed mode, so that
+/// medium-sized queries don't accidentally run single-threaded.
+pub struct ExecutionModeDecider;
+
+impl ExecutionModeDecider {
+    /// Return true if the plan should run in local mode.
+    pub fn run_in_local_mode(plan: PlanRef) -> bool {
+        let mut decider = ExecutionModeDecider;
+        decider.visit(plan)
+    }
+}
+
+impl PlanVisitor<bool> for ExecutionModeDecider {
+    fn merge(a: bool, b: bool) -> bool {
+        a & b
+    }
+
+    /// Point get on a key prefix, e.g. `select * from t where id = 1`.
+    /// A scan without any range is a full table scan and must not run locally.
+    fn visit_batch_seq_scan(&mut self, batch_seq_scan: &BatchSeqScan) -> bool {
+        !batch_seq_scan.scan_ranges().is_empty()
+            && batch_seq_scan
+                .scan_ranges()
+                .iter()
+                .all(|scan_range| scan_range.has_eq_conds())
+    }
+
+    /// A small limit cuts the result set regardless of how large the input is,
+    /// e.g. `select * from t limit 1`.
+    fn visit_batch_limit(&mut self, batch_limit: &BatchLimit) -> bool {
+        batch_limit.limit() + batch_limit.offset() < 100
+    }
+
+    /// Values do not read from storage at all, e.g. `select 1`.
+    fn visit_batch_values(&mut self, _batch_values: &BatchValues) -> bool {
+        true
+    }
+}
@@ -38,9 +38,18 @@ impl Rule for DistinctAggRule {
         let agg: &LogicalAgg = plan.as_logical_agg()?;
         let (mut agg_calls, mut agg_group_keys, input) = agg.clone().decompose();
 
-        if self.for_stream && !agg_group_keys.is_empty() {
+        if self.for_stream
+            && !agg_group_keys.is_empty()
+            && !plan
+                .ctx()
+                .session_ctx()
+                .config()
+                .get_force_split_distinct_agg()
+        {
             // Due to performance issue, we don't do 2-phase agg for stream distinct agg with group
-            // by. See https://github.com/risingwavelabs/risingwave/issues/7271 for more.
+            // by by default. See https://github.com/risingwavelabs/risingwave/issues/7271 for more.
+            // Users may force it with `RW_FORCE_SPLIT_DISTINCT_AGG` when the cardinality of the
+            // distinct keys is too high for per-group deduplication.
             return None;
         }
 
@@ -24,10 +24,10 @@ use pgwire::pg_server::{BoxedError, Session, SessionId};
 use risingwave_batch::executor::BoxedDataChunkStream;
 use risingwave_common::array::DataChunk;
 use risingwave_common::error::RwError;
-use risingwave_common::session_config::QueryMode;
 use risingwave_pb::batch_plan::TaskOutputId;
 use risingwave_pb::common::HostAddress;
 use risingwave_rpc_client::ComputeClientPoolRef;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore, TryAcquireError};
 use tracing::debug;
 
 use super::stats::DistributedQueryMetrics;
@@ -44,6 +44,8 @@ pub struct DistributedQueryStream {
     // Used for cleaning up `QueryExecution` after all data are polled.
     query_id: QueryId,
     query_execution_info: QueryExecutionInfoRef,
+    // Hold the admission permit until the stream is dropped, i.e. the query ends.
+    _permit: Option<OwnedSemaphorePermit>,
 }
 
 impl DistributedQueryStream {
@@ -140,6 +142,9 @@ pub struct QueryManager {
     query_execution_info: QueryExecutionInfoRef,
     pub query_metrics: Arc<DistributedQueryMetrics>,
     disrtibuted_query_limit: Option<u64>,
+    /// Admission permits for distributed queries when `disrtibuted_query_limit` is set. A query
+    /// holds one permit during its whole execution.
+    query_permits: Option<Arc<Semaphore>>,
 }
 
 type QueryManagerRef = Arc<QueryManager>;
@@ -153,6 +158,8 @@ impl QueryManager {
         query_metrics: Arc<DistributedQueryMetrics>,
         disrtibuted_query_limit: Option<u64>,
     ) -> Self {
+        let query_permits =
+            disrtibuted_query_limit.map(|limit| Arc::new(Semaphore::new(limit as usize)));
         Self {
             worker_node_manager,
             hummock_snapshot_manager,
@@ -161,6 +168,7 @@ impl QueryManager {
             query_execution_info: Arc::new(RwLock::new(QueryExecutionInfo::default())),
             query_metrics,
             disrtibuted_query_limit,
+            query_permits,
         }
     }
 
@@ -170,12 +178,32 @@ impl QueryManager {
         query: Query,
         pinned_snapshot: PinnedHummockSnapshot,
     ) -> SchedulerResult<DistributedQueryStream> {
-        if let Some(query_limit) = self.disrtibuted_query_limit && self.query_metrics.running_query_num.get() as u64 == query_limit {
-            self.query_metrics.rejected_query_counter.inc();
-            return Err(
-                crate::scheduler::SchedulerError::QueryReachLimit(QueryMode::Distributed, query_limit)
-            )
-        }
+        // When the limit on concurrently running queries is reached, the query is queued and
+        // admitted in FIFO order as the running ones finish, instead of being rejected right
+        // away. The permit is held until the result stream is dropped.
+        let permit = match &self.query_permits {
+            Some(permits) => match permits.clone().try_acquire_owned() {
+                Ok(permit) => Some(permit),
+                Err(TryAcquireError::NoPermits) => {
+                    debug!(
+                        "distributed query limit {:?} reached, query {:?} queued",
+                        self.disrtibuted_query_limit, query.query_id
+                    );
+                    let _queued = QueuedQueryGuard::new(&self.query_metrics);
+                    Some(
+                        permits
+                            .clone()
+                            .acquire_owned()
+                            .await
+                            .expect("query permit semaphore should not be closed"),
+                    )
+                }
+                Err(TryAcquireError::Closed) => {
+                    unreachable!("query permit semaphore should not be closed")
+                }
+            },
+            None => None,
+        };
         let query_id = query.query_id.clone();
         let query_execution = Arc::new(QueryExecution::new(query, context.session().id()));
 
@@ -207,7 +235,22 @@ impl QueryManager {
                     .delete_query(&query_id);
                 err
             })?;
-        Ok(query_result_fetcher.stream_from_channel())
+        Ok(query_result_fetcher.stream_from_channel(permit))
+    }
+
+    /// The number of queries currently running in distributed execution mode.
+    pub fn running_query_num(&self) -> i64 {
+        self.query_metrics.running_query_num.get()
+    }
+
+    /// The number of queries currently waiting for an admission permit.
+    pub fn queued_query_num(&self) -> i64 {
+        self.query_metrics.queued_query_num.get()
+    }
+
+    /// The limit on concurrently running distributed queries, if any.
+    pub fn disrtibuted_query_limit(&self) -> Option<u64> {
+        self.disrtibuted_query_limit
     }
 
     pub fn cancel_queries_in_session(&self, session_id: SessionId) {
@@ -226,6 +269,25 @@ impl QueryManager {
     }
 }
 
+/// Tracks the number of queued queries with a guard, so that the count stays correct even if a
+/// query is cancelled while waiting for its admission permit.
+struct QueuedQueryGuard<'a> {
+    metrics: &'a DistributedQueryMetrics,
+}
+
+impl<'a> QueuedQueryGuard<'a> {
+    fn new(metrics: &'a DistributedQueryMetrics) -> Self {
+        metrics.queued_query_num.inc();
+        Self { metrics }
+    }
+}
+
+impl Drop for QueuedQueryGuard<'_> {
+    fn drop(&mut self) {
+        self.metrics.queued_query_num.dec();
+    }
+}
+
 impl QueryResultFetcher {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -266,11 +328,12 @@ impl QueryResultFetcher {
         Box::pin(self.run_inner())
     }
 
-    fn stream_from_channel(self) -> DistributedQueryStream {
+    fn stream_from_channel(self, permit: Option<OwnedSemaphorePermit>) -> DistributedQueryStream {
         DistributedQueryStream {
             chunk_rx: self.chunk_rx,
             query_id: self.query_id,
             query_execution_info: self.query_execution_info,
+            _permit: permit,
         }
     }
 }
@@ -18,6 +18,7 @@ use std::collections::HashMap;
 use std::mem;
 use std::rc::Rc;
 use std::sync::Arc;
+use std::time::Instant;
 
 use anyhow::anyhow;
 use arc_swap::ArcSwap;
@@ -25,7 +26,6 @@ use futures::stream::Fuse;
 use futures::{stream, StreamExt};
 use futures_async_stream::for_await;
 use itertools::Itertools;
-use rand::seq::SliceRandom;
 use risingwave_batch::executor::ExecutorBuilder;
 use risingwave_batch::task::TaskId as TaskIdBatch;
 use risingwave_common::array::DataChunk;
@@ -64,6 +64,21 @@ use crate::scheduler::{ExecutionContextRef, SchedulerError, SchedulerResult};
 
 const TASK_SCHEDULING_PARALLELISM: usize = 10;
 
+/// Maximum number of times the tasks of a stage are re-dispatched after a worker becomes
+/// unreachable during scheduling, before the failure is propagated and fails the query.
+const STAGE_MAX_RETRY_ATTEMPTS: usize = 3;
+
+/// The error reported when the task status streams of a stage terminate before all of its tasks
+/// are running, which typically means a compute node crashed or was killed.
+const WORKER_LOST_ERROR: &str = "Compute node lost connection before finishing responding";
+
+/// Whether re-dispatching the stage may succeed, i.e. the failure comes from an unreachable or
+/// crashed worker rather than from executing the query itself.
+fn is_transient_error(e: &SchedulerError) -> bool {
+    matches!(e, SchedulerError::RpcError(_))
+        || matches!(e, TaskExecutionError(msg) if msg == WORKER_LOST_ERROR)
+}
+
 #[derive(Debug)]
 enum StageState {
     /// We put `msg_sender` in `Pending` state to avoid holding it in `StageExecution`. In this
@@ -323,11 +338,40 @@ impl StageRunner {
         }
     }
 
+    /// Schedule all tasks of this stage, transparently re-dispatching them if a worker becomes
+    /// unreachable before the stage is fully scheduled. The task descriptors are reconstructed
+    /// deterministically from the stage plan on every attempt, so a retry is indistinguishable
+    /// from the first dispatch to the compute nodes, while the workers are re-resolved so that
+    /// the tasks land on healthy nodes. Once the stage has been reported as scheduled, its
+    /// outputs may already be consumed by downstream stages, so no more retry is attempted.
+    async fn schedule_tasks(
+        &mut self,
+        mut shutdown_rx: oneshot::Receiver<StageMessage>,
+    ) -> SchedulerResult<()> {
+        let mut attempt = 0;
+        loop {
+            let Err(e) = self.schedule_tasks_inner(&mut shutdown_rx).await else {
+                return Ok(());
+            };
+            attempt += 1;
+            let scheduled = !matches!(*self.state.read().await, StageState::Started);
+            if scheduled || attempt > STAGE_MAX_RETRY_ATTEMPTS || !is_transient_error(&e) {
+                return Err(e);
+            }
+            warn!(
+                "Stage {:?}-{:?} failed to schedule (attempt {}/{}), re-dispatching: {:?}",
+                self.stage.query_id, self.stage.id, attempt, STAGE_MAX_RETRY_ATTEMPTS, e
+            );
+            // Clean up whatever the failed attempt managed to schedule before re-dispatching.
+            self.abort_all_scheduled_tasks().await?;
+        }
+    }
+
     /// Schedule all tasks to CN and wait process all status messages from RPC. Note that when all
     /// task is created, it should tell `QueryRunner` to schedule next.
-    async fn schedule_tasks(
+    async fn schedule_tasks_inner(
         &mut self,
-        shutdown_rx: oneshot::Receiver<StageMessage>,
+        shutdown_rx: &mut oneshot::Receiver<StageMessage>,
     ) -> SchedulerResult<()> {
         let mut futures = vec![];
 
@@ -464,9 +508,7 @@ impl StageRunner {
                 // killed before reporting status, so sent signal flag is not set yet.
                 // In this case, batch query is expected to fail. Client in simulation test
                 // should retry this query (w/o kill nodes).
-                return Err(TaskExecutionError(
-                    "Compute node lost connection before finishing responding".to_string(),
-                ));
+                return Err(TaskExecutionError(WORKER_LOST_ERROR.to_string()));
             }
             tracing::trace!(
                 "Stage [{:?}-{:?}] waiting for stopping signal.",
@@ -636,6 +678,13 @@ impl StageRunner {
                         .worker_node_manager
                         .get_workers_by_parallel_unit_ids(&[pu])?;
                     return Ok(Some(candidates[0].clone()));
+                } else if let Some(scan_table_id) = Self::find_scan_table_id(plan_node) {
+                    // Scans that are not dispatched by data partition (e.g. system tables or
+                    // tables without a vnode mapping) are pinned to a worker chosen by table id,
+                    // so that repeated scans of the same table reuse its block cache.
+                    return Ok(Some(
+                        self.worker_node_manager.next_with_affinity(scan_table_id)?,
+                    ));
                 } else {
                     None
                 }
@@ -648,7 +697,7 @@ impl StageRunner {
                 let candidates = self
                     .worker_node_manager
                     .get_workers_by_parallel_unit_ids(&parallel_unit_ids)?;
-                Some(candidates.choose(&mut rand::thread_rng()).unwrap().clone())
+                Some(self.worker_node_manager.choose_healthy(&candidates))
             }
             None => None,
         };
@@ -656,6 +705,18 @@ impl StageRunner {
         Ok(worker_node)
     }
 
+    fn find_scan_table_id(plan_node: &PlanNode) -> Option<u64> {
+        let node_body = plan_node.node_body.as_ref().expect("fail to get node body");
+
+        match node_body {
+            NodeBody::RowSeqScan(scan_node) => {
+                Some(scan_node.table_desc.as_ref().unwrap().table_id as u64)
+            }
+            NodeBody::SysRowSeqScan(scan_node) => Some(scan_node.table_id as u64),
+            _ => plan_node.children.iter().find_map(Self::find_scan_table_id),
+        }
+    }
+
     fn find_distributed_lookup_join_node(
         plan_node: &PlanNode,
     ) -> Option<&DistributedLookupJoinNode> {
@@ -727,7 +788,11 @@ impl StageRunner {
         for (task, task_status) in self.tasks.iter() {
             // 1. Collect task info and client.
             let loc = &task_status.get_status().location;
-            let addr = loc.as_ref().expect("Get address should not fail");
+            let Some(addr) = loc.as_ref() else {
+                // The task has never been scheduled, e.g. because the dispatch attempt failed
+                // halfway. There is nothing to abort.
+                continue;
+            };
             let client = self
                 .compute_client_pool
                 .get_by_addr(HostAddr::from(addr))
@@ -765,23 +830,36 @@ impl StageRunner {
         plan_fragment: PlanFragment,
         worker: Option<WorkerNode>,
     ) -> SchedulerResult<Fuse<Streaming<TaskInfoResponse>>> {
-        let worker_node_addr = worker
-            .unwrap_or(self.worker_node_manager.next_random()?)
-            .host
-            .unwrap();
+        let worker = match worker {
+            Some(worker) => worker,
+            None => self.worker_node_manager.next_random()?,
+        };
+        let worker_id = worker.id;
+        let worker_node_addr = worker.host.unwrap();
 
+        // Keep the `RpcError` type here so that an unreachable worker can be told apart from
+        // other scheduling failures and the dispatch can be retried.
         let compute_client = self
             .compute_client_pool
             .get_by_addr((&worker_node_addr).into())
             .await
-            .map_err(|e| anyhow!(e))?;
+            .map_err(|e| {
+                self.worker_node_manager.report_rpc_failure(worker_id);
+                e
+            })?;
 
         let t_id = task_id.task_id;
+        let rpc_started_at = Instant::now();
         let stream_status = compute_client
             .create_task(task_id, plan_fragment, self.epoch.clone())
             .await
-            .map_err(|e| anyhow!(e))?
+            .map_err(|e| {
+                self.worker_node_manager.report_rpc_failure(worker_id);
+                e
+            })?
             .fuse();
+        self.worker_node_manager
+            .report_rpc_success(worker_id, rpc_started_at.elapsed());
 
         self.tasks[&t_id].inner.store(Arc::new(TaskStatus {
             _task_id: t_id,
@@ -21,6 +21,7 @@ use prometheus::{
 pub struct DistributedQueryMetrics {
     pub registry: Registry,
     pub running_query_num: GenericGauge<AtomicI64>,
+    pub queued_query_num: GenericGauge<AtomicI64>,
     pub rejected_query_counter: GenericCounter<AtomicU64>,
     pub completed_query_counter: GenericCounter<AtomicU64>,
     pub query_latency: Histogram,
@@ -35,6 +36,13 @@ impl DistributedQueryMetrics {
         )
         .unwrap();
 
+        let queued_query_num = register_int_gauge_with_registry!(
+            "distributed_queued_query_num",
+            "The number of queries waiting for an admission permit in distributed execution mode",
+            &registry
+        )
+        .unwrap();
+
         let rejected_query_counter = register_int_counter_with_registry!(
             "distributed_rejected_query_counter",
             "The number of rejected query in distributed execution mode. ",
@@ -59,6 +67,7 @@ impl DistributedQueryMetrics {
         Self {
             registry,
             running_query_num,
+            queued_query_num,
             rejected_query_counter,
             completed_query_counter,
             query_latency,
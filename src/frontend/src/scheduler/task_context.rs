@@ -51,6 +51,8 @@ impl BatchTaskContext for FrontendBatchTaskContext {
             self.env.worker_node_manager_ref(),
             self.env.meta_client_ref(),
             self.auth_context.clone(),
+            self.env.query_manager().clone(),
+            self.env.user_query_stats_manager().clone(),
         ))
     }
 
@@ -14,6 +14,7 @@
 
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 
 use rand::seq::SliceRandom;
 use risingwave_common::bail;
@@ -24,6 +25,71 @@ use risingwave_pb::common::WorkerNode;
 use crate::catalog::FragmentId;
 use crate::scheduler::{SchedulerError, SchedulerResult};
 
+/// RPC failures older than this window are forgotten.
+const FAILURE_WINDOW: Duration = Duration::from_secs(60);
+/// Number of RPC failures within [`FAILURE_WINDOW`] after which a worker is blacklisted.
+const BLACKLIST_FAILURE_THRESHOLD: u32 = 3;
+/// How long a blacklisted worker is excluded from scheduling.
+const BLACKLIST_DURATION: Duration = Duration::from_secs(30);
+/// Weight of a new sample in the latency moving average, as `1 / LATENCY_EWMA_WEIGHT`.
+const LATENCY_EWMA_WEIGHT: u64 = 8;
+
+/// Health of a single worker as observed by the batch RPCs this frontend issues to it.
+#[derive(Default)]
+struct WorkerHealth {
+    /// RPC failures within the current [`FAILURE_WINDOW`].
+    recent_failures: u32,
+    last_failure: Option<Instant>,
+    /// Exponentially weighted moving average of the RPC latency in microseconds. `None` until
+    /// the first successful RPC.
+    latency_us: Option<u64>,
+    blacklisted_until: Option<Instant>,
+}
+
+impl WorkerHealth {
+    fn on_success(&mut self, latency: Duration) {
+        let sample = latency.as_micros() as u64;
+        self.latency_us = Some(match self.latency_us {
+            Some(ewma) => ewma - ewma / LATENCY_EWMA_WEIGHT + sample / LATENCY_EWMA_WEIGHT,
+            None => sample,
+        });
+    }
+
+    /// Records a failure. Returns whether this failure blacklisted the worker.
+    fn on_failure(&mut self, now: Instant) -> bool {
+        self.recent_failures = self.recent_failures(now) + 1;
+        self.last_failure = Some(now);
+        if self.recent_failures >= BLACKLIST_FAILURE_THRESHOLD && !self.is_blacklisted(now) {
+            self.blacklisted_until = Some(now + BLACKLIST_DURATION);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn recent_failures(&self, now: Instant) -> u32 {
+        match self.last_failure {
+            Some(last) if now.duration_since(last) <= FAILURE_WINDOW => self.recent_failures,
+            _ => 0,
+        }
+    }
+
+    fn is_blacklisted(&self, now: Instant) -> bool {
+        self.blacklisted_until.map_or(false, |until| now < until)
+    }
+}
+
+/// A snapshot of the health of a worker, served through `rw_catalog.rw_worker_health`.
+pub struct WorkerHealthInfo {
+    pub worker_id: u32,
+    /// RPC failures within the current [`FAILURE_WINDOW`].
+    pub recent_failure_count: u32,
+    /// Moving average of the RPC latency in milliseconds. `None` until the first successful RPC.
+    pub rpc_latency_ms: Option<f64>,
+    /// Whether the worker is currently excluded from scheduling.
+    pub blacklisted: bool,
+}
+
 /// `WorkerNodeManager` manages live worker nodes and table vnode mapping information.
 pub struct WorkerNodeManager {
     inner: RwLock<WorkerNodeManagerInner>,
@@ -34,6 +100,29 @@ struct WorkerNodeManagerInner {
     worker_nodes: Vec<WorkerNode>,
     /// fragment vnode mapping info.
     fragment_vnode_mapping: HashMap<FragmentId, ParallelUnitMapping>,
+    /// Health of the workers, keyed by worker id and fed by the batch RPCs of this frontend.
+    worker_health: HashMap<u32, WorkerHealth>,
+}
+
+impl WorkerNodeManagerInner {
+    /// The candidates that are not currently blacklisted. Falls back to every candidate when all
+    /// of them are blacklisted, because a slow worker still beats no worker.
+    fn healthy<'a>(&self, candidates: &'a [WorkerNode], now: Instant) -> Vec<&'a WorkerNode> {
+        let healthy = candidates
+            .iter()
+            .filter(|worker| {
+                !self
+                    .worker_health
+                    .get(&worker.id)
+                    .map_or(false, |health| health.is_blacklisted(now))
+            })
+            .collect::<Vec<_>>();
+        if healthy.is_empty() {
+            candidates.iter().collect()
+        } else {
+            healthy
+        }
+    }
 }
 
 pub type WorkerNodeManagerRef = Arc<WorkerNodeManager>;
@@ -56,6 +145,7 @@ impl WorkerNodeManager {
         let inner = RwLock::new(WorkerNodeManagerInner {
             worker_nodes,
             fragment_vnode_mapping: HashMap::new(),
+            worker_health: HashMap::new(),
         });
         Self { inner }
     }
@@ -69,11 +159,9 @@ impl WorkerNodeManager {
     }
 
     pub fn remove_worker_node(&self, node: WorkerNode) {
-        self.inner
-            .write()
-            .unwrap()
-            .worker_nodes
-            .retain(|x| *x != node);
+        let mut write_guard = self.inner.write().unwrap();
+        write_guard.worker_health.remove(&node.id);
+        write_guard.worker_nodes.retain(|x| *x != node);
     }
 
     pub fn refresh(
@@ -82,11 +170,14 @@ impl WorkerNodeManager {
         mapping: HashMap<FragmentId, ParallelUnitMapping>,
     ) {
         let mut write_guard = self.inner.write().unwrap();
+        write_guard
+            .worker_health
+            .retain(|worker_id, _| nodes.iter().any(|node| node.id == *worker_id));
         write_guard.worker_nodes = nodes;
         write_guard.fragment_vnode_mapping = mapping;
     }
 
-    /// Get a random worker node.
+    /// Get a random worker node that is not blacklisted.
     pub fn next_random(&self) -> SchedulerResult<WorkerNode> {
         let inner = self.inner.read().unwrap();
         if inner.worker_nodes.is_empty() {
@@ -94,11 +185,90 @@ impl WorkerNodeManager {
             return Err(SchedulerError::EmptyWorkerNodes);
         }
 
-        Ok(inner
-            .worker_nodes
+        Ok((*inner
+            .healthy(&inner.worker_nodes, Instant::now())
             .choose(&mut rand::thread_rng())
+            .unwrap())
+        .clone())
+    }
+
+    /// Get a worker node deterministically chosen by `hint`. Requests carrying the same hint
+    /// (e.g. scans of the same table) land on the same worker as long as the cluster topology
+    /// does not change, so that they can reuse the blocks already resident in its block cache.
+    /// Blacklisting a worker temporarily shifts the affinity away from it.
+    pub fn next_with_affinity(&self, hint: u64) -> SchedulerResult<WorkerNode> {
+        let inner = self.inner.read().unwrap();
+        if inner.worker_nodes.is_empty() {
+            tracing::error!("No worker node available.");
+            return Err(SchedulerError::EmptyWorkerNodes);
+        }
+
+        let healthy = inner.healthy(&inner.worker_nodes, Instant::now());
+        Ok(healthy[hint as usize % healthy.len()].clone())
+    }
+
+    /// Picks a random candidate that is not blacklisted, falling back to any candidate when all
+    /// of them are.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `candidates` is empty.
+    pub fn choose_healthy(&self, candidates: &[WorkerNode]) -> WorkerNode {
+        let inner = self.inner.read().unwrap();
+        (*inner
+            .healthy(candidates, Instant::now())
+            .choose(&mut rand::thread_rng())
+            .unwrap())
+        .clone()
+    }
+
+    /// Records a successful batch RPC to the worker, feeding its latency into the worker's
+    /// health stats.
+    pub fn report_rpc_success(&self, worker_id: u32, latency: Duration) {
+        self.inner
+            .write()
             .unwrap()
-            .clone())
+            .worker_health
+            .entry(worker_id)
+            .or_default()
+            .on_success(latency);
+    }
+
+    /// Records a failed batch RPC to the worker. A worker failing repeatedly within a short
+    /// window is temporarily excluded from scheduling.
+    pub fn report_rpc_failure(&self, worker_id: u32) {
+        let mut write_guard = self.inner.write().unwrap();
+        let health = write_guard.worker_health.entry(worker_id).or_default();
+        if health.on_failure(Instant::now()) {
+            tracing::warn!(
+                "Worker {} failed {} RPCs within {:?}, excluding it from scheduling for {:?}",
+                worker_id,
+                health.recent_failures,
+                FAILURE_WINDOW,
+                BLACKLIST_DURATION,
+            );
+        }
+    }
+
+    /// The health of every live worker as observed by this frontend.
+    pub fn worker_health(&self) -> Vec<WorkerHealthInfo> {
+        let inner = self.inner.read().unwrap();
+        let now = Instant::now();
+        inner
+            .worker_nodes
+            .iter()
+            .map(|worker| {
+                let health = inner.worker_health.get(&worker.id);
+                WorkerHealthInfo {
+                    worker_id: worker.id,
+                    recent_failure_count: health.map_or(0, |h| h.recent_failures(now)),
+                    rpc_latency_ms: health
+                        .and_then(|h| h.latency_us)
+                        .map(|us| us as f64 / 1000.0),
+                    blacklisted: health.map_or(false, |h| h.is_blacklisted(now)),
+                }
+            })
+            .collect()
     }
 
     pub fn worker_node_count(&self) -> usize {
@@ -218,4 +388,52 @@ mod tests {
             worker_nodes.as_slice()[1..].to_vec()
         );
     }
+
+    #[test]
+    fn test_worker_health() {
+        use std::time::Duration;
+
+        use super::*;
+
+        let worker_nodes = vec![
+            WorkerNode {
+                id: 1,
+                r#type: WorkerType::ComputeNode as i32,
+                host: Some(HostAddr::try_from("127.0.0.1:1234").unwrap().to_protobuf()),
+                state: worker_node::State::Running as i32,
+                parallel_units: vec![],
+            },
+            WorkerNode {
+                id: 2,
+                r#type: WorkerType::ComputeNode as i32,
+                host: Some(HostAddr::try_from("127.0.0.1:1235").unwrap().to_protobuf()),
+                state: worker_node::State::Running as i32,
+                parallel_units: vec![],
+            },
+        ];
+        let manager = WorkerNodeManager::mock(worker_nodes.clone());
+
+        manager.report_rpc_success(1, Duration::from_millis(10));
+        for _ in 0..BLACKLIST_FAILURE_THRESHOLD {
+            manager.report_rpc_failure(1);
+        }
+        let health = manager.worker_health();
+        assert!(health.iter().find(|h| h.worker_id == 1).unwrap().blacklisted);
+        assert!(!health.iter().find(|h| h.worker_id == 2).unwrap().blacklisted);
+
+        // A blacklisted worker is never picked while a healthy one remains.
+        for _ in 0..16 {
+            assert_eq!(manager.next_random().unwrap().id, 2);
+            assert_eq!(manager.choose_healthy(&worker_nodes).id, 2);
+        }
+        // With every candidate blacklisted, scheduling falls back to all of them.
+        for _ in 0..BLACKLIST_FAILURE_THRESHOLD {
+            manager.report_rpc_failure(2);
+        }
+        manager.next_random().unwrap();
+
+        // Removing a worker drops its health stats.
+        manager.remove_worker_node(worker_nodes[0].clone());
+        assert!(manager.worker_health().iter().all(|h| h.worker_id != 1));
+    }
 }
@@ -16,7 +16,7 @@ use std::collections::HashMap;
 use std::io::{Error, ErrorKind};
 use std::sync::atomic::{AtomicI32, Ordering};
 use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use parking_lot::{RwLock, RwLockReadGuard};
 use pgwire::pg_field_descriptor::PgFieldDescriptor;
@@ -46,7 +46,7 @@ use risingwave_pb::health::health_server::HealthServer;
 use risingwave_pb::user::auth_info::EncryptionType;
 use risingwave_pb::user::grant_privilege::{Action, Object};
 use risingwave_rpc_client::{ComputeClientPool, ComputeClientPoolRef, MetaClient};
-use risingwave_sqlparser::ast::{ObjectName, ShowObject, Statement};
+use risingwave_sqlparser::ast::{Expr, ObjectName, SetExpr, ShowObject, Statement};
 use risingwave_sqlparser::parser::Parser;
 use tokio::sync::oneshot::Sender;
 use tokio::sync::watch;
@@ -55,7 +55,7 @@ use tracing::info;
 
 use crate::binder::Binder;
 use crate::catalog::catalog_service::{CatalogReader, CatalogWriter, CatalogWriterImpl};
-use crate::catalog::root_catalog::Catalog;
+use crate::catalog::root_catalog::{Catalog, SchemaPath};
 use crate::catalog::{check_schema_writable, DatabaseId, SchemaId};
 use crate::handler::privilege::ObjectCheckItem;
 use crate::handler::util::to_pg_field;
@@ -74,6 +74,7 @@ use crate::scheduler::{
 };
 use crate::user::user_authentication::md5_hash_with_salt;
 use crate::user::user_manager::UserInfoManager;
+use crate::user::user_query_stats::{UserQueryStatsManager, UserQueryStatsManagerRef};
 use crate::user::user_service::{UserInfoReader, UserInfoWriter, UserInfoWriterImpl};
 use crate::user::UserId;
 use crate::{FrontendOpts, PgResponseStream};
@@ -108,6 +109,10 @@ pub struct FrontendEnv {
     /// Track creating streaming jobs, used to cancel creating streaming job when cancel request
     /// received.
     creating_streaming_job_tracker: StreamingJobTrackerRef,
+
+    /// Per-user query statistics collected on this frontend node, exposed through the
+    /// `rw_user_query_stats` system table.
+    user_query_stats_manager: UserQueryStatsManagerRef,
 }
 
 type SessionMapRef = Arc<Mutex<HashMap<(i32, i32), Arc<SessionImpl>>>>;
@@ -153,6 +158,7 @@ impl FrontendEnv {
             batch_config: BatchConfig::default(),
             source_metrics: Arc::new(SourceMetrics::default()),
             creating_streaming_job_tracker: Arc::new(creating_streaming_tracker),
+            user_query_stats_manager: Arc::new(UserQueryStatsManager::default()),
         }
     }
 
@@ -290,6 +296,7 @@ impl FrontendEnv {
                 batch_config,
                 source_metrics,
                 creating_streaming_job_tracker,
+                user_query_stats_manager: Arc::new(UserQueryStatsManager::default()),
             },
             observer_join_handle,
             heartbeat_join_handle,
@@ -360,6 +367,10 @@ impl FrontendEnv {
     pub fn creating_streaming_job_tracker(&self) -> &StreamingJobTrackerRef {
         &self.creating_streaming_job_tracker
     }
+
+    pub fn user_query_stats_manager(&self) -> &UserQueryStatsManagerRef {
+        &self.user_query_stats_manager
+    }
 }
 
 pub struct AuthContext {
@@ -688,6 +699,33 @@ impl SessionManagerImpl {
     }
 }
 
+/// Records the finished statement in the per-user query statistics and emits an audit event
+/// under the `risingwave_frontend_query_audit` target, which can be routed to a dedicated sink
+/// by the log configuration.
+fn record_and_audit_query<E>(
+    stats_manager: &UserQueryStatsManagerRef,
+    user_name: &str,
+    sql: &str,
+    start: Instant,
+    rsp: &std::result::Result<PgResponse<PgResponseStream>, E>,
+) {
+    stats_manager.record_query(
+        user_name,
+        rsp.is_err(),
+        rsp.as_ref()
+            .ok()
+            .and_then(|rsp| rsp.get_effected_rows_cnt()),
+    );
+    tracing::debug!(
+        target: "risingwave_frontend_query_audit",
+        user = user_name,
+        sql,
+        duration_ms = start.elapsed().as_millis() as u64,
+        outcome = if rsp.is_ok() { "ok" } else { "error" },
+        "statement finished"
+    );
+}
+
 #[async_trait::async_trait]
 impl Session<PgResponseStream> for SessionImpl {
     async fn run_statement(
@@ -710,6 +748,9 @@ impl Session<PgResponseStream> for SessionImpl {
             ));
         }
         let stmt = stmts.swap_remove(0);
+        let user_name = self.user_name().to_string();
+        let stats_manager = self.env().user_query_stats_manager().clone();
+        let start = Instant::now();
         let rsp = {
             let mut handle_fut = Box::pin(handle(self, stmt, sql, formats));
             if cfg!(debug_assertions) {
@@ -728,8 +769,9 @@ impl Session<PgResponseStream> for SessionImpl {
             } else {
                 handle_fut.await
             }
-        }
-        .inspect_err(|e| tracing::error!("failed to handle sql:\n{}:\n{}", sql, e))?;
+        };
+        record_and_audit_query(&stats_manager, &user_name, sql, start, &rsp);
+        let rsp = rsp.inspect_err(|e| tracing::error!("failed to handle sql:\n{}:\n{}", sql, e))?;
         Ok(rsp)
     }
 
@@ -741,6 +783,9 @@ impl Session<PgResponseStream> for SessionImpl {
         format: Format,
     ) -> std::result::Result<PgResponse<PgResponseStream>, BoxedError> {
         let sql_str = stmt.to_string();
+        let user_name = self.user_name().to_string();
+        let stats_manager = self.env().user_query_stats_manager().clone();
+        let start = Instant::now();
         let rsp = {
             let mut handle_fut = Box::pin(handle(self, stmt, &sql_str, vec![format]));
             if cfg!(debug_assertions) {
@@ -758,8 +803,10 @@ impl Session<PgResponseStream> for SessionImpl {
             } else {
                 handle_fut.await
             }
-        }
-        .inspect_err(|e| tracing::error!("failed to handle sql:\n{}:\n{}", sql_str, e))?;
+        };
+        record_and_audit_query(&stats_manager, &user_name, &sql_str, start, &rsp);
+        let rsp = rsp
+            .inspect_err(|e| tracing::error!("failed to handle sql:\n{}:\n{}", sql_str, e))?;
         Ok(rsp)
     }
 
@@ -878,6 +925,22 @@ impl Session<PgResponseStream> for SessionImpl {
         Ok(rsp)
     }
 
+    async fn infer_param_types(
+        self: Arc<Self>,
+        sql: &str,
+    ) -> std::result::Result<Vec<Option<DataType>>, BoxedError> {
+        // Parse sql.
+        let mut stmts = Parser::parse_sql(sql)
+            .inspect_err(|e| tracing::error!("failed to parse sql:\n{}:\n{}", sql, e))?;
+        if stmts.len() != 1 {
+            return Ok(vec![]);
+        }
+        let stmt = stmts.swap_remove(0);
+        let param_types = infer_dml_param_types(&self, &stmt)
+            .inspect_err(|e| tracing::error!("failed to infer param types:\n{}:\n{}", sql, e))?;
+        Ok(param_types)
+    }
+
     fn user_authenticator(&self) -> &UserAuthenticator {
         &self.user_authenticator
     }
@@ -908,3 +971,139 @@ fn infer(session: Arc<SessionImpl>, stmt: Statement, sql: &str) -> Result<Vec<Pg
 
     Ok(pg_descs)
 }
+
+/// Infers the types of the parameters (`$1`, `$2`, ...) in a DML statement from the types of the
+/// columns they are bound to, so that `Describe` on a prepared `INSERT`/`UPDATE`/`DELETE` can
+/// answer an accurate `ParameterDescription`. Only parameters that appear directly as a `VALUES`
+/// entry, as an assignment value, or as one side of a comparison in the `WHERE` clause get a type;
+/// the remaining entries are left as `None` for the caller to fall back to its default.
+fn infer_dml_param_types(
+    session: &SessionImpl,
+    stmt: &Statement,
+) -> Result<Vec<Option<DataType>>> {
+    let table_name = match stmt {
+        Statement::Insert { table_name, .. }
+        | Statement::Update { table_name, .. }
+        | Statement::Delete { table_name, .. } => table_name.clone(),
+        _ => return Ok(vec![]),
+    };
+
+    let db_name = session.database();
+    let (schema_name, real_table_name) = Binder::resolve_schema_qualified_name(db_name, table_name)?;
+    let search_path = session.config().get_search_path();
+    let user_name = &session.auth_context().user_name;
+    let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+    let table = {
+        let reader = session.env().catalog_reader().read_guard();
+        let (table, _) = reader.get_table_by_name(db_name, schema_path, &real_table_name)?;
+        table.clone()
+    };
+    let type_of = |column_name: &str| {
+        table
+            .columns()
+            .iter()
+            .find(|c| c.name() == column_name)
+            .map(|c| c.data_type().clone())
+    };
+
+    // The parameters found in the statement, along with the inferred type if any.
+    let mut params: Vec<(u64, Option<DataType>)> = vec![];
+    match stmt {
+        Statement::Insert {
+            columns, source, ..
+        } => {
+            // The type of the target column at each `VALUES` position.
+            let target_types: Vec<Option<DataType>> = if columns.is_empty() {
+                table
+                    .columns()
+                    .iter()
+                    .filter(|c| !c.is_hidden())
+                    .map(|c| Some(c.data_type().clone()))
+                    .collect()
+            } else {
+                columns
+                    .iter()
+                    .map(|ident| type_of(&ident.real_value()))
+                    .collect()
+            };
+            if let SetExpr::Values(values) = &source.body {
+                for row in &values.0 {
+                    for (i, expr) in row.iter().enumerate() {
+                        if let Expr::Parameter { index } = expr {
+                            params.push((*index, target_types.get(i).cloned().flatten()));
+                        }
+                    }
+                }
+            }
+        }
+        Statement::Update {
+            assignments,
+            selection,
+            ..
+        } => {
+            for assignment in assignments {
+                if let Expr::Parameter { index } = &assignment.value {
+                    let data_type = assignment
+                        .id
+                        .last()
+                        .and_then(|ident| type_of(&ident.real_value()));
+                    params.push((*index, data_type));
+                }
+            }
+            if let Some(selection) = selection {
+                collect_params_in_where(selection, &type_of, &mut params);
+            }
+        }
+        Statement::Delete { selection, .. } => {
+            if let Some(selection) = selection {
+                collect_params_in_where(selection, &type_of, &mut params);
+            }
+        }
+        _ => unreachable!(),
+    }
+
+    let mut param_types: Vec<Option<DataType>> = vec![];
+    for (index, data_type) in params {
+        let Some(idx) = (index as usize).checked_sub(1) else {
+            continue;
+        };
+        if idx >= param_types.len() {
+            param_types.resize(idx + 1, None);
+        }
+        // If a parameter is used in multiple places, keep the first inferred type.
+        if param_types[idx].is_none() {
+            param_types[idx] = data_type;
+        }
+    }
+    Ok(param_types)
+}
+
+/// Collects the parameters that appear as one side of a comparison in a `WHERE` clause, inferring
+/// the type from the column referenced on the other side if there is one.
+fn collect_params_in_where(
+    expr: &Expr,
+    type_of: &impl Fn(&str) -> Option<DataType>,
+    params: &mut Vec<(u64, Option<DataType>)>,
+) {
+    match expr {
+        Expr::BinaryOp { left, right, .. } => match (left.as_ref(), right.as_ref()) {
+            (Expr::Parameter { index }, other) | (other, Expr::Parameter { index }) => {
+                let data_type = match other {
+                    Expr::Identifier(ident) => type_of(&ident.real_value()),
+                    Expr::CompoundIdentifier(idents) => idents
+                        .last()
+                        .and_then(|ident| type_of(&ident.real_value())),
+                    _ => None,
+                };
+                params.push((*index, data_type));
+            }
+            _ => {
+                collect_params_in_where(left, type_of, params);
+                collect_params_in_where(right, type_of, params);
+            }
+        },
+        Expr::Nested(inner) => collect_params_in_where(inner, type_of, params),
+        _ => {}
+    }
+}
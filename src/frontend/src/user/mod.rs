@@ -15,6 +15,7 @@
 pub(crate) mod user_authentication;
 pub(crate) mod user_manager;
 pub mod user_privilege;
+pub mod user_query_stats;
 pub(crate) mod user_service;
 
 pub type UserId = u32;
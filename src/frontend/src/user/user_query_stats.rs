@@ -0,0 +1,62 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Cumulative query statistics of one user, collected on this frontend node since it started.
+#[derive(Clone, Debug, Default)]
+pub struct UserQueryStats {
+    /// Number of statements run by the user, including failed ones.
+    pub query_count: u64,
+    /// Number of statements that returned an error.
+    pub failed_query_count: u64,
+    /// Total number of rows affected by DML statements.
+    pub dml_row_count: u64,
+}
+
+/// Collects per-user query statistics on this frontend node, keyed by user name.
+#[derive(Debug, Default)]
+pub struct UserQueryStatsManager {
+    stats: Mutex<HashMap<String, UserQueryStats>>,
+}
+
+pub type UserQueryStatsManagerRef = Arc<UserQueryStatsManager>;
+
+impl UserQueryStatsManager {
+    /// Record one finished statement of `user_name`. `dml_row_count` is the number of affected
+    /// rows if the statement is a DML statement.
+    pub fn record_query(&self, user_name: &str, failed: bool, dml_row_count: Option<i32>) {
+        let mut guard = self.stats.lock().unwrap();
+        let stats = guard.entry(user_name.to_string()).or_default();
+        stats.query_count += 1;
+        if failed {
+            stats.failed_query_count += 1;
+        }
+        if let Some(row_count) = dml_row_count {
+            stats.dml_row_count += row_count.max(0) as u64;
+        }
+    }
+
+    /// Returns a snapshot of the statistics of all users, sorted by user name.
+    pub fn snapshot(&self) -> Vec<(String, UserQueryStats)> {
+        let guard = self.stats.lock().unwrap();
+        let mut stats = guard
+            .iter()
+            .map(|(name, stats)| (name.clone(), stats.clone()))
+            .collect::<Vec<_>>();
+        stats.sort_unstable_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+        stats
+    }
+}
@@ -28,6 +28,23 @@ mod options {
     pub const RETENTION_SECONDS: &str = PROPERTIES_RETENTION_SECOND_KEY;
 }
 
+/// The value that secret options are replaced with when a definition is
+/// displayed, e.g. by `SHOW CREATE`.
+pub const SECRET_MASK: &str = "[REDACTED]";
+
+/// Hints identifying options whose values must not be displayed. Matched
+/// case-insensitively against any part of the option name, so that both
+/// e.g. `properties.sasl.password` and `aws.credentials.secret_access_key`
+/// are caught.
+const SECRET_KEY_HINTS: &[&str] = &["password", "secret", "token", "private.key", "credential"];
+
+/// Returns true if the value of the option `key` must be masked when the
+/// definition is displayed.
+pub fn is_secret_option(key: &str) -> bool {
+    let key = key.to_lowercase();
+    SECRET_KEY_HINTS.iter().any(|hint| key.contains(hint))
+}
+
 /// Options or properties extracted from the `WITH` clause of DDLs.
 #[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct WithOptions {
@@ -55,6 +72,11 @@ impl WithOptions {
         &self.inner
     }
 
+    /// Get the mutable reference of the inner map.
+    pub fn inner_mut(&mut self) -> &mut BTreeMap<String, String> {
+        &mut self.inner
+    }
+
     /// Take the value of the inner map.
     pub fn into_inner(self) -> BTreeMap<String, String> {
         self.inner.into_iter().collect()
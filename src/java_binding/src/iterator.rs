@@ -30,7 +30,7 @@ use risingwave_storage::hummock::store::state_store::HummockStorageIterator;
 use risingwave_storage::hummock::store::version::HummockVersionReader;
 use risingwave_storage::hummock::{SstableStore, TieredCache};
 use risingwave_storage::monitor::HummockStateStoreMetrics;
-use risingwave_storage::store::{ReadOptions, StreamTypeOfIter};
+use risingwave_storage::store::{CachePolicy, ReadOptions, StreamTypeOfIter};
 use tokio::sync::mpsc::unbounded_channel;
 
 pub struct Iterator {
@@ -130,9 +130,11 @@ impl Iterator {
                     ReadOptions {
                         prefix_hint: None,
                         ignore_range_tombstone: false,
+                        limit: None,
                         retention_seconds: None,
                         table_id: read_plan.table_id.into(),
                         read_version_from_backup: false,
+                        cache_policy: CachePolicy::Fill,
                     },
                     (
                         vec![],
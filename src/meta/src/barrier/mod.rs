@@ -837,8 +837,10 @@ where
         if self.enable_recovery {
             // If failed, enter recovery mode.
             self.set_status(BarrierManagerStatus::Recovering).await;
-            let mut tracker = self.tracker.lock().await;
-            *tracker = CreateMviewProgressTracker::new();
+            // Roll back the streaming jobs that are still being created: recovery will clean up
+            // their fragments as dirty, so fail the awaiting DDL statements explicitly instead of
+            // leaving them in a stuck creating state.
+            self.tracker.lock().await.abort_all();
             self.snapshot_manager
                 .unpin_all()
                 .await
@@ -940,6 +942,19 @@ where
                 };
 
                 for command in finished_commands {
+                    // Notify about the completion of the backfill, so that interested parties
+                    // (e.g. the dashboard) can subscribe to it.
+                    if let Command::CreateStreamingJob {
+                        table_fragments, ..
+                    } = &command.context.command
+                    {
+                        self.env
+                            .notification_manager()
+                            .notify_local_subscribers(LocalNotification::CreateMviewFinished(
+                                table_fragments.table_id(),
+                            ))
+                            .await;
+                    }
                     checkpoint_control.stash_command_to_finish(command);
                 }
 
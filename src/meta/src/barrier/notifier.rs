@@ -25,8 +25,8 @@ pub(super) struct Notifier {
     /// Get notified when scheduled barrier is collected or failed.
     pub collected: Option<oneshot::Sender<MetaResult<()>>>,
 
-    /// Get notified when scheduled barrier is finished.
-    pub finished: Option<oneshot::Sender<()>>,
+    /// Get notified when scheduled barrier is finished or failed to finish.
+    pub finished: Option<oneshot::Sender<MetaResult<()>>>,
 }
 
 impl Notifier {
@@ -58,7 +58,15 @@ impl Notifier {
     /// However for creating MV, this is only called when all `Chain` report it finished.
     pub fn notify_finished(self) {
         if let Some(tx) = self.finished {
-            tx.send(()).ok();
+            tx.send(Ok(())).ok();
+        }
+    }
+
+    /// Notify when we failed to finish a barrier, e.g. the tracked streaming job is rolled back by
+    /// recovery. This function consumes `self`.
+    pub fn notify_finish_failed(self, err: MetaError) {
+        if let Some(tx) = self.finished {
+            tx.send(Err(err)).ok();
         }
     }
 }
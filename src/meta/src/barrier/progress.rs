@@ -15,6 +15,7 @@
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use itertools::Itertools;
 use risingwave_common::catalog::TableId;
@@ -28,6 +29,7 @@ use super::notifier::Notifier;
 use crate::barrier::Command;
 use crate::model::ActorId;
 use crate::storage::MetaStore;
+use crate::MetaError;
 
 type CreateMviewEpoch = Epoch;
 type ConsumedRows = u64;
@@ -55,6 +57,9 @@ struct Progress {
     /// Upstream mvs total key count.
     upstream_total_key_count: u64,
 
+    /// The time when the tracking is started, for estimating the remaining time.
+    start_time: Instant,
+
     /// DDL definition
     definition: String,
 }
@@ -80,6 +85,7 @@ impl Progress {
             creating_mv_id,
             upstream_mv_count,
             upstream_total_key_count,
+            start_time: Instant::now(),
             definition,
         }
     }
@@ -110,6 +116,17 @@ impl Progress {
         self.states.keys().cloned()
     }
 
+    /// Returns the total number of rows consumed by all chains so far.
+    fn consumed_rows(&self) -> u64 {
+        self.states
+            .values()
+            .map(|x| match x {
+                ChainState::ConsumingUpstream(_, rows) => *rows,
+                _ => 0,
+            })
+            .sum()
+    }
+
     /// `progress` = `done_ratio` + (1 - `done_ratio`) * (`consumed_rows` / `remaining_rows`).
     fn calculate_progress(&self) -> f64 {
         if self.is_done() || self.states.is_empty() {
@@ -120,14 +137,7 @@ impl Progress {
         if remaining_rows == 0.0 {
             remaining_rows = 1.0;
         }
-        let consumed_rows: u64 = self
-            .states
-            .values()
-            .map(|x| match x {
-                ChainState::ConsumingUpstream(_, rows) => *rows,
-                _ => 0,
-            })
-            .sum();
+        let consumed_rows = self.consumed_rows();
         let mut progress =
             done_ratio + (1_f64 - done_ratio) * consumed_rows as f64 / remaining_rows;
         if progress >= 1.0 {
@@ -135,6 +145,24 @@ impl Progress {
         }
         progress
     }
+
+    /// Estimate the remaining time of the backfill, by dividing the remaining key count of the
+    /// upstream mvs by the backfill throughput so far. Returns `None` until some rows have been
+    /// consumed, since the throughput is unknown before that.
+    fn estimate_remaining_time(&self) -> Option<Duration> {
+        if self.is_done() {
+            return Some(Duration::ZERO);
+        }
+        let consumed_rows = self.consumed_rows();
+        if consumed_rows == 0 {
+            return None;
+        }
+        let throughput = consumed_rows as f64 / self.start_time.elapsed().as_secs_f64();
+        let remaining_rows = self.upstream_total_key_count.saturating_sub(consumed_rows);
+        Some(Duration::from_secs_f64(
+            remaining_rows as f64 / throughput.max(f64::MIN_POSITIVE),
+        ))
+    }
 }
 
 /// The command tracking by the [`CreateMviewProgressTracker`].
@@ -171,10 +199,36 @@ impl<S: MetaStore> CreateMviewProgressTracker<S> {
                 id: x.creating_mv_id.table_id as u64,
                 statement: x.definition.clone(),
                 progress: format!("{:.2}%", x.calculate_progress() * 100.0),
+                eta: x
+                    .estimate_remaining_time()
+                    .map(|eta| format!("{}s", eta.as_secs()))
+                    .unwrap_or_default(),
             })
             .collect()
     }
 
+    /// Abort all tracked create-streaming-job commands and clear the tracker. Called by recovery:
+    /// the backfill progress of the creating jobs is not persistent, so recovery rolls them back
+    /// by cleaning up their fragments, and the DDL statements awaiting them should fail instead
+    /// of hanging.
+    pub fn abort_all(&mut self) {
+        self.actor_map.clear();
+        for (_, (progress, command)) in self.progress_map.drain() {
+            tracing::warn!(
+                "creating streaming job {} is rolled back by recovery",
+                progress.creating_mv_id
+            );
+            let err = MetaError::cancelled(format!(
+                "streaming job {} is rolled back by recovery since its backfill was interrupted",
+                progress.creating_mv_id
+            ));
+            command
+                .notifiers
+                .into_iter()
+                .for_each(|notifier| notifier.notify_finish_failed(err.clone()));
+        }
+    }
+
     /// Try to find the target create-streaming-job command from track.
     ///
     /// Return the target command as it should be cancelled based on the input actors.
@@ -64,7 +64,10 @@ where
         .await
     }
 
-    /// Clean up all dirty streaming jobs.
+    /// Clean up all dirty streaming jobs. Since the backfill progress of a creating job is not
+    /// persistent, a job whose table fragments are not fully created is rolled back rather than
+    /// resumed. The awaiting DDL statements have been aborted by the progress tracker before
+    /// recovery, so they fail explicitly instead of being left in a stuck creating state.
     async fn clean_dirty_fragments(&self) -> MetaResult<()> {
         let stream_job_ids = self.catalog_manager.list_stream_job_ids().await?;
         let table_fragments = self.fragment_manager.list_table_fragments().await?;
@@ -160,7 +160,7 @@ impl<S: MetaStore> BarrierScheduler<S> {
     pub async fn run_multiple_commands(&self, commands: Vec<Command>) -> MetaResult<()> {
         struct Context {
             collect_rx: oneshot::Receiver<MetaResult<()>>,
-            finish_rx: oneshot::Receiver<()>,
+            finish_rx: oneshot::Receiver<MetaResult<()>>,
         }
 
         let mut contexts = Vec::with_capacity(commands.len());
@@ -198,10 +198,11 @@ impl<S: MetaStore> BarrierScheduler<S> {
                 .await
                 .map_err(|e| anyhow!("failed to collect barrier: {}", e))??;
 
-            // Wait for this command to be finished.
+            // Wait for this command to be finished. It may fail if the tracked streaming job is
+            // rolled back by recovery.
             finish_rx
                 .await
-                .map_err(|e| anyhow!("failed to finish command: {}", e))?;
+                .map_err(|e| anyhow!("failed to finish command: {}", e))??;
         }
 
         Ok(())
@@ -27,6 +27,7 @@ const DEFAULT_MAX_SUB_COMPACTION: u32 = 4;
 const MAX_LEVEL: u64 = 6;
 const DEFAULT_LEVEL_MULTIPLIER: u64 = 5;
 const DEFAULT_MAX_SPACE_RECLAIM_BYTES: u64 = 512 * 1024 * 1024; // 512MB;
+const DEFAULT_TOMBSTONE_RECLAIM_RATIO: u64 = 40; // 40%
 
 pub struct CompactionConfigBuilder {
     config: CompactionConfig,
@@ -61,6 +62,7 @@ impl CompactionConfigBuilder {
                     .into(),
                 max_sub_compaction: DEFAULT_MAX_SUB_COMPACTION,
                 max_space_reclaim_bytes: DEFAULT_MAX_SPACE_RECLAIM_BYTES,
+                tombstone_reclaim_ratio: DEFAULT_TOMBSTONE_RECLAIM_RATIO,
             },
         }
     }
@@ -105,4 +107,5 @@ builder_field! {
     compaction_filter_mask: u32,
     max_sub_compaction: u32,
     max_space_reclaim_bytes: u64,
+    tombstone_reclaim_ratio: u64,
 }
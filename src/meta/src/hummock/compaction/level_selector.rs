@@ -25,8 +25,8 @@ use risingwave_pb::hummock::hummock_version::Levels;
 use risingwave_pb::hummock::{compact_task, CompactionConfig};
 
 use super::picker::{
-    SpaceReclaimCompactionPicker, SpaceReclaimPickerState, TtlPickerState,
-    TtlReclaimCompactionPicker,
+    SpaceReclaimCompactionPicker, SpaceReclaimPickerState, TombstoneReclaimCompactionPicker,
+    TombstoneReclaimPickerState, TtlPickerState, TtlReclaimCompactionPicker,
 };
 use super::{
     create_compaction_task, LevelCompactionPicker, ManualCompactionOption, ManualCompactionPicker,
@@ -516,6 +516,56 @@ impl LevelSelector for TtlCompactionSelector {
     }
 }
 
+#[derive(Default)]
+pub struct TombstoneCompactionSelector {
+    state: HashMap<u64, TombstoneReclaimPickerState>,
+}
+
+impl LevelSelector for TombstoneCompactionSelector {
+    fn pick_compaction(
+        &mut self,
+        task_id: HummockCompactionTaskId,
+        group: &CompactionGroup,
+        levels: &Levels,
+        level_handlers: &mut [LevelHandler],
+        _selector_stats: &mut LocalSelectorStatistic,
+        _table_id_to_options: HashMap<u32, TableOption>,
+    ) -> Option<CompactionTask> {
+        if group.compaction_config.tombstone_reclaim_ratio == 0 {
+            // it might cause full-compaction when tombstone_reclaim_ratio == 0
+            return None;
+        }
+
+        let dynamic_level_core = DynamicLevelSelectorCore::new(group.compaction_config.clone());
+        let ctx = dynamic_level_core.calculate_level_base_size(levels);
+        let picker = TombstoneReclaimCompactionPicker::new(
+            create_overlap_strategy(group.compaction_config.compaction_mode()),
+            group.compaction_config.tombstone_reclaim_ratio,
+        );
+        let state = self
+            .state
+            .entry(group.group_id)
+            .or_insert_with(TombstoneReclaimPickerState::default);
+        let compaction_input = picker.pick_compaction(levels, level_handlers, state)?;
+        compaction_input.add_pending_task(task_id, level_handlers);
+
+        Some(create_compaction_task(
+            group.compaction_config.as_ref(),
+            compaction_input,
+            ctx.base_level,
+            self.task_type(),
+        ))
+    }
+
+    fn name(&self) -> &'static str {
+        "TombstoneCompaction"
+    }
+
+    fn task_type(&self) -> compact_task::TaskType {
+        compact_task::TaskType::Tombstone
+    }
+}
+
 pub fn default_level_selector() -> Box<dyn LevelSelector> {
     Box::<DynamicLevelSelector>::default()
 }
@@ -598,6 +648,7 @@ pub mod tests {
             uncompressed_file_size: (right - left + 1) as u64,
             min_epoch: 0,
             max_epoch: 0,
+            tombstone_key_count: 0,
         }
     }
 
@@ -628,6 +679,7 @@ pub mod tests {
             uncompressed_file_size: (right - left + 1) as u64,
             min_epoch,
             max_epoch,
+            tombstone_key_count: 0,
         }
     }
 
@@ -34,7 +34,8 @@ use risingwave_pb::hummock::{CompactTask, CompactionConfig, InputLevel, KeyRange
 
 pub use crate::hummock::compaction::level_selector::{
     default_level_selector, DynamicLevelSelector, DynamicLevelSelectorCore, LevelSelector,
-    ManualCompactionSelector, SpaceReclaimCompactionSelector, TtlCompactionSelector,
+    ManualCompactionSelector, SpaceReclaimCompactionSelector, TombstoneCompactionSelector,
+    TtlCompactionSelector,
 };
 use crate::hummock::compaction::overlap_strategy::{OverlapStrategy, RangeOverlapStrategy};
 use crate::hummock::level_handler::LevelHandler;
@@ -0,0 +1,271 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use risingwave_pb::hummock::hummock_version::Levels;
+use risingwave_pb::hummock::{InputLevel, SstableInfo};
+
+use crate::hummock::compaction::overlap_strategy::OverlapStrategy;
+use crate::hummock::compaction::CompactionInput;
+use crate::hummock::level_handler::LevelHandler;
+
+// TombstoneReclaimCompactionPicker scans the levels from top to bottom and picks the first file
+// whose delete-tombstone ratio reaches `delete_ratio`, together with the overlapping files in the
+// next level. Such files are common after ttl expiry or bulk deletes, and compacting them early
+// avoids scans (especially backward scans) wasting time skipping over masses of delete marks.
+pub struct TombstoneReclaimCompactionPicker {
+    overlap_strategy: Arc<dyn OverlapStrategy>,
+
+    // config
+    pub delete_ratio: u64,
+}
+
+// According to the execution model of TombstoneReclaimCompactionPicker,
+// TombstoneReclaimPickerState is designed to record the level that the current round of scanning
+// has reached, so that consecutive picks do not always reclaim from the first level.
+#[derive(Default)]
+pub struct TombstoneReclaimPickerState {
+    pub last_level: usize,
+}
+
+impl TombstoneReclaimCompactionPicker {
+    pub fn new(overlap_strategy: Arc<dyn OverlapStrategy>, delete_ratio: u64) -> Self {
+        Self {
+            overlap_strategy,
+            delete_ratio,
+        }
+    }
+
+    fn filter(&self, sst: &SstableInfo) -> bool {
+        // it means the percentage of tombstones does not reach the ratio, so we do not need to
+        // pick this sst
+        sst.total_key_count == 0
+            || sst.tombstone_key_count * 100 < sst.total_key_count * self.delete_ratio
+    }
+}
+
+impl TombstoneReclaimCompactionPicker {
+    pub fn pick_compaction(
+        &self,
+        levels: &Levels,
+        level_handlers: &[LevelHandler],
+        state: &mut TombstoneReclaimPickerState,
+    ) -> Option<CompactionInput> {
+        assert!(!levels.levels.is_empty());
+        if state.last_level == 0 {
+            state.last_level = 1;
+        }
+
+        while state.last_level <= levels.levels.len() {
+            let select_level = &levels.levels[state.last_level - 1];
+            let mut select_input_ssts = vec![];
+            for sst in &select_level.table_infos {
+                if level_handlers[state.last_level].is_pending_compact(&sst.id) || self.filter(sst)
+                {
+                    continue;
+                }
+                select_input_ssts.push(sst.clone());
+                break;
+            }
+
+            if !select_input_ssts.is_empty() {
+                let target_input_level = if state.last_level == levels.levels.len() {
+                    // the bottommost level shall be rewritten in place to drop the tombstones
+                    InputLevel {
+                        level_idx: select_level.level_idx,
+                        level_type: select_level.level_type,
+                        table_infos: vec![],
+                    }
+                } else {
+                    let next_level = &levels.levels[state.last_level];
+                    let target_table_infos = self
+                        .overlap_strategy
+                        .check_base_level_overlap(&select_input_ssts, &next_level.table_infos);
+                    let mut pending_compact = false;
+                    for sst in &target_table_infos {
+                        if level_handlers[state.last_level + 1].is_pending_compact(&sst.id) {
+                            pending_compact = true;
+                            break;
+                        }
+                    }
+                    if pending_compact {
+                        state.last_level += 1;
+                        continue;
+                    }
+                    InputLevel {
+                        level_idx: next_level.level_idx,
+                        level_type: next_level.level_type,
+                        table_infos: target_table_infos,
+                    }
+                };
+
+                let target_level = target_input_level.level_idx as usize;
+                state.last_level += 1;
+                return Some(CompactionInput {
+                    input_levels: vec![
+                        InputLevel {
+                            level_idx: select_level.level_idx,
+                            level_type: select_level.level_type,
+                            table_infos: select_input_ssts,
+                        },
+                        target_input_level,
+                    ],
+                    target_level,
+                    target_sub_level_id: 0,
+                });
+            }
+            state.last_level += 1;
+        }
+
+        // turn to next_round
+        state.last_level = 0;
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use itertools::Itertools;
+    use risingwave_pb::hummock::compact_task;
+
+    use super::*;
+    use crate::hummock::compaction::compaction_config::CompactionConfigBuilder;
+    use crate::hummock::compaction::level_selector::tests::{
+        assert_compaction_task, generate_l0_nonoverlapping_sublevels, generate_level,
+        generate_table_with_ids_and_epochs,
+    };
+    use crate::hummock::compaction::level_selector::TombstoneCompactionSelector;
+    use crate::hummock::compaction::{LevelSelector, LocalSelectorStatistic};
+    use crate::hummock::model::CompactionGroup;
+
+    #[test]
+    fn test_tombstone_reclaim_compaction_selector() {
+        let config = CompactionConfigBuilder::new()
+            .max_level(4)
+            .tombstone_reclaim_ratio(40)
+            .build();
+        let group_config = CompactionGroup::new(1, config);
+
+        let l0 = generate_l0_nonoverlapping_sublevels(vec![]);
+        let levels = vec![
+            generate_level(1, vec![]),
+            generate_level(2, vec![]),
+            generate_level(
+                3,
+                vec![
+                    generate_table_with_ids_and_epochs(0, 1, 140, 160, 1, vec![0], 0, 0),
+                    generate_table_with_ids_and_epochs(1, 1, 250, 260, 1, vec![1], 0, 0),
+                ],
+            ),
+            generate_level(
+                4,
+                vec![
+                    generate_table_with_ids_and_epochs(2, 1, 100, 200, 1, vec![2], 0, 0),
+                    generate_table_with_ids_and_epochs(3, 1, 201, 300, 1, vec![3], 0, 0),
+                ],
+            ),
+        ];
+        let mut levels = Levels {
+            levels,
+            l0: Some(l0),
+            ..Default::default()
+        };
+        let mut levels_handler = (0..5).map(LevelHandler::new).collect_vec();
+        let mut local_stats = LocalSelectorStatistic::default();
+
+        let mut selector = TombstoneCompactionSelector::default();
+
+        // no sst reaches the delete ratio, so no task is picked
+        assert!(selector
+            .pick_compaction(
+                1,
+                &group_config,
+                &levels,
+                &mut levels_handler,
+                &mut local_stats,
+                HashMap::default(),
+            )
+            .is_none());
+
+        {
+            let sst = levels.levels[2].table_infos.get_mut(0).unwrap();
+            sst.total_key_count = 100;
+            sst.tombstone_key_count = 60;
+        }
+
+        // the high-tombstone sst is compacted with the overlapping files in the next level
+        let task = selector
+            .pick_compaction(
+                1,
+                &group_config,
+                &levels,
+                &mut levels_handler,
+                &mut local_stats,
+                HashMap::default(),
+            )
+            .unwrap();
+        assert_compaction_task(&task, &levels_handler);
+        assert_eq!(task.input.input_levels.len(), 2);
+        assert_eq!(task.input.input_levels[0].level_idx, 3);
+        assert_eq!(task.input.input_levels[0].table_infos.len(), 1);
+        assert_eq!(task.input.input_levels[0].table_infos[0].id, 0);
+        assert_eq!(task.input.input_levels[1].level_idx, 4);
+        assert_eq!(task.input.input_levels[1].table_infos.len(), 1);
+        assert_eq!(task.input.input_levels[1].table_infos[0].id, 2);
+        assert_eq!(task.input.target_level, 4);
+        assert!(matches!(
+            task.compaction_task_type,
+            compact_task::TaskType::Tombstone
+        ));
+
+        for level_handler in &mut levels_handler {
+            for pending_task_id in &level_handler.pending_tasks_ids() {
+                level_handler.remove_task(*pending_task_id);
+            }
+        }
+
+        {
+            let sst = levels.levels[3].table_infos.get_mut(1).unwrap();
+            sst.total_key_count = 100;
+            sst.tombstone_key_count = 50;
+        }
+
+        // a high-tombstone sst in the bottommost level is rewritten in place
+        let task = selector
+            .pick_compaction(
+                1,
+                &group_config,
+                &levels,
+                &mut levels_handler,
+                &mut local_stats,
+                HashMap::default(),
+            )
+            .unwrap();
+        assert_compaction_task(&task, &levels_handler);
+        assert_eq!(task.input.input_levels.len(), 2);
+        assert_eq!(task.input.input_levels[0].level_idx, 4);
+        assert_eq!(task.input.input_levels[0].table_infos.len(), 1);
+        assert_eq!(task.input.input_levels[0].table_infos[0].id, 3);
+        assert_eq!(task.input.input_levels[1].level_idx, 4);
+        assert_eq!(task.input.input_levels[1].table_infos.len(), 0);
+        assert_eq!(task.input.target_level, 4);
+        assert!(matches!(
+            task.compaction_task_type,
+            compact_task::TaskType::Tombstone
+        ));
+    }
+}
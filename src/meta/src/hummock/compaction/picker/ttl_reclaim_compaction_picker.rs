@@ -94,8 +94,8 @@ impl TtlReclaimCompactionPicker {
             match self.table_id_to_ttl.get(&table_id) {
                 Some(ttl_second_u32) => {
                     assert!(*ttl_second_u32 != TABLE_OPTION_DUMMY_RETENTION_SECOND);
-                    // default to zero.
-                    let ttl_mill = (*ttl_second_u32 * 1000) as u64;
+                    // Convert in `u64` to avoid overflowing for large retention settings.
+                    let ttl_mill = *ttl_second_u32 as u64 * 1000;
                     let min_epoch = expire_epoch.subtract_ms(ttl_mill);
                     if Epoch(sst.min_epoch) <= min_epoch {
                         return false;
@@ -425,6 +425,7 @@ mod tests {
                     uncompressed_file_size: input_file_size,
                     min_epoch: 0,
                     max_epoch: 0,
+                    tombstone_key_count: 0,
                 }],
             }],
             splits: vec![],
@@ -33,7 +33,8 @@ use tokio_stream::wrappers::{IntervalStream, UnboundedReceiverStream};
 
 use super::Compactor;
 use crate::hummock::compaction::{
-    DynamicLevelSelector, LevelSelector, SpaceReclaimCompactionSelector, TtlCompactionSelector,
+    DynamicLevelSelector, LevelSelector, SpaceReclaimCompactionSelector,
+    TombstoneCompactionSelector, TtlCompactionSelector,
 };
 use crate::hummock::error::Error;
 use crate::hummock::{CompactorManagerRef, HummockManagerRef};
@@ -145,6 +146,7 @@ where
             sched_rx,
             self.env.opts.periodic_space_reclaim_compaction_interval_sec,
             self.env.opts.periodic_ttl_reclaim_compaction_interval_sec,
+            self.env.opts.periodic_tombstone_reclaim_compaction_interval_sec,
             self.env.opts.periodic_compaction_interval_sec,
         );
         self.schedule_loop(
@@ -171,6 +173,10 @@ where
             compact_task::TaskType::Ttl,
             Box::<TtlCompactionSelector>::default(),
         );
+        compaction_selectors.insert(
+            compact_task::TaskType::Tombstone,
+            Box::<TombstoneCompactionSelector>::default(),
+        );
         compaction_selectors
     }
 
@@ -389,6 +395,19 @@ where
                                 .await;
                                 continue;
                             }
+                            SchedulerEvent::TombstoneReclaimTrigger => {
+                                // Disable periodic trigger for compaction_deterministic_test.
+                                if self.env.opts.compaction_deterministic_test {
+                                    continue;
+                                }
+                                // Periodically trigger compaction for all compaction groups.
+                                self.on_handle_trigger_multi_grouop(
+                                    sched_channel.clone(),
+                                    compact_task::TaskType::Tombstone,
+                                )
+                                .await;
+                                continue;
+                            }
                         }
                     }
                 }
@@ -473,6 +492,7 @@ enum SchedulerEvent {
     DynamicTrigger,
     SpaceReclaimTrigger,
     TtlReclaimTrigger,
+    TombstoneReclaimTrigger,
 }
 
 impl<S> CompactionScheduler<S>
@@ -483,6 +503,7 @@ where
         sched_rx: UnboundedReceiver<(CompactionGroupId, compact_task::TaskType)>,
         periodic_space_reclaim_compaction_interval_sec: u64,
         periodic_ttl_reclaim_compaction_interval_sec: u64,
+        periodic_tombstone_reclaim_compaction_interval_sec: u64,
         periodic_compaction_interval_sec: u64,
     ) -> impl Stream<Item = SchedulerEvent> {
         let dynamic_channel_trigger =
@@ -511,11 +532,22 @@ where
         let ttl_reclaim_trigger = IntervalStream::new(min_ttl_reclaim_trigger_interval)
             .map(|_| SchedulerEvent::TtlReclaimTrigger);
 
+        let mut min_tombstone_reclaim_trigger_interval = tokio::time::interval(
+            Duration::from_secs(periodic_tombstone_reclaim_compaction_interval_sec),
+        );
+        min_tombstone_reclaim_trigger_interval
+            .set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let tombstone_reclaim_trigger = IntervalStream::new(min_tombstone_reclaim_trigger_interval)
+            .map(|_| SchedulerEvent::TombstoneReclaimTrigger);
+
         select(
             dynamic_channel_trigger,
             select(
                 dynamic_tick_trigger,
-                select(space_reclaim_trigger, ttl_reclaim_trigger),
+                select(
+                    space_reclaim_trigger,
+                    select(ttl_reclaim_trigger, tombstone_reclaim_trigger),
+                ),
             ),
         )
     }
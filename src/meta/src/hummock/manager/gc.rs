@@ -23,7 +23,7 @@ use risingwave_hummock_sdk::{HummockSstableId, HummockVersionId, INVALID_VERSION
 
 use crate::hummock::error::Result;
 use crate::hummock::manager::{commit_multi_var, read_lock, write_lock};
-use crate::hummock::metrics_utils::trigger_stale_ssts_stat;
+use crate::hummock::metrics_utils::{trigger_delta_log_stats, trigger_stale_ssts_stat};
 use crate::hummock::HummockManager;
 use crate::model::{BTreeMapTransaction, ValTransaction};
 use crate::storage::{MetaStore, Transaction};
@@ -83,6 +83,7 @@ where
         let deleted = cmp::min(batch_size, versioning.deltas_to_delete.len());
         versioning.deltas_to_delete.drain(..deleted);
         let remain = versioning.deltas_to_delete.len();
+        trigger_delta_log_stats(&self.metrics, versioning.hummock_version_deltas.len());
         #[cfg(test)]
         {
             drop(versioning_guard);
@@ -57,8 +57,8 @@ use crate::hummock::compaction::{
 use crate::hummock::compaction_scheduler::CompactionRequestChannelRef;
 use crate::hummock::error::{Error, Result};
 use crate::hummock::metrics_utils::{
-    trigger_lsm_stat, trigger_pin_unpin_snapshot_state, trigger_pin_unpin_version_state,
-    trigger_sst_stat, trigger_version_stat,
+    trigger_delta_log_stats, trigger_lsm_stat, trigger_pin_unpin_snapshot_state,
+    trigger_pin_unpin_version_state, trigger_sst_stat, trigger_version_stat,
 };
 use crate::hummock::CompactorManagerRef;
 use crate::manager::{
@@ -456,6 +456,7 @@ where
         versioning_guard.current_version = redo_state;
         versioning_guard.branched_ssts = versioning_guard.current_version.build_branched_sst_info();
         versioning_guard.hummock_version_deltas = hummock_version_deltas;
+        trigger_delta_log_stats(&self.metrics, versioning_guard.hummock_version_deltas.len());
 
         versioning_guard.pinned_versions = HummockPinnedVersion::list(self.env.meta_store())
             .await?
@@ -1187,6 +1188,7 @@ where
                 current_version.apply_version_delta(&version_delta);
 
                 trigger_version_stat(&self.metrics, current_version, &versioning.version_stats);
+                trigger_delta_log_stats(&self.metrics, versioning.hummock_version_deltas.len());
 
                 if !deterministic_mode {
                     self.notify_last_version_delta(versioning);
@@ -1484,6 +1486,7 @@ where
             &versioning.current_version,
             &versioning.version_stats,
         );
+        trigger_delta_log_stats(&self.metrics, versioning.hummock_version_deltas.len());
         for compaction_group_id in &modified_compaction_groups {
             trigger_sst_stat(
                 &self.metrics,
@@ -150,7 +150,8 @@ where
                 tracing::info!("Cancelled compaction task {}", task_id);
                 sync_point!("AFTER_CANCEL_COMPACTION_TASK_ASYNC");
             }
-            LocalNotification::SystemParamsChange(_) => {}
+            LocalNotification::SystemParamsChange(_)
+            | LocalNotification::CreateMviewFinished(_) => {}
         }
     }
 }
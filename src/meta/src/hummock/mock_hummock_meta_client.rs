@@ -29,7 +29,7 @@ use risingwave_hummock_sdk::{
 use risingwave_pb::common::{HostAddress, WorkerType};
 use risingwave_pb::hummock::subscribe_compact_tasks_response::Task;
 use risingwave_pb::hummock::{
-    compact_task, CompactTask, CompactTaskProgress, HummockSnapshot, HummockVersion,
+    compact_task, CompactTask, CompactTaskProgress, HummockSnapshot, HummockVersion, KeyRange,
     SubscribeCompactTasksResponse, VacuumTask,
 };
 use risingwave_rpc_client::error::{Result, RpcError};
@@ -251,6 +251,7 @@ impl HummockMetaClient for MockHummockMetaClient {
         _compaction_group_id: u64,
         _table_id: u32,
         _level: u32,
+        _key_range: Option<KeyRange>,
     ) -> Result<()> {
         todo!()
     }
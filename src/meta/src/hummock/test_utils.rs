@@ -166,6 +166,7 @@ pub fn generate_test_tables(epoch: u64, sst_ids: Vec<HummockSstableId>) -> Vec<S
             uncompressed_file_size: 2,
             min_epoch: 0,
             max_epoch: 0,
+            tombstone_key_count: 0,
         });
     }
     sst_info
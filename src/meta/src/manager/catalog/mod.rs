@@ -31,6 +31,7 @@ use risingwave_common::catalog::{
     DEFAULT_SUPER_USER_FOR_PG_ID, DEFAULT_SUPER_USER_ID, SYSTEM_SCHEMAS,
 };
 use risingwave_common::{bail, ensure};
+use risingwave_pb::backup_service::CatalogBundle;
 use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
 use risingwave_pb::catalog::{Database, Function, Index, Schema, Sink, Source, Table, View};
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
@@ -2033,3 +2034,193 @@ where
         users_need_update
     }
 }
+
+/// Format version of [`CatalogBundle`]s produced by
+/// [`CatalogManager::export_catalog_bundle`]. Bump this when the bundle layout
+/// changes incompatibly.
+pub const CATALOG_BUNDLE_FORMAT_VERSION: u32 = 1;
+
+// Catalog export/import for disaster recovery and cluster migration.
+impl<S> CatalogManager<S>
+where
+    S: MetaStore,
+{
+    /// Exports all catalog objects, users and privileges as a self-contained
+    /// bundle. Unlike the full meta backup, the bundle only covers
+    /// definitions: table data and streaming state are not included.
+    pub async fn export_catalog_bundle(&self) -> CatalogBundle {
+        let core = self.core.lock().await;
+        let (databases, schemas, tables, sources, sinks, indexes, views, functions) =
+            core.database.get_catalog();
+        CatalogBundle {
+            format_version: CATALOG_BUNDLE_FORMAT_VERSION,
+            databases,
+            schemas,
+            sources,
+            sinks,
+            tables,
+            indexes,
+            views,
+            functions,
+            users: core.user.list_users(),
+        }
+    }
+
+    /// Imports a catalog bundle exported from another cluster. Object ids are
+    /// kept as-is, so importing fails if any imported id or database name
+    /// collides with an existing one. `path_remap` contains prefix
+    /// replacements applied to connector property values, e.g. to point
+    /// sources at the new cluster's object store paths. Users whose names
+    /// already exist (e.g. the default super users) are skipped.
+    pub async fn restore_catalog_bundle(
+        &self,
+        mut bundle: CatalogBundle,
+        path_remap: &HashMap<String, String>,
+    ) -> MetaResult<()> {
+        if bundle.format_version > CATALOG_BUNDLE_FORMAT_VERSION {
+            bail!(
+                "unsupported catalog bundle format version {}, expect at most {}",
+                bundle.format_version,
+                CATALOG_BUNDLE_FORMAT_VERSION
+            );
+        }
+
+        let mut core = self.core.lock().await;
+        for database in &bundle.databases {
+            core.database.check_database_duplicated(&database.name)?;
+            if core.database.databases.contains_key(&database.id) {
+                bail!("database id {} already exists", database.id);
+            }
+        }
+        for schema in &bundle.schemas {
+            if core.database.schemas.contains_key(&schema.id) {
+                bail!("schema id {} already exists", schema.id);
+            }
+        }
+        let relation_ids = bundle
+            .tables
+            .iter()
+            .map(|t| t.id)
+            .chain(bundle.sources.iter().map(|s| s.id))
+            .chain(bundle.sinks.iter().map(|s| s.id))
+            .chain(bundle.indexes.iter().map(|i| i.id))
+            .chain(bundle.views.iter().map(|v| v.id))
+            .collect_vec();
+        for relation_id in &relation_ids {
+            if core.database.tables.contains_key(relation_id)
+                || core.database.sources.contains_key(relation_id)
+                || core.database.sinks.contains_key(relation_id)
+                || core.database.indexes.contains_key(relation_id)
+                || core.database.views.contains_key(relation_id)
+            {
+                bail!("relation id {} already exists", relation_id);
+            }
+        }
+        for function in &bundle.functions {
+            if core.database.functions.contains_key(&function.id) {
+                bail!("function id {} already exists", function.id);
+            }
+        }
+        bundle
+            .users
+            .retain(|user| !core.user.has_user_name(&user.name));
+        for user in &bundle.users {
+            if core.user.user_info.contains_key(&user.id) {
+                bail!("user id {} already exists", user.id);
+            }
+        }
+
+        for properties in bundle
+            .sources
+            .iter_mut()
+            .map(|s| &mut s.properties)
+            .chain(bundle.sinks.iter_mut().map(|s| &mut s.properties))
+            .chain(bundle.tables.iter_mut().map(|t| &mut t.properties))
+        {
+            remap_path_properties(properties, path_remap);
+        }
+
+        // Advance the id generators past the imported ids, so that objects
+        // created later cannot collide with them.
+        self.env
+            .id_gen_manager()
+            .generate_interval::<{ IdCategory::Database }>(
+                bundle.databases.iter().map(|d| d.id as u64 + 1).max().unwrap_or(0),
+            )
+            .await?;
+        self.env
+            .id_gen_manager()
+            .generate_interval::<{ IdCategory::Schema }>(
+                bundle.schemas.iter().map(|s| s.id as u64 + 1).max().unwrap_or(0),
+            )
+            .await?;
+        self.env
+            .id_gen_manager()
+            .generate_interval::<{ IdCategory::Table }>(
+                relation_ids.iter().map(|id| *id as u64 + 1).max().unwrap_or(0),
+            )
+            .await?;
+        self.env
+            .id_gen_manager()
+            .generate_interval::<{ IdCategory::Function }>(
+                bundle.functions.iter().map(|f| f.id as u64 + 1).max().unwrap_or(0),
+            )
+            .await?;
+        self.env
+            .id_gen_manager()
+            .generate_interval::<{ IdCategory::User }>(
+                bundle.users.iter().map(|u| u.id as u64 + 1).max().unwrap_or(0),
+            )
+            .await?;
+
+        let mut trx = Transaction::default();
+        for database in &bundle.databases {
+            database.upsert_in_transaction(&mut trx)?;
+        }
+        for schema in &bundle.schemas {
+            schema.upsert_in_transaction(&mut trx)?;
+        }
+        for source in &bundle.sources {
+            source.upsert_in_transaction(&mut trx)?;
+        }
+        for sink in &bundle.sinks {
+            sink.upsert_in_transaction(&mut trx)?;
+        }
+        for table in &bundle.tables {
+            table.upsert_in_transaction(&mut trx)?;
+        }
+        for index in &bundle.indexes {
+            index.upsert_in_transaction(&mut trx)?;
+        }
+        for view in &bundle.views {
+            view.upsert_in_transaction(&mut trx)?;
+        }
+        for function in &bundle.functions {
+            function.upsert_in_transaction(&mut trx)?;
+        }
+        for user in &bundle.users {
+            user.upsert_in_transaction(&mut trx)?;
+        }
+        self.env.meta_store().txn(trx).await?;
+
+        // Rebuild the in-memory catalog from the meta store, so that all
+        // reference counts are recomputed with the imported objects.
+        *core = CatalogManagerCore::new(self.env.clone()).await?;
+        Ok(())
+    }
+}
+
+/// Applies the prefix replacements in `path_remap` to all property values.
+fn remap_path_properties(
+    properties: &mut HashMap<String, String>,
+    path_remap: &HashMap<String, String>,
+) {
+    for value in properties.values_mut() {
+        for (from, to) in path_remap {
+            if let Some(suffix) = value.strip_prefix(from) {
+                *value = format!("{}{}", to, suffix);
+                break;
+            }
+        }
+    }
+}
@@ -116,6 +116,9 @@ pub struct MetaOpts {
 
     /// Schedule ttl_reclaim_compaction for all compaction groups with this interval.
     pub periodic_ttl_reclaim_compaction_interval_sec: u64,
+
+    /// Schedule tombstone_reclaim_compaction for all compaction groups with this interval.
+    pub periodic_tombstone_reclaim_compaction_interval_sec: u64,
 }
 
 impl MetaOpts {
@@ -145,6 +148,7 @@ impl MetaOpts {
             data_directory: "hummock_001".to_string(),
             periodic_space_reclaim_compaction_interval_sec: 60,
             periodic_ttl_reclaim_compaction_interval_sec: 60,
+            periodic_tombstone_reclaim_compaction_interval_sec: 60,
         }
     }
 
@@ -13,14 +13,16 @@
 // limitations under the License.
 
 use std::collections::hash_map::Entry;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 
+use risingwave_common::catalog::TableId;
 use risingwave_common::system_param::reader::SystemParamsReader;
 use risingwave_pb::common::{WorkerNode, WorkerType};
 use risingwave_pb::hummock::CompactTask;
+use risingwave_pb::meta::meta_snapshot::SnapshotVersion;
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
-use risingwave_pb::meta::{MetaSnapshot, SubscribeResponse, SubscribeType};
+use risingwave_pb::meta::{MetaSnapshot, MetaSnapshotDeltas, SubscribeResponse, SubscribeType};
 use tokio::sync::mpsc::{self, UnboundedSender};
 use tokio::sync::Mutex;
 use tonic::Status;
@@ -34,11 +36,18 @@ pub type Notification = Result<SubscribeResponse, Status>;
 pub type NotificationManagerRef<S> = Arc<NotificationManager<S>>;
 pub type NotificationVersion = u64;
 
+/// The maximum number of versioned frontend notifications retained in the delta log. Older
+/// entries are compacted away, after which a re-subscribing frontend falls back to a full
+/// snapshot.
+const DELTA_LOG_CAPACITY: usize = 4096;
+
 #[derive(Clone, Debug)]
 pub enum LocalNotification {
     WorkerNodeIsDeleted(WorkerNode),
     CompactionTaskNeedCancel(CompactTask),
     SystemParamsChange(SystemParamsReader),
+    /// The backfill of the given creating streaming job has completed.
+    CreateMviewFinished(TableId),
 }
 
 #[derive(Debug)]
@@ -165,6 +174,29 @@ where
         )
     }
 
+    /// Replay the missed notifications to a re-subscribing frontend, in place of a full
+    /// snapshot.
+    pub fn notify_deltas(&self, worker_key: WorkerKey, deltas: MetaSnapshotDeltas) {
+        self.notify_without_version(
+            Target {
+                subscribe_type: SubscribeType::Frontend,
+                worker_key: Some(worker_key),
+            },
+            Operation::Snapshot,
+            Info::SnapshotDeltas(deltas),
+        )
+    }
+
+    /// Collect the versioned notifications that a frontend which has observed `resume_from` has
+    /// missed. Returns `None` if some of them have been compacted away from the delta log, in
+    /// which case the frontend must be served a full snapshot instead.
+    pub async fn frontend_deltas_since(
+        &self,
+        resume_from: &SnapshotVersion,
+    ) -> Option<MetaSnapshotDeltas> {
+        self.core.lock().await.deltas_since(resume_from)
+    }
+
     pub async fn notify_frontend(&self, operation: Operation, info: Info) -> NotificationVersion {
         self.notify_with_version(SubscribeType::Frontend.into(), operation, info)
             .await
@@ -257,6 +289,11 @@ struct NotificationManagerCore {
     compactor_senders: SenderMap,
     /// The notification sender to local subscribers.
     local_senders: Vec<UnboundedSender<LocalNotification>>,
+    /// Recent versioned notifications broadcast to frontends, retained for delta-based
+    /// re-subscription.
+    delta_log: VecDeque<SubscribeResponse>,
+    /// The version of the latest notification compacted away from `delta_log`.
+    compacted_version: NotificationVersion,
     exiting: bool,
 }
 
@@ -267,11 +304,21 @@ impl NotificationManagerCore {
             hummock_senders: HashMap::new(),
             compactor_senders: HashMap::new(),
             local_senders: vec![],
+            delta_log: VecDeque::new(),
+            compacted_version: 0,
             exiting: false,
         }
     }
 
     fn notify(&mut self, target: Target, response: SubscribeResponse) {
+        // Versioned broadcasts to frontends are recorded in the delta log, so that a frontend
+        // re-subscribing soon can be served only the notifications it missed.
+        if target.subscribe_type == SubscribeType::Frontend
+            && target.worker_key.is_none()
+            && response.version != 0
+        {
+            self.log_delta(response.clone());
+        }
         macro_rules! warn_send_failure {
             ($subscribe_type:expr, $worker_key:expr, $err:expr) => {
                 tracing::warn!(
@@ -309,6 +356,58 @@ impl NotificationManagerCore {
         }
     }
 
+    fn log_delta(&mut self, response: SubscribeResponse) {
+        self.delta_log.push_back(response);
+        while self.delta_log.len() > DELTA_LOG_CAPACITY {
+            let compacted = self.delta_log.pop_front().unwrap();
+            self.compacted_version = compacted.version;
+        }
+    }
+
+    fn deltas_since(&self, resume_from: &SnapshotVersion) -> Option<MetaSnapshotDeltas> {
+        let min_version = resume_from
+            .catalog_version
+            .min(resume_from.parallel_unit_mapping_version)
+            .min(resume_from.worker_node_version);
+        if min_version < self.compacted_version {
+            return None;
+        }
+
+        let mut version = resume_from.clone();
+        let deltas = self
+            .delta_log
+            .iter()
+            .filter_map(|resp| {
+                let observed = match resp.info.as_ref().unwrap() {
+                    Info::Database(_)
+                    | Info::Schema(_)
+                    | Info::Table(_)
+                    | Info::Source(_)
+                    | Info::Sink(_)
+                    | Info::Index(_)
+                    | Info::View(_)
+                    | Info::Function(_)
+                    | Info::User(_) => &mut version.catalog_version,
+                    Info::ParallelUnitMapping(_) => &mut version.parallel_unit_mapping_version,
+                    Info::Node(_) => &mut version.worker_node_version,
+                    info => unreachable!("unexpected notification in the delta log: {:?}", info),
+                };
+                if resp.version > *observed {
+                    *observed = resp.version;
+                    Some(resp.clone())
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        Some(MetaSnapshotDeltas {
+            deltas,
+            hummock_snapshot: None,
+            version: Some(version),
+        })
+    }
+
     fn senders_of(&mut self, subscribe_type: SubscribeType) -> &mut SenderMap {
         match subscribe_type {
             SubscribeType::Frontend => &mut self.frontend_senders,
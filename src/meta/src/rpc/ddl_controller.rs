@@ -12,8 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+use std::sync::Arc;
+
 use anyhow::Context;
 use itertools::Itertools;
+use parking_lot::Mutex;
 use risingwave_common::util::column_index_mapping::ColIndexMapping;
 use risingwave_pb::catalog::{Database, Function, Schema, Source, Table, View};
 use risingwave_pb::ddl_service::DdlProgress;
@@ -68,6 +72,38 @@ pub enum DdlCommand {
     ReplaceTable(StreamingJob, StreamFragmentGraphProto, ColIndexMapping),
 }
 
+impl DdlCommand {
+    /// Returns the ids of the existing catalog objects that this command operates on, which should
+    /// be locked for the duration of the command to fence off conflicting DDL.
+    ///
+    /// Note that creating an object with a duplicated name is already fenced by the in-progress
+    /// creation tracker of the catalog manager, so plain `CREATE` commands lock nothing here.
+    fn object_ids_to_lock(&self) -> Vec<u32> {
+        match self {
+            DdlCommand::CreateDatabase(_)
+            | DdlCommand::CreateSchema(_)
+            | DdlCommand::CreateSource(_)
+            | DdlCommand::CreateFunction(_)
+            | DdlCommand::CreateView(_) => vec![],
+            DdlCommand::DropDatabase(id)
+            | DdlCommand::DropSchema(id)
+            | DdlCommand::DropSource(id)
+            | DdlCommand::DropFunction(id)
+            | DdlCommand::DropView(id) => vec![*id],
+            // Lock the upstream relations, so that e.g. two sessions creating an index on the
+            // same materialized view, or a `DROP` racing with a `CREATE SINK`, are serialized.
+            DdlCommand::CreatingStreamingJob(_, fragment_graph) => {
+                fragment_graph.dependent_relation_ids.clone()
+            }
+            DdlCommand::DropStreamingJob(job_id) => match job_id {
+                StreamingJobId::Table(Some(source_id), table_id) => vec![*source_id, *table_id],
+                _ => vec![job_id.id()],
+            },
+            DdlCommand::ReplaceTable(stream_job, _, _) => vec![stream_job.id()],
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct DdlController<S: MetaStore> {
     env: MetaSrvEnv<S>,
@@ -78,6 +114,25 @@ pub struct DdlController<S: MetaStore> {
     cluster_manager: ClusterManagerRef<S>,
     fragment_manager: FragmentManagerRef<S>,
     barrier_manager: BarrierManagerRef<S>,
+
+    /// The ids of the catalog objects that are locked by the DDL commands in progress.
+    locked_objects: Arc<Mutex<HashSet<u32>>>,
+}
+
+/// A guard of the catalog objects locked by a DDL command. The objects are unlocked when the guard
+/// is dropped, i.e., when the command finishes or fails.
+struct DdlLockGuard {
+    locked_objects: Arc<Mutex<HashSet<u32>>>,
+    object_ids: Vec<u32>,
+}
+
+impl Drop for DdlLockGuard {
+    fn drop(&mut self) {
+        let mut locked_objects = self.locked_objects.lock();
+        for object_id in &self.object_ids {
+            locked_objects.remove(object_id);
+        }
+    }
 }
 
 impl<S> DdlController<S>
@@ -101,7 +156,25 @@ where
             cluster_manager,
             fragment_manager,
             barrier_manager,
+            locked_objects: Arc::new(Mutex::new(HashSet::new())),
+        }
+    }
+
+    /// Locks the given catalog objects for the duration of a DDL command. Returns an error if any
+    /// of them is already locked by another DDL command in progress.
+    fn lock_objects(&self, object_ids: Vec<u32>) -> MetaResult<DdlLockGuard> {
+        let mut locked_objects = self.locked_objects.lock();
+        if let Some(object_id) = object_ids.iter().find(|id| locked_objects.contains(id)) {
+            return Err(MetaError::permission_denied(format!(
+                "catalog object {} is locked by another DDL command in progress",
+                object_id
+            )));
         }
+        locked_objects.extend(object_ids.iter().copied());
+        Ok(DdlLockGuard {
+            locked_objects: self.locked_objects.clone(),
+            object_ids,
+        })
     }
 
     /// `check_barrier_manager_status` checks the status of the barrier manager, return unavailable
@@ -121,8 +194,11 @@ where
     /// would be a huge hassle and pain if we don't spawn here.
     pub(crate) async fn run_command(&self, command: DdlCommand) -> MetaResult<NotificationVersion> {
         self.check_barrier_manager_status().await?;
+        let lock_guard = self.lock_objects(command.object_ids_to_lock())?;
         let ctrl = self.clone();
         let handler = tokio::spawn(async move {
+            // Hold the lock until the command finishes or fails.
+            let _lock_guard = lock_guard;
             match command {
                 DdlCommand::CreateDatabase(database) => ctrl.create_database(database).await,
                 DdlCommand::DropDatabase(database_id) => ctrl.drop_database(database_id).await,
@@ -75,6 +75,9 @@ pub struct MetaMetrics {
     pub min_pinned_version_id: IntGauge,
     /// The smallest version id that is being guarded by meta node safe points.
     pub min_safepoint_version_id: IntGauge,
+    /// The number of version deltas in the delta log, i.e. those not yet compacted into the
+    /// checkpoint version.
+    pub delta_log_count: IntGauge,
     /// Hummock version stats
     pub version_stats: IntGaugeVec,
     /// Total number of SSTs that is no longer referenced by versions but is not yet deleted from
@@ -228,6 +231,13 @@ impl MetaMetrics {
         )
         .unwrap();
 
+        let delta_log_count = register_int_gauge_with_registry!(
+            "storage_delta_log_count",
+            "total number of hummock version deltas",
+            registry
+        )
+        .unwrap();
+
         let level_file_size = register_int_gauge_vec_with_registry!(
             "storage_level_total_file_size",
             "KBs total file bytes in each level",
@@ -323,6 +333,7 @@ impl MetaMetrics {
             checkpoint_version_id,
             min_pinned_version_id,
             min_safepoint_version_id,
+            delta_log_count,
             hummock_manager_lock_time,
             hummock_manager_real_process_time,
             time_after_last_observation: AtomicU64::new(0),
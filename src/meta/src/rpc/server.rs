@@ -487,14 +487,14 @@ pub async fn start_service_as_election_leader<S: MetaStore>(
     );
     let notification_srv = NotificationServiceImpl::new(
         env.clone(),
-        catalog_manager,
+        catalog_manager.clone(),
         cluster_manager.clone(),
         hummock_manager.clone(),
         fragment_manager.clone(),
         backup_manager.clone(),
     );
     let health_srv = HealthServiceImpl::new();
-    let backup_srv = BackupServiceImpl::new(backup_manager);
+    let backup_srv = BackupServiceImpl::new(backup_manager, catalog_manager);
     let system_params_srv = SystemParamsServiceImpl::new(system_params_manager.clone());
 
     if let Some(prometheus_addr) = address_info.prometheus_addr {
@@ -16,13 +16,15 @@ use std::ops::Deref;
 
 use risingwave_pb::backup_service::backup_service_server::BackupService;
 use risingwave_pb::backup_service::{
-    BackupMetaRequest, BackupMetaResponse, DeleteMetaSnapshotRequest, DeleteMetaSnapshotResponse,
-    GetBackupJobStatusRequest, GetBackupJobStatusResponse, GetMetaSnapshotManifestRequest,
-    GetMetaSnapshotManifestResponse,
+    BackupCatalogRequest, BackupCatalogResponse, BackupMetaRequest, BackupMetaResponse,
+    DeleteMetaSnapshotRequest, DeleteMetaSnapshotResponse, GetBackupJobStatusRequest,
+    GetBackupJobStatusResponse, GetMetaSnapshotManifestRequest, GetMetaSnapshotManifestResponse,
+    RestoreCatalogRequest, RestoreCatalogResponse,
 };
 use tonic::{Request, Response, Status};
 
 use crate::backup_restore::BackupManagerRef;
+use crate::manager::CatalogManagerRef;
 use crate::storage::MetaStore;
 
 pub struct BackupServiceImpl<S>
@@ -30,11 +32,18 @@ where
     S: MetaStore,
 {
     backup_manager: BackupManagerRef<S>,
+    catalog_manager: CatalogManagerRef<S>,
 }
 
 impl<S: MetaStore> BackupServiceImpl<S> {
-    pub fn new(backup_manager: BackupManagerRef<S>) -> Self {
-        Self { backup_manager }
+    pub fn new(
+        backup_manager: BackupManagerRef<S>,
+        catalog_manager: CatalogManagerRef<S>,
+    ) -> Self {
+        Self {
+            backup_manager,
+            catalog_manager,
+        }
     }
 }
 
@@ -80,4 +89,28 @@ where
             manifest: Some(self.backup_manager.manifest().deref().into()),
         }))
     }
+
+    async fn backup_catalog(
+        &self,
+        _request: Request<BackupCatalogRequest>,
+    ) -> Result<Response<BackupCatalogResponse>, Status> {
+        let bundle = self.catalog_manager.export_catalog_bundle().await;
+        Ok(Response::new(BackupCatalogResponse {
+            bundle: Some(bundle),
+        }))
+    }
+
+    async fn restore_catalog(
+        &self,
+        request: Request<RestoreCatalogRequest>,
+    ) -> Result<Response<RestoreCatalogResponse>, Status> {
+        let request = request.into_inner();
+        let bundle = request
+            .bundle
+            .ok_or_else(|| Status::invalid_argument("catalog bundle is required"))?;
+        self.catalog_manager
+            .restore_catalog_bundle(bundle, &request.path_remap)
+            .await?;
+        Ok(Response::new(RestoreCatalogResponse {}))
+    }
 }
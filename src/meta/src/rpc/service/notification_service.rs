@@ -200,9 +200,27 @@ where
         let meta_snapshot = match subscribe_type {
             SubscribeType::Compactor => self.compactor_subscribe().await,
             SubscribeType::Frontend => {
-                self.hummock_manager
+                let hummock_snapshot = self
+                    .hummock_manager
                     .pin_snapshot(req.get_worker_id())
                     .await?;
+                // If the frontend is re-subscribing with the version it has observed, replay
+                // only the missed notifications instead of a full catalog snapshot, unless they
+                // have been compacted away from the delta log.
+                if let Some(resume_from) = &req.resume_from {
+                    if let Some(mut deltas) = self
+                        .env
+                        .notification_manager()
+                        .frontend_deltas_since(resume_from)
+                        .await
+                    {
+                        deltas.hummock_snapshot = Some(hummock_snapshot);
+                        self.env
+                            .notification_manager()
+                            .notify_deltas(worker_key, deltas);
+                        return Ok(Response::new(UnboundedReceiverStream::new(rx)));
+                    }
+                }
                 self.frontend_subscribe().await
             }
             SubscribeType::Hummock => {
@@ -78,6 +78,23 @@ impl ObjectError {
     pub fn s3(err: impl Into<BoxedError>) -> Self {
         ObjectErrorInner::S3(err.into()).into()
     }
+
+    /// Tells whether the operation that produced this error may succeed if retried.
+    ///
+    /// The classification is conservative in different directions per backend: S3 errors are
+    /// treated as retryable since the SDK already fails fast on construction errors and the
+    /// remaining ones are dominated by transient 500s and timeouts, while disk and internal
+    /// errors are never retried.
+    pub fn is_retryable(&self) -> bool {
+        match &self.inner {
+            ObjectErrorInner::S3(_) => true,
+            ObjectErrorInner::Opendal(err) => err
+                .downcast_ref::<opendal::Error>()
+                .map_or(false, |e| e.is_temporary()),
+            ObjectErrorInner::Disk { .. } => false,
+            ObjectErrorInner::Internal(_) => false,
+        }
+    }
 }
 
 impl<E> From<aws_sdk_s3::types::SdkError<E>> for ObjectError
@@ -0,0 +1,278 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::env;
+use std::ops::Range;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+use bytes::Bytes;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use super::{
+    BlockLocation, MonitoredStreamingReader, MonitoredStreamingUploader, ObjectError,
+    ObjectMetadata, ObjectResult, ObjectStoreImpl,
+};
+
+/// Hidden env variable enabling object store fault injection, e.g.
+/// `RW_OBJECT_STORE_FAULT_INJECTION="error_rate=0.05;latency_ms=10..50;ops=read,upload"`.
+///
+/// This is for chaos/recovery testing only and must never be set in production.
+const FAULT_INJECTION_ENV: &str = "RW_OBJECT_STORE_FAULT_INJECTION";
+
+/// Runtime-updatable fault injection parameters.
+#[derive(Clone, Debug)]
+pub struct FaultInjectionConfig {
+    /// Probability in `[0.0, 1.0]` that a targeted operation fails with an
+    /// injected error.
+    pub error_rate: f64,
+    /// Uniformly sampled latency added to each targeted operation.
+    pub latency: Range<Duration>,
+    /// Operation names to target, e.g. `read`, `upload`. Empty targets all
+    /// operations.
+    pub ops: HashSet<String>,
+}
+
+impl Default for FaultInjectionConfig {
+    fn default() -> Self {
+        Self {
+            error_rate: 0.0,
+            latency: Duration::ZERO..Duration::ZERO,
+            ops: HashSet::new(),
+        }
+    }
+}
+
+impl FaultInjectionConfig {
+    /// Parses a `;`-separated list of `key=value` pairs. Supported keys:
+    /// `error_rate`, `latency_ms` (as `min..max`), `ops` (comma-separated).
+    /// Panics on malformed input, as this is a test-only configuration.
+    pub fn parse(s: &str) -> Self {
+        let mut config = Self::default();
+        for pair in s.split(';').filter(|p| !p.is_empty()) {
+            let (key, value) = pair
+                .split_once('=')
+                .unwrap_or_else(|| panic!("malformed fault injection config entry: {}", pair));
+            match key.trim() {
+                "error_rate" => {
+                    config.error_rate = value.parse().expect("malformed error_rate");
+                    assert!(
+                        (0.0..=1.0).contains(&config.error_rate),
+                        "error_rate out of range: {}",
+                        config.error_rate
+                    );
+                }
+                "latency_ms" => {
+                    let (min, max) = value.split_once("..").expect("malformed latency_ms");
+                    let min: u64 = min.parse().expect("malformed latency_ms");
+                    let max: u64 = max.parse().expect("malformed latency_ms");
+                    assert!(min <= max, "malformed latency_ms: {}..{}", min, max);
+                    config.latency = Duration::from_millis(min)..Duration::from_millis(max);
+                }
+                "ops" => {
+                    config.ops = value
+                        .split(',')
+                        .map(|op| op.trim().to_string())
+                        .filter(|op| !op.is_empty())
+                        .collect();
+                }
+                other => panic!("unknown fault injection config key: {}", other),
+            }
+        }
+        config
+    }
+}
+
+/// Decides, per operation, whether to delay and/or fail it. Shared by all
+/// operations of a [`FaultInjectedObjectStore`] and reconfigurable at runtime
+/// via [`FaultInjector::update`].
+pub struct FaultInjector {
+    config: RwLock<FaultInjectionConfig>,
+    rng: Mutex<StdRng>,
+}
+
+impl FaultInjector {
+    pub fn new(config: FaultInjectionConfig) -> Arc<Self> {
+        Arc::new(Self {
+            config: RwLock::new(config),
+            rng: Mutex::new(StdRng::from_entropy()),
+        })
+    }
+
+    /// Reads the injector configuration from [`FAULT_INJECTION_ENV`], if set.
+    pub fn from_env() -> Option<Arc<Self>> {
+        let raw = env::var(FAULT_INJECTION_ENV).ok()?;
+        let config = FaultInjectionConfig::parse(&raw);
+        tracing::warn!(
+            "object store fault injection enabled: {:?}. DO NOT use in production.",
+            config
+        );
+        Some(Self::new(config))
+    }
+
+    /// Replaces the current configuration. Takes effect for subsequent
+    /// operations.
+    pub fn update(&self, config: FaultInjectionConfig) {
+        *self.config.write().unwrap() = config;
+    }
+
+    fn sample(&self, op: &'static str) -> (Duration, bool) {
+        let config = self.config.read().unwrap();
+        if !config.ops.is_empty() && !config.ops.contains(op) {
+            return (Duration::ZERO, false);
+        }
+        let mut rng = self.rng.lock().unwrap();
+        let latency = if config.latency.end > Duration::ZERO {
+            rng.gen_range(config.latency.clone())
+        } else {
+            Duration::ZERO
+        };
+        let fail = config.error_rate > 0.0 && rng.gen_bool(config.error_rate);
+        (latency, fail)
+    }
+
+    async fn inject(&self, op: &'static str) -> ObjectResult<()> {
+        let (latency, fail) = self.sample(op);
+        if latency > Duration::ZERO {
+            tokio::time::sleep(latency).await;
+        }
+        if fail {
+            return Err(ObjectError::internal(format!("injected {} failure", op)));
+        }
+        Ok(())
+    }
+
+    /// Error-only injection for synchronous operations, where sleeping is not
+    /// possible.
+    fn inject_sync(&self, op: &'static str) -> ObjectResult<()> {
+        let (_, fail) = self.sample(op);
+        if fail {
+            return Err(ObjectError::internal(format!("injected {} failure", op)));
+        }
+        Ok(())
+    }
+}
+
+/// An object store wrapper that injects configurable latency and errors into
+/// every operation of the wrapped store, for chaos testing of recovery paths.
+pub struct FaultInjectedObjectStore {
+    inner: Box<ObjectStoreImpl>,
+    injector: Arc<FaultInjector>,
+}
+
+impl FaultInjectedObjectStore {
+    pub fn new(inner: ObjectStoreImpl, injector: Arc<FaultInjector>) -> Self {
+        assert!(
+            !matches!(inner, ObjectStoreImpl::Hybrid { .. }),
+            "fault injection does not support hybrid object store"
+        );
+        Self {
+            inner: Box::new(inner),
+            injector,
+        }
+    }
+
+    pub fn injector(&self) -> &Arc<FaultInjector> {
+        &self.injector
+    }
+
+    pub async fn upload(&self, path: &str, obj: Bytes) -> ObjectResult<()> {
+        self.injector.inject("upload").await?;
+        self.inner.upload(path, obj).await
+    }
+
+    pub fn streaming_upload(&self, path: &str) -> ObjectResult<MonitoredStreamingUploader> {
+        self.injector.inject_sync("streaming_upload")?;
+        self.inner.streaming_upload(path)
+    }
+
+    pub async fn read(&self, path: &str, block_loc: Option<BlockLocation>) -> ObjectResult<Bytes> {
+        self.injector.inject("read").await?;
+        self.inner.read(path, block_loc).await
+    }
+
+    pub async fn readv(
+        &self,
+        path: &str,
+        block_locs: &[BlockLocation],
+    ) -> ObjectResult<Vec<Bytes>> {
+        self.injector.inject("readv").await?;
+        self.inner.readv(path, block_locs).await
+    }
+
+    pub async fn metadata(&self, path: &str) -> ObjectResult<ObjectMetadata> {
+        self.injector.inject("metadata").await?;
+        self.inner.metadata(path).await
+    }
+
+    pub async fn streaming_read(
+        &self,
+        path: &str,
+        start_pos: Option<usize>,
+    ) -> ObjectResult<MonitoredStreamingReader> {
+        self.injector.inject("streaming_read").await?;
+        self.inner.streaming_read(path, start_pos).await
+    }
+
+    pub async fn delete(&self, path: &str) -> ObjectResult<()> {
+        self.injector.inject("delete").await?;
+        self.inner.delete(path).await
+    }
+
+    pub async fn delete_objects(&self, paths: &[String]) -> ObjectResult<()> {
+        self.injector.inject("delete_objects").await?;
+        self.inner.delete_objects(paths).await
+    }
+
+    pub async fn list(&self, prefix: &str) -> ObjectResult<Vec<ObjectMetadata>> {
+        self.injector.inject("list").await?;
+        self.inner.list(prefix).await
+    }
+
+    pub fn get_object_prefix(&self, obj_id: u64, is_remote: bool) -> String {
+        self.inner.get_object_prefix(obj_id, is_remote)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::FaultInjectionConfig;
+
+    #[test]
+    fn test_parse_config() {
+        let config = FaultInjectionConfig::parse("error_rate=0.05;latency_ms=10..50;ops=read,upload");
+        assert_eq!(config.error_rate, 0.05);
+        assert_eq!(
+            config.latency,
+            Duration::from_millis(10)..Duration::from_millis(50)
+        );
+        assert!(config.ops.contains("read"));
+        assert!(config.ops.contains("upload"));
+        assert!(!config.ops.contains("delete"));
+
+        let config = FaultInjectionConfig::parse("error_rate=1.0");
+        assert_eq!(config.error_rate, 1.0);
+        assert!(config.ops.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_parse_malformed_config() {
+        FaultInjectionConfig::parse("error_rate=yes");
+    }
+}
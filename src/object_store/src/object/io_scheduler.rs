@@ -0,0 +1,313 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use bytes::Bytes;
+
+use super::object_metrics::ObjectStoreMetrics;
+use super::{
+    BlockLocation, MonitoredStreamingReader, MonitoredStreamingUploader, ObjectMetadata,
+    ObjectResult, ObjectStoreImpl,
+};
+
+/// The class of an object store request, used to charge it against the right bandwidth quota.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IoClass {
+    /// Reads and writes issued by compaction.
+    Compaction,
+    /// Writes flushing the shared buffer.
+    Flush,
+    /// Foreground reads serving `get` and `iter`.
+    Read,
+}
+
+impl IoClass {
+    const COUNT: usize = 3;
+
+    fn index(self) -> usize {
+        match self {
+            IoClass::Compaction => 0,
+            IoClass::Flush => 1,
+            IoClass::Read => 2,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            IoClass::Compaction => "compaction",
+            IoClass::Flush => "flush",
+            IoClass::Read => "read",
+        }
+    }
+}
+
+/// Per-class bandwidth quotas in bytes per second. `0` leaves the class unthrottled.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct IoQuota {
+    pub compaction_bytes_per_sec: u64,
+    pub flush_bytes_per_sec: u64,
+    pub read_bytes_per_sec: u64,
+    /// Quota shared by all writes, i.e. shared buffer flushes and compaction output. When it is
+    /// exhausted, flush writes take priority over compaction writes.
+    pub write_bytes_per_sec: u64,
+}
+
+impl IoQuota {
+    pub fn is_unlimited(&self) -> bool {
+        self.compaction_bytes_per_sec == 0
+            && self.flush_bytes_per_sec == 0
+            && self.read_bytes_per_sec == 0
+            && self.write_bytes_per_sec == 0
+    }
+
+    fn bytes_per_sec(&self, class: IoClass) -> u64 {
+        match class {
+            IoClass::Compaction => self.compaction_bytes_per_sec,
+            IoClass::Flush => self.flush_bytes_per_sec,
+            IoClass::Read => self.read_bytes_per_sec,
+        }
+    }
+}
+
+/// A token bucket that is refilled at the quota rate and may run into debt: `acquire` only waits
+/// until the balance is non-negative and then charges the full request, so a single request larger
+/// than one second of quota is not blocked forever but delays the following ones instead.
+struct TokenBucket {
+    bytes_per_sec: u64,
+    balance: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(bytes_per_sec: u64) -> Self {
+        Self {
+            bytes_per_sec,
+            balance: 0.0,
+            last_refill: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let refill = now.duration_since(self.last_refill).as_secs_f64() * self.bytes_per_sec as f64;
+        // Cap the balance at one second worth of quota so that an idle period does not accumulate
+        // into an arbitrarily large burst.
+        self.balance = (self.balance + refill).min(self.bytes_per_sec as f64);
+        self.last_refill = now;
+    }
+
+    /// The time until the balance becomes non-negative at the refill rate.
+    fn wait_until_positive(&self) -> Duration {
+        if self.balance < 0.0 {
+            Duration::from_secs_f64(-self.balance / self.bytes_per_sec as f64)
+        } else {
+            Duration::ZERO
+        }
+    }
+
+    /// Charges `bytes` and returns how long the caller must wait before issuing the request.
+    fn charge(&mut self, bytes: usize) -> Duration {
+        self.refill();
+        let wait = self.wait_until_positive();
+        self.balance -= bytes as f64;
+        wait
+    }
+
+    /// Charges `bytes` only if the balance is non-negative, otherwise returns how long to wait
+    /// before retrying. Low-priority requests use this so that they never run the bucket into a
+    /// debt that a high-priority request would have to wait out.
+    fn try_charge(&mut self, bytes: usize) -> Result<(), Duration> {
+        self.refill();
+        let wait = self.wait_until_positive();
+        if wait.is_zero() {
+            self.balance -= bytes as f64;
+            Ok(())
+        } else {
+            Err(wait)
+        }
+    }
+}
+
+/// Enforces the per-class bandwidth quotas of an [`IoQuota`]. One scheduler is shared by all
+/// object store wrappers of a process, so that e.g. compaction and flush traffic of the same node
+/// are throttled against their own quotas.
+pub struct IoScheduler {
+    buckets: [Option<Mutex<TokenBucket>>; IoClass::COUNT],
+    /// The quota shared by all writes. Flush writes charge it with priority: they may run it into
+    /// debt, while compaction writes wait until the balance has fully recovered, so a compaction
+    /// burst cannot delay the uploads a barrier commit is waiting on.
+    write_bucket: Option<Mutex<TokenBucket>>,
+    metrics: Arc<ObjectStoreMetrics>,
+}
+
+impl IoScheduler {
+    pub fn new(quota: IoQuota, metrics: Arc<ObjectStoreMetrics>) -> Self {
+        let bucket_of = |class: IoClass| {
+            let bytes_per_sec = quota.bytes_per_sec(class);
+            (bytes_per_sec > 0).then(|| Mutex::new(TokenBucket::new(bytes_per_sec)))
+        };
+        Self {
+            buckets: [
+                bucket_of(IoClass::Compaction),
+                bucket_of(IoClass::Flush),
+                bucket_of(IoClass::Read),
+            ],
+            write_bucket: (quota.write_bytes_per_sec > 0)
+                .then(|| Mutex::new(TokenBucket::new(quota.write_bytes_per_sec))),
+            metrics,
+        }
+    }
+
+    /// Charges `bytes` against the quota of `class`, waiting if the quota is exhausted. Returns
+    /// immediately if the class is unthrottled.
+    pub async fn acquire(&self, class: IoClass, bytes: usize) {
+        if let Some(bucket) = &self.buckets[class.index()] {
+            let wait = bucket.lock().unwrap().charge(bytes);
+            self.throttled(class, wait).await;
+        }
+    }
+
+    /// Charges `bytes` of a write against both the quota of `class` and the shared write quota.
+    /// [`IoClass::Compaction`] writes yield the shared quota to concurrent flush writes.
+    pub async fn acquire_write(&self, class: IoClass, bytes: usize) {
+        self.acquire(class, bytes).await;
+        if let Some(bucket) = &self.write_bucket {
+            if class == IoClass::Compaction {
+                loop {
+                    match bucket.lock().unwrap().try_charge(bytes) {
+                        Ok(()) => break,
+                        Err(wait) => self.throttled(class, wait).await,
+                    }
+                }
+            } else {
+                let wait = bucket.lock().unwrap().charge(bytes);
+                self.throttled(class, wait).await;
+            }
+        }
+    }
+
+    async fn throttled(&self, class: IoClass, wait: Duration) {
+        if !wait.is_zero() {
+            self.metrics
+                .io_throttled_duration
+                .with_label_values(&[class.as_str()])
+                .observe(wait.as_secs_f64());
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// An object store wrapper that charges the payload of every request against the bandwidth quota
+/// of its [`IoClass`] before issuing it, so that e.g. compaction bursts do not starve foreground
+/// read latency on shared bandwidth.
+///
+/// The classes are fixed per wrapper instance: serving nodes tag uploads as [`IoClass::Flush`] and
+/// reads as [`IoClass::Read`], while compactor nodes tag everything as [`IoClass::Compaction`].
+pub struct IoScheduledObjectStore {
+    inner: Box<ObjectStoreImpl>,
+    scheduler: Arc<IoScheduler>,
+    upload_class: IoClass,
+    read_class: IoClass,
+}
+
+impl IoScheduledObjectStore {
+    pub fn new(
+        inner: ObjectStoreImpl,
+        scheduler: Arc<IoScheduler>,
+        upload_class: IoClass,
+        read_class: IoClass,
+    ) -> Self {
+        Self {
+            inner: Box::new(inner),
+            scheduler,
+            upload_class,
+            read_class,
+        }
+    }
+
+    pub async fn upload(&self, path: &str, obj: Bytes) -> ObjectResult<()> {
+        self.scheduler
+            .acquire_write(self.upload_class, obj.len())
+            .await;
+        self.inner.upload(path, obj).await
+    }
+
+    /// The creation of a streaming upload is not throttled: the payload is only known part by
+    /// part, and the parts are charged by the caller-driven writes of the underlying uploader.
+    pub fn streaming_upload(&self, path: &str) -> ObjectResult<MonitoredStreamingUploader> {
+        self.inner.streaming_upload(path)
+    }
+
+    pub async fn read(&self, path: &str, block_loc: Option<BlockLocation>) -> ObjectResult<Bytes> {
+        match block_loc {
+            Some(loc) => {
+                self.scheduler.acquire(self.read_class, loc.size).await;
+                self.inner.read(path, block_loc).await
+            }
+            None => {
+                // The size is only known after the object has been fetched, so charge the quota
+                // afterwards. The bucket may run into debt, delaying subsequent requests.
+                let data = self.inner.read(path, block_loc).await?;
+                self.scheduler.acquire(self.read_class, data.len()).await;
+                Ok(data)
+            }
+        }
+    }
+
+    pub async fn readv(
+        &self,
+        path: &str,
+        block_locs: &[BlockLocation],
+    ) -> ObjectResult<Vec<Bytes>> {
+        let total_size = block_locs.iter().map(|loc| loc.size).sum();
+        self.scheduler.acquire(self.read_class, total_size).await;
+        self.inner.readv(path, block_locs).await
+    }
+
+    pub async fn metadata(&self, path: &str) -> ObjectResult<ObjectMetadata> {
+        self.inner.metadata(path).await
+    }
+
+    /// Only the initiation of the read is throttled, with the remaining length of the object
+    /// charged upfront if it is known.
+    pub async fn streaming_read(
+        &self,
+        path: &str,
+        start_pos: Option<usize>,
+    ) -> ObjectResult<MonitoredStreamingReader> {
+        if let Ok(metadata) = self.inner.metadata(path).await {
+            let remaining = metadata.total_size.saturating_sub(start_pos.unwrap_or(0));
+            self.scheduler.acquire(self.read_class, remaining).await;
+        }
+        self.inner.streaming_read(path, start_pos).await
+    }
+
+    pub async fn delete(&self, path: &str) -> ObjectResult<()> {
+        self.inner.delete(path).await
+    }
+
+    pub async fn delete_objects(&self, paths: &[String]) -> ObjectResult<()> {
+        self.inner.delete_objects(paths).await
+    }
+
+    pub async fn list(&self, prefix: &str) -> ObjectResult<Vec<ObjectMetadata>> {
+        self.inner.list(prefix).await
+    }
+
+    pub fn get_object_prefix(&self, obj_id: u64, is_remote: bool) -> String {
+        self.inner.get_object_prefix(obj_id, is_remote)
+    }
+}
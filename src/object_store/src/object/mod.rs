@@ -30,6 +30,12 @@ pub use s3::*;
 
 mod disk;
 pub mod error;
+pub mod fault_injection;
+pub use fault_injection::*;
+pub mod io_scheduler;
+pub use io_scheduler::*;
+pub mod retry;
+pub use retry::*;
 pub mod object_metrics;
 
 pub use error::*;
@@ -42,7 +48,7 @@ pub const LOCAL_OBJECT_STORE_PATH_PREFIX: &str = "@local:";
 pub type ObjectStoreRef = Arc<ObjectStoreImpl>;
 pub type ObjectStreamingUploader = MonitoredStreamingUploader;
 
-type BoxedStreamingUploader = Box<dyn StreamingUploader>;
+pub type BoxedStreamingUploader = Box<dyn StreamingUploader>;
 
 #[derive(Debug)]
 pub enum ObjectStorePath<'a> {
@@ -190,6 +196,15 @@ pub enum ObjectStoreImpl {
     Opendal(MonitoredObjectStore<OpendalObjectStore>),
     S3(MonitoredObjectStore<S3ObjectStore>),
     S3Compatible(MonitoredObjectStore<S3ObjectStore>),
+    /// A wrapper injecting latency and errors into the wrapped store, enabled
+    /// via the hidden `RW_OBJECT_STORE_FAULT_INJECTION` env variable.
+    FaultInjected(FaultInjectedObjectStore),
+    /// A wrapper retrying transient failures of the wrapped store with
+    /// exponential backoff.
+    Retry(RetryObjectStore),
+    /// A wrapper charging every request against the bandwidth quota of its
+    /// IO class before issuing it.
+    Scheduled(IoScheduledObjectStore),
     Hybrid {
         local: Box<ObjectStoreImpl>,
         remote: Box<ObjectStoreImpl>,
@@ -248,6 +263,18 @@ macro_rules! object_store_impl_method_body {
                     assert!(path.is_remote(), "get local path in pure s3 compatible object store: {:?}", $path);
                     $dispatch_macro!(s3, $method_name, path.as_str() $(, $args)*)
                 },
+                ObjectStoreImpl::FaultInjected(fi) => {
+                    assert!(path.is_remote(), "get local path in fault injected object store: {:?}", $path);
+                    $dispatch_macro!(fi, $method_name, path.as_str() $(, $args)*)
+                },
+                ObjectStoreImpl::Retry(retry) => {
+                    assert!(path.is_remote(), "get local path in retry object store: {:?}", $path);
+                    $dispatch_macro!(retry, $method_name, path.as_str() $(, $args)*)
+                },
+                ObjectStoreImpl::Scheduled(scheduled) => {
+                    assert!(path.is_remote(), "get local path in scheduled object store: {:?}", $path);
+                    $dispatch_macro!(scheduled, $method_name, path.as_str() $(, $args)*)
+                },
                 ObjectStoreImpl::Hybrid {
                     local: local,
                     remote: remote,
@@ -259,6 +286,9 @@ macro_rules! object_store_impl_method_body {
                             ObjectStoreImpl::Opendal(_) => unreachable!("Opendal object store cannot be used as local object store"),
                             ObjectStoreImpl::S3(_) => unreachable!("S3 cannot be used as local object store"),
                             ObjectStoreImpl::S3Compatible(_) => unreachable!("S3 compatible cannot be used as local object store"),
+                            ObjectStoreImpl::FaultInjected(_) => unreachable!("fault injected object store cannot be used as local object store"),
+                            ObjectStoreImpl::Retry(_) => unreachable!("retry object store cannot be used as local object store"),
+                            ObjectStoreImpl::Scheduled(_) => unreachable!("scheduled object store cannot be used as local object store"),
                             ObjectStoreImpl::Hybrid {..} => unreachable!("local object store of hybrid object store cannot be hybrid")
                         },
                         ObjectStorePath::Remote(_) => match remote.as_ref() {
@@ -267,6 +297,9 @@ macro_rules! object_store_impl_method_body {
                             ObjectStoreImpl::Opendal(opendal) => $dispatch_macro!(opendal, $method_name, path.as_str() $(, $args)*),
                             ObjectStoreImpl::S3(s3) => $dispatch_macro!(s3, $method_name, path.as_str() $(, $args)*),
                             ObjectStoreImpl::S3Compatible(s3_compatible) => $dispatch_macro!(s3_compatible, $method_name, path.as_str() $(, $args)*),
+                            ObjectStoreImpl::FaultInjected(fi) => $dispatch_macro!(fi, $method_name, path.as_str() $(, $args)*),
+                            ObjectStoreImpl::Retry(retry) => $dispatch_macro!(retry, $method_name, path.as_str() $(, $args)*),
+                            ObjectStoreImpl::Scheduled(scheduled) => $dispatch_macro!(scheduled, $method_name, path.as_str() $(, $args)*),
                             ObjectStoreImpl::Hybrid {..} => unreachable!("remote object store of hybrid object store cannot be hybrid")
                         },
                     }
@@ -308,6 +341,18 @@ macro_rules! object_store_impl_method_body_slice {
                     assert!(paths_loc.is_empty(), "get local path in pure s3 compatible object store: {:?}", $paths);
                     $dispatch_macro!(s3, $method_name, &paths_rem $(, $args)*)
                 },
+                ObjectStoreImpl::FaultInjected(fi) => {
+                    assert!(paths_loc.is_empty(), "get local path in fault injected object store: {:?}", $paths);
+                    $dispatch_macro!(fi, $method_name, &paths_rem $(, $args)*)
+                },
+                ObjectStoreImpl::Retry(retry) => {
+                    assert!(paths_loc.is_empty(), "get local path in retry object store: {:?}", $paths);
+                    $dispatch_macro!(retry, $method_name, &paths_rem $(, $args)*)
+                },
+                ObjectStoreImpl::Scheduled(scheduled) => {
+                    assert!(paths_loc.is_empty(), "get local path in scheduled object store: {:?}", $paths);
+                    $dispatch_macro!(scheduled, $method_name, &paths_rem $(, $args)*)
+                },
                 ObjectStoreImpl::Hybrid {
                     local: local,
                     remote: remote,
@@ -319,6 +364,9 @@ macro_rules! object_store_impl_method_body_slice {
                         ObjectStoreImpl::Opendal(_) => unreachable!("Opendal object store cannot be used as local object store"),
                         ObjectStoreImpl::S3(_) => unreachable!("S3 cannot be used as local object store"),
                         ObjectStoreImpl::S3Compatible(_) => unreachable!("S3 cannot be used as local object store"),
+                        ObjectStoreImpl::FaultInjected(_) => unreachable!("fault injected object store cannot be used as local object store"),
+                        ObjectStoreImpl::Retry(_) => unreachable!("retry object store cannot be used as local object store"),
+                        ObjectStoreImpl::Scheduled(_) => unreachable!("scheduled object store cannot be used as local object store"),
                         ObjectStoreImpl::Hybrid {..} => unreachable!("local object store of hybrid object store cannot be hybrid")
                     }?;
 
@@ -329,6 +377,9 @@ macro_rules! object_store_impl_method_body_slice {
                         ObjectStoreImpl::Opendal(opendal) =>  $dispatch_macro!(opendal, $method_name, &paths_rem $(, $args)*),
                         ObjectStoreImpl::S3(s3) =>  $dispatch_macro!(s3, $method_name, &paths_rem $(, $args)*),
                         ObjectStoreImpl::S3Compatible(s3) =>  $dispatch_macro!(s3, $method_name, &paths_rem $(, $args)*),
+                        ObjectStoreImpl::FaultInjected(fi) =>  $dispatch_macro!(fi, $method_name, &paths_rem $(, $args)*),
+                        ObjectStoreImpl::Retry(retry) =>  $dispatch_macro!(retry, $method_name, &paths_rem $(, $args)*),
+                        ObjectStoreImpl::Scheduled(scheduled) =>  $dispatch_macro!(scheduled, $method_name, &paths_rem $(, $args)*),
                         ObjectStoreImpl::Hybrid {..} => unreachable!("remote object store of hybrid object store cannot be hybrid")
                     }
                 }
@@ -400,6 +451,9 @@ impl ObjectStoreImpl {
             ObjectStoreImpl::Opendal(store) => store.inner.get_object_prefix(obj_id),
             ObjectStoreImpl::S3(store) => store.inner.get_object_prefix(obj_id),
             ObjectStoreImpl::S3Compatible(store) => store.inner.get_object_prefix(obj_id),
+            ObjectStoreImpl::FaultInjected(store) => store.get_object_prefix(obj_id, is_remote),
+            ObjectStoreImpl::Retry(store) => store.get_object_prefix(obj_id, is_remote),
+            ObjectStoreImpl::Scheduled(store) => store.get_object_prefix(obj_id, is_remote),
             ObjectStoreImpl::Hybrid { local, remote } => {
                 if is_remote {
                     remote.get_object_prefix(obj_id, true)
@@ -810,7 +864,7 @@ pub async fn parse_remote_object_store(
     metrics: Arc<ObjectStoreMetrics>,
     ident: &str,
 ) -> ObjectStoreImpl {
-    match url {
+    let store = match url {
         s3 if s3.starts_with("s3://") => ObjectStoreImpl::S3(
             S3ObjectStore::new(
                 s3.strip_prefix("s3://").unwrap().to_string(),
@@ -848,6 +902,15 @@ pub async fn parse_remote_object_store(
                     .monitored(metrics),
             )
         }
+        azblob if azblob.starts_with("azblob://") => {
+            let azblob = azblob.strip_prefix("azblob://").unwrap();
+            let (container, root) = azblob.split_once('@').unwrap();
+            ObjectStoreImpl::Opendal(
+                OpendalObjectStore::new_azblob_engine(container.to_string(), root.to_string())
+                    .unwrap()
+                    .monitored(metrics),
+            )
+        }
         webhdfs if webhdfs.starts_with("webhdfs://") => {
             let webhdfs = webhdfs.strip_prefix("webhdfs://").unwrap();
             let (endpoint, root) = webhdfs.split_once('@').unwrap();
@@ -888,10 +951,16 @@ pub async fn parse_remote_object_store(
         }
         other => {
             unimplemented!(
-                "{} remote object store only supports s3, minio, disk, memory, and memory-shared for now.",
+                "{} remote object store only supports s3, minio, gcs, oss, azblob, webhdfs, disk, memory, and memory-shared for now.",
                 other
             )
         }
+    };
+    match FaultInjector::from_env() {
+        Some(injector) => {
+            ObjectStoreImpl::FaultInjected(FaultInjectedObjectStore::new(store, injector))
+        }
+        None => store,
     }
 }
 
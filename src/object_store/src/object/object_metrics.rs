@@ -25,6 +25,8 @@ pub struct ObjectStoreMetrics {
     pub operation_latency: HistogramVec,
     pub operation_size: HistogramVec,
     pub failure_count: GenericCounterVec<AtomicU64>,
+    /// Time requests spent waiting for bandwidth quotas before being issued, per IO class.
+    pub io_throttled_duration: HistogramVec,
 }
 
 impl ObjectStoreMetrics {
@@ -81,12 +83,21 @@ impl ObjectStoreMetrics {
         )
         .unwrap();
 
+        let throttled_opts = histogram_opts!(
+            "object_store_io_throttled_duration",
+            "Time object store requests spent waiting for bandwidth quotas",
+            exponential_buckets(0.001, 2.0, 22).unwrap(), // max 209s
+        );
+        let io_throttled_duration =
+            register_histogram_vec_with_registry!(throttled_opts, &["class"], registry).unwrap();
+
         Self {
             write_bytes,
             read_bytes,
             operation_latency,
             operation_size,
             failure_count,
+            io_throttled_duration,
         }
     }
 
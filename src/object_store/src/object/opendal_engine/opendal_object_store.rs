@@ -39,6 +39,7 @@ pub enum EngineType {
     Gcs,
     Oss,
     Webhdfs,
+    Azblob,
 }
 
 impl OpendalObjectStore {
@@ -186,6 +187,7 @@ impl ObjectStore for OpendalObjectStore {
             EngineType::Gcs => "Gcs",
             EngineType::Oss => "Oss",
             EngineType::Webhdfs => "Webhdfs",
+            EngineType::Azblob => "Azblob",
         }
     }
 }
@@ -0,0 +1,155 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::future::Future;
+use std::time::Duration;
+
+use bytes::Bytes;
+
+use super::{
+    BlockLocation, MonitoredStreamingReader, MonitoredStreamingUploader, ObjectMetadata,
+    ObjectResult, ObjectStoreImpl,
+};
+
+/// Configuration of [`RetryObjectStore`].
+#[derive(Clone, Debug)]
+pub struct RetryConfig {
+    /// Number of attempts for an operation before the error is returned to the caller. `1`
+    /// disables retrying.
+    pub max_attempts: usize,
+    /// The backoff before the first retry. The backoff doubles after each failed attempt, capped
+    /// at `max_backoff`.
+    pub initial_backoff: Duration,
+    /// The maximum backoff between two attempts.
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+}
+
+/// An object store wrapper that retries transient failures (as classified by
+/// `ObjectError::is_retryable`) of the wrapped store with exponential backoff, so that e.g.
+/// sporadic S3 500s or timeouts do not bubble up as query failures or compactor panics.
+///
+/// Note that the streaming upload is not retried here: once the uploader is handed out, the
+/// transfer is driven by the caller and a mid-stream failure cannot be replayed by this wrapper.
+pub struct RetryObjectStore {
+    inner: Box<ObjectStoreImpl>,
+    config: RetryConfig,
+}
+
+impl RetryObjectStore {
+    pub fn new(inner: ObjectStoreImpl, config: RetryConfig) -> Self {
+        assert!(config.max_attempts > 0, "max_attempts must be positive");
+        Self {
+            inner: Box::new(inner),
+            config,
+        }
+    }
+
+    /// Runs `f` up to `max_attempts` times, sleeping with exponential backoff between attempts,
+    /// until it succeeds or fails with a non-retryable error.
+    async fn with_retry<T, Fut>(
+        &self,
+        op: &'static str,
+        mut f: impl FnMut() -> Fut,
+    ) -> ObjectResult<T>
+    where
+        Fut: Future<Output = ObjectResult<T>>,
+    {
+        let mut backoff = self.config.initial_backoff;
+        let mut attempt = 1;
+        loop {
+            match f().await {
+                Ok(resp) => return Ok(resp),
+                Err(e) if e.is_retryable() && attempt < self.config.max_attempts => {
+                    tracing::warn!(
+                        "object store {} attempt {} failed, retrying in {:?}: {:?}",
+                        op,
+                        attempt,
+                        backoff,
+                        e
+                    );
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(self.config.max_backoff);
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    pub async fn upload(&self, path: &str, obj: Bytes) -> ObjectResult<()> {
+        self.with_retry("upload", || self.inner.upload(path, obj.clone()))
+            .await
+    }
+
+    pub fn streaming_upload(&self, path: &str) -> ObjectResult<MonitoredStreamingUploader> {
+        self.inner.streaming_upload(path)
+    }
+
+    pub async fn read(&self, path: &str, block_loc: Option<BlockLocation>) -> ObjectResult<Bytes> {
+        self.with_retry("read", || self.inner.read(path, block_loc))
+            .await
+    }
+
+    pub async fn readv(
+        &self,
+        path: &str,
+        block_locs: &[BlockLocation],
+    ) -> ObjectResult<Vec<Bytes>> {
+        self.with_retry("readv", || self.inner.readv(path, block_locs))
+            .await
+    }
+
+    pub async fn metadata(&self, path: &str) -> ObjectResult<ObjectMetadata> {
+        self.with_retry("metadata", || self.inner.metadata(path))
+            .await
+    }
+
+    pub async fn streaming_read(
+        &self,
+        path: &str,
+        start_pos: Option<usize>,
+    ) -> ObjectResult<MonitoredStreamingReader> {
+        // Only the initiation of the read is retried. A failure in the returned stream is
+        // surfaced to the caller, who can initiate a new streaming read from the failed position.
+        self.with_retry("streaming_read", || self.inner.streaming_read(path, start_pos))
+            .await
+    }
+
+    pub async fn delete(&self, path: &str) -> ObjectResult<()> {
+        self.with_retry("delete", || self.inner.delete(path)).await
+    }
+
+    pub async fn delete_objects(&self, paths: &[String]) -> ObjectResult<()> {
+        self.with_retry("delete_objects", || self.inner.delete_objects(paths))
+            .await
+    }
+
+    pub async fn list(&self, prefix: &str) -> ObjectResult<Vec<ObjectMetadata>> {
+        self.with_retry("list", || self.inner.list(prefix)).await
+    }
+
+    pub fn get_object_prefix(&self, obj_id: u64, is_remote: bool) -> String {
+        self.inner.get_object_prefix(obj_id, is_remote)
+    }
+}
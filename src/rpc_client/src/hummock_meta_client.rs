@@ -19,7 +19,7 @@ use risingwave_hummock_sdk::{
     HummockEpoch, HummockSstableId, HummockVersionId, LocalSstableInfo, SstIdRange,
 };
 use risingwave_pb::hummock::{
-    CompactTask, CompactTaskProgress, HummockSnapshot, HummockVersion, VacuumTask,
+    CompactTask, CompactTaskProgress, HummockSnapshot, HummockVersion, KeyRange, VacuumTask,
 };
 
 use crate::error::Result;
@@ -63,6 +63,7 @@ pub trait HummockMetaClient: Send + Sync + 'static {
         compaction_group_id: u64,
         table_id: u32,
         level: u32,
+        key_range: Option<KeyRange>,
     ) -> Result<()>;
     async fn report_full_scan_task(&self, sst_ids: Vec<HummockSstableId>) -> Result<()>;
     async fn trigger_full_gc(&self, sst_retention_time_sec: u64) -> Result<()>;
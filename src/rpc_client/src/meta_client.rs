@@ -53,6 +53,7 @@ use risingwave_pb::meta::heartbeat_request::{extra_info, ExtraInfo};
 use risingwave_pb::meta::heartbeat_service_client::HeartbeatServiceClient;
 use risingwave_pb::meta::list_table_fragments_response::TableFragmentInfo;
 use risingwave_pb::meta::meta_member_service_client::MetaMemberServiceClient;
+use risingwave_pb::meta::meta_snapshot::SnapshotVersion;
 use risingwave_pb::meta::notification_service_client::NotificationServiceClient;
 use risingwave_pb::meta::reschedule_request::Reschedule as ProstReschedule;
 use risingwave_pb::meta::scale_service_client::ScaleServiceClient;
@@ -103,15 +104,19 @@ impl MetaClient {
         self.worker_type
     }
 
-    /// Subscribe to notification from meta.
+    /// Subscribe to notification from meta. `resume_from` is the snapshot version observed
+    /// before re-subscribing, with which meta may replay only the missed notifications instead
+    /// of a full snapshot.
     pub async fn subscribe(
         &self,
         subscribe_type: SubscribeType,
+        resume_from: Option<SnapshotVersion>,
     ) -> Result<Streaming<SubscribeResponse>> {
         let request = SubscribeRequest {
             subscribe_type: subscribe_type as i32,
             host: Some(self.host_addr.to_protobuf()),
             worker_id: self.worker_id(),
+            resume_from,
         };
         let retry_strategy = GrpcMetaClient::retry_strategy_for_request();
         tokio_retry::Retry::spawn(retry_strategy, || async {
@@ -778,6 +783,25 @@ impl MetaClient {
         Ok(resp.manifest.expect("should exist"))
     }
 
+    pub async fn backup_catalog(&self) -> Result<CatalogBundle> {
+        let req = BackupCatalogRequest {};
+        let resp = self.inner.backup_catalog(req).await?;
+        Ok(resp.bundle.expect("should exist"))
+    }
+
+    pub async fn restore_catalog(
+        &self,
+        bundle: CatalogBundle,
+        path_remap: HashMap<String, String>,
+    ) -> Result<()> {
+        let req = RestoreCatalogRequest {
+            bundle: Some(bundle),
+            path_remap,
+        };
+        let _resp = self.inner.restore_catalog(req).await?;
+        Ok(())
+    }
+
     pub async fn get_system_params(&self) -> Result<SystemParamsReader> {
         let req = GetSystemParamsRequest {};
         let resp = self.inner.get_system_params(req).await?;
@@ -930,14 +954,15 @@ impl HummockMetaClient for MetaClient {
         compaction_group_id: u64,
         table_id: u32,
         level: u32,
+        key_range: Option<KeyRange>,
     ) -> Result<()> {
-        // TODO: support key_range parameter
         let req = TriggerManualCompactionRequest {
             compaction_group_id,
             table_id,
             // if table_id not exist, manual_compaction will include all the sst
             // without check internal_table_id
             level,
+            key_range,
             ..Default::default()
         };
 
@@ -1405,6 +1430,8 @@ macro_rules! for_all_meta_rpc {
             ,{ backup_client, get_backup_job_status, GetBackupJobStatusRequest, GetBackupJobStatusResponse }
             ,{ backup_client, delete_meta_snapshot, DeleteMetaSnapshotRequest, DeleteMetaSnapshotResponse}
             ,{ backup_client, get_meta_snapshot_manifest, GetMetaSnapshotManifestRequest, GetMetaSnapshotManifestResponse}
+            ,{ backup_client, backup_catalog, BackupCatalogRequest, BackupCatalogResponse }
+            ,{ backup_client, restore_catalog, RestoreCatalogRequest, RestoreCatalogResponse }
             ,{ system_params_client, get_system_params, GetSystemParamsRequest, GetSystemParamsResponse }
             ,{ system_params_client, set_system_param, SetSystemParamRequest, SetSystemParamResponse }
         }
@@ -743,6 +743,7 @@ pub enum ShowObject {
     Source { schema: Option<Ident> },
     Sink { schema: Option<Ident> },
     Columns { table: ObjectName },
+    Jobs,
 }
 
 impl fmt::Display for ShowObject {
@@ -773,6 +774,7 @@ impl fmt::Display for ShowObject {
             ShowObject::Source { schema } => write!(f, "SOURCES{}", fmt_schema(schema)),
             ShowObject::Sink { schema } => write!(f, "SINKS{}", fmt_schema(schema)),
             ShowObject::Columns { table } => write!(f, "COLUMNS FROM {}", table),
+            ShowObject::Jobs => f.write_str("JOBS"),
         }
     }
 }
@@ -844,6 +846,8 @@ pub struct ExplainOptions {
     pub verbose: bool,
     // Trace plan transformation of the optimizer step by step
     pub trace: bool,
+    // Display estimated storage access of scan nodes
+    pub storage: bool,
     // explain's plan type
     pub explain_type: ExplainType,
 }
@@ -852,6 +856,7 @@ impl Default for ExplainOptions {
         Self {
             verbose: false,
             trace: false,
+            storage: false,
             explain_type: ExplainType::Physical,
         }
     }
@@ -869,6 +874,9 @@ impl fmt::Display for ExplainOptions {
             if self.trace {
                 option_strs.push("TRACE".to_string());
             }
+            if self.storage {
+                option_strs.push("STORAGE".to_string());
+            }
             if self.explain_type == default.explain_type {
                 option_strs.push(self.explain_type.to_string());
             }
@@ -955,6 +963,8 @@ pub enum Statement {
         source_watermarks: Vec<SourceWatermark>,
         /// Append only table.
         append_only: bool,
+        /// How writes whose primary key already exists are handled.
+        on_conflict: Option<OnConflict>,
         /// `AS ( query )`
         query: Option<Box<Query>>,
     },
@@ -991,6 +1001,12 @@ pub enum Statement {
         name: ObjectName,
         operation: AlterTableOperation,
     },
+    /// ALTER MATERIALIZED VIEW name SET ( parameter = value [, ... ] )
+    AlterMaterializedView {
+        /// Materialized view name
+        name: ObjectName,
+        set_options: Vec<SqlOption>,
+    },
     /// DESCRIBE TABLE OR SOURCE
     Describe {
         /// Table or Source name
@@ -1306,6 +1322,7 @@ impl fmt::Display for Statement {
                 source_schema,
                 source_watermarks,
                 append_only,
+                on_conflict,
                 query,
             } => {
                 // We want to allow the following options
@@ -1332,6 +1349,9 @@ impl fmt::Display for Statement {
                 if *append_only {
                     write!(f, " APPEND ONLY")?;
                 }
+                if let Some(on_conflict) = on_conflict {
+                    write!(f, " ON CONFLICT {}", on_conflict)?;
+                }
                 if !with_options.is_empty() {
                     write!(f, " WITH ({})", display_comma_separated(with_options))?;
                 }
@@ -1381,6 +1401,14 @@ impl fmt::Display for Statement {
             Statement::AlterTable { name, operation } => {
                 write!(f, "ALTER TABLE {} {}", name, operation)
             }
+            Statement::AlterMaterializedView { name, set_options } => {
+                write!(
+                    f,
+                    "ALTER MATERIALIZED VIEW {} SET ({})",
+                    name,
+                    display_comma_separated(set_options)
+                )
+            }
             Statement::Drop(stmt) => write!(f, "DROP {}", stmt),
             Statement::DropFunction {
                 if_exists,
@@ -1959,6 +1987,27 @@ impl fmt::Display for SqlOption {
     }
 }
 
+/// The conflict handling strategy of the `ON CONFLICT` clause of `CREATE TABLE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OnConflict {
+    Overwrite,
+    IgnoreNew,
+    ReportError,
+    DoUpdateIfNotNull,
+}
+
+impl fmt::Display for OnConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            OnConflict::Overwrite => "OVERWRITE",
+            OnConflict::IgnoreNew => "IGNORE",
+            OnConflict::ReportError => "ERROR",
+            OnConflict::DoUpdateIfNotNull => "DO UPDATE IF NOT NULL",
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum EmitMode {
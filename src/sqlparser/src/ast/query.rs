@@ -132,6 +132,8 @@ pub struct Select {
     pub distinct: Distinct,
     /// projection expressions
     pub projection: Vec<SelectItem>,
+    /// `INTO` — `SELECT ... INTO t` is PostgreSQL's equivalent of `CREATE TABLE t AS SELECT ...`
+    pub into: Option<ObjectName>,
     /// FROM
     pub from: Vec<TableWithJoins>,
     /// LATERAL VIEWs
@@ -148,6 +150,9 @@ impl fmt::Display for Select {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "SELECT{}", &self.distinct)?;
         write!(f, " {}", display_comma_separated(&self.projection))?;
+        if let Some(ref into) = self.into {
+            write!(f, " INTO {}", into)?;
+        }
         if !self.from.is_empty() {
             write!(f, " FROM {}", display_comma_separated(&self.from))?;
         }
@@ -442,6 +442,9 @@ impl fmt::Display for CreateSink {
 pub struct CreateSinkStatement {
     pub if_not_exists: bool,
     pub sink_name: ObjectName,
+    /// Sink into another table in the same cluster instead of an external system, i.e.
+    /// `CREATE SINK s INTO t FROM ...`.
+    pub into_table_name: Option<ObjectName>,
     pub with_properties: WithProperties,
     pub sink_from: CreateSink,
     pub columns: Vec<Ident>,
@@ -452,6 +455,13 @@ impl ParseTo for CreateSinkStatement {
         impl_parse_to!(if_not_exists => [Keyword::IF, Keyword::NOT, Keyword::EXISTS], p);
         impl_parse_to!(sink_name: ObjectName, p);
 
+        let into_table_name = if p.parse_keyword(Keyword::INTO) {
+            impl_parse_to!(into_table_name: ObjectName, p);
+            Some(into_table_name)
+        } else {
+            None
+        };
+
         let columns = p.parse_parenthesized_column_list(IsOptional::Optional)?;
 
         let sink_from = if p.parse_keyword(Keyword::FROM) {
@@ -465,7 +475,8 @@ impl ParseTo for CreateSinkStatement {
         };
 
         impl_parse_to!(with_properties: WithProperties, p);
-        if with_properties.0.is_empty() {
+        // A sink into a table needs no connector properties, the target is given by `INTO`.
+        if with_properties.0.is_empty() && into_table_name.is_none() {
             return Err(ParserError::ParserError(
                 "sink properties not provided".to_string(),
             ));
@@ -474,6 +485,7 @@ impl ParseTo for CreateSinkStatement {
         Ok(Self {
             if_not_exists,
             sink_name,
+            into_table_name,
             with_properties,
             sink_from,
             columns,
@@ -486,6 +498,10 @@ impl fmt::Display for CreateSinkStatement {
         let mut v: Vec<String> = vec![];
         impl_fmt_display!(if_not_exists => [Keyword::IF, Keyword::NOT, Keyword::EXISTS], v, self);
         impl_fmt_display!(sink_name, v, self);
+        if let Some(into_table_name) = &self.into_table_name {
+            v.push(format!("{}", Keyword::INTO));
+            v.push(format!("{}", into_table_name));
+        }
         impl_fmt_display!(sink_from, v, self);
         impl_fmt_display!(with_properties, v, self);
         v.iter().join(" ").fmt(f)
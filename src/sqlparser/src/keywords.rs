@@ -134,6 +134,7 @@ define_keywords!(
     COMMITTED,
     CONCURRENTLY,
     CONDITION,
+    CONFLICT,
     CONFLUENT,
     CONNECT,
     CONSTRAINT,
@@ -269,6 +270,7 @@ define_keywords!(
     IS,
     ISNULL,
     ISOLATION,
+    JOBS,
     JOIN,
     KEY,
     LANGUAGE,
@@ -344,6 +346,7 @@ define_keywords!(
     OVER,
     OVERLAPS,
     OVERLAY,
+    OVERWRITE,
     OWNER,
     PARAMETER,
     PARQUET,
@@ -451,6 +454,7 @@ define_keywords!(
     STDDEV_POP,
     STDDEV_SAMP,
     STDIN,
+    STORAGE,
     STORED,
     STRING,
     STRUCT,
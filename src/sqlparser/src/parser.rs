@@ -1978,6 +1978,8 @@ impl Parser {
             false
         };
 
+        let on_conflict = self.parse_on_conflict()?;
+
         // PostgreSQL supports `WITH ( options )`, before `AS`
         let with_options = self.parse_with_properties()?;
 
@@ -2045,10 +2047,36 @@ impl Parser {
             source_schema,
             source_watermarks,
             append_only,
+            on_conflict,
             query,
         })
     }
 
+    pub fn parse_on_conflict(&mut self) -> Result<Option<OnConflict>, ParserError> {
+        if !self.parse_keywords(&[Keyword::ON, Keyword::CONFLICT]) {
+            return Ok(None);
+        }
+        match self.parse_one_of_keywords(&[
+            Keyword::OVERWRITE,
+            Keyword::IGNORE,
+            Keyword::ERROR,
+            Keyword::DO,
+        ]) {
+            Some(Keyword::OVERWRITE) => Ok(Some(OnConflict::Overwrite)),
+            Some(Keyword::IGNORE) => Ok(Some(OnConflict::IgnoreNew)),
+            Some(Keyword::ERROR) => Ok(Some(OnConflict::ReportError)),
+            Some(Keyword::DO) => {
+                self.expect_keywords(&[Keyword::UPDATE, Keyword::IF, Keyword::NOT, Keyword::NULL])?;
+                Ok(Some(OnConflict::DoUpdateIfNotNull))
+            }
+            Some(_) => unreachable!(),
+            None => self.expected(
+                "OVERWRITE, IGNORE, ERROR or DO UPDATE IF NOT NULL after ON CONFLICT",
+                self.peek_token(),
+            ),
+        }
+    }
+
     pub fn parse_columns_with_watermark(&mut self) -> Result<ColumnsDefTuple, ParserError> {
         let mut columns = vec![];
         let mut constraints = vec![];
@@ -2315,15 +2343,29 @@ impl Parser {
     pub fn parse_alter(&mut self) -> Result<Statement, ParserError> {
         if self.parse_keyword(Keyword::TABLE) {
             self.parse_alter_table()
+        } else if self.parse_keywords(&[Keyword::MATERIALIZED, Keyword::VIEW]) {
+            self.parse_alter_materialized_view()
         } else if self.parse_keyword(Keyword::USER) {
             self.parse_alter_user()
         } else if self.parse_keyword(Keyword::SYSTEM) {
             self.parse_alter_system()
         } else {
-            self.expected("TABLE or USER after ALTER", self.peek_token())
+            self.expected(
+                "TABLE, MATERIALIZED VIEW, USER or SYSTEM after ALTER",
+                self.peek_token(),
+            )
         }
     }
 
+    pub fn parse_alter_materialized_view(&mut self) -> Result<Statement, ParserError> {
+        let name = self.parse_object_name()?;
+        self.expect_keyword(Keyword::SET)?;
+        self.expect_token(&Token::LParen)?;
+        let set_options = self.parse_comma_separated(Parser::parse_sql_option)?;
+        self.expect_token(&Token::RParen)?;
+        Ok(Statement::AlterMaterializedView { name, set_options })
+    }
+
     pub fn parse_alter_user(&mut self) -> Result<Statement, ParserError> {
         Ok(Statement::AlterUser(AlterUserStatement::parse_to(self)?))
     }
@@ -2946,6 +2988,7 @@ impl Parser {
         let explain_key_words = [
             Keyword::VERBOSE,
             Keyword::TRACE,
+            Keyword::STORAGE,
             Keyword::TYPE,
             Keyword::LOGICAL,
             Keyword::PHYSICAL,
@@ -2957,6 +3000,7 @@ impl Parser {
             match keyword {
                 Keyword::VERBOSE => options.verbose = parser.parse_optional_boolean(true),
                 Keyword::TRACE => options.trace = parser.parse_optional_boolean(true),
+                Keyword::STORAGE => options.storage = parser.parse_optional_boolean(true),
                 Keyword::TYPE => {
                     let explain_type = parser.expect_one_of_keywords(&[
                         Keyword::LOGICAL,
@@ -3161,6 +3205,13 @@ impl Parser {
 
         let projection = self.parse_comma_separated(Parser::parse_select_item)?;
 
+        // `SELECT ... INTO t` is PostgreSQL's equivalent of `CREATE TABLE t AS SELECT ...`.
+        let into = if self.parse_keyword(Keyword::INTO) {
+            Some(self.parse_object_name()?)
+        } else {
+            None
+        };
+
         // Note that for keywords to be properly handled here, they need to be
         // added to `RESERVED_FOR_COLUMN_ALIAS` / `RESERVED_FOR_TABLE_ALIAS`,
         // otherwise they may be parsed as an alias as part of the `projection`
@@ -3223,6 +3274,7 @@ impl Parser {
         Ok(Select {
             distinct,
             projection,
+            into,
             from,
             lateral_views,
             selection,
@@ -3331,6 +3383,9 @@ impl Parser {
                         return self.expected("from after columns", self.peek_token());
                     }
                 }
+                Keyword::JOBS => {
+                    return Ok(Statement::ShowObjects(ShowObject::Jobs));
+                }
                 _ => {}
             }
         }
@@ -1768,6 +1768,14 @@ fn parse_explain_analyze_with_simple_select() {
             ..Default::default()
         },
     );
+    run_explain_analyze(
+        "EXPLAIN (STORAGE) SELECT sqrt(id) FROM foo",
+        false,
+        ExplainOptions {
+            storage: true,
+            ..Default::default()
+        },
+    );
     run_explain_analyze(
         "EXPLAIN ANALYZE (VERBOSE) SELECT sqrt(id) FROM foo",
         true,
@@ -1793,6 +1801,7 @@ fn parse_explain_analyze_with_simple_select() {
             trace: true,
             verbose: true,
             explain_type: ExplainType::DistSql,
+            ..Default::default()
         },
     );
     run_explain_analyze(
@@ -1802,6 +1811,7 @@ fn parse_explain_analyze_with_simple_select() {
             trace: false,
             verbose: true,
             explain_type: ExplainType::DistSql,
+            ..Default::default()
         },
     );
     run_explain_analyze(
@@ -1811,6 +1821,7 @@ fn parse_explain_analyze_with_simple_select() {
             trace: false,
             verbose: true,
             explain_type: ExplainType::DistSql,
+            ..Default::default()
         },
     );
 }
@@ -884,6 +884,12 @@ fn parse_drop_function() {
     );
 }
 
+#[test]
+fn parse_select_into() {
+    let select = verified_only_select("SELECT a, b INTO t2 FROM t");
+    assert_eq!(select.into, Some(ObjectName(vec![Ident::new("t2")])));
+}
+
 #[test]
 fn parse_array() {
     let sql = "SELECT ARRAY[ARRAY[1, 2], ARRAY[3, 4]]";
@@ -893,6 +899,7 @@ fn parse_array() {
             with: None,
             body: SetExpr::Select(Box::new(Select {
                 distinct: Distinct::All,
+                into: None,
                 projection: vec![SelectItem::UnnamedExpr(Expr::Array(Array {
                     elem: vec![
                         Expr::Array(Array {
@@ -932,6 +939,7 @@ fn parse_array() {
             with: None,
             body: SetExpr::Select(Box::new(Select {
                 distinct: Distinct::All,
+                into: None,
                 projection: vec![SelectItem::UnnamedExpr(Expr::Array(Array {
                     elem: vec![
                         Expr::Array(Array {
@@ -971,6 +979,7 @@ fn parse_array() {
             with: None,
             body: SetExpr::Select(Box::new(Select {
                 distinct: Distinct::All,
+                into: None,
                 projection: vec![SelectItem::UnnamedExpr(Expr::Array(Array {
                     elem: vec![
                         Expr::Array(Array {
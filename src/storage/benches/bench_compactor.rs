@@ -37,7 +37,7 @@ use risingwave_storage::hummock::sstable_store::SstableStoreRef;
 use risingwave_storage::hummock::value::HummockValue;
 use risingwave_storage::hummock::{
     CachePolicy, CompressionAlgorithm, SstableBuilder, SstableBuilderOptions, SstableIterator,
-    SstableStore, SstableWriterOptions, TieredCache,
+    SstableStore, SstableWriterOptions, TieredCache, DEFAULT_COMPRESSION_LEVEL,
 };
 use risingwave_storage::monitor::{CompactorMetrics, StoreLocalStatistic};
 
@@ -59,6 +59,7 @@ pub fn default_writer_opts() -> SstableWriterOptions {
         capacity_hint: None,
         tracker: None,
         policy: CachePolicy::Fill,
+        encryption: None,
     }
 }
 
@@ -84,6 +85,7 @@ async fn build_table(
         restart_interval: 16,
         bloom_false_positive: 0.001,
         compression_algorithm: CompressionAlgorithm::None,
+        compression_level: DEFAULT_COMPRESSION_LEVEL,
     };
     let writer = sstable_store.create_sst_writer(
         sstable_id,
@@ -91,6 +93,7 @@ async fn build_table(
             capacity_hint: None,
             tracker: None,
             policy: CachePolicy::Fill,
+            encryption: None,
         },
     );
     let mut builder = SstableBuilder::for_test(sstable_id, writer, opt);
@@ -168,6 +171,7 @@ async fn compact<I: HummockIterator<Direction = Forward>>(iter: I, sstable_store
         restart_interval: 16,
         bloom_false_positive: 0.001,
         compression_algorithm: CompressionAlgorithm::None,
+        compression_level: DEFAULT_COMPRESSION_LEVEL,
     };
     let mut builder =
         CapacitySplitTableBuilder::for_test(LocalTableBuilderFactory::new(32, sstable_store, opt));
@@ -208,7 +212,10 @@ fn bench_merge_iterator_compactor(c: &mut Criterion) {
     let info2 = runtime
         .block_on(async { build_table(sstable_store.clone(), 4, 0..test_key_size, 2).await });
     let level2 = vec![info1, info2];
-    let read_options = Arc::new(SstableIteratorReadOptions { prefetch: true });
+    let read_options = Arc::new(SstableIteratorReadOptions {
+        prefetch: true,
+        ..Default::default()
+    });
     c.bench_function("bench_union_merge_iterator", |b| {
         b.to_async(FuturesExecutor).iter(|| {
             let sstable_store1 = sstable_store.clone();
@@ -31,6 +31,7 @@ use risingwave_storage::hummock::{
     BatchSstableWriterFactory, CachePolicy, CompressionAlgorithm, HummockResult, MemoryLimiter,
     SstableBuilder, SstableBuilderOptions, SstableStore, SstableWriterFactory,
     SstableWriterOptions, StreamingSstableWriterFactory, TieredCache, XorFilterBuilder,
+    DEFAULT_COMPRESSION_LEVEL,
 };
 use risingwave_storage::monitor::ObjectStoreMetrics;
 
@@ -71,6 +72,7 @@ impl<F: SstableWriterFactory> TableBuilderFactory for LocalTableBuilderFactory<F
             capacity_hint: Some(self.options.capacity),
             tracker: Some(tracker),
             policy: self.policy,
+            encryption: None,
         };
         let writer = self
             .writer_factory
@@ -89,6 +91,7 @@ fn get_builder_options(capacity_mb: usize) -> SstableBuilderOptions {
         restart_interval: 16,
         bloom_false_positive: 0.001,
         compression_algorithm: CompressionAlgorithm::None,
+        compression_level: DEFAULT_COMPRESSION_LEVEL,
     }
 }
 
@@ -24,14 +24,18 @@ use risingwave_common_service::metrics_manager::MetricsManager;
 use risingwave_common_service::observer_manager::ObserverManager;
 use risingwave_hummock_sdk::compact::CompactorRuntimeConfig;
 use risingwave_hummock_sdk::filter_key_extractor::FilterKeyExtractorManager;
-use risingwave_object_store::object::parse_remote_object_store;
+use risingwave_object_store::object::{
+    parse_remote_object_store, IoClass, IoQuota, IoScheduledObjectStore, IoScheduler,
+    ObjectStoreImpl,
+};
 use risingwave_pb::common::WorkerType;
 use risingwave_pb::compactor::compactor_service_server::CompactorServiceServer;
 use risingwave_rpc_client::MetaClient;
 use risingwave_storage::hummock::compactor::{CompactionExecutor, CompactorContext};
 use risingwave_storage::hummock::hummock_meta_client::MonitoredHummockMetaClient;
 use risingwave_storage::hummock::{
-    CompactorMemoryCollector, MemoryLimiter, SstableIdManager, SstableStore,
+    CompactorMemoryCollector, FileEncryptionKeyProvider, MemoryLimiter, SstableIdManager,
+    SstableStore,
 };
 use risingwave_storage::monitor::{
     monitor_cache, CompactorMetrics, HummockMetrics, ObjectStoreMetrics,
@@ -90,22 +94,44 @@ pub async fn compactor_serve(
     };
 
     let storage_opts = Arc::new(StorageOpts::from((&config, &system_params)));
-    let object_store = Arc::new(
-        parse_remote_object_store(
-            state_store_url
-                .strip_prefix("hummock+")
-                .expect("object store must be hummock for compactor server"),
-            object_metrics,
-            "Hummock",
-        )
-        .await,
-    );
+    let object_store = parse_remote_object_store(
+        state_store_url
+            .strip_prefix("hummock+")
+            .expect("object store must be hummock for compactor server"),
+        object_metrics.clone(),
+        "Hummock",
+    )
+    .await;
+    // All traffic of the compactor node is compaction traffic, so charge it against the
+    // compaction bandwidth quota to keep it from starving foreground reads of serving nodes
+    // on shared bandwidth.
+    let io_quota = IoQuota {
+        compaction_bytes_per_sec: storage_opts.object_store_compaction_bandwidth_mb_per_sec << 20,
+        write_bytes_per_sec: storage_opts.object_store_write_bandwidth_mb_per_sec << 20,
+        ..Default::default()
+    };
+    let object_store = Arc::new(if !io_quota.is_unlimited() {
+        ObjectStoreImpl::Scheduled(IoScheduledObjectStore::new(
+            object_store,
+            Arc::new(IoScheduler::new(io_quota, object_metrics.clone())),
+            IoClass::Compaction,
+            IoClass::Compaction,
+        ))
+    } else {
+        object_store
+    });
     let sstable_store = Arc::new(SstableStore::for_compactor(
         object_store,
         storage_opts.data_directory.to_string(),
         1 << 20, // set 1MB memory to avoid panic.
         storage_opts.meta_cache_capacity_mb * (1 << 20),
     ));
+    if !storage_opts.sstable_encryption_key_file.is_empty() {
+        sstable_store.set_encryption_key_provider(Arc::new(
+            FileEncryptionKeyProvider::load(&storage_opts.sstable_encryption_key_file)
+                .expect("failed to load the sstable encryption key file"),
+        ));
+    }
 
     let filter_key_extractor_manager = Arc::new(FilterKeyExtractorManager::default());
     let compactor_observer_node = CompactorObserverNode::new(filter_key_extractor_manager.clone());
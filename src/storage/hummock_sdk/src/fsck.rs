@@ -0,0 +1,309 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+
+use risingwave_pb::hummock::{HummockVersion, Level, LevelType};
+
+use crate::key_range::KeyRangeCommon;
+use crate::{CompactionGroupId, HummockEpoch, HummockSstableId};
+
+/// A single inconsistency found while checking a [`HummockVersion`].
+///
+/// Each variant carries enough context to locate the offending SST in the
+/// version manifest, so that the report is actionable without re-running the
+/// check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FsckIssue {
+    /// Two SSTs in a non-overlapping (non-L0) level have overlapping key
+    /// ranges, or are not sorted by key range.
+    OverlappingSsts {
+        compaction_group_id: CompactionGroupId,
+        level_idx: u32,
+        left_sst_id: HummockSstableId,
+        right_sst_id: HummockSstableId,
+    },
+    /// An SST referenced by the version manifest does not exist in the object
+    /// store.
+    MissingObject { sst_id: HummockSstableId },
+    /// An object in the object store is not referenced by the version
+    /// manifest. This is not necessarily an error: it may be pending vacuum,
+    /// or referenced by a pinned older version.
+    OrphanObject { sst_id: HummockSstableId },
+    /// An SST's epoch range is inverted, or exceeds the version's max
+    /// committed epoch.
+    EpochViolation {
+        compaction_group_id: CompactionGroupId,
+        sst_id: HummockSstableId,
+        min_epoch: HummockEpoch,
+        max_epoch: HummockEpoch,
+        max_committed_epoch: HummockEpoch,
+    },
+    /// The version's safe epoch exceeds its max committed epoch.
+    SafeEpochViolation {
+        safe_epoch: HummockEpoch,
+        max_committed_epoch: HummockEpoch,
+    },
+}
+
+impl FsckIssue {
+    /// A short machine-readable tag identifying the kind of issue.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            FsckIssue::OverlappingSsts { .. } => "overlapping_ssts",
+            FsckIssue::MissingObject { .. } => "missing_object",
+            FsckIssue::OrphanObject { .. } => "orphan_object",
+            FsckIssue::EpochViolation { .. } => "epoch_violation",
+            FsckIssue::SafeEpochViolation { .. } => "safe_epoch_violation",
+        }
+    }
+}
+
+impl Display for FsckIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FsckIssue::OverlappingSsts {
+                compaction_group_id,
+                level_idx,
+                left_sst_id,
+                right_sst_id,
+            } => write!(
+                f,
+                "SST {} and SST {} overlap in group {} level {}",
+                left_sst_id, right_sst_id, compaction_group_id, level_idx
+            ),
+            FsckIssue::MissingObject { sst_id } => {
+                write!(f, "SST {} is referenced but missing in object store", sst_id)
+            }
+            FsckIssue::OrphanObject { sst_id } => {
+                write!(f, "SST {} exists in object store but is unreferenced", sst_id)
+            }
+            FsckIssue::EpochViolation {
+                compaction_group_id,
+                sst_id,
+                min_epoch,
+                max_epoch,
+                max_committed_epoch,
+            } => write!(
+                f,
+                "SST {} in group {} has invalid epoch range [{}, {}] (max committed epoch {})",
+                sst_id, compaction_group_id, min_epoch, max_epoch, max_committed_epoch
+            ),
+            FsckIssue::SafeEpochViolation {
+                safe_epoch,
+                max_committed_epoch,
+            } => write!(
+                f,
+                "safe epoch {} exceeds max committed epoch {}",
+                safe_epoch, max_committed_epoch
+            ),
+        }
+    }
+}
+
+/// Result of checking a [`HummockVersion`] against the set of objects actually
+/// present in the object store.
+#[derive(Debug, Default)]
+pub struct FsckReport {
+    pub version_id: u64,
+    pub issues: Vec<FsckIssue>,
+}
+
+impl FsckReport {
+    pub fn is_consistent(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Validates the version manifest offline:
+/// - key ranges within each non-L0 level must be sorted and non-overlapping;
+/// - every referenced SST must exist in `existing_sst_ids` (if provided);
+/// - SST epoch ranges must be well-formed and bounded by the max committed
+///   epoch;
+/// - objects in `existing_sst_ids` not referenced by the version are reported
+///   as orphans.
+///
+/// Pass `None` for `existing_sst_ids` to skip the object store checks, e.g.
+/// when only the manifest is available.
+pub fn check_version(
+    version: &HummockVersion,
+    existing_sst_ids: Option<&HashSet<HummockSstableId>>,
+) -> FsckReport {
+    let mut report = FsckReport {
+        version_id: version.id,
+        ..Default::default()
+    };
+
+    if version.safe_epoch > version.max_committed_epoch {
+        report.issues.push(FsckIssue::SafeEpochViolation {
+            safe_epoch: version.safe_epoch,
+            max_committed_epoch: version.max_committed_epoch,
+        });
+    }
+
+    let mut referenced_sst_ids = HashSet::new();
+    for (compaction_group_id, levels) in &version.levels {
+        let mut check_level = |level: &Level| {
+            for sst in &level.table_infos {
+                referenced_sst_ids.insert(sst.id);
+                if let Some(existing) = existing_sst_ids {
+                    if !existing.contains(&sst.id) {
+                        report.issues.push(FsckIssue::MissingObject { sst_id: sst.id });
+                    }
+                }
+                if sst.min_epoch > sst.max_epoch
+                    || sst.max_epoch > version.max_committed_epoch
+                {
+                    report.issues.push(FsckIssue::EpochViolation {
+                        compaction_group_id: *compaction_group_id,
+                        sst_id: sst.id,
+                        min_epoch: sst.min_epoch,
+                        max_epoch: sst.max_epoch,
+                        max_committed_epoch: version.max_committed_epoch,
+                    });
+                }
+            }
+            // Key ranges of a non-overlapping level must be sorted and
+            // disjoint. L0 sub-levels of type `Overlapping` are exempt.
+            if level.level_type() == LevelType::Nonoverlapping {
+                for (left, right) in level.table_infos.iter().zip(level.table_infos.iter().skip(1))
+                {
+                    let (Some(left_range), Some(right_range)) =
+                        (left.key_range.as_ref(), right.key_range.as_ref()) else {
+                        continue;
+                    };
+                    if left_range.sstable_overlap(right_range)
+                        || left_range.compare_right_with(&right_range.left)
+                            != std::cmp::Ordering::Less
+                    {
+                        report.issues.push(FsckIssue::OverlappingSsts {
+                            compaction_group_id: *compaction_group_id,
+                            level_idx: level.level_idx,
+                            left_sst_id: left.id,
+                            right_sst_id: right.id,
+                        });
+                    }
+                }
+            }
+        };
+        if let Some(l0) = &levels.l0 {
+            for sub_level in &l0.sub_levels {
+                check_level(sub_level);
+            }
+        }
+        for level in &levels.levels {
+            check_level(level);
+        }
+    }
+
+    if let Some(existing) = existing_sst_ids {
+        for sst_id in existing {
+            if !referenced_sst_ids.contains(sst_id) {
+                report.issues.push(FsckIssue::OrphanObject { sst_id: *sst_id });
+            }
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use risingwave_pb::hummock::hummock_version::Levels;
+    use risingwave_pb::hummock::{
+        HummockVersion, KeyRange, Level, LevelType, OverlappingLevel, SstableInfo,
+    };
+
+    use super::{check_version, FsckIssue};
+    use crate::key::key_with_epoch;
+
+    fn sst(id: u64, left: &[u8], right: &[u8]) -> SstableInfo {
+        SstableInfo {
+            id,
+            key_range: Some(KeyRange {
+                left: key_with_epoch(left.to_vec(), 2),
+                right: key_with_epoch(right.to_vec(), 1),
+                right_exclusive: false,
+            }),
+            min_epoch: 1,
+            max_epoch: 2,
+            ..Default::default()
+        }
+    }
+
+    fn version_with_level(level: Level) -> HummockVersion {
+        HummockVersion {
+            id: 1,
+            levels: [(
+                1,
+                Levels {
+                    levels: vec![level],
+                    l0: Some(OverlappingLevel::default()),
+                    ..Default::default()
+                },
+            )]
+            .into_iter()
+            .collect(),
+            max_committed_epoch: 2,
+            safe_epoch: 0,
+        }
+    }
+
+    #[test]
+    fn test_check_consistent_version() {
+        let version = version_with_level(Level {
+            level_idx: 1,
+            level_type: LevelType::Nonoverlapping as i32,
+            table_infos: vec![sst(1, b"a", b"b"), sst(2, b"c", b"d")],
+            ..Default::default()
+        });
+        let existing: HashSet<_> = [1, 2].into_iter().collect();
+        let report = check_version(&version, Some(&existing));
+        assert!(report.is_consistent(), "{:?}", report.issues);
+    }
+
+    #[test]
+    fn test_check_overlapping_level() {
+        let version = version_with_level(Level {
+            level_idx: 1,
+            level_type: LevelType::Nonoverlapping as i32,
+            table_infos: vec![sst(1, b"a", b"c"), sst(2, b"b", b"d")],
+            ..Default::default()
+        });
+        let report = check_version(&version, None);
+        assert_eq!(report.issues.len(), 1);
+        assert_eq!(report.issues[0].kind(), "overlapping_ssts");
+    }
+
+    #[test]
+    fn test_check_missing_and_orphan_objects() {
+        let version = version_with_level(Level {
+            level_idx: 1,
+            level_type: LevelType::Nonoverlapping as i32,
+            table_infos: vec![sst(1, b"a", b"b")],
+            ..Default::default()
+        });
+        let existing: HashSet<_> = [2].into_iter().collect();
+        let report = check_version(&version, Some(&existing));
+        assert!(report
+            .issues
+            .contains(&FsckIssue::MissingObject { sst_id: 1 }));
+        assert!(report
+            .issues
+            .contains(&FsckIssue::OrphanObject { sst_id: 2 }));
+    }
+}
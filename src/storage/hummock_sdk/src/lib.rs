@@ -38,6 +38,7 @@ use crate::table_stats::{to_prost_table_stats_map, ProstTableStatsMap, TableStat
 pub mod compact;
 pub mod compaction_group;
 pub mod filter_key_extractor;
+pub mod fsck;
 pub mod key;
 pub mod key_range;
 pub mod prost_key_range;
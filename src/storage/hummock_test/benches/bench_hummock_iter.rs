@@ -102,9 +102,11 @@ fn criterion_benchmark(c: &mut Criterion) {
                     ReadOptions {
                         prefix_hint: None,
                         ignore_range_tombstone: true,
+                        limit: None,
                         retention_seconds: None,
                         table_id: Default::default(),
                         read_version_from_backup: false,
+                        cache_policy: CachePolicy::Fill,
                     },
                 ))
                 .unwrap();
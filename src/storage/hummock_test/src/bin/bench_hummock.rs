@@ -0,0 +1,536 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A benchmark tool that drives configurable workloads against a Hummock state store built from a
+//! `StateStoreImpl` URL (e.g. `hummock+memory`, `hummock+minio://KEY:SECRET@ip:port`). The meta
+//! service is an embedded mock, so iterator or cache changes can be measured without a full
+//! cluster.
+//!
+//! Example:
+//!
+//! ```shell
+//! cargo run -p risingwave_hummock_test --features test --bin bench_hummock -- \
+//!     --state-store hummock+memory --workloads get-random,scan-random
+//! ```
+
+use std::ops::Bound::{Excluded, Included};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use bytes::Bytes;
+use clap::Parser;
+use futures::{pin_mut, TryStreamExt};
+use hdrhistogram::Histogram;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use risingwave_common::catalog::TableId;
+use risingwave_hummock_sdk::HummockReadEpoch;
+use risingwave_hummock_test::get_notification_client_for_test;
+use risingwave_hummock_test::test_utils::{register_tables_with_id_for_test, TestIngestBatch};
+use risingwave_meta::hummock::test_utils::setup_compute_env;
+use risingwave_meta::hummock::MockHummockMetaClient;
+use risingwave_object_store::object::parse_remote_object_store;
+use risingwave_rpc_client::HummockMetaClient;
+use risingwave_storage::hummock::test_utils::default_opts_for_test;
+use risingwave_storage::hummock::{HummockStorage, SstableStore, TieredCache};
+use risingwave_storage::monitor::ObjectStoreMetrics;
+use risingwave_storage::storage_value::StorageValue;
+use risingwave_storage::store::*;
+
+#[derive(Parser, Debug)]
+pub struct Opts {
+    /// `StateStoreImpl` URL of the store to benchmark. Only `hummock+` stores are supported.
+    #[clap(long, default_value = "hummock+memory")]
+    state_store: String,
+    /// Comma-separated list of workloads to run in order.
+    #[clap(
+        long,
+        default_value = "get-seq,get-random,scan-random,scan-reverse-random,write-batch,mixed"
+    )]
+    workloads: String,
+    /// Number of keys to preload before read workloads.
+    #[clap(long, default_value = "100000")]
+    num_keys: u64,
+    /// Value size in bytes.
+    #[clap(long, default_value = "64")]
+    value_size: usize,
+    /// Number of key-value pairs in one write batch.
+    #[clap(long, default_value = "1000")]
+    batch_size: usize,
+    /// Number of keys covered by one range scan.
+    #[clap(long, default_value = "100")]
+    scan_size: u64,
+    /// Number of operations per workload. For write workloads this is the number of rows written.
+    #[clap(long, default_value = "10000")]
+    operations: u64,
+    #[clap(long, default_value = "0")]
+    table_id: u32,
+    #[clap(long, default_value = "0")]
+    seed: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Workload {
+    /// Point gets over the key space in ascending order.
+    GetSeq,
+    /// Point gets of uniformly random keys.
+    GetRandom,
+    /// Forward scans of `scan_size` keys starting at random keys.
+    ScanRandom,
+    /// Reverse scans of `scan_size` keys starting at random keys.
+    ScanReverseRandom,
+    /// Batched writes of fresh keys, one epoch per batch.
+    WriteBatch,
+    /// Random point gets interleaved with batched writes.
+    Mixed,
+}
+
+impl FromStr for Workload {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "get-seq" => Ok(Self::GetSeq),
+            "get-random" => Ok(Self::GetRandom),
+            "scan-random" => Ok(Self::ScanRandom),
+            "scan-reverse-random" => Ok(Self::ScanReverseRandom),
+            "write-batch" => Ok(Self::WriteBatch),
+            "mixed" => Ok(Self::Mixed),
+            other => Err(format!("unknown workload: {}", other)),
+        }
+    }
+}
+
+impl Workload {
+    fn needs_preload(&self) -> bool {
+        !matches!(self, Self::WriteBatch)
+    }
+}
+
+fn key_of(idx: u64) -> Bytes {
+    Bytes::from(format!("bench_key_{:012}", idx))
+}
+
+fn value_of(rng: &mut StdRng, value_size: usize) -> Bytes {
+    let mut value = vec![0; value_size];
+    rng.fill(&mut value[..]);
+    Bytes::from(value)
+}
+
+fn read_options(table_id: TableId) -> ReadOptions {
+    ReadOptions {
+        prefix_hint: None,
+        ignore_range_tombstone: false,
+        limit: None,
+        retention_seconds: None,
+        table_id,
+        read_version_from_backup: false,
+        cache_policy: CachePolicy::Fill,
+    }
+}
+
+fn new_latency_histogram() -> Histogram<u64> {
+    Histogram::new_with_bounds(1, 10_000_000, 2).unwrap()
+}
+
+fn report(name: &str, histogram: &Histogram<u64>, elapsed: Duration, ops: u64, bytes: u64) {
+    let secs = elapsed.as_secs_f64();
+    println!(
+        "{}: {} ops in {:.3}s, {:.0} ops/s, {:.2} MiB/s, latency (us): p50 {} p90 {} p99 {} p999 {} max {}",
+        name,
+        ops,
+        secs,
+        ops as f64 / secs,
+        bytes as f64 / secs / (1 << 20) as f64,
+        histogram.value_at_quantile(0.5),
+        histogram.value_at_quantile(0.9),
+        histogram.value_at_quantile(0.99),
+        histogram.value_at_quantile(0.999),
+        histogram.max(),
+    );
+}
+
+/// Creates a `HummockStorage` against the object store given in the URL, backed by an embedded
+/// mock meta service.
+async fn create_hummock_store(
+    opts: &Opts,
+    table_id: TableId,
+) -> (HummockStorage, Arc<MockHummockMetaClient>) {
+    let object_store_url = opts
+        .state_store
+        .strip_prefix("hummock+")
+        .expect("only `hummock+` state stores are supported");
+    let object_store = parse_remote_object_store(
+        object_store_url,
+        Arc::new(ObjectStoreMetrics::unused()),
+        "Hummock",
+    )
+    .await;
+
+    let storage_opts = Arc::new(default_opts_for_test());
+    let sstable_store = Arc::new(SstableStore::new(
+        Arc::new(object_store),
+        storage_opts.data_directory.to_string(),
+        storage_opts.block_cache_capacity_mb * (1 << 20),
+        storage_opts.meta_cache_capacity_mb * (1 << 20),
+        TieredCache::none(),
+    ));
+
+    let (env, hummock_manager_ref, _cluster_manager_ref, worker_node) =
+        setup_compute_env(8080).await;
+    let meta_client = Arc::new(MockHummockMetaClient::new(
+        hummock_manager_ref.clone(),
+        worker_node.id,
+    ));
+
+    let storage = HummockStorage::for_test(
+        storage_opts,
+        sstable_store,
+        meta_client.clone(),
+        get_notification_client_for_test(env, hummock_manager_ref.clone(), worker_node),
+    )
+    .await
+    .unwrap();
+
+    register_tables_with_id_for_test(
+        storage.filter_key_extractor_manager(),
+        &hummock_manager_ref,
+        &[table_id.table_id()],
+    )
+    .await;
+
+    (storage, meta_client)
+}
+
+/// Seals and syncs `epoch`, then commits it to the mock meta service and waits until it is
+/// readable.
+async fn sync_and_commit(
+    storage: &HummockStorage,
+    meta_client: &MockHummockMetaClient,
+    epoch: u64,
+) {
+    storage.seal_epoch(epoch, true);
+    let sync_result = storage.sync(epoch).await.unwrap();
+    meta_client
+        .commit_epoch(epoch, sync_result.uncommitted_ssts)
+        .await
+        .unwrap();
+    storage
+        .try_wait_epoch(HummockReadEpoch::Committed(epoch))
+        .await
+        .unwrap();
+}
+
+/// Preloads `num_keys` keys in one epoch so that read workloads have data to read.
+async fn preload(
+    storage: &HummockStorage,
+    local: &mut impl TestIngestBatch,
+    meta_client: &MockHummockMetaClient,
+    opts: &Opts,
+    table_id: TableId,
+    epoch: u64,
+) {
+    let mut rng = StdRng::seed_from_u64(opts.seed);
+    let mut next_idx = 0;
+    while next_idx < opts.num_keys {
+        let batch_size = std::cmp::min(opts.num_keys - next_idx, opts.batch_size as u64);
+        let batch = (next_idx..next_idx + batch_size)
+            .map(|idx| {
+                (
+                    key_of(idx),
+                    StorageValue::new_put(value_of(&mut rng, opts.value_size)),
+                )
+            })
+            .collect();
+        next_idx += batch_size;
+        local
+            .ingest_batch(batch, vec![], WriteOptions { epoch, table_id })
+            .await
+            .unwrap();
+    }
+    local.seal_current_epoch(epoch + 1);
+    sync_and_commit(storage, meta_client, epoch).await;
+}
+
+async fn run_get(storage: &HummockStorage, opts: &Opts, table_id: TableId, epoch: u64, seq: bool) {
+    let mut rng = StdRng::seed_from_u64(opts.seed);
+    let mut histogram = new_latency_histogram();
+    let mut bytes = 0;
+    let start = Instant::now();
+    for i in 0..opts.operations {
+        let idx = if seq {
+            i % opts.num_keys
+        } else {
+            rng.gen_range(0..opts.num_keys)
+        };
+        let key = key_of(idx);
+        let op_start = Instant::now();
+        let value = storage
+            .get(&key, epoch, read_options(table_id))
+            .await
+            .unwrap();
+        histogram.saturating_record(op_start.elapsed().as_micros() as u64);
+        bytes += (key.len() + value.map(|v| v.len()).unwrap_or(0)) as u64;
+    }
+    let name = if seq { "get-seq" } else { "get-random" };
+    report(name, &histogram, start.elapsed(), opts.operations, bytes);
+}
+
+/// Scans ranges of `scan_size` keys starting at random keys. Since the state store API only
+/// iterates forward, a reverse scan materializes the range and walks it backwards, which is
+/// exactly what a reverse range read costs through today's API.
+async fn run_scan(
+    storage: &HummockStorage,
+    opts: &Opts,
+    table_id: TableId,
+    epoch: u64,
+    reverse: bool,
+) {
+    let mut rng = StdRng::seed_from_u64(opts.seed);
+    let mut histogram = new_latency_histogram();
+    let mut bytes = 0;
+    let start = Instant::now();
+    for _ in 0..opts.operations {
+        let begin = rng.gen_range(0..opts.num_keys.saturating_sub(opts.scan_size).max(1));
+        let key_range = (
+            Included(key_of(begin).to_vec()),
+            Excluded(key_of(begin + opts.scan_size).to_vec()),
+        );
+        let op_start = Instant::now();
+        if reverse {
+            let kvs = storage
+                .scan(key_range, epoch, None, read_options(table_id))
+                .await
+                .unwrap();
+            for (key, value) in kvs.into_iter().rev() {
+                bytes += (key.user_key.table_key.len() + value.len()) as u64;
+            }
+        } else {
+            let iter = storage
+                .iter(key_range, epoch, read_options(table_id))
+                .await
+                .unwrap();
+            pin_mut!(iter);
+            while let Some((key, value)) = iter.try_next().await.unwrap() {
+                bytes += (key.user_key.table_key.len() + value.len()) as u64;
+            }
+        }
+        histogram.saturating_record(op_start.elapsed().as_micros() as u64);
+    }
+    let name = if reverse {
+        "scan-reverse-random"
+    } else {
+        "scan-random"
+    };
+    report(name, &histogram, start.elapsed(), opts.operations, bytes);
+}
+
+/// Writes `operations` fresh rows in batches of `batch_size`, committing one epoch per batch.
+async fn run_write(
+    storage: &HummockStorage,
+    local: &mut impl TestIngestBatch,
+    meta_client: &MockHummockMetaClient,
+    opts: &Opts,
+    table_id: TableId,
+    epoch: &mut u64,
+) {
+    let mut rng = StdRng::seed_from_u64(opts.seed);
+    let mut histogram = new_latency_histogram();
+    let mut bytes = 0;
+    let mut next_idx = opts.num_keys;
+    let mut remain = opts.operations;
+    let start = Instant::now();
+    while remain > 0 {
+        let batch_size = std::cmp::min(remain, opts.batch_size as u64);
+        let batch = (0..batch_size)
+            .map(|i| {
+                (
+                    key_of(next_idx + i),
+                    StorageValue::new_put(value_of(&mut rng, opts.value_size)),
+                )
+            })
+            .collect();
+        next_idx += batch_size;
+        remain -= batch_size;
+        bytes += batch_size * (key_of(0).len() + opts.value_size) as u64;
+        let op_start = Instant::now();
+        local
+            .ingest_batch(
+                batch,
+                vec![],
+                WriteOptions {
+                    epoch: *epoch,
+                    table_id,
+                },
+            )
+            .await
+            .unwrap();
+        local.seal_current_epoch(*epoch + 1);
+        sync_and_commit(storage, meta_client, *epoch).await;
+        histogram.saturating_record(op_start.elapsed().as_micros() as u64);
+        *epoch += 1;
+    }
+    report(
+        "write-batch",
+        &histogram,
+        start.elapsed(),
+        opts.operations,
+        bytes,
+    );
+}
+
+/// Interleaves random point gets with batched writes at a 4:1 ratio. Writes are buffered and
+/// committed whenever a full batch accumulates.
+async fn run_mixed(
+    storage: &HummockStorage,
+    local: &mut impl TestIngestBatch,
+    meta_client: &MockHummockMetaClient,
+    opts: &Opts,
+    table_id: TableId,
+    epoch: &mut u64,
+) {
+    let mut rng = StdRng::seed_from_u64(opts.seed);
+    let mut get_histogram = new_latency_histogram();
+    let mut write_histogram = new_latency_histogram();
+    let mut get_ops = 0;
+    let mut write_ops = 0;
+    let mut get_bytes = 0;
+    let mut write_bytes = 0;
+    let mut next_idx = 2 * opts.num_keys;
+    let mut batch = Vec::with_capacity(opts.batch_size);
+    let start = Instant::now();
+    for _ in 0..opts.operations {
+        if rng.gen_ratio(4, 5) {
+            let key = key_of(rng.gen_range(0..opts.num_keys));
+            let op_start = Instant::now();
+            let value = storage
+                .get(&key, *epoch - 1, read_options(table_id))
+                .await
+                .unwrap();
+            get_histogram.saturating_record(op_start.elapsed().as_micros() as u64);
+            get_ops += 1;
+            get_bytes += (key.len() + value.map(|v| v.len()).unwrap_or(0)) as u64;
+        } else {
+            batch.push((
+                key_of(next_idx),
+                StorageValue::new_put(value_of(&mut rng, opts.value_size)),
+            ));
+            next_idx += 1;
+            write_ops += 1;
+            write_bytes += (key_of(0).len() + opts.value_size) as u64;
+        }
+        if batch.len() >= opts.batch_size {
+            let op_start = Instant::now();
+            local
+                .ingest_batch(
+                    std::mem::take(&mut batch),
+                    vec![],
+                    WriteOptions {
+                        epoch: *epoch,
+                        table_id,
+                    },
+                )
+                .await
+                .unwrap();
+            local.seal_current_epoch(*epoch + 1);
+            sync_and_commit(storage, meta_client, *epoch).await;
+            write_histogram.saturating_record(op_start.elapsed().as_micros() as u64);
+            *epoch += 1;
+        }
+    }
+    if !batch.is_empty() {
+        local
+            .ingest_batch(
+                batch,
+                vec![],
+                WriteOptions {
+                    epoch: *epoch,
+                    table_id,
+                },
+            )
+            .await
+            .unwrap();
+        local.seal_current_epoch(*epoch + 1);
+        sync_and_commit(storage, meta_client, *epoch).await;
+        *epoch += 1;
+    }
+    let elapsed = start.elapsed();
+    report("mixed/get", &get_histogram, elapsed, get_ops, get_bytes);
+    report(
+        "mixed/write",
+        &write_histogram,
+        elapsed,
+        write_ops,
+        write_bytes,
+    );
+}
+
+#[tokio::main]
+async fn main() {
+    let opts = Opts::parse();
+    let workloads = opts
+        .workloads
+        .split(',')
+        .map(|w| w.parse::<Workload>().unwrap())
+        .collect::<Vec<_>>();
+    let table_id = TableId::new(opts.table_id);
+
+    let (storage, meta_client) = create_hummock_store(&opts, table_id).await;
+    let mut local = storage.new_local(NewLocalOptions::for_test(table_id)).await;
+    let mut epoch = storage.get_pinned_version().max_committed_epoch() + 1;
+    local.init(epoch);
+
+    if workloads.iter().any(|w| w.needs_preload()) {
+        println!(
+            "preloading {} keys of {} value bytes each",
+            opts.num_keys, opts.value_size
+        );
+        preload(&storage, &mut local, &meta_client, &opts, table_id, epoch).await;
+        epoch += 1;
+    }
+
+    for workload in workloads {
+        match workload {
+            Workload::GetSeq => run_get(&storage, &opts, table_id, epoch - 1, true).await,
+            Workload::GetRandom => run_get(&storage, &opts, table_id, epoch - 1, false).await,
+            Workload::ScanRandom => run_scan(&storage, &opts, table_id, epoch - 1, false).await,
+            Workload::ScanReverseRandom => {
+                run_scan(&storage, &opts, table_id, epoch - 1, true).await
+            }
+            Workload::WriteBatch => {
+                run_write(
+                    &storage,
+                    &mut local,
+                    &meta_client,
+                    &opts,
+                    table_id,
+                    &mut epoch,
+                )
+                .await
+            }
+            Workload::Mixed => {
+                run_mixed(
+                    &storage,
+                    &mut local,
+                    &meta_client,
+                    &opts,
+                    table_id,
+                    &mut epoch,
+                )
+                .await
+            }
+        }
+    }
+}
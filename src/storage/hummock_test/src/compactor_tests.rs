@@ -298,11 +298,13 @@ pub(crate) mod tests {
                 (32 * 1000) << 16,
                 ReadOptions {
                     ignore_range_tombstone: false,
+                    limit: None,
 
                     prefix_hint: None,
                     table_id: Default::default(),
                     retention_seconds: None,
                     read_version_from_backup: false,
+                    cache_policy: CachePolicy::Fill,
                 },
             )
             .await
@@ -317,10 +319,12 @@ pub(crate) mod tests {
                 (31 * 1000) << 16,
                 ReadOptions {
                     ignore_range_tombstone: false,
+                    limit: None,
                     prefix_hint: Some(key.clone()),
                     table_id: Default::default(),
                     retention_seconds: None,
                     read_version_from_backup: false,
+                    cache_policy: CachePolicy::Fill,
                 },
             )
             .await;
@@ -431,11 +435,13 @@ pub(crate) mod tests {
                 129,
                 ReadOptions {
                     ignore_range_tombstone: false,
+                    limit: None,
 
                     prefix_hint: None,
                     table_id: Default::default(),
                     retention_seconds: None,
                     read_version_from_backup: false,
+                    cache_policy: CachePolicy::Fill,
                 },
             )
             .await
@@ -796,11 +802,13 @@ pub(crate) mod tests {
                 None,
                 ReadOptions {
                     ignore_range_tombstone: false,
+                    limit: None,
 
                     prefix_hint: None,
                     table_id: TableId::from(existing_table_ids),
                     retention_seconds: None,
                     read_version_from_backup: false,
+                    cache_policy: CachePolicy::Fill,
                 },
             )
             .await
@@ -977,11 +985,13 @@ pub(crate) mod tests {
                 None,
                 ReadOptions {
                     ignore_range_tombstone: false,
+                    limit: None,
 
                     prefix_hint: None,
                     table_id: TableId::from(existing_table_id),
                     retention_seconds: None,
                     read_version_from_backup: false,
+                    cache_policy: CachePolicy::Fill,
                 },
             )
             .await
@@ -1161,10 +1171,12 @@ pub(crate) mod tests {
                 None,
                 ReadOptions {
                     ignore_range_tombstone: false,
+                    limit: None,
                     prefix_hint: Some(Bytes::from(bloom_filter_key)),
                     table_id: TableId::from(existing_table_id),
                     retention_seconds: None,
                     read_version_from_backup: false,
+                    cache_policy: CachePolicy::Fill,
                 },
             )
             .await
@@ -27,7 +27,7 @@ use risingwave_storage::hummock::test_utils::{count_stream, default_opts_for_tes
 use risingwave_storage::hummock::HummockStorage;
 use risingwave_storage::storage_value::StorageValue;
 use risingwave_storage::store::{
-    LocalStateStore, NewLocalOptions, ReadOptions, StateStoreRead, WriteOptions,
+    CachePolicy, LocalStateStore, NewLocalOptions, ReadOptions, StateStoreRead, WriteOptions,
 };
 use risingwave_storage::StateStore;
 
@@ -101,10 +101,12 @@ async fn test_failpoints_state_store_read_upload() {
             1,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
                 prefix_hint: Some(Bytes::from(anchor_prefix_hint)),
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -154,10 +156,12 @@ async fn test_failpoints_state_store_read_upload() {
             2,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
                 prefix_hint: Some(Bytes::from(anchor_prefix_hint)),
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await;
@@ -168,10 +172,12 @@ async fn test_failpoints_state_store_read_upload() {
             2,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
                 prefix_hint: None,
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await;
@@ -189,10 +195,12 @@ async fn test_failpoints_state_store_read_upload() {
             2,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
                 prefix_hint: Some(Bytes::from(bee_prefix_hint)),
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -229,10 +237,12 @@ async fn test_failpoints_state_store_read_upload() {
             5,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
                 prefix_hint: Some(Bytes::from(anchor_prefix_hint)),
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -245,10 +255,12 @@ async fn test_failpoints_state_store_read_upload() {
             5,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
                 prefix_hint: None,
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -161,6 +161,7 @@ async fn test_read_version_basic() {
                     uncompressed_file_size: 1,
                     min_epoch: 0,
                     max_epoch: 0,
+                    tombstone_key_count: 0,
                 }),
                 LocalSstableInfo::for_test(SstableInfo {
                     id: 2,
@@ -178,6 +179,7 @@ async fn test_read_version_basic() {
                     uncompressed_file_size: 1,
                     min_epoch: 0,
                     max_epoch: 0,
+                    tombstone_key_count: 0,
                 }),
             ],
             epoch_id_vec_for_clear,
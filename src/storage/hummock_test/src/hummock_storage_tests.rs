@@ -91,11 +91,13 @@ async fn test_storage_basic() {
             epoch1,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
                 table_id: TEST_TABLE_ID,
                 retention_seconds: None,
 
                 prefix_hint: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -109,11 +111,13 @@ async fn test_storage_basic() {
             epoch1,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
                 table_id: TEST_TABLE_ID,
                 retention_seconds: None,
 
                 prefix_hint: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -129,11 +133,13 @@ async fn test_storage_basic() {
             epoch1,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
                 table_id: TEST_TABLE_ID,
                 retention_seconds: None,
 
                 prefix_hint: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -162,11 +168,13 @@ async fn test_storage_basic() {
             epoch2,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
                 table_id: TEST_TABLE_ID,
                 retention_seconds: None,
 
                 prefix_hint: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -197,11 +205,13 @@ async fn test_storage_basic() {
             epoch3,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
                 table_id: TEST_TABLE_ID,
                 retention_seconds: None,
 
                 prefix_hint: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -216,11 +226,13 @@ async fn test_storage_basic() {
             epoch3,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
                 table_id: TEST_TABLE_ID,
                 retention_seconds: None,
 
                 prefix_hint: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -235,11 +247,13 @@ async fn test_storage_basic() {
             epoch1,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
                 table_id: TEST_TABLE_ID,
                 retention_seconds: None,
 
                 prefix_hint: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -269,11 +283,13 @@ async fn test_storage_basic() {
             epoch1,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
                 table_id: TEST_TABLE_ID,
                 retention_seconds: None,
 
                 prefix_hint: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -289,11 +305,13 @@ async fn test_storage_basic() {
             epoch2,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
                 table_id: TEST_TABLE_ID,
                 retention_seconds: None,
 
                 prefix_hint: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -308,11 +326,13 @@ async fn test_storage_basic() {
             epoch2,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
                 table_id: TEST_TABLE_ID,
                 retention_seconds: None,
 
                 prefix_hint: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -349,11 +369,13 @@ async fn test_storage_basic() {
             epoch3,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
                 table_id: TEST_TABLE_ID,
                 retention_seconds: None,
 
                 prefix_hint: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -494,11 +516,13 @@ async fn test_state_store_sync() {
                     epoch1,
                     ReadOptions {
                         ignore_range_tombstone: false,
+                        limit: None,
                         table_id: TEST_TABLE_ID,
                         retention_seconds: None,
 
                         prefix_hint: None,
                         read_version_from_backup: false,
+                        cache_policy: CachePolicy::Fill,
                     },
                 )
                 .await
@@ -539,11 +563,13 @@ async fn test_state_store_sync() {
                     epoch2,
                     ReadOptions {
                         ignore_range_tombstone: false,
+                        limit: None,
                         table_id: TEST_TABLE_ID,
                         retention_seconds: None,
 
                         prefix_hint: None,
                         read_version_from_backup: false,
+                        cache_policy: CachePolicy::Fill,
                     },
                 )
                 .await
@@ -562,11 +588,13 @@ async fn test_state_store_sync() {
                 epoch1,
                 ReadOptions {
                     ignore_range_tombstone: false,
+                    limit: None,
                     table_id: TEST_TABLE_ID,
                     retention_seconds: None,
 
                     prefix_hint: None,
                     read_version_from_backup: false,
+                    cache_policy: CachePolicy::Fill,
                 },
             )
             .await
@@ -603,11 +631,13 @@ async fn test_state_store_sync() {
                 epoch2,
                 ReadOptions {
                     ignore_range_tombstone: false,
+                    limit: None,
                     table_id: TEST_TABLE_ID,
                     retention_seconds: None,
 
                     prefix_hint: None,
                     read_version_from_backup: false,
+                    cache_policy: CachePolicy::Fill,
                 },
             )
             .await
@@ -705,11 +735,13 @@ async fn test_delete_get() {
             epoch2,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
                 prefix_hint: None,
 
                 table_id: TEST_TABLE_ID,
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             }
         )
         .await
@@ -793,11 +825,13 @@ async fn test_multiple_epoch_sync() {
                         epoch1,
                         ReadOptions {
                             ignore_range_tombstone: false,
+                            limit: None,
                             table_id: TEST_TABLE_ID,
                             retention_seconds: None,
 
                             prefix_hint: None,
                             read_version_from_backup: false,
+                            cache_policy: CachePolicy::Fill,
                         },
                     )
                     .await
@@ -811,11 +845,13 @@ async fn test_multiple_epoch_sync() {
                     epoch2,
                     ReadOptions {
                         ignore_range_tombstone: false,
+                        limit: None,
                         table_id: TEST_TABLE_ID,
                         retention_seconds: None,
 
                         prefix_hint: None,
                         read_version_from_backup: false,
+                        cache_policy: CachePolicy::Fill,
                     },
                 )
                 .await
@@ -828,11 +864,13 @@ async fn test_multiple_epoch_sync() {
                         epoch3,
                         ReadOptions {
                             ignore_range_tombstone: false,
+                            limit: None,
                             table_id: TEST_TABLE_ID,
                             retention_seconds: None,
 
                             prefix_hint: None,
                             read_version_from_backup: false,
+                            cache_policy: CachePolicy::Fill,
                         },
                     )
                     .await
@@ -936,10 +974,12 @@ async fn test_iter_with_min_epoch() {
                     epoch1,
                     ReadOptions {
                         ignore_range_tombstone: false,
+                        limit: None,
                         table_id: TEST_TABLE_ID,
                         retention_seconds: None,
                         prefix_hint: None,
                         read_version_from_backup: false,
+                        cache_policy: CachePolicy::Fill,
                     },
                 )
                 .await
@@ -959,10 +999,12 @@ async fn test_iter_with_min_epoch() {
                     epoch2,
                     ReadOptions {
                         ignore_range_tombstone: false,
+                        limit: None,
                         table_id: TEST_TABLE_ID,
                         retention_seconds: None,
                         prefix_hint: None,
                         read_version_from_backup: false,
+                        cache_policy: CachePolicy::Fill,
                     },
                 )
                 .await
@@ -980,10 +1022,12 @@ async fn test_iter_with_min_epoch() {
                     epoch2,
                     ReadOptions {
                         ignore_range_tombstone: false,
+                        limit: None,
                         table_id: TEST_TABLE_ID,
                         retention_seconds: Some(1),
                         prefix_hint: None,
                         read_version_from_backup: false,
+                        cache_policy: CachePolicy::Fill,
                     },
                 )
                 .await
@@ -1021,10 +1065,12 @@ async fn test_iter_with_min_epoch() {
                     epoch1,
                     ReadOptions {
                         ignore_range_tombstone: false,
+                        limit: None,
                         table_id: TEST_TABLE_ID,
                         retention_seconds: None,
                         prefix_hint: None,
                         read_version_from_backup: false,
+                        cache_policy: CachePolicy::Fill,
                     },
                 )
                 .await
@@ -1044,10 +1090,12 @@ async fn test_iter_with_min_epoch() {
                     epoch2,
                     ReadOptions {
                         ignore_range_tombstone: false,
+                        limit: None,
                         table_id: TEST_TABLE_ID,
                         retention_seconds: None,
                         prefix_hint: None,
                         read_version_from_backup: false,
+                        cache_policy: CachePolicy::Fill,
                     },
                 )
                 .await
@@ -1067,10 +1115,12 @@ async fn test_iter_with_min_epoch() {
                     epoch2,
                     ReadOptions {
                         ignore_range_tombstone: false,
+                        limit: None,
                         table_id: TEST_TABLE_ID,
                         retention_seconds: Some(1),
                         prefix_hint: None,
                         read_version_from_backup: false,
+                        cache_policy: CachePolicy::Fill,
                     },
                 )
                 .await
@@ -1189,10 +1239,12 @@ async fn test_hummock_version_reader() {
                         epoch1,
                         ReadOptions {
                             ignore_range_tombstone: false,
+                            limit: None,
                             table_id: TEST_TABLE_ID,
                             retention_seconds: None,
                             prefix_hint: None,
                             read_version_from_backup: false,
+                            cache_policy: CachePolicy::Fill,
                         },
                         read_snapshot,
                     )
@@ -1218,10 +1270,12 @@ async fn test_hummock_version_reader() {
                         epoch2,
                         ReadOptions {
                             ignore_range_tombstone: false,
+                            limit: None,
                             table_id: TEST_TABLE_ID,
                             retention_seconds: None,
                             prefix_hint: None,
                             read_version_from_backup: false,
+                            cache_policy: CachePolicy::Fill,
                         },
                         read_snapshot,
                     )
@@ -1247,10 +1301,12 @@ async fn test_hummock_version_reader() {
                         epoch2,
                         ReadOptions {
                             ignore_range_tombstone: false,
+                            limit: None,
                             table_id: TEST_TABLE_ID,
                             retention_seconds: Some(1),
                             prefix_hint: None,
                             read_version_from_backup: false,
+                            cache_policy: CachePolicy::Fill,
                         },
                         read_snapshot,
                     )
@@ -1318,10 +1374,12 @@ async fn test_hummock_version_reader() {
                         epoch1,
                         ReadOptions {
                             ignore_range_tombstone: false,
+                            limit: None,
                             table_id: TEST_TABLE_ID,
                             retention_seconds: None,
                             prefix_hint: None,
                             read_version_from_backup: false,
+                            cache_policy: CachePolicy::Fill,
                         },
                         read_snapshot,
                     )
@@ -1356,10 +1414,12 @@ async fn test_hummock_version_reader() {
                         epoch2,
                         ReadOptions {
                             ignore_range_tombstone: false,
+                            limit: None,
                             table_id: TEST_TABLE_ID,
                             retention_seconds: None,
                             prefix_hint: None,
                             read_version_from_backup: false,
+                            cache_policy: CachePolicy::Fill,
                         },
                         read_snapshot,
                     )
@@ -1394,10 +1454,12 @@ async fn test_hummock_version_reader() {
                         epoch2,
                         ReadOptions {
                             ignore_range_tombstone: false,
+                            limit: None,
                             table_id: TEST_TABLE_ID,
                             retention_seconds: Some(1),
                             prefix_hint: None,
                             read_version_from_backup: false,
+                            cache_policy: CachePolicy::Fill,
                         },
                         read_snapshot,
                     )
@@ -1432,10 +1494,12 @@ async fn test_hummock_version_reader() {
                         epoch3,
                         ReadOptions {
                             ignore_range_tombstone: false,
+                            limit: None,
                             table_id: TEST_TABLE_ID,
                             retention_seconds: None,
                             prefix_hint: None,
                             read_version_from_backup: false,
+                            cache_policy: CachePolicy::Fill,
                         },
                         read_snapshot,
                     )
@@ -1476,10 +1540,12 @@ async fn test_hummock_version_reader() {
                             epoch2,
                             ReadOptions {
                                 ignore_range_tombstone: false,
+                                limit: None,
                                 table_id: TEST_TABLE_ID,
                                 retention_seconds: None,
                                 prefix_hint: None,
                                 read_version_from_backup: false,
+                                cache_policy: CachePolicy::Fill,
                             },
                             read_snapshot,
                         )
@@ -1514,10 +1580,12 @@ async fn test_hummock_version_reader() {
                             epoch3,
                             ReadOptions {
                                 ignore_range_tombstone: false,
+                                limit: None,
                                 table_id: TEST_TABLE_ID,
                                 retention_seconds: None,
                                 prefix_hint: None,
                                 read_version_from_backup: false,
+                                cache_policy: CachePolicy::Fill,
                             },
                             read_snapshot,
                         )
@@ -1614,10 +1682,12 @@ async fn test_get_with_min_epoch() {
                     epoch1,
                     ReadOptions {
                         ignore_range_tombstone: false,
+                        limit: None,
                         table_id: TEST_TABLE_ID,
                         retention_seconds: None,
                         prefix_hint: None,
                         read_version_from_backup: false,
+                        cache_policy: CachePolicy::Fill,
                     },
                 )
                 .await
@@ -1633,10 +1703,12 @@ async fn test_get_with_min_epoch() {
                     epoch1,
                     ReadOptions {
                         ignore_range_tombstone: false,
+                        limit: None,
                         table_id: TEST_TABLE_ID,
                         retention_seconds: None,
                         prefix_hint: Some(Bytes::from(prefix_hint.clone())),
                         read_version_from_backup: false,
+                        cache_policy: CachePolicy::Fill,
                     },
                 )
                 .await
@@ -1652,10 +1724,12 @@ async fn test_get_with_min_epoch() {
                     epoch2,
                     ReadOptions {
                         ignore_range_tombstone: false,
+                        limit: None,
                         table_id: TEST_TABLE_ID,
                         retention_seconds: None,
                         prefix_hint: Some(Bytes::from(prefix_hint.clone())),
                         read_version_from_backup: false,
+                        cache_policy: CachePolicy::Fill,
                     },
                 )
                 .await
@@ -1671,10 +1745,12 @@ async fn test_get_with_min_epoch() {
                     epoch2,
                     ReadOptions {
                         ignore_range_tombstone: false,
+                        limit: None,
                         table_id: TEST_TABLE_ID,
                         retention_seconds: Some(1),
                         prefix_hint: Some(Bytes::from(prefix_hint.clone())),
                         read_version_from_backup: false,
+                        cache_policy: CachePolicy::Fill,
                     },
                 )
                 .await
@@ -1715,11 +1791,13 @@ async fn test_get_with_min_epoch() {
                 epoch1,
                 ReadOptions {
                     ignore_range_tombstone: false,
+                    limit: None,
                     table_id: TEST_TABLE_ID,
                     retention_seconds: None,
 
                     prefix_hint: None,
                     read_version_from_backup: false,
+                    cache_policy: CachePolicy::Fill,
                 },
             )
             .await
@@ -1735,11 +1813,13 @@ async fn test_get_with_min_epoch() {
                 epoch1,
                 ReadOptions {
                     ignore_range_tombstone: false,
+                    limit: None,
                     table_id: TEST_TABLE_ID,
                     retention_seconds: None,
 
                     prefix_hint: Some(Bytes::from(prefix_hint.clone())),
                     read_version_from_backup: false,
+                    cache_policy: CachePolicy::Fill,
                 },
             )
             .await
@@ -1757,11 +1837,13 @@ async fn test_get_with_min_epoch() {
                 epoch2,
                 ReadOptions {
                     ignore_range_tombstone: false,
+                    limit: None,
                     table_id: TEST_TABLE_ID,
                     retention_seconds: None,
 
                     prefix_hint: Some(Bytes::from(prefix_hint.clone())),
                     read_version_from_backup: false,
+                    cache_policy: CachePolicy::Fill,
                 },
             )
             .await
@@ -1778,11 +1860,13 @@ async fn test_get_with_min_epoch() {
                 epoch2,
                 ReadOptions {
                     ignore_range_tombstone: false,
+                    limit: None,
                     table_id: TEST_TABLE_ID,
                     retention_seconds: Some(1),
 
                     prefix_hint: Some(Bytes::from(prefix_hint.clone())),
                     read_version_from_backup: false,
+                    cache_policy: CachePolicy::Fill,
                 },
             )
             .await
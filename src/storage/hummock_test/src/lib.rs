@@ -27,6 +27,8 @@ mod snapshot_tests;
 #[cfg(test)]
 mod state_store_tests;
 #[cfg(any(test, feature = "test"))]
+pub mod simulation;
+#[cfg(any(test, feature = "test"))]
 pub mod test_utils;
 #[cfg(test)]
 mod vacuum_tests;
@@ -22,6 +22,7 @@ use risingwave_meta::manager::{MessageStatus, MetaSrvEnv, NotificationManagerRef
 use risingwave_meta::storage::{MemStore, MetaStore};
 use risingwave_pb::backup_service::MetaBackupManifestId;
 use risingwave_pb::common::WorkerNode;
+use risingwave_pb::meta::meta_snapshot::SnapshotVersion;
 use risingwave_pb::meta::{MetaSnapshot, SubscribeResponse, SubscribeType};
 use tokio::sync::mpsc::UnboundedReceiver;
 
@@ -49,7 +50,11 @@ impl<S: MetaStore> MockNotificationClient<S> {
 impl<S: MetaStore> NotificationClient for MockNotificationClient<S> {
     type Channel = TestChannel<SubscribeResponse>;
 
-    async fn subscribe(&self, subscribe_type: SubscribeType) -> Result<Self::Channel> {
+    async fn subscribe(
+        &self,
+        subscribe_type: SubscribeType,
+        _resume_from: Option<SnapshotVersion>,
+    ) -> Result<Self::Channel> {
         let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
 
         let worker_key = WorkerKey(self.addr.to_protobuf());
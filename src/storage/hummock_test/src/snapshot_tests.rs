@@ -21,7 +21,9 @@ use risingwave_hummock_sdk::HummockReadEpoch;
 use risingwave_meta::hummock::MockHummockMetaClient;
 use risingwave_rpc_client::HummockMetaClient;
 use risingwave_storage::storage_value::StorageValue;
-use risingwave_storage::store::{LocalStateStore, NewLocalOptions, ReadOptions, WriteOptions};
+use risingwave_storage::store::{
+    CachePolicy, LocalStateStore, NewLocalOptions, ReadOptions, WriteOptions,
+};
 
 use crate::test_utils::{with_hummock_storage_v2, HummockStateStoreTestTrait, TestIngestBatch};
 
@@ -39,10 +41,12 @@ macro_rules! assert_count_range_scan {
                 $epoch,
                 ReadOptions {
                     ignore_range_tombstone: false,
+                    limit: None,
                     prefix_hint: None,
                     table_id: Default::default(),
                     retention_seconds: None,
                     read_version_from_backup: false,
+                    cache_policy: CachePolicy::Fill,
                 },
             )
             .await
@@ -73,10 +77,12 @@ macro_rules! assert_count_backward_range_scan {
                 bounds,
                 ReadOptions {
                     ignore_range_tombstone: false,
+                    limit: None,
                     epoch: $epoch,
                     table_id: Default::default(),
                     retention_seconds: None,
                     read_version_from_backup: false,
+                    cache_policy: CachePolicy::Fill,
                 },
             )
             .await
@@ -43,9 +43,11 @@ async fn test_empty_read_v2() {
             ReadOptions {
                 prefix_hint: None,
                 ignore_range_tombstone: false,
+                limit: None,
                 retention_seconds: None,
                 table_id: TableId { table_id: 2333 },
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -58,9 +60,11 @@ async fn test_empty_read_v2() {
             ReadOptions {
                 prefix_hint: None,
                 ignore_range_tombstone: false,
+                limit: None,
                 retention_seconds: None,
                 table_id: TableId { table_id: 2333 },
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -153,11 +157,13 @@ async fn test_basic_inner(
             epoch1,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
 
                 prefix_hint: None,
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -170,11 +176,13 @@ async fn test_basic_inner(
             epoch1,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
 
                 prefix_hint: None,
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -189,11 +197,13 @@ async fn test_basic_inner(
             epoch1,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
 
                 prefix_hint: None,
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -223,11 +233,13 @@ async fn test_basic_inner(
             epoch2,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
 
                 prefix_hint: None,
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -258,11 +270,13 @@ async fn test_basic_inner(
             epoch3,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
 
                 prefix_hint: None,
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -276,11 +290,13 @@ async fn test_basic_inner(
             epoch3,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
 
                 prefix_hint: None,
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -294,11 +310,13 @@ async fn test_basic_inner(
             epoch1,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
 
                 prefix_hint: None,
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -313,11 +331,13 @@ async fn test_basic_inner(
             epoch1,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
 
                 prefix_hint: None,
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -332,11 +352,13 @@ async fn test_basic_inner(
             epoch2,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
 
                 prefix_hint: None,
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -350,11 +372,13 @@ async fn test_basic_inner(
             epoch2,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
 
                 prefix_hint: None,
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -369,11 +393,13 @@ async fn test_basic_inner(
             epoch3,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
 
                 prefix_hint: None,
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -396,11 +422,13 @@ async fn test_basic_inner(
             epoch2,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
 
                 prefix_hint: None,
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -413,11 +441,13 @@ async fn test_basic_inner(
             epoch2,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
 
                 prefix_hint: None,
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -594,11 +624,13 @@ async fn test_reload_storage() {
             epoch1,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
 
                 prefix_hint: None,
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -613,11 +645,13 @@ async fn test_reload_storage() {
             epoch1,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
 
                 prefix_hint: None,
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -646,11 +680,13 @@ async fn test_reload_storage() {
             epoch2,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
 
                 prefix_hint: None,
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -665,11 +701,13 @@ async fn test_reload_storage() {
             epoch1,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
 
                 prefix_hint: None,
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -684,11 +722,13 @@ async fn test_reload_storage() {
             epoch1,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
 
                 prefix_hint: None,
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -703,11 +743,13 @@ async fn test_reload_storage() {
             epoch2,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
 
                 prefix_hint: None,
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -721,11 +763,13 @@ async fn test_reload_storage() {
             epoch2,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
 
                 prefix_hint: None,
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             },
         )
         .await
@@ -760,11 +804,13 @@ async fn test_write_anytime_inner(
                         epoch,
                         ReadOptions {
                             ignore_range_tombstone: false,
+                            limit: None,
 
                             prefix_hint: None,
                             table_id: Default::default(),
                             retention_seconds: None,
                             read_version_from_backup: false,
+                            cache_policy: CachePolicy::Fill,
                         }
                     )
                     .await
@@ -779,11 +825,13 @@ async fn test_write_anytime_inner(
                         epoch,
                         ReadOptions {
                             ignore_range_tombstone: false,
+                            limit: None,
 
                             prefix_hint: None,
                             table_id: Default::default(),
                             retention_seconds: None,
                             read_version_from_backup: false,
+                            cache_policy: CachePolicy::Fill,
                         }
                     )
                     .await
@@ -798,11 +846,13 @@ async fn test_write_anytime_inner(
                         epoch,
                         ReadOptions {
                             ignore_range_tombstone: false,
+                            limit: None,
 
                             prefix_hint: None,
                             table_id: Default::default(),
                             retention_seconds: None,
                             read_version_from_backup: false,
+                            cache_policy: CachePolicy::Fill,
                         }
                     )
                     .await
@@ -819,11 +869,13 @@ async fn test_write_anytime_inner(
                     epoch,
                     ReadOptions {
                         ignore_range_tombstone: false,
+                        limit: None,
 
                         prefix_hint: None,
                         table_id: Default::default(),
                         retention_seconds: None,
                         read_version_from_backup: false,
+                        cache_policy: CachePolicy::Fill,
                     },
                 )
                 .await
@@ -888,11 +940,13 @@ async fn test_write_anytime_inner(
                         epoch,
                         ReadOptions {
                             ignore_range_tombstone: false,
+                            limit: None,
 
                             prefix_hint: None,
                             table_id: Default::default(),
                             retention_seconds: None,
                             read_version_from_backup: false,
+                            cache_policy: CachePolicy::Fill,
                         }
                     )
                     .await
@@ -906,11 +960,13 @@ async fn test_write_anytime_inner(
                     epoch,
                     ReadOptions {
                         ignore_range_tombstone: false,
+                        limit: None,
 
                         prefix_hint: None,
                         table_id: Default::default(),
                         retention_seconds: None,
                         read_version_from_backup: false,
+                        cache_policy: CachePolicy::Fill,
                     }
                 )
                 .await
@@ -924,11 +980,13 @@ async fn test_write_anytime_inner(
                         epoch,
                         ReadOptions {
                             ignore_range_tombstone: false,
+                            limit: None,
 
                             prefix_hint: None,
                             table_id: Default::default(),
                             retention_seconds: None,
                             read_version_from_backup: false,
+                            cache_policy: CachePolicy::Fill,
                         }
                     )
                     .await
@@ -944,11 +1002,13 @@ async fn test_write_anytime_inner(
                     epoch,
                     ReadOptions {
                         ignore_range_tombstone: false,
+                        limit: None,
 
                         prefix_hint: None,
                         table_id: Default::default(),
                         retention_seconds: None,
                         read_version_from_backup: false,
+                        cache_policy: CachePolicy::Fill,
                     },
                 )
                 .await
@@ -1100,11 +1160,13 @@ async fn test_delete_get_inner(
             epoch2,
             ReadOptions {
                 ignore_range_tombstone: false,
+                limit: None,
 
                 prefix_hint: None,
                 table_id: Default::default(),
                 retention_seconds: None,
                 read_version_from_backup: false,
+                cache_policy: CachePolicy::Fill,
             }
         )
         .await
@@ -1186,11 +1248,13 @@ async fn test_multiple_epoch_sync_inner(
                         epoch1,
                         ReadOptions {
                             ignore_range_tombstone: false,
+                            limit: None,
 
                             prefix_hint: None,
                             table_id: Default::default(),
                             retention_seconds: None,
                             read_version_from_backup: false,
+                            cache_policy: CachePolicy::Fill,
                         }
                     )
                     .await
@@ -1204,11 +1268,13 @@ async fn test_multiple_epoch_sync_inner(
                     epoch2,
                     ReadOptions {
                         ignore_range_tombstone: false,
+                        limit: None,
 
                         prefix_hint: None,
                         table_id: Default::default(),
                         retention_seconds: None,
                         read_version_from_backup: false,
+                        cache_policy: CachePolicy::Fill,
                     }
                 )
                 .await
@@ -1221,11 +1287,13 @@ async fn test_multiple_epoch_sync_inner(
                         epoch3,
                         ReadOptions {
                             ignore_range_tombstone: false,
+                            limit: None,
 
                             prefix_hint: None,
                             table_id: Default::default(),
                             retention_seconds: None,
                             read_version_from_backup: false,
+                            cache_policy: CachePolicy::Fill,
                         }
                     )
                     .await
@@ -36,7 +36,7 @@ use risingwave_pb::hummock::compact_task::TaskStatus;
 use risingwave_rpc_client::HummockMetaClient;
 use risingwave_storage::hummock::compactor::{Compactor, CompactorContext};
 use risingwave_storage::hummock::SstableIdManager;
-use risingwave_storage::store::{LocalStateStore, NewLocalOptions, ReadOptions};
+use risingwave_storage::store::{CachePolicy, LocalStateStore, NewLocalOptions, ReadOptions};
 use risingwave_storage::StateStore;
 use serial_test::serial;
 
@@ -359,11 +359,13 @@ async fn test_syncpoints_get_in_delete_range_boundary() {
     storage.wait_version(version).await;
     let read_options = ReadOptions {
         ignore_range_tombstone: false,
+        limit: None,
 
         prefix_hint: None,
         table_id: TableId::from(existing_table_id),
         retention_seconds: None,
         read_version_from_backup: false,
+        cache_policy: CachePolicy::Fill,
     };
     let get_result = storage
         .get(b"hhh", 120, read_options.clone())
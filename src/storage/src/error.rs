@@ -43,6 +43,14 @@ pub enum StorageError {
         sled::Error,
     ),
 
+    #[cfg(feature = "rocksdb-backend")]
+    #[error("RocksDB error: {0}")]
+    Rocksdb(
+        #[backtrace]
+        #[from]
+        rocksdb::Error,
+    ),
+
     #[error("MemTable error: {0}")]
     MemTable(
         #[backtrace]
@@ -53,6 +61,17 @@ pub enum StorageError {
 
 pub type StorageResult<T> = std::result::Result<T, StorageError>;
 
+impl StorageError {
+    /// Whether the failed operation may succeed if simply retried. See
+    /// [`HummockError::is_retryable`].
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            StorageError::Hummock(e) => e.is_retryable(),
+            _ => false,
+        }
+    }
+}
+
 impl From<ValueEncodingError> for StorageError {
     fn from(error: ValueEncodingError) -> Self {
         StorageError::DeserializeRow(error)
@@ -0,0 +1,140 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures::{stream, StreamExt};
+use prost::Message;
+use risingwave_hummock_sdk::compaction_group::hummock_version_ext::HummockVersionExt;
+use risingwave_hummock_sdk::{HummockSstableId, HummockVersionId};
+use risingwave_pb::hummock::HummockVersion;
+
+use super::{HummockError, HummockResult};
+use crate::hummock::SstableStoreRef;
+
+/// Number of SST objects copied concurrently during backup and restore.
+const COPY_CONCURRENCY: usize = 8;
+
+/// `HummockBackup` exports a Hummock version to a separate object-store prefix and restores it
+/// from there.
+///
+/// A backup consists of a copy of every SST object referenced by the version, plus a manifest
+/// object holding the encoded version metadata. The manifest is written only after all SST copies
+/// have succeeded, so the presence of a manifest implies a complete backup. SST objects are
+/// content-immutable, so re-running a backup of a later version into the same prefix only copies
+/// the SSTs that are not part of an earlier backup.
+pub struct HummockBackup;
+
+impl HummockBackup {
+    /// Path of the manifest object of `version_id` under `target_object_prefix`.
+    pub fn manifest_path(target_object_prefix: &str, version_id: HummockVersionId) -> String {
+        format!("{}/version-{}.manifest", target_object_prefix, version_id)
+    }
+
+    /// Path of the backup copy of `sst_id` under `target_object_prefix`.
+    ///
+    /// Backup objects are laid out flat. The hashed object prefix used in the live data
+    /// directory only serves to spread request load and is not needed here.
+    fn backup_sst_data_path(target_object_prefix: &str, sst_id: HummockSstableId) -> String {
+        format!("{}/{}.data", target_object_prefix, sst_id)
+    }
+
+    /// Copy the SST set of `version` and its metadata to `target_object_prefix`.
+    pub async fn backup(
+        version: &HummockVersion,
+        sstable_store: SstableStoreRef,
+        target_object_prefix: &str,
+    ) -> HummockResult<()> {
+        let sst_ids = version.get_sst_ids();
+        tracing::info!(
+            "Start backup of version {} with {} SSTs to {}",
+            version.id,
+            sst_ids.len(),
+            target_object_prefix
+        );
+        let store = sstable_store.store();
+        let mut copy_futures = stream::iter(sst_ids.into_iter().map(|sst_id| {
+            let store = store.clone();
+            let source_path = sstable_store.get_sst_data_path(sst_id);
+            let target_path = Self::backup_sst_data_path(target_object_prefix, sst_id);
+            async move {
+                let data = store.read(&source_path, None).await?;
+                store.upload(&target_path, data).await?;
+                Ok::<_, HummockError>(())
+            }
+        }))
+        .buffer_unordered(COPY_CONCURRENCY);
+        while let Some(result) = copy_futures.next().await {
+            result?;
+        }
+
+        // The manifest is written last so that a manifest never refers to an SST that has not
+        // been copied yet.
+        store
+            .upload(
+                &Self::manifest_path(target_object_prefix, version.id),
+                version.encode_to_vec().into(),
+            )
+            .await?;
+        tracing::info!("Finished backup of version {}", version.id);
+        Ok(())
+    }
+
+    /// Copy the SST set of the backup of `version_id` from `target_object_prefix` back into the
+    /// live data directory of `sstable_store` and return the version metadata.
+    ///
+    /// The returned version must be imported into the meta store of the target cluster before it
+    /// can serve reads. This is expected to be done against a fresh cluster, i.e. one whose data
+    /// directory does not contain conflicting SST ids.
+    pub async fn restore(
+        version_id: HummockVersionId,
+        sstable_store: SstableStoreRef,
+        target_object_prefix: &str,
+    ) -> HummockResult<HummockVersion> {
+        let store = sstable_store.store();
+        let encoded_version = store
+            .read(&Self::manifest_path(target_object_prefix, version_id), None)
+            .await?;
+        let version =
+            HummockVersion::decode(encoded_version).map_err(HummockError::decode_error)?;
+        if version.id != version_id {
+            return Err(HummockError::corruption(format!(
+                "backup manifest of version {} holds version {}",
+                version_id, version.id
+            )));
+        }
+
+        let sst_ids = version.get_sst_ids();
+        tracing::info!(
+            "Start restore of version {} with {} SSTs from {}",
+            version_id,
+            sst_ids.len(),
+            target_object_prefix
+        );
+        let mut copy_futures = stream::iter(sst_ids.into_iter().map(|sst_id| {
+            let store = store.clone();
+            let source_path = Self::backup_sst_data_path(target_object_prefix, sst_id);
+            let target_path = sstable_store.get_sst_data_path(sst_id);
+            async move {
+                let data = store.read(&source_path, None).await?;
+                store.upload(&target_path, data).await?;
+                Ok::<_, HummockError>(())
+            }
+        }))
+        .buffer_unordered(COPY_CONCURRENCY);
+        while let Some(result) = copy_futures.next().await {
+            result?;
+        }
+        tracing::info!("Finished restore of version {}", version_id);
+        Ok(version)
+    }
+}
@@ -13,12 +13,16 @@
 // limitations under the License.
 
 use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 use std::ops::Deref;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
 use async_stack_trace::StackTrace;
 use futures::Future;
+use parking_lot::Mutex;
 use risingwave_common::cache::{CacheableEntry, LruCache, LruCacheEventListener};
 use risingwave_hummock_sdk::HummockSstableId;
 
@@ -27,6 +31,186 @@ use crate::hummock::HummockError;
 
 const MIN_BUFFER_SIZE_PER_SHARD: usize = 32 * 1024 * 1024;
 
+/// Blocks are only admitted into the cache under [`BlockCachePolicy::TinyLfu`] once they have
+/// been accessed at least this many times within the current sample window.
+const TINY_LFU_ADMIT_THRESHOLD: u64 = 2;
+
+/// The assumed average block size used to estimate the number of cache entries when sizing the
+/// frequency sketch.
+const SKETCH_ENTRY_SIZE: usize = 64 * 1024;
+
+/// Policy for admitting newly fetched blocks into the block cache.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlockCachePolicy {
+    /// Admit every fetched block, relying on LRU eviction alone.
+    Lru,
+    /// TinyLFU admission on top of the LRU eviction: block accesses are counted in a compact
+    /// frequency sketch and a fetched block is only admitted once it has been accessed more than
+    /// once within the current sample window, so that one-off scans do not wash hot blocks out
+    /// of the cache.
+    TinyLfu,
+}
+
+impl FromStr for BlockCachePolicy {
+    type Err = HummockError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "lru" => Ok(Self::Lru),
+            "tinylfu" => Ok(Self::TinyLfu),
+            _ => Err(HummockError::other(format!(
+                "not a valid block cache policy: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// A Count-Min sketch with 4-bit saturating counters and periodic aging, estimating how often a
+/// block has been accessed recently. Each `u64` of the table packs 16 counters, and every access
+/// increments one counter in each of [`SKETCH_DEPTH`] table slots; the estimate is the minimum of
+/// them. After `sample_size` recorded accesses all counters are halved, so the sketch tracks
+/// recent popularity instead of the all-time one.
+pub struct FrequencySketch {
+    table: Vec<AtomicU64>,
+    table_mask: usize,
+    sample_size: u64,
+    additions: AtomicU64,
+}
+
+const SKETCH_DEPTH: usize = 4;
+const SKETCH_SEEDS: [u64; SKETCH_DEPTH] = [
+    0xc3a5_c85c_97cb_3127,
+    0xb492_b66f_be98_f273,
+    0x9ae1_6a3b_2f90_404f,
+    0xcbf2_9ce4_8422_2325,
+];
+
+impl FrequencySketch {
+    /// Creates a sketch sized for a cache expected to hold `entries` blocks.
+    fn with_capacity(entries: usize) -> Self {
+        let table_len = entries.next_power_of_two().max(64);
+        Self {
+            table: (0..table_len).map(|_| AtomicU64::new(0)).collect(),
+            table_mask: table_len - 1,
+            sample_size: entries as u64 * 8,
+            additions: AtomicU64::new(0),
+        }
+    }
+
+    fn positions(&self, hash: u64) -> [(usize, u32); SKETCH_DEPTH] {
+        SKETCH_SEEDS.map(|seed| {
+            let h = hash.wrapping_mul(seed);
+            let h = h ^ (h >> 32);
+            (h as usize & self.table_mask, ((h >> 33) as u32 & 15) * 4)
+        })
+    }
+
+    fn record(&self, hash: u64) {
+        for (slot, shift) in self.positions(hash) {
+            let _ = self.table[slot].fetch_update(Ordering::Relaxed, Ordering::Relaxed, |v| {
+                ((v >> shift) & 0xf < 0xf).then_some(v + (1 << shift))
+            });
+        }
+        if self.additions.fetch_add(1, Ordering::Relaxed) + 1 >= self.sample_size {
+            self.age();
+        }
+    }
+
+    fn estimate(&self, hash: u64) -> u64 {
+        self.positions(hash)
+            .into_iter()
+            .map(|(slot, shift)| (self.table[slot].load(Ordering::Relaxed) >> shift) & 0xf)
+            .min()
+            .unwrap()
+    }
+
+    /// Halves all counters. The sweep is racy with concurrent increments, which is acceptable
+    /// for a heuristic.
+    fn age(&self) {
+        self.additions.store(0, Ordering::Relaxed);
+        for slot in &self.table {
+            let _ = slot.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |v| {
+                Some((v >> 1) & 0x7777_7777_7777_7777)
+            });
+        }
+    }
+}
+
+/// Tracks the block cache usage per table, so that the cache footprint of every table can be
+/// observed through the metrics and optionally capped. The table of a block is derived from the
+/// smallest key of its block meta when it is inserted.
+pub struct BlockCacheUsageTracker {
+    /// Per-table cap in bytes. `0` disables the cap.
+    per_table_capacity: usize,
+    inner: Mutex<BlockCacheUsageInner>,
+}
+
+#[derive(Default)]
+struct BlockCacheUsageInner {
+    /// The table and charge of every cached block, needed to account its release.
+    blocks: HashMap<(HummockSstableId, u64), (u32, usize)>,
+    /// Total cached bytes per table.
+    tables: HashMap<u32, usize>,
+}
+
+impl BlockCacheUsageTracker {
+    pub fn new(per_table_capacity: usize) -> Self {
+        Self {
+            per_table_capacity,
+            inner: Mutex::new(BlockCacheUsageInner::default()),
+        }
+    }
+
+    /// Accounts the insertion of a block. Idempotent: re-inserting the same block, e.g. by a
+    /// deduplicated concurrent fetch, does not change the usage.
+    fn on_insert(&self, key: (HummockSstableId, u64), table_id: u32, charge: usize) {
+        let mut inner = self.inner.lock();
+        if let Some((old_table_id, old_charge)) = inner.blocks.insert(key, (table_id, charge)) {
+            Self::sub_usage(&mut inner, old_table_id, old_charge);
+        }
+        *inner.tables.entry(table_id).or_default() += charge;
+    }
+
+    /// Accounts the release of a block, i.e. its eviction or erasure from the cache.
+    pub fn on_release(&self, key: (HummockSstableId, u64)) {
+        let mut inner = self.inner.lock();
+        if let Some((table_id, charge)) = inner.blocks.remove(&key) {
+            Self::sub_usage(&mut inner, table_id, charge);
+        }
+    }
+
+    fn sub_usage(inner: &mut BlockCacheUsageInner, table_id: u32, charge: usize) {
+        if let Some(usage) = inner.tables.get_mut(&table_id) {
+            *usage = usage.saturating_sub(charge);
+            if *usage == 0 {
+                inner.tables.remove(&table_id);
+            }
+        }
+    }
+
+    /// Whether the table has reached its cap and its blocks should not be admitted anymore.
+    fn exceeds_capacity(&self, table_id: u32) -> bool {
+        self.per_table_capacity != 0
+            && self
+                .inner
+                .lock()
+                .tables
+                .get(&table_id)
+                .map_or(false, |usage| *usage >= self.per_table_capacity)
+    }
+
+    /// The total cached bytes of every table with at least one cached block.
+    pub fn usage_per_table(&self) -> Vec<(u32, u64)> {
+        self.inner
+            .lock()
+            .tables
+            .iter()
+            .map(|(table_id, usage)| (*table_id, *usage as u64))
+            .collect()
+    }
+}
+
 enum BlockEntry {
     Cache(CacheableEntry<(HummockSstableId, u64), Box<Block>>),
     Owned(Box<Block>),
@@ -88,25 +272,40 @@ type BlockCacheEventListener =
 #[derive(Clone)]
 pub struct BlockCache {
     inner: Arc<LruCache<(HummockSstableId, u64), Box<Block>>>,
+    /// `Some` under [`BlockCachePolicy::TinyLfu`], `None` under [`BlockCachePolicy::Lru`].
+    sketch: Option<Arc<FrequencySketch>>,
+    /// Per-table usage accounting. `None` when the cache has no event listener, e.g. for the
+    /// compactor, because the usage cannot be decremented without the release events.
+    tracker: Option<Arc<BlockCacheUsageTracker>>,
 }
 
 impl BlockCache {
-    pub fn new(capacity: usize, max_shard_bits: usize) -> Self {
-        Self::new_inner(capacity, max_shard_bits, None)
+    pub fn new(capacity: usize, max_shard_bits: usize, policy: BlockCachePolicy) -> Self {
+        Self::new_inner(capacity, max_shard_bits, policy, None, None)
     }
 
     pub fn with_event_listener(
         capacity: usize,
         max_shard_bits: usize,
+        policy: BlockCachePolicy,
         listener: BlockCacheEventListener,
+        tracker: Arc<BlockCacheUsageTracker>,
     ) -> Self {
-        Self::new_inner(capacity, max_shard_bits, Some(listener))
+        Self::new_inner(
+            capacity,
+            max_shard_bits,
+            policy,
+            Some(listener),
+            Some(tracker),
+        )
     }
 
     fn new_inner(
         capacity: usize,
         mut max_shard_bits: usize,
+        policy: BlockCachePolicy,
         listener: Option<BlockCacheEventListener>,
+        tracker: Option<Arc<BlockCacheUsageTracker>>,
     ) -> Self {
         if capacity == 0 {
             panic!("block cache capacity == 0");
@@ -119,36 +318,73 @@ impl BlockCache {
             Some(listener) => LruCache::with_event_listener(max_shard_bits, capacity, listener),
             None => LruCache::new(max_shard_bits, capacity),
         };
+        let sketch = match policy {
+            BlockCachePolicy::Lru => None,
+            BlockCachePolicy::TinyLfu => Some(Arc::new(FrequencySketch::with_capacity(
+                capacity / SKETCH_ENTRY_SIZE,
+            ))),
+        };
 
         Self {
             inner: Arc::new(cache),
+            sketch,
+            tracker,
         }
     }
 
     pub fn get(&self, sst_id: HummockSstableId, block_idx: u64) -> Option<BlockHolder> {
+        let h = Self::hash(sst_id, block_idx);
+        if let Some(sketch) = &self.sketch {
+            sketch.record(h);
+        }
         self.inner
-            .lookup(Self::hash(sst_id, block_idx), &(sst_id, block_idx))
+            .lookup(h, &(sst_id, block_idx))
             .map(BlockHolder::from_cached_block)
     }
 
+    /// Inserts a block into the cache. `high_priority` bypasses the admission heuristics (the
+    /// TinyLFU admission filter and the per-table cap) so the block is always cached.
     pub fn insert(
         &self,
         sst_id: HummockSstableId,
         block_idx: u64,
+        table_id: u32,
         block: Box<Block>,
+        high_priority: bool,
     ) -> BlockHolder {
-        BlockHolder::from_cached_block(self.inner.insert(
-            (sst_id, block_idx),
-            Self::hash(sst_id, block_idx),
-            block.capacity(),
-            block,
-        ))
+        let h = Self::hash(sst_id, block_idx);
+        if let Some(sketch) = &self.sketch {
+            sketch.record(h);
+            if !high_priority && sketch.estimate(h) < TINY_LFU_ADMIT_THRESHOLD {
+                // Not popular enough to be admitted yet: serve the block without caching it.
+                return BlockHolder::from_owned_block(block);
+            }
+        }
+        if !high_priority && let Some(tracker) = &self.tracker && tracker.exceeds_capacity(table_id)
+        {
+            // The table has reached its cap: serve the block without caching it, so that one
+            // table cannot evict everyone else's blocks.
+            return BlockHolder::from_owned_block(block);
+        }
+        let charge = block.capacity();
+        let entry = self.inner.insert((sst_id, block_idx), h, charge, block);
+        // Account after the insertion, so that the release of a replaced entry is accounted
+        // first.
+        if let Some(tracker) = &self.tracker {
+            tracker.on_insert((sst_id, block_idx), table_id, charge);
+        }
+        BlockHolder::from_cached_block(entry)
     }
 
+    /// Looks up a block and fetches and caches it on miss. `high_priority` bypasses the admission
+    /// heuristics (the TinyLFU admission filter and the per-table cap) so the block is always
+    /// cached.
     pub async fn get_or_insert_with<F, Fut>(
         &self,
         sst_id: HummockSstableId,
         block_idx: u64,
+        table_id: u32,
+        high_priority: bool,
         mut fetch_block: F,
     ) -> HummockResult<BlockHolder>
     where
@@ -157,6 +393,28 @@ impl BlockCache {
     {
         let h = Self::hash(sst_id, block_idx);
         let key = (sst_id, block_idx);
+        if let Some(sketch) = &self.sketch {
+            sketch.record(h);
+            if let Some(entry) = self.inner.lookup(h, &key) {
+                return Ok(BlockHolder::from_cached_block(entry));
+            }
+            if !high_priority && sketch.estimate(h) < TINY_LFU_ADMIT_THRESHOLD {
+                // Not popular enough to be admitted yet: fetch the block without caching it, so
+                // that one-off accesses do not evict hot blocks.
+                let block = fetch_block().await?;
+                return Ok(BlockHolder::from_owned_block(block));
+            }
+        }
+        if !high_priority && let Some(tracker) = &self.tracker && tracker.exceeds_capacity(table_id)
+        {
+            if let Some(entry) = self.inner.lookup(h, &key) {
+                return Ok(BlockHolder::from_cached_block(entry));
+            }
+            // The table has reached its cap: fetch the block without caching it, so that one
+            // table cannot evict everyone else's blocks.
+            let block = fetch_block().await?;
+            return Ok(BlockHolder::from_owned_block(block));
+        }
         let block = self
             .inner
             .lookup_with_request_dedup::<_, HummockError, _>(h, key, || {
@@ -169,9 +427,20 @@ impl BlockCache {
             })
             .verbose_stack_trace("block_cache_lookup")
             .await?;
+        if let Some(tracker) = &self.tracker {
+            tracker.on_insert(key, table_id, block.value().capacity());
+        }
         Ok(BlockHolder::from_cached_block(block))
     }
 
+    /// The total cached bytes of every table, or empty when usage is not tracked.
+    pub fn usage_per_table(&self) -> Vec<(u32, u64)> {
+        self.tracker
+            .as_ref()
+            .map(|tracker| tracker.usage_per_table())
+            .unwrap_or_default()
+    }
+
     fn hash(sst_id: HummockSstableId, block_idx: u64) -> u64 {
         let mut hasher = DefaultHasher::default();
         sst_id.hash(&mut hasher);
@@ -189,3 +458,54 @@ impl BlockCache {
         self.inner.clear();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{BlockCacheUsageTracker, FrequencySketch};
+
+    #[test]
+    fn test_block_cache_usage_tracker() {
+        let tracker = BlockCacheUsageTracker::new(100);
+        tracker.on_insert((1, 0), 1, 60);
+        tracker.on_insert((1, 1), 1, 60);
+        tracker.on_insert((2, 0), 2, 10);
+        assert!(tracker.exceeds_capacity(1));
+        assert!(!tracker.exceeds_capacity(2));
+        // Re-inserting a block, e.g. by a deduplicated concurrent fetch, does not double count.
+        tracker.on_insert((1, 0), 1, 60);
+        assert_eq!(
+            {
+                let mut usage = tracker.usage_per_table();
+                usage.sort();
+                usage
+            },
+            vec![(1, 120), (2, 10)]
+        );
+        tracker.on_release((1, 0));
+        assert!(!tracker.exceeds_capacity(1));
+        // Tables without cached blocks disappear from the report.
+        tracker.on_release((2, 0));
+        assert_eq!(tracker.usage_per_table(), vec![(1, 60)]);
+        // A cap of 0 disables the limit.
+        let unlimited = BlockCacheUsageTracker::new(0);
+        unlimited.on_insert((1, 0), 1, usize::MAX);
+        assert!(!unlimited.exceeds_capacity(1));
+    }
+
+    #[test]
+    fn test_frequency_sketch() {
+        let sketch = FrequencySketch::with_capacity(256);
+        let hash = 0x1234_5678_9abc_def0_u64;
+        assert_eq!(sketch.estimate(hash), 0);
+        sketch.record(hash);
+        assert_eq!(sketch.estimate(hash), 1);
+        // Counters saturate at 15.
+        for _ in 0..100 {
+            sketch.record(hash);
+        }
+        assert_eq!(sketch.estimate(hash), 15);
+        // Aging halves the counters.
+        sketch.age();
+        assert_eq!(sketch.estimate(hash), 7);
+    }
+}
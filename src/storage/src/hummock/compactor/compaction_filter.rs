@@ -81,8 +81,8 @@ impl CompactionFilter for TtlCompactionFilter {
         match self.table_id_to_ttl.get(&table_id) {
             Some(ttl_second_u32) => {
                 assert!(*ttl_second_u32 != TABLE_OPTION_DUMMY_RETENTION_SECOND);
-                // default to zero.
-                let ttl_mill = (*ttl_second_u32 * 1000) as u64;
+                // Convert in `u64` to avoid overflowing for large retention settings.
+                let ttl_mill = *ttl_second_u32 as u64 * 1000;
                 let min_epoch = Epoch(self.expire_epoch).subtract_ms(ttl_mill);
                 self.last_table_and_ttl = Some((table_id, ttl_mill));
                 Epoch(epoch) <= min_epoch
@@ -35,7 +35,7 @@ use crate::hummock::compactor::{
 use crate::hummock::multi_builder::TableBuilderFactory;
 use crate::hummock::sstable::DEFAULT_ENTRY_SIZE;
 use crate::hummock::{
-    CachePolicy, FilterBuilder, HummockResult, MemoryLimiter, SstableBuilder,
+    BlockEncryption, CachePolicy, FilterBuilder, HummockResult, MemoryLimiter, SstableBuilder,
     SstableBuilderOptions, SstableIdManagerRef, SstableWriterFactory, SstableWriterOptions,
 };
 use crate::monitor::StoreLocalStatistic;
@@ -48,6 +48,7 @@ pub struct RemoteBuilderFactory<W: SstableWriterFactory, F: FilterBuilder> {
     pub remote_rpc_cost: Arc<AtomicU64>,
     pub filter_key_extractor: Arc<FilterKeyExtractorImpl>,
     pub sstable_writer_factory: W,
+    pub encryption: Option<Arc<BlockEncryption>>,
     pub _phantom: PhantomData<F>,
 }
 
@@ -70,6 +71,7 @@ impl<W: SstableWriterFactory, F: FilterBuilder> TableBuilderFactory for RemoteBu
             capacity_hint: Some(self.options.capacity + self.options.block_capacity),
             tracker: Some(tracker),
             policy: self.policy,
+            encryption: self.encryption.clone(),
         };
         let writer = self
             .sstable_writer_factory
@@ -23,6 +23,8 @@ pub(super) mod task_progress;
 
 use std::collections::{HashMap, HashSet};
 use std::marker::PhantomData;
+use std::ops::Bound;
+use std::path::Path;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
@@ -37,14 +39,18 @@ use futures::future::try_join_all;
 use futures::{stream, StreamExt};
 pub use iterator::ConcatSstableIterator;
 use itertools::Itertools;
+use rand::Rng;
 use risingwave_hummock_sdk::compact::compact_task_to_string;
 use risingwave_hummock_sdk::filter_key_extractor::FilterKeyExtractorImpl;
 use risingwave_hummock_sdk::key::FullKey;
+use risingwave_hummock_sdk::key_range::KeyRange;
 use risingwave_hummock_sdk::table_stats::{add_table_stats_map, TableStats, TableStatsMap};
-use risingwave_hummock_sdk::LocalSstableInfo;
+use risingwave_hummock_sdk::{can_concat, HummockEpoch, LocalSstableInfo};
 use risingwave_pb::hummock::compact_task::TaskStatus;
 use risingwave_pb::hummock::subscribe_compact_tasks_response::Task;
-use risingwave_pb::hummock::{CompactTask, CompactTaskProgress, SubscribeCompactTasksResponse};
+use risingwave_pb::hummock::{
+    CompactTask, CompactTaskProgress, LevelType, SstableInfo, SubscribeCompactTasksResponse,
+};
 use risingwave_rpc_client::HummockMetaClient;
 pub use shared_buffer_compact::compact;
 use tokio::sync::oneshot::{Receiver, Sender};
@@ -59,12 +65,15 @@ use crate::hummock::compactor::compaction_utils::{
 };
 use crate::hummock::compactor::compactor_runner::CompactorRunner;
 use crate::hummock::compactor::task_progress::TaskProgressGuard;
-use crate::hummock::iterator::{Forward, HummockIterator};
+use crate::hummock::iterator::{
+    Forward, ForwardMergeRangeIterator, HummockIterator, UnorderedMergeIteratorInner, UserIterator,
+};
 use crate::hummock::multi_builder::{SplitTableOutput, TableBuilderFactory};
 use crate::hummock::vacuum::Vacuum;
 use crate::hummock::{
     validate_ssts, BatchSstableWriterFactory, DeleteRangeAggregator, HummockError,
-    RangeTombstonesCollector, SstableWriterFactory, StreamingSstableWriterFactory,
+    RangeTombstonesCollector, SpillSstableWriterFactory, SstableDeleteRangeIterator,
+    SstableWriterFactory, StreamingSstableWriterFactory,
 };
 use crate::monitor::{CompactorMetrics, StoreLocalStatistic};
 
@@ -251,6 +260,42 @@ impl Compactor {
             output_ssts.sort_by_key(|(split_index, ..)| *split_index);
         }
 
+        // Optionally verify that the output is equivalent to the input from a user's point of
+        // view, before the task is reported as successful and the input SSTs become eligible for
+        // vacuum.
+        let verification_ratio = context.storage_opts.compaction_result_verification_ratio;
+        if task_status == TaskStatus::Success
+            && verification_ratio > 0.0
+            && rand::thread_rng().gen::<f64>() < verification_ratio
+        {
+            let sorted_output_ssts = output_ssts
+                .iter()
+                .flat_map(|(_, ssts, _)| ssts.iter().map(|sst| sst.sst_info.clone()))
+                .collect_vec();
+            match Compactor::check_compaction_result(&compact_task, sorted_output_ssts, &context)
+                .await
+            {
+                Ok(true) => (),
+                Ok(false) => {
+                    task_status = TaskStatus::ExecuteFailed;
+                    tracing::error!(
+                        "Compaction task {} failed verification: output is not equivalent to input:\n{}",
+                        compact_task.task_id,
+                        compact_task_to_string(&compact_task)
+                    );
+                }
+                Err(e) => {
+                    // The verification pass itself failed, e.g. due to an IO error. Keep the task
+                    // result as-is since nothing indicates it is wrong.
+                    tracing::warn!(
+                        "Failed to verify compaction task {}: {:#?}",
+                        compact_task.task_id,
+                        e
+                    );
+                }
+            }
+        }
+
         sync_point::sync_point!("BEFORE_COMPACT_REPORT");
         // After a compaction is done, mutate the compaction task.
         Self::compact_done(&mut compact_task, context.clone(), output_ssts, task_status).await;
@@ -324,6 +369,120 @@ impl Compactor {
         }
     }
 
+    /// Checks whether the KV-pairs visible to users are identical before and after the compaction,
+    /// by merging the input SSTs and the output SSTs of `compact_task` respectively and comparing
+    /// them at the maximum read epoch. Returns `Ok(false)` on a mismatch.
+    ///
+    /// This is a safety net against compactor bugs. It roughly doubles the read cost of a task, so
+    /// it's sampled by `compaction_result_verification_ratio`.
+    async fn check_compaction_result(
+        compact_task: &CompactTask,
+        sorted_output_ssts: Vec<SstableInfo>,
+        context: &Arc<CompactorContext>,
+    ) -> HummockResult<bool> {
+        let mut local_stats = StoreLocalStatistic::default();
+
+        let mut input_iters = Vec::new();
+        let mut input_del_iter = ForwardMergeRangeIterator::default();
+        for level in &compact_task.input_ssts {
+            if level.table_infos.is_empty() {
+                continue;
+            }
+            for table_info in &level.table_infos {
+                let table = context
+                    .sstable_store
+                    .sstable(table_info, &mut local_stats)
+                    .await?;
+                input_del_iter.add_sst_iter(SstableDeleteRangeIterator::new(table));
+            }
+            if level.level_type == LevelType::Nonoverlapping as i32 {
+                debug_assert!(can_concat(&level.table_infos));
+                input_iters.push(ConcatSstableIterator::new(
+                    level.table_infos.clone(),
+                    KeyRange::inf(),
+                    context.sstable_store.clone(),
+                ));
+            } else {
+                for table_info in &level.table_infos {
+                    input_iters.push(ConcatSstableIterator::new(
+                        vec![table_info.clone()],
+                        KeyRange::inf(),
+                        context.sstable_store.clone(),
+                    ));
+                }
+            }
+        }
+        let mut input_iter = UserIterator::new(
+            UnorderedMergeIteratorInner::for_compactor(input_iters),
+            (Bound::Unbounded, Bound::Unbounded),
+            HummockEpoch::MAX,
+            0,
+            None,
+            DeleteRangeAggregator::new(input_del_iter, HummockEpoch::MAX),
+        );
+
+        let mut output_del_iter = ForwardMergeRangeIterator::default();
+        for table_info in &sorted_output_ssts {
+            let table = context
+                .sstable_store
+                .sstable(table_info, &mut local_stats)
+                .await?;
+            output_del_iter.add_sst_iter(SstableDeleteRangeIterator::new(table));
+        }
+        debug_assert!(can_concat(&sorted_output_ssts));
+        let mut output_iter = UserIterator::new(
+            ConcatSstableIterator::new(
+                sorted_output_ssts,
+                KeyRange::inf(),
+                context.sstable_store.clone(),
+            ),
+            (Bound::Unbounded, Bound::Unbounded),
+            HummockEpoch::MAX,
+            0,
+            None,
+            DeleteRangeAggregator::new(output_del_iter, HummockEpoch::MAX),
+        );
+
+        input_iter.rewind().await?;
+        output_iter.rewind().await?;
+        let mut equivalent = true;
+        while input_iter.is_valid() && output_iter.is_valid() {
+            if input_iter.key() != output_iter.key() {
+                tracing::error!(
+                    "Compaction task {} altered a user key: input {:?} vs. output {:?}",
+                    compact_task.task_id,
+                    input_iter.key(),
+                    output_iter.key()
+                );
+                equivalent = false;
+                break;
+            }
+            if input_iter.value() != output_iter.value() {
+                tracing::error!(
+                    "Compaction task {} altered the value of key {:?}",
+                    compact_task.task_id,
+                    input_iter.key()
+                );
+                equivalent = false;
+                break;
+            }
+            input_iter.next().await?;
+            output_iter.next().await?;
+        }
+        if equivalent && (input_iter.is_valid() || output_iter.is_valid()) {
+            tracing::error!(
+                "Compaction task {} dropped or added user keys: input and output ended at \
+                 different keys",
+                compact_task.task_id
+            );
+            equivalent = false;
+        }
+        input_iter.collect_local_statistic(&mut local_stats);
+        output_iter.collect_local_statistic(&mut local_stats);
+        local_stats.ignore();
+        Ok(equivalent)
+    }
+
     /// The background compaction thread that receives compaction tasks from hummock compaction
     /// manager and runs compaction tasks.
     #[cfg_attr(coverage, no_coverage)]
@@ -648,7 +807,25 @@ impl Compactor {
                 .start_timer()
         };
 
-        let (split_table_outputs, table_stats_map) = if self.options.capacity as u64
+        // A shared buffer flush may spill to the local disk first, so that barriers are bounded
+        // by the local disk bandwidth instead of the object store throughput. The resulting SSTs
+        // are uploaded in the background by the spill writer.
+        let spill_dir = (self.context.is_share_buffer_compact
+            && self.context.storage_opts.shared_buffer_spill_enabled
+            && !self.context.storage_opts.file_cache_dir.is_empty())
+        .then(|| Path::new(&self.context.storage_opts.file_cache_dir).join("spill"));
+
+        let (split_table_outputs, table_stats_map) = if let Some(spill_dir) = spill_dir {
+            self.compact_key_range_impl(
+                SpillSstableWriterFactory::new(self.context.sstable_store.clone(), spill_dir)?,
+                iter,
+                compaction_filter,
+                del_agg,
+                filter_key_extractor,
+                task_progress.clone(),
+            )
+            .await?
+        } else if self.options.capacity as u64
             > self.context.storage_opts.min_sst_size_for_streaming_upload
         {
             self.compact_key_range_impl(
@@ -739,6 +916,7 @@ impl Compactor {
             remote_rpc_cost: self.get_id_time.clone(),
             filter_key_extractor,
             sstable_writer_factory: writer_factory,
+            encryption: self.context.sstable_store.write_encryption()?,
             _phantom: PhantomData,
         };
 
@@ -26,8 +26,19 @@ enum HummockErrorInner {
     InvalidFormatVersion(u32),
     #[error("Checksum mismatch: expected {expected}, found: {found}.")]
     ChecksumMismatch { expected: u64, found: u64 },
+    #[error(
+        "Checksum mismatch in SST {sst_id} at block offset {offset}: expected {expected}, found: {found}."
+    )]
+    BlockChecksumMismatch {
+        sst_id: u64,
+        offset: u32,
+        expected: u64,
+        found: u64,
+    },
     #[error("Invalid block.")]
     InvalidBlock,
+    #[error("Data corruption: {0}.")]
+    Corruption(String),
     #[error("Encode error {0}.")]
     EncodeError(String),
     #[error("Decode error {0}.")]
@@ -61,6 +72,8 @@ enum HummockErrorInner {
     SstableUploadError(String),
     #[error("Read backup error {0}.")]
     ReadBackupError(String),
+    #[error("Read-only replica error {0}.")]
+    ReadOnly(String),
     #[error("Other error {0}.")]
     Other(String),
 }
@@ -73,6 +86,22 @@ pub struct HummockError {
     backtrace: Backtrace,
 }
 
+/// A coarse-grained classification of [`HummockError`], mainly used to decide whether an
+/// operation can be transparently retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HummockErrorCategory {
+    /// Errors from the underlying object store or meta service that are likely to succeed on
+    /// retry, e.g. a network hiccup.
+    TransientIo,
+    /// Persisted data that fails integrity or format checks. Retrying will read the same bytes
+    /// and fail again.
+    CorruptedData,
+    /// Reads against an epoch that has been vacuumed or is not yet readable.
+    InvalidEpoch,
+    /// Everything else, including logic errors. Not retryable.
+    Internal,
+}
+
 impl HummockError {
     pub fn object_io_error(error: ObjectError) -> HummockError {
         HummockErrorInner::ObjectIoError(error.into()).into()
@@ -102,6 +131,28 @@ impl HummockError {
         HummockErrorInner::ChecksumMismatch { expected, found }.into()
     }
 
+    /// Attaches the SST id and block offset to a checksum mismatch raised while decoding a
+    /// block, so that the corrupted object can be located in the object store. Other errors are
+    /// returned unchanged.
+    pub fn with_block_location(self, sst_id: u64, offset: u32) -> HummockError {
+        match self.inner {
+            HummockErrorInner::ChecksumMismatch { expected, found } => {
+                HummockErrorInner::BlockChecksumMismatch {
+                    sst_id,
+                    offset,
+                    expected,
+                    found,
+                }
+                .into()
+            }
+            _ => self,
+        }
+    }
+
+    pub fn corruption(error: impl ToString) -> HummockError {
+        HummockErrorInner::Corruption(error.to_string()).into()
+    }
+
     pub fn meta_error(error: impl ToString) -> HummockError {
         HummockErrorInner::MetaError(error.to_string()).into()
     }
@@ -154,9 +205,49 @@ impl HummockError {
         HummockErrorInner::ReadBackupError(error.to_string()).into()
     }
 
+    pub fn read_only(error: impl ToString) -> HummockError {
+        HummockErrorInner::ReadOnly(error.to_string()).into()
+    }
+
     pub fn other(error: impl ToString) -> HummockError {
         HummockErrorInner::Other(error.to_string()).into()
     }
+
+    /// Classifies the error into a [`HummockErrorCategory`].
+    pub fn category(&self) -> HummockErrorCategory {
+        match &self.inner {
+            HummockErrorInner::ObjectIoError(_)
+            | HummockErrorInner::MetaError(_)
+            | HummockErrorInner::TieredCache(_)
+            | HummockErrorInner::SstableUploadError(_) => HummockErrorCategory::TransientIo,
+            HummockErrorInner::MagicMismatch { .. }
+            | HummockErrorInner::InvalidFormatVersion(_)
+            | HummockErrorInner::ChecksumMismatch { .. }
+            | HummockErrorInner::BlockChecksumMismatch { .. }
+            | HummockErrorInner::InvalidBlock
+            | HummockErrorInner::Corruption(_)
+            | HummockErrorInner::DecodeError(_) => HummockErrorCategory::CorruptedData,
+            HummockErrorInner::WaitEpoch(_)
+            | HummockErrorInner::ReadCurrentEpoch(_)
+            | HummockErrorInner::ExpiredEpoch { .. } => HummockErrorCategory::InvalidEpoch,
+            HummockErrorInner::EncodeError(_)
+            | HummockErrorInner::MockError(_)
+            | HummockErrorInner::InvalidWriteBatch
+            | HummockErrorInner::SharedBufferError(_)
+            | HummockErrorInner::CompactionExecutor(_)
+            | HummockErrorInner::SstIdTrackerError(_)
+            | HummockErrorInner::CompactionGroupError(_)
+            | HummockErrorInner::ReadBackupError(_)
+            | HummockErrorInner::ReadOnly(_)
+            | HummockErrorInner::Other(_) => HummockErrorCategory::Internal,
+        }
+    }
+
+    /// Whether the failed operation may succeed if simply retried, i.e. the error is caused by
+    /// transient IO instead of corrupted data or a logic error.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self.category(), HummockErrorCategory::TransientIo)
+    }
 }
 
 impl From<prost::DecodeError> for HummockError {
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::ops::DerefMut;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
@@ -21,12 +21,13 @@ use arc_swap::ArcSwap;
 use async_stack_trace::StackTrace;
 use futures::future::{select, Either};
 use futures::FutureExt;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
+use risingwave_common::catalog::TableId;
 use risingwave_hummock_sdk::compaction_group::hummock_version_ext::HummockVersionUpdateExt;
 use risingwave_hummock_sdk::{info_in_release, HummockEpoch, LocalSstableInfo};
 use risingwave_pb::hummock::version_update_payload::Payload;
 use tokio::spawn;
-use tokio::sync::{mpsc, oneshot};
+use tokio::sync::{mpsc, oneshot, OwnedSemaphorePermit, Semaphore};
 use tracing::{error, info};
 
 use super::{LocalInstanceGuard, LocalInstanceId, ReadVersionMappingType};
@@ -109,11 +110,72 @@ pub struct HummockEventHandler {
     sstable_id_manager: SstableIdManagerRef,
 }
 
+/// Limits the number of upload tasks that may run concurrently, both globally and per table, so
+/// that uploading many tables in parallel does not overwhelm the object store while a few large
+/// tables cannot starve the others either.
+struct UploadConcurrencyLimiter {
+    global: Arc<Semaphore>,
+    per_table_limit: usize,
+    table_semaphores: Mutex<HashMap<TableId, Arc<Semaphore>>>,
+}
+
+impl UploadConcurrencyLimiter {
+    fn new(opts: &StorageOpts) -> Self {
+        Self {
+            global: Arc::new(Semaphore::new(opts.share_buffer_upload_concurrency)),
+            per_table_limit: opts.share_buffer_upload_per_table_concurrency,
+            table_semaphores: Mutex::new(HashMap::default()),
+        }
+    }
+
+    /// Acquires a global permit and then one permit for each table in `table_ids`. The caller must
+    /// pass the table ids sorted and deduplicated so that concurrent tasks acquire the per-table
+    /// permits in the same order and never deadlock.
+    async fn acquire(&self, table_ids: Vec<TableId>) -> Vec<OwnedSemaphorePermit> {
+        let mut permits = Vec::with_capacity(table_ids.len() + 1);
+        permits.push(
+            self.global
+                .clone()
+                .acquire_owned()
+                .await
+                .expect("the semaphore is never closed"),
+        );
+        if self.per_table_limit == 0 {
+            return permits;
+        }
+        for table_id in table_ids {
+            let semaphore = self
+                .table_semaphores
+                .lock()
+                .entry(table_id)
+                .or_insert_with(|| Arc::new(Semaphore::new(self.per_table_limit)))
+                .clone();
+            permits.push(
+                semaphore
+                    .acquire_owned()
+                    .await
+                    .expect("the semaphore is never closed"),
+            );
+        }
+        permits
+    }
+}
+
 async fn flush_imms(
     payload: UploadTaskPayload,
     task_info: UploadTaskInfo,
     compactor_context: Arc<crate::hummock::compactor::CompactorContext>,
+    upload_limiter: Arc<UploadConcurrencyLimiter>,
 ) -> HummockResult<Vec<LocalSstableInfo>> {
+    // Bound the global and per-table upload concurrency. Tasks of different tables proceed in
+    // parallel up to the limits while the permits are held until the upload finishes.
+    let table_ids: Vec<TableId> = payload
+        .iter()
+        .map(|imm| imm.table_id)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let _permits = upload_limiter.acquire(table_ids).await;
     for epoch in &task_info.epochs {
         let _ = compactor_context
             .sstable_id_manager
@@ -143,12 +205,22 @@ impl HummockEventHandler {
         let write_conflict_detector =
             ConflictDetector::new_from_config(&compactor_context.storage_opts);
         let sstable_id_manager = compactor_context.sstable_id_manager.clone();
+        let upload_task_target_size = compactor_context.storage_opts.share_buffer_upload_task_size;
+        let upload_limiter = Arc::new(UploadConcurrencyLimiter::new(
+            &compactor_context.storage_opts,
+        ));
         let uploader = HummockUploader::new(
             pinned_version.clone(),
             Arc::new(move |payload, task_info| {
-                spawn(flush_imms(payload, task_info, compactor_context.clone()))
+                spawn(flush_imms(
+                    payload,
+                    task_info,
+                    compactor_context.clone(),
+                    upload_limiter.clone(),
+                ))
             }),
             buffer_tracker,
+            upload_task_target_size,
         );
 
         Self {
@@ -270,6 +342,19 @@ impl HummockEventHandler {
         })
     }
 
+    fn handle_ingest_external_sst(
+        &mut self,
+        epoch: HummockEpoch,
+        sstable_infos: Vec<LocalSstableInfo>,
+    ) {
+        let staging_sstable_info = StagingSstableInfo::new(sstable_infos, vec![epoch], vec![], 0);
+        self.uploader
+            .add_external_sst(epoch, staging_sstable_info.clone());
+        // Make the ingested data readable before the epoch is committed, consistent with spilled
+        // shared-buffer data.
+        self.handle_data_spilled(staging_sstable_info);
+    }
+
     fn handle_await_sync_epoch(
         &mut self,
         new_sync_epoch: HummockEpoch,
@@ -468,6 +553,13 @@ impl HummockEventHandler {
                             self.uploader.may_flush();
                         }
 
+                        HummockEvent::IngestExternalSst {
+                            epoch,
+                            sstable_infos,
+                        } => {
+                            self.handle_ingest_external_sst(epoch, sstable_infos);
+                        }
+
                         HummockEvent::SealEpoch {
                             epoch,
                             is_checkpoint,
@@ -17,7 +17,7 @@ use std::sync::Arc;
 
 use parking_lot::RwLock;
 use risingwave_common::catalog::TableId;
-use risingwave_hummock_sdk::HummockEpoch;
+use risingwave_hummock_sdk::{HummockEpoch, LocalSstableInfo};
 use risingwave_pb::hummock::version_update_payload;
 use tokio::sync::{mpsc, oneshot};
 
@@ -61,6 +61,13 @@ pub enum HummockEvent {
 
     ImmToUploader(ImmutableMemtable),
 
+    /// Register pre-built SST files carrying the data of an in-flight epoch directly, bypassing
+    /// the shared buffer.
+    IngestExternalSst {
+        epoch: HummockEpoch,
+        sstable_infos: Vec<LocalSstableInfo>,
+    },
+
     SealEpoch {
         epoch: HummockEpoch,
         is_checkpoint: bool,
@@ -103,6 +110,15 @@ impl HummockEvent {
 
             HummockEvent::ImmToUploader(imm) => format!("ImmToUploader {:?}", imm),
 
+            HummockEvent::IngestExternalSst {
+                epoch,
+                sstable_infos,
+            } => format!(
+                "IngestExternalSst epoch {} sst count {}",
+                epoch,
+                sstable_infos.len()
+            ),
+
             HummockEvent::SealEpoch {
                 epoch,
                 is_checkpoint,
@@ -91,6 +91,35 @@ impl Debug for UploadingTask {
 }
 
 impl UploadingTask {
+    /// Partition `payload` by table and pack the per-table groups into upload tasks of roughly
+    /// the target task size, so that a flush covering many tables is uploaded as several
+    /// concurrent tasks instead of one task whose duration scales with the table count. The
+    /// data of a single table is never split across tasks, to keep the epoch order of each key
+    /// within one task.
+    fn new_batched(payload: UploadTaskPayload, context: &UploaderContext) -> Vec<Self> {
+        let mut grouped: BTreeMap<TableId, UploadTaskPayload> = BTreeMap::new();
+        for imm in payload {
+            grouped.entry(imm.table_id).or_default().push(imm);
+        }
+
+        let mut tasks = Vec::new();
+        let mut batch = Vec::new();
+        let mut batch_size = 0;
+        for (_table_id, imms) in grouped {
+            let group_size: usize = imms.iter().map(|imm| imm.size()).sum();
+            if !batch.is_empty() && batch_size + group_size > context.upload_task_target_size {
+                tasks.push(Self::new(std::mem::take(&mut batch), context));
+                batch_size = 0;
+            }
+            batch.extend(imms);
+            batch_size += group_size;
+        }
+        if !batch.is_empty() {
+            tasks.push(Self::new(batch, context));
+        }
+        tasks
+    }
+
     fn new(payload: UploadTaskPayload, context: &UploaderContext) -> Self {
         assert!(!payload.is_empty());
         let mut epochs = payload
@@ -228,8 +257,9 @@ impl UnsealedEpochData {
     fn flush(&mut self, context: &UploaderContext) {
         let imms = self.imms.drain(..).collect_vec();
         if !imms.is_empty() {
-            self.spilled_data
-                .add_task(UploadingTask::new(imms, context));
+            for task in UploadingTask::new_batched(imms, context) {
+                self.spilled_data.add_task(task);
+            }
         }
     }
 }
@@ -307,8 +337,9 @@ impl SealedData {
             .collect_vec();
 
         if !payload.is_empty() {
-            self.spilled_data
-                .add_task(UploadingTask::new(payload, context));
+            for task in UploadingTask::new_batched(payload, context) {
+                self.spilled_data.add_task(task);
+            }
         }
     }
 
@@ -340,6 +371,9 @@ struct UploaderContext {
     /// When called, it will spawn a task to flush the imm into sst and return the join handle.
     spawn_upload_task: SpawnUploadTask,
     buffer_tracker: BufferTracker,
+    /// Target size of a single upload task. Data of different tables is packed into tasks of
+    /// roughly this size on flush.
+    upload_task_target_size: usize,
 }
 
 impl UploaderContext {
@@ -347,11 +381,13 @@ impl UploaderContext {
         pinned_version: PinnedVersion,
         spawn_upload_task: SpawnUploadTask,
         buffer_tracker: BufferTracker,
+        upload_task_target_size: usize,
     ) -> Self {
         UploaderContext {
             pinned_version,
             spawn_upload_task,
             buffer_tracker,
+            upload_task_target_size,
         }
     }
 }
@@ -401,6 +437,7 @@ impl HummockUploader {
         pinned_version: PinnedVersion,
         spawn_upload_task: SpawnUploadTask,
         buffer_tracker: BufferTracker,
+        upload_task_target_size: usize,
     ) -> Self {
         let initial_epoch = pinned_version.version().max_committed_epoch;
         Self {
@@ -411,7 +448,12 @@ impl HummockUploader {
             sealed_data: Default::default(),
             syncing_data: Default::default(),
             synced_data: Default::default(),
-            context: UploaderContext::new(pinned_version, spawn_upload_task, buffer_tracker),
+            context: UploaderContext::new(
+                pinned_version,
+                spawn_upload_task,
+                buffer_tracker,
+                upload_task_target_size,
+            ),
         }
     }
 
@@ -451,6 +493,28 @@ impl HummockUploader {
             .push_front(imm);
     }
 
+    /// Registers pre-built SSTs that carry the data of `epoch` without going through the shared
+    /// buffer. The SSTs are uploaded already, so they only wait to be included in the sync result
+    /// of the epoch.
+    pub(crate) fn add_external_sst(
+        &mut self,
+        epoch: HummockEpoch,
+        staging_sst: StagingSstableInfo,
+    ) {
+        assert!(
+            epoch > self.max_sealed_epoch,
+            "external sst epoch {} older than max sealed epoch {}",
+            epoch,
+            self.max_sealed_epoch
+        );
+        self.unsealed_data
+            .entry(epoch)
+            .or_default()
+            .spilled_data
+            .uploaded_data
+            .push_front(staging_sst);
+    }
+
     pub(crate) fn seal_epoch(&mut self, epoch: HummockEpoch) {
         info_in_release!("epoch {} is sealed", epoch);
         assert!(
@@ -829,6 +893,7 @@ mod tests {
             uncompressed_file_size: 0,
             min_epoch: 0,
             max_epoch: 0,
+            tombstone_key_count: 0,
         })]
     }
 
@@ -841,6 +906,7 @@ mod tests {
             initial_pinned_version(),
             Arc::new(move |payload, task_info| spawn(upload_fn(payload, task_info))),
             BufferTracker::for_test(),
+            usize::MAX,
         )
     }
 
@@ -853,6 +919,7 @@ mod tests {
             initial_pinned_version(),
             Arc::new(move |payload, task_info| spawn(upload_fn(payload, task_info))),
             BufferTracker::for_test(),
+            usize::MAX,
         )
     }
 
@@ -1140,6 +1207,7 @@ mod tests {
                 }
             }),
             buffer_tracker.clone(),
+            usize::MAX,
         );
         (buffer_tracker, uploader, new_task_notifier)
     }
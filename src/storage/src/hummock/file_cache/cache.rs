@@ -305,7 +305,10 @@ mod tests {
 
     use prometheus::Registry;
 
-    use super::super::test_utils::{datasize, key, FlushHolder, ModuloHasherBuilder, TestCacheKey};
+    #[cfg(target_os = "linux")]
+    use super::super::test_utils::datasize;
+    use super::super::test_utils::{key, FlushHolder, ModuloHasherBuilder, TestCacheKey};
+    #[cfg(target_os = "linux")]
     use super::super::utils;
     use super::*;
     use crate::hummock::file_cache::metrics::FileCacheMetrics;
@@ -401,6 +404,9 @@ mod tests {
         assert_eq!(cache.get(&key(1)).await.unwrap().as_deref(), None);
     }
 
+    /// The expected file sizes rely on `fallocate(2)` pre-allocation and hole punching, which
+    /// only Linux performs.
+    #[cfg(target_os = "linux")]
     #[tokio::test]
     async fn test_cache_grow() {
         let dir = tempdir();
@@ -13,17 +13,21 @@
 // limitations under the License.
 
 use std::fs::{File, OpenOptions};
-use std::os::unix::prelude::{AsRawFd, FileExt, OpenOptionsExt, RawFd};
+#[cfg(target_os = "linux")]
+use std::os::unix::prelude::OpenOptionsExt;
+use std::os::unix::prelude::{AsRawFd, FileExt, RawFd};
 use std::path::Path;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
+#[cfg(target_os = "linux")]
 use nix::fcntl::{fallocate, FallocateFlags};
 use nix::sys::stat::fstat;
 use nix::unistd::ftruncate;
 use tracing::Instrument;
 
 use super::error::Result;
+use super::io_engine::IoEngine;
 use super::{asyncify, utils, DioBuffer, DIO_BUFFER_ALLOCATOR, LOGICAL_BLOCK_SIZE, ST_BLOCK_SIZE};
 
 #[derive(Clone, Debug)]
@@ -31,6 +35,7 @@ pub struct CacheFileOptions {
     /// NOTE: `block_size` must be a multiple of `fs_block_size`.
     pub block_size: usize,
     pub fallocate_unit: usize,
+    pub io_engine: Arc<IoEngine>,
 }
 
 impl CacheFileOptions {
@@ -58,10 +63,53 @@ impl Drop for CacheFileCore {
     }
 }
 
+/// Pre-allocates space so that the file covers at least `end` bytes.
+///
+/// Targets without `fallocate(2)` only advance the capacity bookkeeping; the file simply grows
+/// as it is written.
+fn reserve_capacity(core: &CacheFileCore, fallocate_unit: usize, end: usize) -> Result<()> {
+    let mut capacity = core.capacity.load(Ordering::Acquire);
+    if end <= capacity {
+        return Ok(());
+    }
+    loop {
+        match core.capacity.compare_exchange_weak(
+            capacity,
+            capacity + fallocate_unit,
+            Ordering::SeqCst,
+            Ordering::Acquire,
+        ) {
+            // Pre-allocate space in this thread.
+            Ok(_) => {
+                #[cfg(target_os = "linux")]
+                fallocate(
+                    core.file.as_raw_fd(),
+                    FallocateFlags::FALLOC_FL_KEEP_SIZE,
+                    capacity as i64,
+                    fallocate_unit as i64,
+                )?;
+                break;
+            }
+            Err(c) => {
+                // The cache file has been pre-allocated by another thread, skip if
+                // pre-allocated space is enough.
+                if end > c {
+                    break;
+                } else {
+                    capacity = c;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
 #[derive(Clone)]
 pub struct CacheFile {
     fallocate_unit: usize,
 
+    io_engine: Arc<IoEngine>,
+
     core: Arc<CacheFileCore>,
 }
 
@@ -74,26 +122,29 @@ impl std::fmt::Debug for CacheFile {
 impl CacheFile {
     /// Opens the cache file.
     ///
-    /// The underlying file is opened with `O_DIRECT` flag. All I/O requests must be aligned with
-    /// the logical block size. Additionally, [`CacheFile`] requires I/O size must be a multiple of
-    /// `options.block_size` (which is required to be a multiple of the file system block size).
-    /// With this restriction, blocks can be directly reclaimed by the file system after hole
-    /// punching.
+    /// On Linux, the underlying file is opened with `O_DIRECT` flag. All I/O requests must be
+    /// aligned with the logical block size. Additionally, [`CacheFile`] requires I/O size must be
+    /// a multiple of `options.block_size` (which is required to be a multiple of the file system
+    /// block size). With this restriction, blocks can be directly reclaimed by the file system
+    /// after hole punching. Targets without `O_DIRECT` fall back to regular buffered IO.
     pub async fn open(path: impl AsRef<Path>, options: CacheFileOptions) -> Result<Self> {
         options.assert();
 
         let path = path.as_ref().to_owned();
+        let io_engine = options.io_engine.clone();
 
         let mut oopts = OpenOptions::new();
         oopts.create(true);
         oopts.read(true);
         oopts.write(true);
+        #[cfg(target_os = "linux")]
         oopts.custom_flags(libc::O_DIRECT | libc::O_NOATIME);
 
         let (file, len, capacity) = asyncify(move || {
             let file = oopts.open(path)?;
             let fd = file.as_raw_fd();
             let stat = fstat(fd)?;
+            #[cfg(target_os = "linux")]
             fallocate(
                 fd,
                 FallocateFlags::FALLOC_FL_KEEP_SIZE,
@@ -111,6 +162,8 @@ impl CacheFile {
         let cache_file = Self {
             fallocate_unit: options.fallocate_unit,
 
+            io_engine,
+
             core: Arc::new(CacheFileCore {
                 block_size: options.block_size,
 
@@ -132,43 +185,25 @@ impl CacheFile {
 
         let offset = core.len.fetch_add(buf.len(), Ordering::SeqCst);
 
+        #[cfg(target_os = "linux")]
+        if let IoEngine::Uring(uring) = self.io_engine.as_ref() {
+            // Appending the buffer may exceed the cache file allocated capacity, pre-allocate
+            // some space for the cache file.
+            let end = offset + buf.len();
+            let fallocate_core = core.clone();
+            asyncify(move || reserve_capacity(&fallocate_core, fallocate_unit, end)).await?;
+            uring
+                .write(self.fd(), offset as u64, buf, Box::new(core))
+                .await?;
+            return Ok(offset as u64);
+        }
+
         let span = tracing::trace_span!("write_all_at");
 
         asyncify(move || {
-            let mut capacity = core.capacity.load(Ordering::Acquire);
-
             // Append the buffer will exceed the cache file allocated capacity, pre-allocate some
             // space for the cache file.
-            if offset + buf.len() > capacity {
-                loop {
-                    match core.capacity.compare_exchange_weak(
-                        capacity,
-                        capacity + fallocate_unit,
-                        Ordering::SeqCst,
-                        Ordering::Acquire,
-                    ) {
-                        // Pre-allocate space in this thread.
-                        Ok(_) => {
-                            fallocate(
-                                core.file.as_raw_fd(),
-                                FallocateFlags::FALLOC_FL_KEEP_SIZE,
-                                capacity as i64,
-                                fallocate_unit as i64,
-                            )?;
-                            break;
-                        }
-                        Err(c) => {
-                            // The cache file has been pre-allocated by another thread, skip if
-                            // pre-allocated space is enough.
-                            if offset + buf.len() > c {
-                                break;
-                            } else {
-                                capacity = c;
-                            }
-                        }
-                    }
-                }
-            }
+            reserve_capacity(&core, fallocate_unit, offset + buf.len())?;
 
             span.in_scope(|| core.file.write_all_at(&buf, offset as u64))?;
 
@@ -185,6 +220,11 @@ impl CacheFile {
         utils::debug_assert_aligned(self.core.block_size, len);
         let core = self.core.clone();
 
+        #[cfg(target_os = "linux")]
+        if let IoEngine::Uring(uring) = self.io_engine.as_ref() {
+            return uring.read(self.fd(), offset, len, Box::new(core)).await;
+        }
+
         let span = tracing::trace_span!("read_exact_at", sid = tracing::field::Empty);
 
         asyncify(move || {
@@ -203,10 +243,13 @@ impl CacheFile {
         .await
     }
 
+    /// Targets without `FALLOC_FL_PUNCH_HOLE` cannot reclaim the space of individual blocks; the
+    /// space of freed blocks is only released when the file is truncated on drop.
     // TODO(MrCroxx): Should be async (likely not)?
     pub fn punch_hole(&self, offset: u64, len: usize) -> Result<()> {
         utils::debug_assert_aligned(self.core.block_size as u64, offset);
         utils::debug_assert_aligned(self.core.block_size, len);
+        #[cfg(target_os = "linux")]
         fallocate(
             self.fd(),
             FallocateFlags::FALLOC_FL_PUNCH_HOLE | FallocateFlags::FALLOC_FL_KEEP_SIZE,
@@ -284,15 +327,33 @@ mod tests {
 
     #[tokio::test]
     async fn test_file_cache() {
+        test_file_cache_with_io_engine(Arc::new(IoEngine::Psync)).await;
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn test_file_cache_io_uring() {
+        let io_engine = Arc::new(IoEngine::detect());
+        if matches!(io_engine.as_ref(), IoEngine::Psync) {
+            // The running kernel does not support io_uring.
+            return;
+        }
+        test_file_cache_with_io_engine(io_engine).await;
+    }
+
+    async fn test_file_cache_with_io_engine(io_engine: Arc<IoEngine>) {
         let tempdir = tempfile::tempdir().unwrap();
         let path = tempdir.path().join("test-cache-file");
         let options = CacheFileOptions {
             block_size: 4096,
             fallocate_unit: 4 * 4096,
+            io_engine,
         };
         let cf = CacheFile::open(&path, options.clone()).await.unwrap();
         assert_eq!(cf.block_size(), 4096);
         assert_eq!(cf.len(), 0);
+        // `size()` only reflects `fallocate(2)` pre-allocation on Linux.
+        #[cfg(target_os = "linux")]
         assert_eq!(cf.size(), 4 * 4096);
 
         let mut wbuf = DioBuffer::with_capacity_in(4096, &DIO_BUFFER_ALLOCATOR);
@@ -300,6 +361,7 @@ mod tests {
 
         cf.append(wbuf.clone()).await.unwrap();
         assert_eq!(cf.len(), 4096);
+        #[cfg(target_os = "linux")]
         assert_eq!(cf.size(), 4 * 4096);
 
         let rbuf = cf.read(0, 4096).await.unwrap();
@@ -310,6 +372,7 @@ mod tests {
         cf.append(wbuf.clone()).await.unwrap();
         cf.append(wbuf.clone()).await.unwrap();
         assert_eq!(cf.len(), 5 * 4096);
+        #[cfg(target_os = "linux")]
         assert_eq!(cf.size(), 8 * 4096);
 
         drop(cf);
@@ -317,6 +380,7 @@ mod tests {
         let cf = CacheFile::open(&path, options).await.unwrap();
         assert_eq!(cf.block_size(), 4096);
         assert_eq!(cf.len(), 5 * 4096);
+        #[cfg(target_os = "linux")]
         assert_eq!(cf.size(), 9 * 4096);
     }
 }
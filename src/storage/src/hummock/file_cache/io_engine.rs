@@ -0,0 +1,329 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! IO engines for the cache file.
+//!
+//! The default `psync` engine performs `pread(2)`/`pwrite(2)` on a blocking thread pool. On
+//! kernels that support it, the `io_uring` engine drives the same operations through a shared
+//! ring owned by a dedicated thread, which yields higher IOPS on NVMe without occupying the
+//! blocking pool. Support is probed at runtime with [`IoEngine::detect`], which transparently
+//! falls back to `psync`. `io_uring` is Linux-only, other targets always use `psync`.
+
+#[cfg(target_os = "linux")]
+use std::any::Any;
+#[cfg(target_os = "linux")]
+use std::collections::HashMap;
+#[cfg(target_os = "linux")]
+use std::os::unix::prelude::RawFd;
+
+#[cfg(target_os = "linux")]
+use crossbeam::channel::{unbounded, Receiver, Sender, TryRecvError};
+#[cfg(target_os = "linux")]
+use io_uring::{opcode, types, IoUring};
+#[cfg(target_os = "linux")]
+use tokio::sync::oneshot;
+
+#[cfg(target_os = "linux")]
+use super::error::{Error, Result};
+#[cfg(target_os = "linux")]
+use super::{DioBuffer, DIO_BUFFER_ALLOCATOR};
+
+/// The IO engine that a [`super::file::CacheFile`] performs its reads and writes with.
+pub enum IoEngine {
+    /// `pread(2)`/`pwrite(2)` on the blocking thread pool.
+    Psync,
+    /// `io_uring(7)` driven by a dedicated thread.
+    #[cfg(target_os = "linux")]
+    Uring(UringIoEngine),
+}
+
+impl IoEngine {
+    #[cfg(target_os = "linux")]
+    const URING_QUEUE_DEPTH: usize = 256;
+
+    /// Probes `io_uring` support of the running kernel and returns the `io_uring` engine if
+    /// available, falling back to the `psync` engine otherwise.
+    #[cfg(target_os = "linux")]
+    pub fn detect() -> Self {
+        match UringIoEngine::new(Self::URING_QUEUE_DEPTH) {
+            Ok(engine) => {
+                tracing::info!("file cache: using io_uring io engine");
+                Self::Uring(engine)
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "file cache: io_uring unavailable, falling back to psync io engine: {}",
+                    e
+                );
+                Self::Psync
+            }
+        }
+    }
+
+    /// There is no `io_uring` outside Linux, always use the `psync` engine.
+    #[cfg(not(target_os = "linux"))]
+    pub fn detect() -> Self {
+        Self::Psync
+    }
+}
+
+impl std::fmt::Debug for IoEngine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Psync => write!(f, "Psync"),
+            #[cfg(target_os = "linux")]
+            Self::Uring(_) => write!(f, "Uring"),
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+enum UringTask {
+    Read {
+        fd: RawFd,
+        offset: u64,
+        len: usize,
+        tx: oneshot::Sender<Result<DioBuffer>>,
+        keep_alive: Box<dyn Any + Send>,
+    },
+    Write {
+        fd: RawFd,
+        offset: u64,
+        buf: DioBuffer,
+        tx: oneshot::Sender<Result<()>>,
+        keep_alive: Box<dyn Any + Send>,
+    },
+}
+
+#[cfg(target_os = "linux")]
+enum Completion {
+    Read(oneshot::Sender<Result<DioBuffer>>),
+    Write(oneshot::Sender<Result<()>>),
+}
+
+/// An operation submitted to the kernel. The buffer must be kept until the operation completes,
+/// and `keep_alive` pins the file so that its fd cannot be closed and reused in the meantime,
+/// even if the caller is cancelled.
+#[cfg(target_os = "linux")]
+struct InFlight {
+    buf: DioBuffer,
+    completion: Completion,
+    _keep_alive: Box<dyn Any + Send>,
+}
+
+/// An `io_uring` shared by all operations of a cache file, driven by a dedicated thread.
+#[cfg(target_os = "linux")]
+pub struct UringIoEngine {
+    tx: Sender<UringTask>,
+}
+
+#[cfg(target_os = "linux")]
+impl UringIoEngine {
+    fn new(queue_depth: usize) -> Result<Self> {
+        let ring = IoUring::new(queue_depth as u32).map_err(Error::Io)?;
+        let (tx, rx) = unbounded();
+        std::thread::Builder::new()
+            .name("file-cache-io-uring".to_string())
+            .spawn(move || Self::run(ring, rx, queue_depth))
+            .map_err(Error::Io)?;
+        Ok(Self { tx })
+    }
+
+    /// Reads `len` bytes at `offset`. `keep_alive` must keep `fd` open while the operation is in
+    /// flight.
+    pub async fn read(
+        &self,
+        fd: RawFd,
+        offset: u64,
+        len: usize,
+        keep_alive: Box<dyn Any + Send>,
+    ) -> Result<DioBuffer> {
+        let (tx, rx) = oneshot::channel();
+        self.tx
+            .send(UringTask::Read {
+                fd,
+                offset,
+                len,
+                tx,
+                keep_alive,
+            })
+            .map_err(|_| Error::Other("io_uring engine thread exited".to_string()))?;
+        rx.await
+            .map_err(|_| Error::Other("io_uring engine thread exited".to_string()))?
+    }
+
+    /// Writes `buf` at `offset`. `keep_alive` must keep `fd` open while the operation is in
+    /// flight.
+    pub async fn write(
+        &self,
+        fd: RawFd,
+        offset: u64,
+        buf: DioBuffer,
+        keep_alive: Box<dyn Any + Send>,
+    ) -> Result<()> {
+        let (tx, rx) = oneshot::channel();
+        self.tx
+            .send(UringTask::Write {
+                fd,
+                offset,
+                buf,
+                tx,
+                keep_alive,
+            })
+            .map_err(|_| Error::Other("io_uring engine thread exited".to_string()))?;
+        rx.await
+            .map_err(|_| Error::Other("io_uring engine thread exited".to_string()))?
+    }
+
+    fn run(mut ring: IoUring, rx: Receiver<UringTask>, queue_depth: usize) {
+        let mut in_flight: HashMap<u64, InFlight> = HashMap::new();
+        let mut next_id: u64 = 0;
+
+        loop {
+            // Block for a task when idle; otherwise only drain what is immediately available so
+            // that completions are reaped promptly.
+            if in_flight.is_empty() {
+                match rx.recv() {
+                    Ok(task) => Self::submit(&mut ring, task, &mut in_flight, &mut next_id),
+                    // All senders are gone and nothing is in flight, shut down.
+                    Err(_) => return,
+                }
+            }
+            while in_flight.len() < queue_depth {
+                match rx.try_recv() {
+                    Ok(task) => Self::submit(&mut ring, task, &mut in_flight, &mut next_id),
+                    Err(TryRecvError::Empty) | Err(TryRecvError::Disconnected) => break,
+                }
+            }
+
+            if let Err(e) = ring.submit_and_wait(1) {
+                // The ring is broken, fail all in-flight operations.
+                for (_, entry) in in_flight.drain() {
+                    Self::complete(entry, Err(Error::Other(format!("io_uring error: {}", e))));
+                }
+                continue;
+            }
+            let cqes: Vec<(u64, i32)> = ring
+                .completion()
+                .map(|cqe| (cqe.user_data(), cqe.result()))
+                .collect();
+            for (id, res) in cqes {
+                let entry = in_flight.remove(&id).expect("unknown completion");
+                let result = if res < 0 {
+                    Err(Error::Io(std::io::Error::from_raw_os_error(-res)))
+                } else if (res as usize) < entry.buf.len() {
+                    // `O_DIRECT` IO of aligned sizes within the allocated file range must not be
+                    // short.
+                    Err(Error::Other(format!(
+                        "short io: {} < {}",
+                        res,
+                        entry.buf.len()
+                    )))
+                } else {
+                    Ok(())
+                };
+                Self::complete(entry, result);
+            }
+        }
+    }
+
+    #[allow(clippy::uninit_vec)]
+    fn submit(
+        ring: &mut IoUring,
+        task: UringTask,
+        in_flight: &mut HashMap<u64, InFlight>,
+        next_id: &mut u64,
+    ) {
+        let id = *next_id;
+        *next_id += 1;
+
+        let entry = match task {
+            UringTask::Read {
+                fd,
+                offset,
+                len,
+                tx,
+                keep_alive,
+            } => {
+                let mut buf = DioBuffer::with_capacity_in(len, &DIO_BUFFER_ALLOCATOR);
+                buf.reserve(len);
+                unsafe {
+                    buf.set_len(len);
+                }
+                let sqe = opcode::Read::new(types::Fd(fd), buf.as_mut_ptr(), len as u32)
+                    .offset(offset as i64)
+                    .build()
+                    .user_data(id);
+                let entry = InFlight {
+                    buf,
+                    completion: Completion::Read(tx),
+                    _keep_alive: keep_alive,
+                };
+                // Safety: the buffer is kept in `in_flight` until the operation completes.
+                if unsafe { ring.submission().push(&sqe) }.is_err() {
+                    Self::complete(entry, Err(Error::Other("io_uring queue full".to_string())));
+                    return;
+                }
+                entry
+            }
+            UringTask::Write {
+                fd,
+                offset,
+                buf,
+                tx,
+                keep_alive,
+            } => {
+                let sqe = opcode::Write::new(types::Fd(fd), buf.as_ptr(), buf.len() as u32)
+                    .offset(offset as i64)
+                    .build()
+                    .user_data(id);
+                let entry = InFlight {
+                    buf,
+                    completion: Completion::Write(tx),
+                    _keep_alive: keep_alive,
+                };
+                // Safety: the buffer is kept in `in_flight` until the operation completes.
+                if unsafe { ring.submission().push(&sqe) }.is_err() {
+                    Self::complete(entry, Err(Error::Other("io_uring queue full".to_string())));
+                    return;
+                }
+                entry
+            }
+        };
+        in_flight.insert(id, entry);
+    }
+
+    fn complete(entry: InFlight, result: Result<()>) {
+        match entry.completion {
+            Completion::Read(tx) => {
+                let _ = tx.send(result.map(|_| entry.buf));
+            }
+            Completion::Write(tx) => {
+                let _ = tx.send(result);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_send_sync<T: Send + Sync + 'static>() {}
+
+    #[test]
+    fn ensure_send_sync() {
+        is_send_sync::<IoEngine>();
+    }
+}
@@ -21,14 +21,17 @@ use std::path::Path;
 
 use bytes::{Buf, BufMut};
 use libc::c_void;
+#[cfg(target_os = "linux")]
 use nix::fcntl::{fallocate, FallocateFlags};
-use nix::sys::mman::{
-    madvise, mmap, mremap, msync, munmap, MRemapFlags, MapFlags, MmapAdvise, MsFlags, ProtFlags,
-};
+#[cfg(target_os = "linux")]
+use nix::sys::mman::{mremap, MRemapFlags};
+use nix::sys::mman::{madvise, mmap, msync, munmap, MapFlags, MmapAdvise, MsFlags, ProtFlags};
 use nix::sys::stat::fstat;
 
 use super::error::Result;
-use super::{utils, ST_BLOCK_SIZE};
+use super::utils;
+#[cfg(target_os = "linux")]
+use super::ST_BLOCK_SIZE;
 use crate::hummock::TieredCacheKey;
 
 pub type SlotId = usize;
@@ -114,10 +117,19 @@ where
         let stat = fstat(fd)?;
         let size = if stat.st_blocks == 0 {
             // newly created
-            fallocate(fd, FallocateFlags::empty(), 0, fallocate_unit as i64)?;
+            Self::allocate(fd, 0, fallocate_unit)?;
             fallocate_unit
         } else {
-            stat.st_blocks as usize * ST_BLOCK_SIZE
+            #[cfg(target_os = "linux")]
+            {
+                stat.st_blocks as usize * ST_BLOCK_SIZE
+            }
+            // Without `fallocate(2)` the file is extended with `ftruncate(2)` and may be sparse,
+            // so `st_blocks` underestimates its size. Use `st_size` instead.
+            #[cfg(not(target_os = "linux"))]
+            {
+                stat.st_size as usize
+            }
         };
 
         let (ptr, buffer) = unsafe {
@@ -242,17 +254,23 @@ where
         BlockLoc::encoded_len() + K::encoded_len()
     }
 
+    /// Allocates `len` bytes at `offset` of the meta file. Targets without `fallocate(2)` extend
+    /// the file with `ftruncate(2)` instead, and the blocks are allocated lazily on first write.
+    fn allocate(fd: RawFd, offset: usize, len: usize) -> Result<()> {
+        #[cfg(target_os = "linux")]
+        fallocate(fd, FallocateFlags::empty(), offset as i64, len as i64)?;
+        #[cfg(not(target_os = "linux"))]
+        nix::unistd::ftruncate(fd, (offset + len) as i64)?;
+        Ok(())
+    }
+
     fn grow(&mut self) -> Result<()> {
         let old_size = self.size;
         let new_size = old_size + self.fallocate_unit;
 
-        fallocate(
-            self.fd,
-            FallocateFlags::empty(),
-            old_size as i64,
-            self.fallocate_unit as i64,
-        )?;
+        Self::allocate(self.fd, old_size, self.fallocate_unit)?;
         let (ptr, buffer) = unsafe {
+            #[cfg(target_os = "linux")]
             let ptr = mremap(
                 self.ptr as *mut c_void,
                 old_size,
@@ -260,6 +278,21 @@ where
                 MRemapFlags::MREMAP_MAYMOVE,
                 None,
             )? as *mut u8;
+            // There is no `mremap(2)` outside Linux, remap the file from scratch. The old
+            // mapping must not be accessed after it is unmapped here.
+            #[cfg(not(target_os = "linux"))]
+            let ptr = {
+                msync(self.ptr as *mut c_void, old_size, MsFlags::MS_SYNC)?;
+                munmap(self.ptr as *mut c_void, old_size)?;
+                mmap(
+                    std::ptr::null_mut(),
+                    new_size,
+                    ProtFlags::PROT_READ | ProtFlags::PROT_WRITE,
+                    MapFlags::MAP_SHARED,
+                    self.fd,
+                    0,
+                )? as *mut u8
+            };
             if let Err(e) = madvise(ptr as *mut c_void, new_size, MmapAdvise::MADV_WILLNEED) {
                 tracing::error!("madvise fail: {:?}", e);
             }
@@ -17,6 +17,7 @@ pub mod buffer;
 pub mod cache;
 pub mod error;
 pub mod file;
+pub mod io_engine;
 pub mod meta;
 pub mod metrics;
 pub mod store;
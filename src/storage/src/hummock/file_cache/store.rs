@@ -16,9 +16,9 @@ use std::marker::PhantomData;
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use nix::sys::statfs::{
-    statfs, FsType as NixFsType, BTRFS_SUPER_MAGIC, EXT4_SUPER_MAGIC, TMPFS_MAGIC,
-};
+use nix::sys::statfs::statfs;
+#[cfg(target_os = "linux")]
+use nix::sys::statfs::{FsType as NixFsType, BTRFS_SUPER_MAGIC, EXT4_SUPER_MAGIC, TMPFS_MAGIC};
 use parking_lot::RwLock;
 use risingwave_common::cache::{LruCache, LruCacheEventListener};
 use risingwave_common::util::iter_util::ZipEqFast;
@@ -27,6 +27,7 @@ use tracing::Instrument;
 
 use super::error::{Error, Result};
 use super::file::{CacheFile, CacheFileOptions};
+use super::io_engine::IoEngine;
 use super::meta::{BlockLoc, MetaFile, SlotId};
 use super::metrics::FileCacheMetricsRef;
 use super::{utils, DioBuffer, DIO_BUFFER_ALLOCATOR};
@@ -43,6 +44,9 @@ pub enum FsType {
     Ext4,
     Btrfs,
     Tmpfs,
+    /// A file system that is not specially recognized. Only used on targets other than Linux,
+    /// where no file-system-specific behavior is applied.
+    Other,
 }
 
 pub struct StoreBatchWriter<'a, K, V>
@@ -255,6 +259,11 @@ where
 
         // Get file system type and block size by `statfs(2)`.
         let fs_stat = statfs(options.dir.as_str())?;
+        // Only file systems whose hole-punching behavior is known are allowed on Linux. Other
+        // targets never punch holes (see `CacheFile::punch_hole`), so any file system works.
+        #[cfg(not(target_os = "linux"))]
+        let fs_type = FsType::Other;
+        #[cfg(target_os = "linux")]
         let fs_type = match fs_stat.filesystem_type() {
             // FYI: https://github.com/nix-rust/nix/issues/1742
             // FYI: Aftere https://github.com/nix-rust/nix/pull/1743 is release,
@@ -271,6 +280,7 @@ where
             // TODO: Make it configurable.
             block_size: fs_block_size,
             fallocate_unit: options.cache_file_fallocate_unit,
+            io_engine: Arc::new(IoEngine::detect()),
         };
 
         let mf = MetaFile::open(
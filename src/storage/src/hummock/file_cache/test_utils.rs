@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::hash::{BuildHasher, Hasher};
+#[cfg(target_os = "linux")]
 use std::path::Path;
 use std::sync::Arc;
 
@@ -191,6 +192,7 @@ mod tests {
 /// So it'not accurate if you really want to know the data size of sparse file with `fstat`.
 ///
 /// `datasize` is implemented by iterates the `fiemap` of the file.
+#[cfg(target_os = "linux")]
 pub fn datasize(path: impl AsRef<Path>) -> Result<usize> {
     let mut size = 0;
 
@@ -19,7 +19,7 @@ use futures::stream::BoxStream;
 use risingwave_hummock_sdk::table_stats::TableStatsMap;
 use risingwave_hummock_sdk::{HummockSstableId, LocalSstableInfo, SstIdRange};
 use risingwave_pb::hummock::{
-    CompactTask, CompactTaskProgress, HummockSnapshot, HummockVersion, VacuumTask,
+    CompactTask, CompactTaskProgress, HummockSnapshot, HummockVersion, KeyRange, VacuumTask,
 };
 use risingwave_rpc_client::error::Result;
 use risingwave_rpc_client::{CompactTaskItem, HummockMetaClient, MetaClient};
@@ -146,9 +146,10 @@ impl HummockMetaClient for MonitoredHummockMetaClient {
         compaction_group_id: u64,
         table_id: u32,
         level: u32,
+        key_range: Option<KeyRange>,
     ) -> Result<()> {
         self.meta_client
-            .trigger_manual_compaction(compaction_group_id, table_id, level)
+            .trigger_manual_compaction(compaction_group_id, table_id, level, key_range)
             .await
     }
 
@@ -13,15 +13,18 @@
 // limitations under the License.
 
 use std::ops::Bound::*;
+use std::sync::Arc;
 
 use bytes::Bytes;
 use risingwave_hummock_sdk::key::{FullKey, UserKey, UserKeyRange};
 use risingwave_hummock_sdk::HummockEpoch;
 
-use crate::hummock::iterator::{Backward, HummockIterator};
+use crate::hummock::iterator::{
+    Backward, HummockIterator, UserIterItem, MAX_VERSION_STEPS_BEFORE_SEEK,
+};
 use crate::hummock::local_version::pinned_version::PinnedVersion;
 use crate::hummock::value::HummockValue;
-use crate::hummock::HummockResult;
+use crate::hummock::{HummockResult, RangeTombstonesCollector};
 use crate::monitor::StoreLocalStatistic;
 
 /// [`BackwardUserIterator`] can be used by user directly.
@@ -53,22 +56,31 @@ pub struct BackwardUserIterator<I: HummockIterator<Direction = Backward>> {
     /// Only reads values if `ts > self.min_epoch`. use for ttl
     min_epoch: HummockEpoch,
 
+    /// Number of consecutive too-new versions that have been skipped by stepping the inner
+    /// iterator.
+    version_step_count: usize,
+
     /// Ensures the SSTs needed by `iterator` won't be vacuumed.
     _version: Option<PinnedVersion>,
 
     /// Store scan statistic
     stats: StoreLocalStatistic,
+
+    /// Range tombstones covering the key range, to be merged with the point data natively during
+    /// iteration. Unlike the forward `UserIterator`, the keys are visited in descending order, so
+    /// a collector with random access is used instead of a streaming aggregator.
+    delete_range_collector: Arc<RangeTombstonesCollector>,
 }
 
 impl<I: HummockIterator<Direction = Backward>> BackwardUserIterator<I> {
     /// Creates [`BackwardUserIterator`] with given `read_epoch`.
-    #[cfg(test)]
-    pub(crate) fn with_epoch(
+    pub fn new(
         iterator: I,
         key_range: UserKeyRange,
         read_epoch: u64,
         min_epoch: u64,
         version: Option<PinnedVersion>,
+        delete_range_collector: Arc<RangeTombstonesCollector>,
     ) -> Self {
         Self {
             iterator,
@@ -80,11 +92,55 @@ impl<I: HummockIterator<Direction = Backward>> BackwardUserIterator<I> {
             last_delete: true,
             read_epoch,
             min_epoch,
+            version_step_count: 0,
             stats: StoreLocalStatistic::default(),
             _version: version,
+            delete_range_collector,
         }
     }
 
+    /// Creates [`BackwardUserIterator`] that only reads versions written within the epoch window
+    /// `(min_epoch, max_epoch]`. A key is yielded iff its latest version in the window is a put,
+    /// which serves incremental diff scans between two checkpoints without replaying the full
+    /// keyspace.
+    pub fn new_with_epoch_range(
+        iterator: I,
+        key_range: UserKeyRange,
+        min_epoch: u64,
+        max_epoch: u64,
+        version: Option<PinnedVersion>,
+        delete_range_collector: Arc<RangeTombstonesCollector>,
+    ) -> Self {
+        assert!(min_epoch <= max_epoch);
+        Self::new(
+            iterator,
+            key_range,
+            max_epoch,
+            min_epoch,
+            version,
+            delete_range_collector,
+        )
+    }
+
+    /// Creates [`BackwardUserIterator`] with given `read_epoch` and no range tombstone.
+    #[cfg(test)]
+    pub(crate) fn with_epoch(
+        iterator: I,
+        key_range: UserKeyRange,
+        read_epoch: u64,
+        min_epoch: u64,
+        version: Option<PinnedVersion>,
+    ) -> Self {
+        Self::new(
+            iterator,
+            key_range,
+            read_epoch,
+            min_epoch,
+            version,
+            Arc::new(RangeTombstonesCollector::for_test()),
+        )
+    }
+
     fn out_of_range(&self, key: UserKey<&[u8]>) -> bool {
         match &self.key_range.0 {
             Included(begin_key) => key < begin_key.as_ref(),
@@ -98,6 +154,7 @@ impl<I: HummockIterator<Direction = Backward>> BackwardUserIterator<I> {
         self.just_met_new_key = false;
         self.last_delete = true;
         self.out_of_range = false;
+        self.version_step_count = 0;
     }
 
     /// Gets the iterator move to the next step.
@@ -140,6 +197,7 @@ impl<I: HummockIterator<Direction = Backward>> BackwardUserIterator<I> {
             let key = &full_key.user_key;
 
             if epoch > self.min_epoch && epoch <= self.read_epoch {
+                self.version_step_count = 0;
                 if self.just_met_new_key {
                     self.last_key = full_key.copy_into();
                     self.just_met_new_key = false;
@@ -167,28 +225,69 @@ impl<I: HummockIterator<Direction = Backward>> BackwardUserIterator<I> {
                 } else {
                     self.stats.skip_multi_version_key_count += 1;
                 }
-                // TODO: Since the real world workload may follow power law or 20/80 rule, or
-                // whatever name. We may directly seek to the next key if we have
-                // been seeing the same key for too many times.
 
                 // 1 and 2(a)
-                match self.iterator.value() {
+                match self.iterator.value_bytes() {
                     HummockValue::Put(val) => {
                         // TODO: unconditionally set the last key may lead to redundant copies
                         self.last_key = full_key.copy_into();
-                        self.last_val = Bytes::copy_from_slice(val);
-                        self.last_delete = false;
+                        if self.delete_range_collector.should_delete(key, epoch) {
+                            // The value is covered by a range tombstone, which is equivalent to a
+                            // point delete of the same epoch.
+                            self.stats.skip_delete_key_count += 1;
+                            self.last_delete = true;
+                        } else {
+                            // May share the block data of the inner iterator without copying.
+                            self.last_val = val;
+                            self.last_delete = false;
+                        }
                     }
                     HummockValue::Delete => {
                         self.last_delete = true;
                     }
                 }
+            } else if epoch > self.read_epoch {
+                // Since the real world workload may follow power law, a hot key may have
+                // accumulated hundreds of versions newer than `read_epoch`. As we visit the
+                // versions of a key from the oldest to the newest, the remaining versions of
+                // this key are invisible as well, so after stepping over enough of them,
+                // directly seek to the position right before this user key instead.
+                self.version_step_count += 1;
+                if self.version_step_count >= MAX_VERSION_STEPS_BEFORE_SEEK {
+                    self.version_step_count = 0;
+                    let seek_key = full_key.copy_into();
+                    self.iterator
+                        .seek(FullKey {
+                            user_key: seek_key.user_key.as_ref(),
+                            epoch: HummockEpoch::MAX,
+                        })
+                        .await?;
+                    continue;
+                }
             }
             self.iterator.next().await?;
         }
         Ok(()) // not valid, EOF
     }
 
+    /// Fetches up to `limit` deduplicated key-value pairs in descending key order starting from
+    /// the current position, and moves the iterator past them. Fetching a batch per call amortizes
+    /// the per-row `await` overhead of `next` in wide scans.
+    ///
+    /// A returned batch shorter than `limit` means the iterator has reached the end. As with
+    /// `next`, the iterator must have been positioned with `rewind` or `seek` before.
+    pub async fn next_batch(&mut self, limit: usize) -> HummockResult<Vec<UserIterItem>> {
+        let mut batch = Vec::with_capacity(limit);
+        while batch.len() < limit && self.is_valid() {
+            batch.push((
+                Bytes::from(self.key().user_key.encode()),
+                self.value().clone(),
+            ));
+            self.next().await?;
+        }
+        Ok(batch)
+    }
+
     /// Returns the key with the newest version. Thus no version in it, and only the `user_key` will
     /// be returned.
     ///
@@ -268,6 +367,17 @@ impl<I: HummockIterator<Direction = Backward>> BackwardUserIterator<I> {
         stats.add(&self.stats);
         self.iterator.collect_local_statistic(stats);
     }
+
+    /// Returns the statistic accumulated by this scan so far (keys processed, versions and
+    /// tombstones skipped, blocks read), for inspecting the read amplification of a single query
+    /// instead of the aggregate store metrics. This counts as the reporting point of the
+    /// statistic, so it should be called at most once, when the scan finishes.
+    pub fn stats(&self) -> StoreLocalStatistic {
+        let mut stats = StoreLocalStatistic::default();
+        self.collect_local_statistic(&mut stats);
+        stats.ignore();
+        stats
+    }
 }
 
 #[cfg(test)]
@@ -303,14 +413,18 @@ mod tests {
     use crate::hummock::iterator::test_utils::{
         default_builder_opt_for_test, gen_iterator_test_sstable_base,
         gen_iterator_test_sstable_from_kv_pair, gen_iterator_test_sstable_with_incr_epoch,
-        iterator_test_bytes_key_of, iterator_test_bytes_key_of_epoch, iterator_test_user_key_of,
+        iterator_test_bytes_key_of, iterator_test_bytes_key_of_epoch,
+        iterator_test_bytes_user_key_of, iterator_test_table_key_of, iterator_test_user_key_of,
         iterator_test_value_of, mock_sstable_store, TEST_KEYS_COUNT,
     };
-    use crate::hummock::iterator::UnorderedMergeIteratorInner;
+    use crate::hummock::iterator::{UnorderedMergeIteratorInner, MAX_VERSION_STEPS_BEFORE_SEEK};
     use crate::hummock::sstable::Sstable;
     use crate::hummock::test_utils::{create_small_table_cache, gen_test_sstable};
     use crate::hummock::value::HummockValue;
-    use crate::hummock::{BackwardSstableIterator, SstableStoreRef};
+    use crate::hummock::{
+        BackwardSstableIterator, DeleteRangeAggregatorBuilder, DeleteRangeTombstone,
+        SstableStoreRef,
+    };
 
     #[tokio::test]
     async fn test_backward_user_basic() {
@@ -443,6 +557,60 @@ mod tests {
         assert_eq!(k, &iterator_test_bytes_key_of(3 * TEST_KEYS_COUNT));
     }
 
+    #[tokio::test]
+    async fn test_backward_user_range_delete() {
+        let sstable_store = mock_sstable_store();
+        // key=[idx, epoch], value
+        let kv_pairs = vec![
+            (0, 200, HummockValue::put(iterator_test_value_of(0))),
+            (1, 100, HummockValue::put(iterator_test_value_of(1))),
+            (2, 300, HummockValue::put(iterator_test_value_of(2))),
+            (3, 100, HummockValue::put(iterator_test_value_of(3))),
+        ];
+        let table0 =
+            gen_iterator_test_sstable_from_kv_pair(0, kv_pairs, sstable_store.clone()).await;
+        let cache = create_small_table_cache();
+        let backward_iters = vec![BackwardSstableIterator::new(
+            cache.insert(table0.id, table0.id, 1, Box::new(table0)),
+            sstable_store,
+        )];
+        let bmi = UnorderedMergeIteratorInner::new(backward_iters);
+
+        // A tombstone of epoch 200 covering [1, 3). It deletes key 1 (epoch 100), but neither
+        // key 2 (epoch 300, newer than the tombstone) nor key 3 (its exclusive end).
+        let mut builder = DeleteRangeAggregatorBuilder::default();
+        builder.add_tombstone(vec![DeleteRangeTombstone::new(
+            TableId::default(),
+            iterator_test_table_key_of(1),
+            iterator_test_table_key_of(3),
+            200,
+        )]);
+        let collector = builder.build(HummockEpoch::MAX, false);
+        let mut bui = BackwardUserIterator::new(
+            bmi,
+            (Unbounded, Unbounded),
+            HummockEpoch::MAX,
+            0,
+            None,
+            collector,
+        );
+
+        bui.rewind().await.unwrap();
+        let mut keys = vec![];
+        while bui.is_valid() {
+            keys.push(bui.key().user_key.clone());
+            bui.next().await.unwrap();
+        }
+        assert_eq!(
+            keys,
+            vec![
+                iterator_test_bytes_user_key_of(3),
+                iterator_test_bytes_user_key_of(2),
+                iterator_test_bytes_user_key_of(0),
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn test_backward_user_delete() {
         let sstable_store = mock_sstable_store();
@@ -488,6 +656,45 @@ mod tests {
         assert!(!bui.is_valid());
     }
 
+    #[tokio::test]
+    async fn test_backward_user_seek_over_multi_version_key() {
+        let sstable_store = mock_sstable_store();
+        let read_epoch = 100;
+        // key=[idx, epoch], value
+        let mut kv_pairs = vec![(0, 100, HummockValue::put(iterator_test_value_of(0)))];
+        // A hot key that has accumulated hundreds of versions newer than `read_epoch`.
+        for epoch in (read_epoch + 1..=read_epoch + MAX_VERSION_STEPS_BEFORE_SEEK as u64 * 4).rev()
+        {
+            kv_pairs.push((
+                1,
+                epoch,
+                HummockValue::put(iterator_test_value_of(epoch as usize)),
+            ));
+        }
+        kv_pairs.push((1, read_epoch, HummockValue::put(iterator_test_value_of(1))));
+        kv_pairs.push((2, 100, HummockValue::put(iterator_test_value_of(2))));
+        let sstable =
+            gen_iterator_test_sstable_from_kv_pair(0, kv_pairs, sstable_store.clone()).await;
+        let cache = create_small_table_cache();
+        let handle = cache.insert(sstable.id, sstable.id, 1, Box::new(sstable));
+        let backward_iters = vec![BackwardSstableIterator::new(handle, sstable_store)];
+        let bmi = UnorderedMergeIteratorInner::new(backward_iters);
+        let mut bui =
+            BackwardUserIterator::with_epoch(bmi, (Unbounded, Unbounded), read_epoch, 0, None);
+
+        bui.rewind().await.unwrap();
+        assert_eq!(bui.key(), &iterator_test_bytes_key_of_epoch(2, 100));
+        bui.next().await.unwrap();
+        // The too-new versions of the hot key are skipped, and only the newest visible one is
+        // returned.
+        assert_eq!(bui.key(), &iterator_test_bytes_key_of_epoch(1, read_epoch));
+        assert_eq!(bui.value(), &Bytes::from(iterator_test_value_of(1)));
+        bui.next().await.unwrap();
+        assert_eq!(bui.key(), &iterator_test_bytes_key_of_epoch(0, 100));
+        bui.next().await.unwrap();
+        assert!(!bui.is_valid());
+    }
+
     // left..=end
     #[tokio::test]
     async fn test_backward_user_range_inclusive() {
@@ -1114,4 +1321,49 @@ mod tests {
         let expect_count = TEST_KEYS_COUNT - min_epoch as usize;
         assert_eq!(i, expect_count);
     }
+
+    #[tokio::test]
+    async fn test_epoch_range() {
+        let sstable_store = mock_sstable_store();
+        let table0 = gen_iterator_test_sstable_with_incr_epoch(
+            0,
+            default_builder_opt_for_test(),
+            |x| x * 3,
+            sstable_store.clone(),
+            TEST_KEYS_COUNT,
+            1,
+        )
+        .await;
+
+        let cache = create_small_table_cache();
+        let handle0 = cache.insert(table0.id, table0.id, 1, Box::new(table0));
+
+        let backward_iters = vec![BackwardSstableIterator::new(handle0, sstable_store)];
+
+        let min_epoch = (TEST_KEYS_COUNT / 5) as u64;
+        let max_epoch = (TEST_KEYS_COUNT * 4 / 5) as u64;
+        let mi = UnorderedMergeIteratorInner::new(backward_iters);
+        let mut ui = BackwardUserIterator::new_with_epoch_range(
+            mi,
+            (Unbounded, Unbounded),
+            min_epoch,
+            max_epoch,
+            None,
+            Arc::new(RangeTombstonesCollector::for_test()),
+        );
+        ui.rewind().await.unwrap();
+
+        let mut i = 0;
+        while ui.is_valid() {
+            let key_epoch = ui.key().epoch;
+            assert!(key_epoch > min_epoch);
+            assert!(key_epoch <= max_epoch);
+
+            i += 1;
+            ui.next().await.unwrap();
+        }
+
+        let expect_count = (max_epoch - min_epoch) as usize;
+        assert_eq!(i, expect_count);
+    }
 }
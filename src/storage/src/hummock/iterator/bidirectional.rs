@@ -0,0 +1,238 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bytes::Bytes;
+use risingwave_hummock_sdk::key::{FullKey, UserKey};
+
+use crate::hummock::iterator::{
+    Backward, BackwardUserIterator, DirectionEnum, Forward, HummockIterator, UserIterator,
+};
+use crate::hummock::HummockResult;
+use crate::monitor::StoreLocalStatistic;
+
+/// [`BidirectionalUserKeyIterator`] iterates on the user key space and can flip between forward
+/// and backward iteration on the fly, like RocksDB's `Prev()` after `Next()`. It pairs a
+/// [`UserIterator`] with a [`BackwardUserIterator`] over the same snapshot and keeps exactly one
+/// of them positioned at the current key; flipping the direction seeks the other stack to the
+/// current position. Both stacks read through the same sstable store, so blocks touched by one
+/// direction are served from the block cache when the other direction crosses the same range.
+///
+/// This serves executors that need to peek a few entries backwards (e.g. window frames) without
+/// materializing the scanned range or re-creating an iterator per flip. Note that since each flip
+/// costs a `seek` on the other stack, ping-ponging over a long distance is better served by two
+/// separate scans.
+pub struct BidirectionalUserKeyIterator<
+    F: HummockIterator<Direction = Forward>,
+    B: HummockIterator<Direction = Backward>,
+> {
+    forward: UserIterator<F>,
+    backward: BackwardUserIterator<B>,
+
+    /// The direction of the last movement. The iterator of this direction holds the current
+    /// position.
+    direction: DirectionEnum,
+}
+
+impl<F: HummockIterator<Direction = Forward>, B: HummockIterator<Direction = Backward>>
+    BidirectionalUserKeyIterator<F, B>
+{
+    /// Creates the iterator from a forward and a backward user iterator. The two iterators must be
+    /// built over the same key range and read epoch, otherwise flipping the direction observes an
+    /// inconsistent snapshot.
+    pub fn new(forward: UserIterator<F>, backward: BackwardUserIterator<B>) -> Self {
+        Self {
+            forward,
+            backward,
+            direction: DirectionEnum::Forward,
+        }
+    }
+
+    /// Resets the position to the first key of the range, iterating forward.
+    pub async fn rewind(&mut self) -> HummockResult<()> {
+        self.direction = DirectionEnum::Forward;
+        self.forward.rewind().await
+    }
+
+    /// Resets the position to the last key of the range, iterating backward.
+    pub async fn rewind_to_last(&mut self) -> HummockResult<()> {
+        self.direction = DirectionEnum::Backward;
+        self.backward.rewind().await
+    }
+
+    /// Resets the position to the first key >= `user_key`, iterating forward.
+    pub async fn seek(&mut self, user_key: UserKey<&[u8]>) -> HummockResult<()> {
+        self.direction = DirectionEnum::Forward;
+        self.forward.seek(user_key).await
+    }
+
+    /// Moves to the next user key in ascending order.
+    ///
+    /// When the last movement was backward, this flips the direction by seeking the forward stack
+    /// to the successor of the current key. Calling `next` on an iterator that ran off the front
+    /// of the range moves back to the first key.
+    pub async fn next(&mut self) -> HummockResult<()> {
+        if self.direction == DirectionEnum::Forward {
+            return self.forward.next().await;
+        }
+        self.direction = DirectionEnum::Forward;
+        if !self.backward.is_valid() {
+            return self.forward.rewind().await;
+        }
+        let current_key = self.backward.key().user_key.clone();
+        self.forward.seek(current_key.as_ref()).await?;
+        if self.forward.is_valid() && self.forward.key().user_key == current_key {
+            self.forward.next().await?;
+        }
+        Ok(())
+    }
+
+    /// Moves to the previous user key in descending order, the counterpart of `next`. Calling
+    /// `prev` on an iterator that ran off the end of the range moves back to the last key.
+    pub async fn prev(&mut self) -> HummockResult<()> {
+        if self.direction == DirectionEnum::Backward {
+            return self.backward.next().await;
+        }
+        self.direction = DirectionEnum::Backward;
+        if !self.forward.is_valid() {
+            return self.backward.rewind().await;
+        }
+        let current_key = self.forward.key().user_key.clone();
+        self.backward.seek(current_key.as_ref()).await?;
+        if self.backward.is_valid() && self.backward.key().user_key == current_key {
+            self.backward.next().await?;
+        }
+        Ok(())
+    }
+
+    /// Returns the current key. The iterator must be valid.
+    pub fn key(&self) -> &FullKey<Bytes> {
+        match self.direction {
+            DirectionEnum::Forward => self.forward.key(),
+            DirectionEnum::Backward => self.backward.key(),
+        }
+    }
+
+    /// Returns the current value. The iterator must be valid.
+    pub fn value(&self) -> &Bytes {
+        match self.direction {
+            DirectionEnum::Forward => self.forward.value(),
+            DirectionEnum::Backward => self.backward.value(),
+        }
+    }
+
+    /// Indicates whether the iterator can be used.
+    pub fn is_valid(&self) -> bool {
+        match self.direction {
+            DirectionEnum::Forward => self.forward.is_valid(),
+            DirectionEnum::Backward => self.backward.is_valid(),
+        }
+    }
+
+    pub fn collect_local_statistic(&self, stats: &mut StoreLocalStatistic) {
+        self.forward.collect_local_statistic(stats);
+        self.backward.collect_local_statistic(stats);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Bound::Unbounded;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::hummock::iterator::test_utils::{
+        default_builder_opt_for_test, gen_iterator_test_sstable_base, iterator_test_bytes_key_of,
+        iterator_test_user_key_of, iterator_test_value_of, mock_sstable_store, TEST_KEYS_COUNT,
+    };
+    use crate::hummock::sstable::{
+        SstableIterator, SstableIteratorReadOptions, SstableIteratorType,
+    };
+    use crate::hummock::test_utils::create_small_table_cache;
+    use crate::hummock::BackwardSstableIterator;
+
+    async fn gen_bidirectional_iter(
+    ) -> BidirectionalUserKeyIterator<SstableIterator, BackwardSstableIterator> {
+        let sstable_store = mock_sstable_store();
+        let table = gen_iterator_test_sstable_base(
+            0,
+            default_builder_opt_for_test(),
+            |x| x + 1,
+            sstable_store.clone(),
+            TEST_KEYS_COUNT,
+        )
+        .await;
+        let cache = create_small_table_cache();
+        let forward_handle = cache.insert(table.id, table.id, 1, Box::new(table.clone()));
+        let backward_handle = cache.insert(table.id, table.id, 1, Box::new(table));
+        let forward = UserIterator::for_test(
+            SstableIterator::create(
+                forward_handle,
+                sstable_store.clone(),
+                Arc::new(SstableIteratorReadOptions::default()),
+            ),
+            (Unbounded, Unbounded),
+        );
+        let backward = BackwardUserIterator::for_test(
+            BackwardSstableIterator::new(backward_handle, sstable_store),
+            (Unbounded, Unbounded),
+        );
+        BidirectionalUserKeyIterator::new(forward, backward)
+    }
+
+    #[tokio::test]
+    async fn test_bidirectional_basic() {
+        let mut iter = gen_bidirectional_iter().await;
+        iter.rewind().await.unwrap();
+
+        // Walk forwards, then flip and walk backwards over the same keys.
+        for i in 1..=3 {
+            assert_eq!(iter.key(), &iterator_test_bytes_key_of(i));
+            assert_eq!(iter.value(), iterator_test_value_of(i).as_slice());
+            iter.next().await.unwrap();
+        }
+        for i in (1..=3).rev() {
+            iter.prev().await.unwrap();
+            assert_eq!(iter.key(), &iterator_test_bytes_key_of(i));
+            assert_eq!(iter.value(), iterator_test_value_of(i).as_slice());
+        }
+
+        // Running off the front and moving forward again restarts from the first key.
+        iter.prev().await.unwrap();
+        assert!(!iter.is_valid());
+        iter.next().await.unwrap();
+        assert_eq!(iter.key(), &iterator_test_bytes_key_of(1));
+    }
+
+    #[tokio::test]
+    async fn test_bidirectional_seek_and_flip() {
+        let mut iter = gen_bidirectional_iter().await;
+        let mid = TEST_KEYS_COUNT / 2;
+        iter.seek(iterator_test_user_key_of(mid).as_ref())
+            .await
+            .unwrap();
+        assert_eq!(iter.key(), &iterator_test_bytes_key_of(mid));
+        iter.prev().await.unwrap();
+        assert_eq!(iter.key(), &iterator_test_bytes_key_of(mid - 1));
+        iter.next().await.unwrap();
+        assert_eq!(iter.key(), &iterator_test_bytes_key_of(mid));
+
+        // Running off the end and moving backward again restarts from the last key.
+        iter.rewind_to_last().await.unwrap();
+        assert_eq!(iter.key(), &iterator_test_bytes_key_of(TEST_KEYS_COUNT));
+        iter.next().await.unwrap();
+        assert!(!iter.is_valid());
+        iter.prev().await.unwrap();
+        assert_eq!(iter.key(), &iterator_test_bytes_key_of(TEST_KEYS_COUNT));
+    }
+}
@@ -16,6 +16,7 @@ use std::cmp::Ordering::{Equal, Greater, Less};
 use std::future::Future;
 use std::sync::Arc;
 
+use bytes::Bytes;
 use itertools::Itertools;
 use risingwave_common::must_match;
 use risingwave_hummock_sdk::key::FullKey;
@@ -132,6 +133,15 @@ impl<TI: SstableIteratorType> ConcatIteratorInner<TI> {
             if let Some(old_iter) = self.sstable_iter.take() {
                 old_iter.collect_local_statistic(&mut self.stats);
             }
+        } else if idx == self.cur_idx && let Some(sstable_iter) = self.sstable_iter.as_mut() {
+            // The binary search in `seek` often lands on the table we are already positioned in,
+            // e.g. for repeated seeks within one sorted run. Reuse its iterator instead of
+            // recreating it, so such seeks do not pay the table fetch again.
+            if let Some(key) = seek_key {
+                sstable_iter.seek(key).await?;
+            } else {
+                sstable_iter.rewind().await?;
+            }
         } else {
             let table = self.tables[idx]
                 .prefetch(&self.sstable_store, &mut self.stats)
@@ -185,6 +195,13 @@ impl<TI: SstableIteratorType> HummockIterator for ConcatIteratorInner<TI> {
         self.sstable_iter.as_ref().expect("no table iter").value()
     }
 
+    fn value_bytes(&self) -> HummockValue<Bytes> {
+        self.sstable_iter
+            .as_ref()
+            .expect("no table iter")
+            .value_bytes()
+    }
+
     fn is_valid(&self) -> bool {
         self.sstable_iter.as_ref().map_or(false, |i| i.is_valid())
     }
@@ -18,7 +18,10 @@ use bytes::Bytes;
 use risingwave_hummock_sdk::key::{FullKey, UserKey, UserKeyRange};
 use risingwave_hummock_sdk::HummockEpoch;
 
-use crate::hummock::iterator::{Forward, ForwardMergeRangeIterator, HummockIterator};
+use crate::hummock::iterator::{
+    Forward, ForwardMergeRangeIterator, HummockIterator, UserIterItem,
+    MAX_VERSION_STEPS_BEFORE_SEEK,
+};
 use crate::hummock::local_version::pinned_version::PinnedVersion;
 use crate::hummock::value::HummockValue;
 use crate::hummock::{DeleteRangeAggregator, HummockResult};
@@ -47,6 +50,9 @@ pub struct UserIterator<I: HummockIterator<Direction = Forward>> {
     /// Only reads values if `ts > self.min_epoch`. use for ttl
     min_epoch: HummockEpoch,
 
+    /// Number of versions of `last_key` that have been skipped by stepping the inner iterator.
+    version_step_count: usize,
+
     /// Ensures the SSTs needed by `iterator` won't be vacuumed.
     _version: Option<PinnedVersion>,
 
@@ -74,12 +80,36 @@ impl<I: HummockIterator<Direction = Forward>> UserIterator<I> {
             last_val: Bytes::new(),
             read_epoch,
             min_epoch,
+            version_step_count: 0,
             stats: StoreLocalStatistic::default(),
             delete_range_aggregator,
             _version: version,
         }
     }
 
+    /// Creates [`UserIterator`] that only reads versions written within the epoch window
+    /// `(min_epoch, max_epoch]`. A key is yielded iff its latest version in the window is a put,
+    /// which serves incremental diff scans between two checkpoints without replaying the full
+    /// keyspace.
+    pub fn new_with_epoch_range(
+        iterator: I,
+        key_range: UserKeyRange,
+        min_epoch: u64,
+        max_epoch: u64,
+        version: Option<PinnedVersion>,
+        delete_range_aggregator: DeleteRangeAggregator<ForwardMergeRangeIterator>,
+    ) -> Self {
+        assert!(min_epoch <= max_epoch);
+        Self::new(
+            iterator,
+            key_range,
+            max_epoch,
+            min_epoch,
+            version,
+            delete_range_aggregator,
+        )
+    }
+
     /// Gets the iterator move to the next step.
     ///
     /// Returned result:
@@ -99,14 +129,16 @@ impl<I: HummockIterator<Direction = Forward>> UserIterator<I> {
             }
 
             if &self.last_key.user_key.as_ref() != key {
+                self.version_step_count = 0;
                 self.last_key = full_key.copy_into();
                 // handle delete operation
-                match self.iterator.value() {
+                match self.iterator.value_bytes() {
                     HummockValue::Put(val) => {
                         if self.delete_range_aggregator.should_delete(key, epoch) {
                             self.stats.skip_delete_key_count += 1;
                         } else {
-                            self.last_val = Bytes::copy_from_slice(val);
+                            // May share the block data of the inner iterator without copying.
+                            self.last_val = val;
 
                             // handle range scan
                             match &self.key_range.1 {
@@ -131,6 +163,19 @@ impl<I: HummockIterator<Direction = Forward>> UserIterator<I> {
                 }
             } else {
                 self.stats.skip_multi_version_key_count += 1;
+                self.version_step_count += 1;
+                // All the remaining versions of `last_key` are older than the one we have
+                // decided on, so once we have stepped over enough of them, directly seek to
+                // the position right after this user key instead.
+                if self.version_step_count >= MAX_VERSION_STEPS_BEFORE_SEEK {
+                    self.version_step_count = 0;
+                    let seek_key = FullKey {
+                        user_key: self.last_key.user_key.as_ref(),
+                        epoch: 0,
+                    };
+                    self.iterator.seek(seek_key).await?;
+                    continue;
+                }
             }
 
             self.iterator.next().await?;
@@ -139,6 +184,25 @@ impl<I: HummockIterator<Direction = Forward>> UserIterator<I> {
         Ok(()) // not valid, EOF
     }
 
+    /// Fetches up to `limit` deduplicated key-value pairs starting from the current position, and
+    /// moves the iterator past them. The per-row `await` of `next` dominates the CPU cost of wide
+    /// scans, and fetching a batch per call amortizes it and allows the caller to deserialize the
+    /// rows in a vectorized manner.
+    ///
+    /// A returned batch shorter than `limit` means the iterator has reached the end. As with
+    /// `next`, the iterator must have been positioned with `rewind` or `seek` before.
+    pub async fn next_batch(&mut self, limit: usize) -> HummockResult<Vec<UserIterItem>> {
+        let mut batch = Vec::with_capacity(limit);
+        while batch.len() < limit && self.is_valid() {
+            batch.push((
+                Bytes::from(self.key().user_key.encode()),
+                self.value().clone(),
+            ));
+            self.next().await?;
+        }
+        Ok(batch)
+    }
+
     /// Returns the key with the newest version. Thus no version in it, and only the `user_key` will
     /// be returned.
     ///
@@ -225,6 +289,17 @@ impl<I: HummockIterator<Direction = Forward>> UserIterator<I> {
         stats.add(&self.stats);
         self.iterator.collect_local_statistic(stats);
     }
+
+    /// Returns the statistic accumulated by this scan so far (keys processed, versions and
+    /// tombstones skipped, blocks read), for inspecting the read amplification of a single query
+    /// instead of the aggregate store metrics. This counts as the reporting point of the
+    /// statistic, so it should be called at most once, when the scan finishes.
+    pub fn stats(&self) -> StoreLocalStatistic {
+        let mut stats = StoreLocalStatistic::default();
+        self.collect_local_statistic(&mut stats);
+        stats.ignore();
+        stats
+    }
 }
 
 #[cfg(test)]
@@ -271,7 +346,7 @@ mod tests {
         iterator_test_bytes_key_of_epoch, iterator_test_bytes_user_key_of,
         iterator_test_user_key_of, iterator_test_value_of, mock_sstable_store, TEST_KEYS_COUNT,
     };
-    use crate::hummock::iterator::UnorderedMergeIteratorInner;
+    use crate::hummock::iterator::{UnorderedMergeIteratorInner, MAX_VERSION_STEPS_BEFORE_SEEK};
     use crate::hummock::sstable::{
         SstableIterator, SstableIteratorReadOptions, SstableIteratorType,
     };
@@ -347,6 +422,47 @@ mod tests {
         assert!(i >= TEST_KEYS_COUNT * 3);
     }
 
+    #[tokio::test]
+    async fn test_next_batch() {
+        let sstable_store = mock_sstable_store();
+        let read_options = Arc::new(SstableIteratorReadOptions::default());
+        let table = gen_iterator_test_sstable_base(
+            0,
+            default_builder_opt_for_test(),
+            |x| x,
+            sstable_store.clone(),
+            TEST_KEYS_COUNT,
+        )
+        .await;
+        let cache = create_small_table_cache();
+        let iters = vec![SstableIterator::create(
+            cache.insert(table.id, table.id, 1, Box::new(table)),
+            sstable_store,
+            read_options,
+        )];
+
+        let mi = UnorderedMergeIteratorInner::new(iters);
+        let mut ui = UserIterator::for_test(mi, (Unbounded, Unbounded));
+        ui.rewind().await.unwrap();
+
+        let batch_size = TEST_KEYS_COUNT / 2 + 1;
+        let mut i = 0;
+        loop {
+            let batch = ui.next_batch(batch_size).await.unwrap();
+            for (key, value) in &batch {
+                assert_eq!(key, &iterator_test_bytes_user_key_of(i).encode());
+                assert_eq!(value, &iterator_test_value_of(i));
+                i += 1;
+            }
+            if batch.len() < batch_size {
+                break;
+            }
+        }
+        assert_eq!(i, TEST_KEYS_COUNT);
+        assert!(!ui.is_valid());
+        assert!(ui.next_batch(batch_size).await.unwrap().is_empty());
+    }
+
     #[tokio::test]
     async fn test_seek() {
         let sstable_store = mock_sstable_store();
@@ -481,6 +597,42 @@ mod tests {
         assert!(!ui.is_valid());
     }
 
+    #[tokio::test]
+    async fn test_stats() {
+        let sstable_store = mock_sstable_store();
+
+        // key=[idx, epoch], value
+        let kv_pairs = vec![
+            (1, 200, HummockValue::put(iterator_test_value_of(1))),
+            (1, 100, HummockValue::put(iterator_test_value_of(1))),
+            (2, 300, HummockValue::delete()),
+            (3, 100, HummockValue::put(iterator_test_value_of(3))),
+        ];
+        let table0 =
+            gen_iterator_test_sstable_from_kv_pair(0, kv_pairs, sstable_store.clone()).await;
+
+        let cache = create_small_table_cache();
+        let iter = SstableIterator::create(
+            cache.insert(table0.id, table0.id, 1, Box::new(table0)),
+            sstable_store.clone(),
+            Arc::new(SstableIteratorReadOptions::default()),
+        );
+
+        let mut ui = UserIterator::for_test(iter, (Unbounded, Unbounded));
+        ui.rewind().await.unwrap();
+        while ui.is_valid() {
+            ui.next().await.unwrap();
+        }
+
+        let stats = ui.stats();
+        // Keys 1 and 3 are yielded, the delete of key 2 and the old version of key 1 are skipped.
+        assert_eq!(stats.processed_key_count, 2);
+        assert_eq!(stats.skip_delete_key_count, 1);
+        assert_eq!(stats.skip_multi_version_key_count, 1);
+        // At least one data block has been read from the store.
+        assert!(stats.cache_data_block_total >= 1);
+    }
+
     async fn generate_test_data(
         sstable_store: SstableStoreRef,
         range_tombstones: Vec<(usize, usize, u64)>,
@@ -843,6 +995,96 @@ mod tests {
         assert_eq!(i, expect_count);
     }
 
+    #[tokio::test]
+    async fn test_epoch_range() {
+        let sstable_store = mock_sstable_store();
+        let read_options = Arc::new(SstableIteratorReadOptions::default());
+        let table0 = gen_iterator_test_sstable_with_incr_epoch(
+            0,
+            default_builder_opt_for_test(),
+            |x| x * 3,
+            sstable_store.clone(),
+            TEST_KEYS_COUNT,
+            1,
+        )
+        .await;
+        let cache = create_small_table_cache();
+        let iters = vec![SstableIterator::create(
+            cache.insert(table0.id, table0.id, 1, Box::new(table0)),
+            sstable_store.clone(),
+            read_options.clone(),
+        )];
+
+        let min_epoch = (TEST_KEYS_COUNT / 5) as u64;
+        let max_epoch = (TEST_KEYS_COUNT * 4 / 5) as u64;
+        let mi = UnorderedMergeIteratorInner::new(iters);
+        let mut ui = UserIterator::new_with_epoch_range(
+            mi,
+            (Unbounded, Unbounded),
+            min_epoch,
+            max_epoch,
+            None,
+            DeleteRangeAggregator::new(ForwardMergeRangeIterator::default(), max_epoch),
+        );
+        ui.rewind().await.unwrap();
+
+        let mut i = 0;
+        while ui.is_valid() {
+            let key_epoch = ui.key().epoch;
+            assert!(key_epoch > min_epoch);
+            assert!(key_epoch <= max_epoch);
+
+            i += 1;
+            ui.next().await.unwrap();
+        }
+
+        let expect_count = (max_epoch - min_epoch) as usize;
+        assert_eq!(i, expect_count);
+    }
+
+    #[tokio::test]
+    async fn test_seek_over_multi_version_key() {
+        let sstable_store = mock_sstable_store();
+        let version_count = MAX_VERSION_STEPS_BEFORE_SEEK as u64 * 4;
+        // key=[idx, epoch], value
+        let mut kv_pairs = vec![(0, 100, HummockValue::put(iterator_test_value_of(0)))];
+        // A hot key that has accumulated hundreds of versions.
+        for epoch in (1..=version_count).rev() {
+            kv_pairs.push((
+                1,
+                epoch,
+                HummockValue::put(iterator_test_value_of(epoch as usize)),
+            ));
+        }
+        kv_pairs.push((2, 100, HummockValue::put(iterator_test_value_of(2))));
+        let sstable =
+            gen_iterator_test_sstable_from_kv_pair(0, kv_pairs, sstable_store.clone()).await;
+        let cache = create_small_table_cache();
+        let read_options = Arc::new(SstableIteratorReadOptions::default());
+        let iters = vec![SstableIterator::create(
+            cache.insert(sstable.id, sstable.id, 1, Box::new(sstable)),
+            sstable_store,
+            read_options,
+        )];
+        let mi = UnorderedMergeIteratorInner::new(iters);
+        let mut ui = UserIterator::for_test(mi, (Unbounded, Unbounded));
+
+        ui.rewind().await.unwrap();
+        assert_eq!(ui.key(), &iterator_test_bytes_key_of_epoch(0, 100));
+        ui.next().await.unwrap();
+        // Only the newest version of the hot key is visible, no matter whether its older
+        // versions are stepped or sought over.
+        assert_eq!(ui.key(), &iterator_test_bytes_key_of_epoch(1, version_count));
+        assert_eq!(
+            ui.value(),
+            iterator_test_value_of(version_count as usize).as_slice()
+        );
+        ui.next().await.unwrap();
+        assert_eq!(ui.key(), &iterator_test_bytes_key_of_epoch(2, 100));
+        ui.next().await.unwrap();
+        assert!(!ui.is_valid());
+    }
+
     #[tokio::test]
     async fn test_delete_range() {
         let sstable_store = mock_sstable_store();
@@ -17,6 +17,7 @@ use std::collections::{BinaryHeap, LinkedList};
 use std::future::Future;
 use std::ops::{Deref, DerefMut};
 
+use bytes::Bytes;
 use risingwave_hummock_sdk::key::{FullKey, TableKey, UserKey};
 
 use crate::hummock::iterator::{DirectionEnum, HummockIterator, HummockIteratorDirection};
@@ -378,6 +379,10 @@ where
         self.heap.peek().expect("no inner iter").iter.value()
     }
 
+    fn value_bytes(&self) -> HummockValue<Bytes> {
+        self.heap.peek().expect("no inner iter").iter.value_bytes()
+    }
+
     fn is_valid(&self) -> bool {
         self.heap.peek().map_or(false, |n| n.iter.is_valid())
     }
@@ -16,6 +16,8 @@ use std::future::Future;
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
 
+use bytes::Bytes;
+
 use super::{HummockResult, HummockValue};
 
 mod forward_concat;
@@ -26,6 +28,8 @@ pub use backward_concat::*;
 pub use concat_inner::ConcatIteratorInner;
 mod backward_merge;
 pub use backward_merge::*;
+mod bidirectional;
+pub use bidirectional::BidirectionalUserKeyIterator;
 mod backward_user;
 pub use backward_user::*;
 mod forward_merge;
@@ -39,12 +43,24 @@ use risingwave_hummock_sdk::key::FullKey;
 use crate::hummock::iterator::HummockIteratorUnion::{First, Fourth, Second, Third};
 
 mod delete_range_iterator;
+mod sanity_check;
+pub use sanity_check::SanityCheckIterator;
+mod stream;
+#[cfg(any(test, feature = "test"))]
+pub mod test_support;
 #[cfg(any(test, feature = "test"))]
 pub mod test_utils;
 pub use delete_range_iterator::{DeleteRangeIterator, ForwardMergeRangeIterator};
+pub use stream::*;
 
 use crate::monitor::StoreLocalStatistic;
 
+/// After this many versions of the same user key have been skipped by stepping the inner iterator
+/// one entry at a time, user iterators will `seek` over the remaining versions instead. Since the
+/// real world workload may follow power law, a hot key can accumulate hundreds of versions before
+/// compaction catches up, and stepping over them one by one dominates the scan cost.
+pub(crate) const MAX_VERSION_STEPS_BEFORE_SEEK: usize = 100;
+
 /// `HummockIterator` defines the interface of all iterators, including `SstableIterator`,
 /// `MergeIterator`, `UserIterator` and `ConcatIterator`.
 ///
@@ -98,6 +114,19 @@ pub trait HummockIterator: Send + 'static {
     // TODO: Add lifetime
     fn value(&self) -> HummockValue<&[u8]>;
 
+    /// Retrieves the current value as shared [`Bytes`], sharing the underlying refcounted memory
+    /// (e.g. an SST block or a shared buffer batch) instead of copying it whenever possible.
+    ///
+    /// The default implementation falls back to copying the borrowed value. Delegating iterators
+    /// must forward this method, otherwise the zero-copy path of the inner iterator is lost.
+    ///
+    /// # Panics
+    /// This function will panic if the iterator is invalid, or the value cannot be decoded into
+    /// [`HummockValue`].
+    fn value_bytes(&self) -> HummockValue<Bytes> {
+        self.value().to_bytes()
+    }
+
     /// Indicates whether the iterator can be used.
     ///
     /// Note:
@@ -233,6 +262,15 @@ impl<
         }
     }
 
+    fn value_bytes(&self) -> HummockValue<Bytes> {
+        match self {
+            First(iter) => iter.value_bytes(),
+            Second(iter) => iter.value_bytes(),
+            Third(iter) => iter.value_bytes(),
+            Fourth(iter) => iter.value_bytes(),
+        }
+    }
+
     fn is_valid(&self) -> bool {
         match self {
             First(iter) => iter.is_valid(),
@@ -293,6 +331,10 @@ impl<I: HummockIterator> HummockIterator for Box<I> {
         (*self).deref().value()
     }
 
+    fn value_bytes(&self) -> HummockValue<Bytes> {
+        (*self).deref().value_bytes()
+    }
+
     fn is_valid(&self) -> bool {
         (*self).deref().is_valid()
     }
@@ -0,0 +1,222 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::future::Future;
+
+use bytes::Bytes;
+use risingwave_hummock_sdk::key::FullKey;
+
+use crate::hummock::iterator::{DirectionEnum, HummockIterator, HummockIteratorDirection};
+use crate::hummock::value::HummockValue;
+use crate::hummock::{HummockError, HummockResult};
+use crate::monitor::StoreLocalStatistic;
+
+/// An opt-in wrapper that validates the output order of the inner iterator, mainly intended for
+/// merge iterators whose output depends on all inputs being correctly sorted.
+///
+/// Full keys must be strictly increasing for a forward iterator and strictly decreasing for a
+/// backward one. Since the full key order sorts greater epochs first under the same user key, this
+/// also catches versions of a user key whose epochs are not properly ordered. A violation returns
+/// [`HummockError::corruption`] instead of silently yielding wrong results, which is what a
+/// compaction bug producing out-of-order SSTs would otherwise manifest as.
+pub struct SanityCheckIterator<I: HummockIterator> {
+    inner: I,
+
+    /// The key yielded by the last `next`. Empty if the iterator has not yielded any key since the
+    /// last `rewind` or `seek`.
+    last_key: FullKey<Vec<u8>>,
+}
+
+impl<I: HummockIterator> SanityCheckIterator<I> {
+    pub fn new(inner: I) -> Self {
+        Self {
+            inner,
+            last_key: FullKey::default(),
+        }
+    }
+
+    /// Checks the current position of the inner iterator against the last yielded key.
+    fn check_key_order(&mut self) -> HummockResult<()> {
+        if !self.inner.is_valid() {
+            return Ok(());
+        }
+        let key = self.inner.key();
+        if !self.last_key.is_empty() {
+            let ordering = self.last_key.to_ref().cmp(&key);
+            let expected = match I::Direction::direction() {
+                DirectionEnum::Forward => Ordering::Less,
+                DirectionEnum::Backward => Ordering::Greater,
+            };
+            if ordering != expected {
+                return Err(HummockError::corruption(format!(
+                    "{:?} iterator yields out-of-order keys: last key {:?}, current key {:?}",
+                    I::Direction::direction(),
+                    self.last_key,
+                    key
+                )));
+            }
+        }
+        self.last_key.set(key);
+        Ok(())
+    }
+}
+
+impl<I: HummockIterator> HummockIterator for SanityCheckIterator<I> {
+    type Direction = I::Direction;
+
+    type NextFuture<'a> = impl Future<Output = HummockResult<()>> + 'a;
+    type RewindFuture<'a> = impl Future<Output = HummockResult<()>> + 'a;
+    type SeekFuture<'a> = impl Future<Output = HummockResult<()>> + 'a;
+
+    fn next(&mut self) -> Self::NextFuture<'_> {
+        async move {
+            self.inner.next().await?;
+            self.check_key_order()
+        }
+    }
+
+    fn key(&self) -> FullKey<&[u8]> {
+        self.inner.key()
+    }
+
+    fn value(&self) -> HummockValue<&[u8]> {
+        self.inner.value()
+    }
+
+    fn value_bytes(&self) -> HummockValue<Bytes> {
+        self.inner.value_bytes()
+    }
+
+    fn is_valid(&self) -> bool {
+        self.inner.is_valid()
+    }
+
+    fn rewind(&mut self) -> Self::RewindFuture<'_> {
+        async move {
+            self.inner.rewind().await?;
+            self.last_key = FullKey::default();
+            self.check_key_order()
+        }
+    }
+
+    fn seek<'a>(&'a mut self, key: FullKey<&'a [u8]>) -> Self::SeekFuture<'a> {
+        async move {
+            self.inner.seek(key).await?;
+            self.last_key = FullKey::default();
+            self.check_key_order()
+        }
+    }
+
+    fn collect_local_statistic(&self, stats: &mut StoreLocalStatistic) {
+        self.inner.collect_local_statistic(stats);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hummock::iterator::test_utils::{
+        gen_merge_iterator_interleave_test_sstable_iters, iterator_test_key_of,
+        iterator_test_key_of_epoch, TEST_KEYS_COUNT,
+    };
+    use crate::hummock::iterator::{Forward, UnorderedMergeIteratorInner};
+
+    /// An iterator that replays the given keys as-is, without any ordering guarantee.
+    struct UnsortedTestIterator {
+        keys: Vec<FullKey<Vec<u8>>>,
+        idx: usize,
+    }
+
+    impl HummockIterator for UnsortedTestIterator {
+        type Direction = Forward;
+
+        type NextFuture<'a> = impl Future<Output = HummockResult<()>> + 'a;
+        type RewindFuture<'a> = impl Future<Output = HummockResult<()>> + 'a;
+        type SeekFuture<'a> = impl Future<Output = HummockResult<()>> + 'a;
+
+        fn next(&mut self) -> Self::NextFuture<'_> {
+            async {
+                self.idx += 1;
+                Ok(())
+            }
+        }
+
+        fn key(&self) -> FullKey<&[u8]> {
+            self.keys[self.idx].to_ref()
+        }
+
+        fn value(&self) -> HummockValue<&[u8]> {
+            HummockValue::put(&b"value"[..])
+        }
+
+        fn is_valid(&self) -> bool {
+            self.idx < self.keys.len()
+        }
+
+        fn rewind(&mut self) -> Self::RewindFuture<'_> {
+            async {
+                self.idx = 0;
+                Ok(())
+            }
+        }
+
+        fn seek<'a>(&'a mut self, _key: FullKey<&'a [u8]>) -> Self::SeekFuture<'a> {
+            async {
+                self.idx = 0;
+                Ok(())
+            }
+        }
+
+        fn collect_local_statistic(&self, _stats: &mut StoreLocalStatistic) {}
+    }
+
+    #[tokio::test]
+    async fn test_sanity_check_pass() {
+        let mut iter = SanityCheckIterator::new(UnorderedMergeIteratorInner::new(
+            gen_merge_iterator_interleave_test_sstable_iters(TEST_KEYS_COUNT, 3).await,
+        ));
+        iter.rewind().await.unwrap();
+        let mut count = 0;
+        while iter.is_valid() {
+            count += 1;
+            iter.next().await.unwrap();
+        }
+        assert_eq!(count, TEST_KEYS_COUNT);
+    }
+
+    #[tokio::test]
+    async fn test_sanity_check_out_of_order_key() {
+        let mut iter = SanityCheckIterator::new(UnsortedTestIterator {
+            keys: vec![iterator_test_key_of(1), iterator_test_key_of(0)],
+            idx: 0,
+        });
+        iter.rewind().await.unwrap();
+        assert!(iter.next().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_sanity_check_out_of_order_epoch() {
+        // Versions of the same user key must be yielded with decreasing epochs.
+        let mut iter = SanityCheckIterator::new(UnsortedTestIterator {
+            keys: vec![
+                iterator_test_key_of_epoch(0, 1),
+                iterator_test_key_of_epoch(0, 2),
+            ],
+            idx: 0,
+        });
+        iter.rewind().await.unwrap();
+        assert!(iter.next().await.is_err());
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bytes::Bytes;
+use futures::Stream;
+use futures_async_stream::try_stream;
+
+use crate::hummock::iterator::{
+    Backward, BackwardUserIterator, Forward, HummockIterator, UserIterator,
+};
+use crate::hummock::{HummockError, HummockResult};
+
+/// The item yielded by the streams over user iterators: the encoded user key (without epoch) and
+/// the user value.
+pub type UserIterItem = (Bytes, Bytes);
+
+/// A [`Stream`] over the remaining key-value pairs of a user iterator, so that callers can use
+/// `StreamExt` combinators and `select!` over multiple sources rather than manually driving
+/// `next().await` loops.
+pub trait UserIterItemStream = Stream<Item = HummockResult<UserIterItem>> + Send;
+
+#[try_stream(ok = UserIterItem, error = HummockError)]
+async fn into_stream_inner<I: HummockIterator<Direction = Forward>>(mut iter: UserIterator<I>) {
+    while iter.is_valid() {
+        let kv = (
+            Bytes::from(iter.key().user_key.encode()),
+            iter.value().clone(),
+        );
+        iter.next().await?;
+        yield kv;
+    }
+}
+
+#[try_stream(ok = UserIterItem, error = HummockError)]
+async fn into_backward_stream_inner<I: HummockIterator<Direction = Backward>>(
+    mut iter: BackwardUserIterator<I>,
+) {
+    while iter.is_valid() {
+        let kv = (
+            Bytes::from(iter.key().user_key.encode()),
+            iter.value().clone(),
+        );
+        iter.next().await?;
+        yield kv;
+    }
+}
+
+impl<I: HummockIterator<Direction = Forward>> UserIterator<I> {
+    /// Consumes the iterator and turns it into a [`Stream`] over the remaining key-value pairs.
+    ///
+    /// The iterator must have been positioned with `rewind` or `seek` before, since the stream
+    /// only drives `next` and never repositions.
+    pub fn into_stream(self) -> impl UserIterItemStream {
+        into_stream_inner(self)
+    }
+}
+
+impl<I: HummockIterator<Direction = Backward>> BackwardUserIterator<I> {
+    /// Consumes the iterator and turns it into a [`Stream`] over the remaining key-value pairs in
+    /// descending key order.
+    ///
+    /// The iterator must have been positioned with `rewind` or `seek` before, since the stream
+    /// only drives `next` and never repositions.
+    pub fn into_stream(self) -> impl UserIterItemStream {
+        into_backward_stream_inner(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Bound::Unbounded;
+    use std::sync::Arc;
+
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::hummock::iterator::test_utils::{
+        default_builder_opt_for_test, gen_iterator_test_sstable_base,
+        iterator_test_bytes_user_key_of, iterator_test_value_of, mock_sstable_store,
+        TEST_KEYS_COUNT,
+    };
+    use crate::hummock::iterator::UnorderedMergeIteratorInner;
+    use crate::hummock::sstable::{
+        SstableIterator, SstableIteratorReadOptions, SstableIteratorType,
+    };
+    use crate::hummock::test_utils::create_small_table_cache;
+
+    #[tokio::test]
+    async fn test_into_stream() {
+        let sstable_store = mock_sstable_store();
+        let read_options = Arc::new(SstableIteratorReadOptions::default());
+        let table = gen_iterator_test_sstable_base(
+            0,
+            default_builder_opt_for_test(),
+            |x| x,
+            sstable_store.clone(),
+            TEST_KEYS_COUNT,
+        )
+        .await;
+        let cache = create_small_table_cache();
+        let iters = vec![SstableIterator::create(
+            cache.insert(table.id, table.id, 1, Box::new(table)),
+            sstable_store,
+            read_options,
+        )];
+
+        let mi = UnorderedMergeIteratorInner::new(iters);
+        let mut ui = UserIterator::for_test(mi, (Unbounded, Unbounded));
+        ui.rewind().await.unwrap();
+
+        let kvs: Vec<UserIterItem> = ui.into_stream().try_collect().await.unwrap();
+        assert_eq!(kvs.len(), TEST_KEYS_COUNT);
+        for (i, (key, value)) in kvs.into_iter().enumerate() {
+            assert_eq!(key, iterator_test_bytes_user_key_of(i).encode());
+            assert_eq!(value, iterator_test_value_of(i));
+        }
+    }
+}
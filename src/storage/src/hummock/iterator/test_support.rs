@@ -0,0 +1,168 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test-support utilities for [`HummockIterator`] implementations.
+//!
+//! This module re-exports the key/value generators of [`test_utils`](super::test_utils) and adds
+//! a generic checker that validates any forward iterator against a truth map, so that new iterator
+//! implementations get their basic contract covered without hand-rolling the assertions. It is
+//! only available in tests or with the `test` feature enabled.
+
+use std::collections::BTreeMap;
+
+use itertools::Itertools;
+use risingwave_hummock_sdk::key::FullKey;
+
+pub use super::test_utils::*;
+use super::{Forward, HummockIterator};
+use crate::hummock::{HummockResult, HummockValue};
+
+/// The expected contents of an iterator, ordered the same way the iterator orders its entries.
+pub type TruthMap = BTreeMap<FullKey<Vec<u8>>, HummockValue<Vec<u8>>>;
+
+/// Builds a [`TruthMap`] from `(idx, epoch, value)` triples using the same key generators as
+/// [`gen_iterator_test_sstable_from_kv_pair`], so that an SST and its truth can be derived from
+/// one set of pairs.
+pub fn truth_map_of(
+    kv_pairs: impl IntoIterator<Item = (usize, u64, HummockValue<Vec<u8>>)>,
+) -> TruthMap {
+    kv_pairs
+        .into_iter()
+        .map(|(idx, epoch, value)| (iterator_test_key_of_epoch(idx, epoch), value))
+        .collect()
+}
+
+/// Validates an arbitrary forward [`HummockIterator`] against `truth`:
+///
+/// - a full scan after `rewind` must yield exactly the entries of `truth`, in order;
+/// - `seek` to every key in `truth` must position the iterator on that entry;
+/// - `seek` strictly between two entries must position the iterator on the later one, and `seek`
+///   past the largest key must invalidate the iterator.
+///
+/// Panics on the first violated assertion; IO errors of the iterator are propagated.
+pub async fn validate_iterator_against_truth<I>(iter: &mut I, truth: &TruthMap) -> HummockResult<()>
+where
+    I: HummockIterator<Direction = Forward>,
+{
+    // Full scan.
+    iter.rewind().await?;
+    for (key, value) in truth {
+        assert!(
+            iter.is_valid(),
+            "iterator ends before the truth entry {:?}",
+            key
+        );
+        assert_eq!(iter.key(), key.to_ref(), "full scan yields a wrong key");
+        assert_eq!(
+            iter.value(),
+            value.as_slice(),
+            "full scan yields a wrong value for key {:?}",
+            key
+        );
+        iter.next().await?;
+    }
+    assert!(!iter.is_valid(), "iterator yields entries beyond the truth");
+
+    // Seek to every existing key.
+    for (key, value) in truth {
+        iter.seek(key.to_ref()).await?;
+        assert!(iter.is_valid(), "seek to {:?} finds nothing", key);
+        assert_eq!(iter.key(), key.to_ref(), "seek to {:?} overshoots", key);
+        assert_eq!(
+            iter.value(),
+            value.as_slice(),
+            "seek to {:?} yields a wrong value",
+            key
+        );
+    }
+
+    // Seek strictly between entries. A key with the same user key but a smaller epoch orders
+    // right after the entry itself, so the iterator must land on the next entry, or become
+    // invalid after the last one.
+    let entries = truth.iter().collect_vec();
+    for (i, (key, _)) in entries.iter().enumerate() {
+        if key.epoch == 0 {
+            continue;
+        }
+        let probe = FullKey {
+            user_key: key.user_key.as_ref(),
+            epoch: key.epoch - 1,
+        };
+        iter.seek(probe).await?;
+        match entries.get(i + 1) {
+            Some((next_key, next_value)) => {
+                assert!(iter.is_valid(), "seek past {:?} finds nothing", key);
+                assert_eq!(
+                    iter.key(),
+                    next_key.to_ref(),
+                    "seek past {:?} does not land on the next entry",
+                    key
+                );
+                assert_eq!(
+                    iter.value(),
+                    next_value.as_slice(),
+                    "seek past {:?} yields a wrong value",
+                    key
+                );
+            }
+            None => {
+                assert!(
+                    !iter.is_valid(),
+                    "seek past the largest key {:?} still finds an entry",
+                    key
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::hummock::sstable::SstableIteratorReadOptions;
+    use crate::hummock::test_utils::create_small_table_cache;
+    use crate::hummock::{SstableIterator, SstableIteratorType};
+
+    #[tokio::test]
+    async fn test_validate_sstable_iterator_against_truth() {
+        let sstable_store = mock_sstable_store();
+        let kv_pairs = (0..TEST_KEYS_COUNT)
+            .flat_map(|idx| {
+                [
+                    (idx, 300, HummockValue::put(iterator_test_value_of(idx))),
+                    (idx, 100, HummockValue::delete()),
+                ]
+            })
+            .collect_vec();
+        let table =
+            gen_iterator_test_sstable_from_kv_pair(0, kv_pairs.clone(), sstable_store.clone())
+                .await;
+        let cache = create_small_table_cache();
+        let handle = cache.insert(table.id, table.id, 1, Box::new(table));
+        let mut iter = SstableIterator::create(
+            handle,
+            sstable_store,
+            Arc::new(SstableIteratorReadOptions::default()),
+        );
+
+        let truth = truth_map_of(kv_pairs);
+        validate_iterator_against_truth(&mut iter, &truth)
+            .await
+            .unwrap();
+    }
+}
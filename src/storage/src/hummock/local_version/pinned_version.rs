@@ -136,6 +136,11 @@ impl PinnedVersion {
         ret
     }
 
+    /// All tables that this version contains data of.
+    pub fn table_ids(&self) -> impl Iterator<Item = TableId> + '_ {
+        self.compaction_group_index.keys().copied()
+    }
+
     pub fn levels(&self, table_id: TableId) -> Vec<&Level> {
         match self.compaction_group_index.get(&table_id) {
             Some(compaction_group_id) => self.levels_by_compaction_groups_id(*compaction_group_id),
@@ -14,6 +14,7 @@
 
 //! Hummock is the state store of the streaming system.
 
+use std::collections::HashSet;
 use std::ops::Deref;
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
@@ -25,7 +26,7 @@ use risingwave_hummock_sdk::key::{FullKey, TableKey};
 use risingwave_hummock_sdk::{HummockEpoch, *};
 #[cfg(any(test, feature = "test"))]
 use risingwave_pb::hummock::HummockVersion;
-use risingwave_pb::hummock::{version_update_payload, SstableInfo};
+use risingwave_pb::hummock::{version_update_payload, KeyRange, SstableInfo};
 use risingwave_rpc_client::HummockMetaClient;
 use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
 use tracing::log::error;
@@ -36,7 +37,7 @@ pub use block_cache::*;
 use crate::hummock::store::state_store::LocalHummockStorage;
 use crate::opts::StorageOpts;
 
-#[cfg(target_os = "linux")]
+#[cfg(unix)]
 pub mod file_cache;
 
 mod tiered_cache;
@@ -57,6 +58,7 @@ mod state_store;
 pub mod test_utils;
 pub mod utils;
 pub use utils::MemoryLimiter;
+pub mod backup;
 pub mod backup_reader;
 pub mod event_handler;
 pub mod local_version;
@@ -68,6 +70,7 @@ pub mod value;
 
 pub use error::*;
 pub use risingwave_common::cache::{CacheableEntry, LookupResult, LruCache};
+use risingwave_common::catalog::TableId;
 use risingwave_common_service::observer_manager::{NotificationClient, ObserverManager};
 use risingwave_hummock_sdk::filter_key_extractor::{
     FilterKeyExtractorManager, FilterKeyExtractorManagerRef,
@@ -80,6 +83,7 @@ use self::iterator::HummockIterator;
 pub use self::sstable_store::*;
 use super::monitor::HummockStateStoreMetrics;
 #[cfg(any(test, feature = "test"))]
+use crate::hummock::backup::HummockBackup;
 use crate::hummock::backup_reader::BackupReader;
 use crate::hummock::backup_reader::BackupReaderRef;
 use crate::hummock::compactor::CompactorContext;
@@ -225,6 +229,10 @@ impl HummockStorage {
     }
 
     async fn new_local_inner(&self, option: NewLocalOptions) -> LocalHummockStorage {
+        assert!(
+            !self.is_read_only(),
+            "cannot create a local state store in read-only replica mode"
+        );
         let (tx, rx) = tokio::sync::oneshot::channel();
         self.hummock_event_sender
             .send(HummockEvent::RegisterReadVersion {
@@ -245,6 +253,12 @@ impl HummockStorage {
         )
     }
 
+    /// Whether this state store runs in read-only replica mode, in which local writes, epoch
+    /// syncs and sealing are rejected and reads are served from pinned versions only.
+    pub fn is_read_only(&self) -> bool {
+        self.context.storage_opts.read_only
+    }
+
     pub fn sstable_store(&self) -> SstableStoreRef {
         self.context.sstable_store.clone()
     }
@@ -264,6 +278,86 @@ impl HummockStorage {
     pub fn get_pinned_version(&self) -> PinnedVersion {
         self.pinned_version.load().deref().deref().clone()
     }
+
+    /// Warms up the meta cache and the block cache with the SSTs of `table_ids` in the currently
+    /// pinned version, so that reads right after a recovery are not served entirely from a cold
+    /// cache. Recency is used as the proxy for hotness: the overlapping L0 sub-levels are warmed
+    /// up newest-first, followed by the lower levels, and block preloading stops once half of the
+    /// block cache capacity has been filled.
+    pub async fn warm_up(&self, table_ids: &[TableId]) {
+        let version = self.get_pinned_version();
+        let mut visited_sst_ids = HashSet::new();
+        let mut ssts = vec![];
+        for table_id in table_ids {
+            for level in version.levels(*table_id) {
+                for sst in &level.table_infos {
+                    if sst.table_ids.contains(&table_id.table_id) && visited_sst_ids.insert(sst.id)
+                    {
+                        ssts.push(sst.clone());
+                    }
+                }
+            }
+        }
+        let max_preload_bytes = self.context.storage_opts.block_cache_capacity_mb * (1 << 20) / 2;
+        self.context
+            .sstable_store
+            .warm_up(ssts, max_preload_bytes)
+            .await;
+    }
+
+    /// Backs up the currently pinned version to `target_object_prefix` via [`HummockBackup`] and
+    /// returns the id of the backed-up version. The version stays pinned for the duration of the
+    /// backup, so none of its SSTs can be vacuumed while they are being copied.
+    pub async fn backup(&self, target_object_prefix: &str) -> HummockResult<HummockVersionId> {
+        let pinned_version = self.get_pinned_version();
+        let version = pinned_version.version();
+        HummockBackup::backup(
+            &version,
+            self.context.sstable_store.clone(),
+            target_object_prefix,
+        )
+        .await?;
+        Ok(version.id)
+    }
+
+    /// Triggers a manual compaction of the given `level` of compaction group
+    /// `compaction_group_id` via the meta client, restricted to `table_id` and optionally to
+    /// `key_range`. This lets operators force compaction of a hot key range, e.g. after a large
+    /// deletion, instead of waiting for the background heuristics to pick it up.
+    pub async fn trigger_manual_compaction(
+        &self,
+        compaction_group_id: u64,
+        table_id: u32,
+        level: u32,
+        key_range: Option<KeyRange>,
+    ) -> HummockResult<()> {
+        self.context
+            .hummock_meta_client
+            .trigger_manual_compaction(compaction_group_id, table_id, level, key_range)
+            .await
+            .map_err(HummockError::meta_error)
+    }
+
+    /// Registers pre-built, sorted SST files carrying data of `epoch` with the version manager
+    /// directly, bypassing the shared buffer. The SSTs must be non-overlapping with each other,
+    /// contain only data of `epoch`, and have been uploaded to the object store already. They
+    /// become readable immediately and are committed together with the other data of the epoch on
+    /// its next checkpoint sync.
+    pub fn ingest_external_sst(&self, epoch: HummockEpoch, sstable_infos: Vec<LocalSstableInfo>) {
+        assert!(
+            !self.is_read_only(),
+            "cannot ingest external SSTs in read-only replica mode"
+        );
+        if sstable_infos.is_empty() {
+            return;
+        }
+        self.hummock_event_sender
+            .send(HummockEvent::IngestExternalSst {
+                epoch,
+                sstable_infos,
+            })
+            .unwrap();
+    }
 }
 
 #[cfg(any(test, feature = "test"))]
@@ -366,11 +460,14 @@ pub async fn get_from_sstable_info(
     }
 
     // TODO: now SstableIterator does not use prefetch through SstableIteratorReadOptions, so we
-    // use default before refinement.
+    // only propagate the cache policy before refinement.
     let mut iter = SstableIterator::create(
         sstable,
         sstable_store_ref.clone(),
-        Arc::new(SstableIteratorReadOptions::default()),
+        Arc::new(SstableIteratorReadOptions {
+            cache_policy: read_options.cache_policy,
+            ..Default::default()
+        }),
     );
     iter.seek(full_key).await?;
     // Iterator has sought passed the borders.
@@ -45,6 +45,9 @@ pub(crate) struct SharedBufferBatchInner {
     range_tombstone_list: Vec<DeleteRangeTombstone>,
     largest_table_key: Vec<u8>,
     smallest_table_key: Vec<u8>,
+    /// Whether there's a range tombstone covering everything up to the end of the table key
+    /// space, in which case `largest_table_key` is meaningless as the right boundary.
+    largest_table_key_unbounded: bool,
     size: usize,
     _tracker: Option<MemoryTracker>,
     batch_id: SharedBufferBatchId,
@@ -60,6 +63,7 @@ impl SharedBufferBatchInner {
         let mut largest_table_key = vec![];
         let mut smallest_table_key = vec![];
         let mut smallest_empty = true;
+        let mut largest_table_key_unbounded = false;
         if !range_tombstone_list.is_empty() {
             range_tombstone_list.sort();
             let mut range_tombstones: Vec<DeleteRangeTombstone> = vec![];
@@ -67,7 +71,11 @@ impl SharedBufferBatchInner {
                 // Although `end_user_key` of tombstone is exclusive, we still use it as a boundary
                 // of `SharedBufferBatch` because it just expands an useless query
                 // and does not affect correctness.
-                if largest_table_key.lt(&tombstone.end_user_key.table_key.0) {
+                if tombstone.end_user_key.table_id != tombstone.start_user_key.table_id {
+                    // The tombstone covers everything up to the end of the table key space, whose
+                    // exclusive end is the zero-length table key of the next table id.
+                    largest_table_key_unbounded = true;
+                } else if largest_table_key.lt(&tombstone.end_user_key.table_key.0) {
                     largest_table_key.clear();
                     largest_table_key.extend_from_slice(&tombstone.end_user_key.table_key.0);
                 }
@@ -107,6 +115,7 @@ impl SharedBufferBatchInner {
             size,
             largest_table_key,
             smallest_table_key,
+            largest_table_key_unbounded,
             _tracker,
             batch_id: SHARED_BUFFER_BATCH_ID_GENERATOR.fetch_add(1, Relaxed),
         }
@@ -178,11 +187,12 @@ impl SharedBufferBatch {
         B: AsRef<[u8]>,
     {
         self.table_id == table_id
-            && range_overlap(
-                table_key_range,
-                *self.start_table_key(),
-                *self.end_table_key(),
-            )
+            && (self.inner.largest_table_key_unbounded
+                || range_overlap(
+                    table_key_range,
+                    *self.start_table_key(),
+                    *self.end_table_key(),
+                ))
     }
 
     pub fn get(&self, table_key: TableKey<&[u8]>) -> Option<HummockValue<Bytes>> {
@@ -198,16 +208,18 @@ impl SharedBufferBatch {
         if self.inner.range_tombstone_list.is_empty() {
             return false;
         }
+        // Compare by user key rather than table key, so that a tombstone whose end is the
+        // zero-length table key of the next table id correctly covers all table keys.
+        let user_key = UserKey::new(self.table_id, table_key);
         let idx = self
             .inner
             .range_tombstone_list
-            .partition_point(|item| item.end_user_key.table_key.as_ref().le(table_key.as_ref()));
+            .partition_point(|item| item.end_user_key.as_ref().le(&user_key));
         idx < self.inner.range_tombstone_list.len()
             && self.inner.range_tombstone_list[idx]
                 .start_user_key
-                .table_key
                 .as_ref()
-                .le(table_key.as_ref())
+                .le(&user_key)
     }
 
     pub fn range_exists(&self, table_key_range: &TableKeyRange) -> bool {
@@ -316,12 +328,23 @@ impl SharedBufferBatch {
         let delete_range_tombstones = delete_ranges
             .into_iter()
             .map(|(start_table_key, end_table_key)| {
-                DeleteRangeTombstone::new(
-                    table_id,
-                    start_table_key.to_vec(),
-                    end_table_key.to_vec(),
-                    epoch,
-                )
+                // An empty `end_table_key` means the range is unbounded on the right, i.e. the
+                // tombstone covers everything from `start_table_key` to the end of the key space
+                // of this table.
+                if end_table_key.is_empty() {
+                    DeleteRangeTombstone::new_until_table_end(
+                        table_id,
+                        start_table_key.to_vec(),
+                        epoch,
+                    )
+                } else {
+                    DeleteRangeTombstone::new(
+                        table_id,
+                        start_table_key.to_vec(),
+                        end_table_key.to_vec(),
+                        epoch,
+                    )
+                }
             })
             .collect_vec();
         #[cfg(test)]
@@ -348,9 +371,12 @@ impl SharedBufferBatch {
                 tombstone.start_user_key.table_id, table_id,
                 "delete range tombstone in a shared buffer batch must begin with the same table id"
             );
-            assert_eq!(
-                tombstone.end_user_key.table_id, table_id,
-                "delete range tombstone in a shared buffer batch must begin with the same table id"
+            assert!(
+                tombstone.end_user_key.table_id == table_id
+                    || (tombstone.end_user_key.table_id.table_id() == table_id.table_id() + 1
+                        && tombstone.end_user_key.table_key.0.is_empty()),
+                "delete range tombstone in a shared buffer batch must end within the same table \
+                 id, or at the zero-length table key of the next table id"
             );
         }
     }
@@ -412,6 +438,10 @@ impl<D: HummockIteratorDirection> HummockIterator for SharedBufferBatchIterator<
         self.current_item().1.as_slice()
     }
 
+    fn value_bytes(&self) -> HummockValue<Bytes> {
+        self.current_item().1.clone()
+    }
+
     fn is_valid(&self) -> bool {
         self.current_idx < self.inner.len()
     }
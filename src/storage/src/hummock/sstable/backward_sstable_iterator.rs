@@ -13,20 +13,30 @@
 // limitations under the License.
 
 use std::cmp::Ordering::{Equal, Less};
+use std::collections::VecDeque;
 use std::future::Future;
 use std::sync::Arc;
 
+use bytes::Bytes;
 use risingwave_hummock_sdk::key::FullKey;
 use risingwave_hummock_sdk::KeyComparator;
+use tokio::task::JoinHandle;
 
 use crate::hummock::iterator::{Backward, HummockIterator};
 use crate::hummock::sstable::SstableIteratorReadOptions;
 use crate::hummock::value::HummockValue;
 use crate::hummock::{
-    BlockIterator, HummockResult, SstableIteratorType, SstableStoreRef, TableHolder,
+    BlockHolder, BlockIterator, CachePolicy, HummockError, HummockResult, Sstable,
+    SstableIteratorType, SstableStoreRef, TableHolder,
 };
 use crate::monitor::StoreLocalStatistic;
 
+/// An in-flight fetch of an upcoming (i.e. preceding) block.
+struct BlockFetch {
+    idx: usize,
+    handle: JoinHandle<HummockResult<(BlockHolder, StoreLocalStatistic)>>,
+}
+
 /// Iterates backwards on a sstable.
 pub struct BackwardSstableIterator {
     /// The iterator of the current block.
@@ -40,49 +50,176 @@ pub struct BackwardSstableIterator {
 
     sstable_store: SstableStoreRef,
 
+    /// Number of blocks to fetch ahead of the cursor, to hide object-store latency of descending
+    /// scans, e.g. `ORDER BY ... DESC LIMIT n` queries. 0 disables read-ahead.
+    read_ahead_count: usize,
+
+    /// In-flight fetches of the blocks preceding the current one, the one closest to the cursor
+    /// at the front.
+    read_ahead: VecDeque<BlockFetch>,
+
+    /// An owned copy of the sstable for the spawned fetches. Only created when read-ahead is
+    /// enabled.
+    read_ahead_sst: Option<Arc<Sstable>>,
+
+    /// A hint for the number of keys the caller will consume. Once that many keys have been
+    /// yielded, no further read-ahead is issued; blocks are still fetched on demand, so iterating
+    /// beyond the hint stays correct. See [`crate::store::ReadOptions::limit`].
+    limit: Option<usize>,
+
+    cache_policy: CachePolicy,
+
     stats: StoreLocalStatistic,
 }
 
 impl BackwardSstableIterator {
     pub fn new(sstable: TableHolder, sstable_store: SstableStoreRef) -> Self {
+        Self::new_inner(sstable, sstable_store, 0, None, CachePolicy::Fill)
+    }
+
+    /// Creates an iterator that keeps up to `read_ahead_count` fetches of the upcoming blocks in
+    /// flight while the current block is consumed.
+    pub fn with_read_ahead(
+        sstable: TableHolder,
+        sstable_store: SstableStoreRef,
+        read_ahead_count: usize,
+    ) -> Self {
+        Self::new_inner(
+            sstable,
+            sstable_store,
+            read_ahead_count,
+            None,
+            CachePolicy::Fill,
+        )
+    }
+
+    fn new_inner(
+        sstable: TableHolder,
+        sstable_store: SstableStoreRef,
+        read_ahead_count: usize,
+        limit: Option<usize>,
+        cache_policy: CachePolicy,
+    ) -> Self {
         Self {
             block_iter: None,
             cur_idx: sstable.value().meta.block_metas.len() - 1,
             sst: sstable,
             sstable_store,
+            read_ahead_count,
+            read_ahead: VecDeque::new(),
+            read_ahead_sst: None,
+            limit,
+            cache_policy,
             stats: StoreLocalStatistic::default(),
         }
     }
 
+    /// Issues fetches until `read_ahead_count` of the blocks preceding `cur_idx` are in flight,
+    /// unless the caller already got all the keys it asked for.
+    fn fill_read_ahead(&mut self) {
+        if self.read_ahead_count == 0 {
+            return;
+        }
+        if let Some(limit) = self.limit && self.stats.total_key_count >= limit as u64 {
+            return;
+        }
+        let sst = self
+            .read_ahead_sst
+            .get_or_insert_with(|| Arc::new(self.sst.value().as_ref().clone()))
+            .clone();
+        let mut next_idx = match self.read_ahead.back() {
+            Some(fetch) => fetch.idx as isize - 1,
+            None => self.cur_idx as isize - 1,
+        };
+        while self.read_ahead.len() < self.read_ahead_count && next_idx >= 0 {
+            let idx = next_idx as usize;
+            let sstable_store = self.sstable_store.clone();
+            let sst = sst.clone();
+            let cache_policy = self.cache_policy;
+            let handle = tokio::spawn(async move {
+                let mut stats = StoreLocalStatistic::default();
+                let block = sstable_store
+                    .get(&sst, idx as u64, cache_policy, &mut stats)
+                    .await?;
+                Ok((block, stats))
+            });
+            self.read_ahead.push_back(BlockFetch { idx, handle });
+            next_idx -= 1;
+        }
+    }
+
+    /// Aborts and drains all in-flight fetches, accounting for the work of those that already
+    /// completed.
+    async fn clear_read_ahead(&mut self) {
+        for fetch in self.read_ahead.drain(..) {
+            fetch.handle.abort();
+            if let Ok(Ok((_, stats))) = fetch.handle.await {
+                self.stats.add(&stats);
+            }
+        }
+    }
+
     /// Seeks to a block, and then seeks to the key if `seek_key` is given.
     async fn seek_idx(&mut self, idx: isize, seek_key: Option<&[u8]>) -> HummockResult<()> {
         if idx >= self.sst.value().block_count() as isize || idx < 0 {
+            self.clear_read_ahead().await;
             self.block_iter = None;
         } else {
-            let block = self
-                .sstable_store
-                .get(
-                    self.sst.value(),
-                    idx as u64,
-                    crate::hummock::CachePolicy::Fill,
-                    &mut self.stats,
-                )
-                .await?;
-            let mut block_iter = BlockIterator::new(block);
+            let block = if self
+                .read_ahead
+                .front()
+                .map_or(false, |fetch| fetch.idx == idx as usize)
+            {
+                let fetch = self.read_ahead.pop_front().unwrap();
+                let (block, stats) = fetch.handle.await.map_err(HummockError::other)??;
+                self.stats.add(&stats);
+                block
+            } else {
+                // After a seek, the in-flight fetches are no longer adjacent to the cursor.
+                self.clear_read_ahead().await;
+                self.sstable_store
+                    .get(
+                        self.sst.value(),
+                        idx as u64,
+                        self.cache_policy,
+                        &mut self.stats,
+                    )
+                    .await?
+            };
+            // Reuse the existing block iterator and its key scratch buffer if there is one, to
+            // avoid re-allocating them for every block of the scan.
+            self.stats.block_iter_pool_total_count += 1;
+            let block_iter = match self.block_iter.as_mut() {
+                Some(block_iter) => {
+                    self.stats.block_iter_pool_hit_count += 1;
+                    block_iter.reset(block);
+                    block_iter
+                }
+                None => self.block_iter.insert(BlockIterator::new(block)),
+            };
             if let Some(key) = seek_key {
                 block_iter.seek_le(key);
             } else {
                 block_iter.seek_to_last();
             }
 
-            self.block_iter = Some(block_iter);
             self.cur_idx = idx as usize;
+            self.fill_read_ahead();
         }
 
         Ok(())
     }
 }
 
+impl Drop for BackwardSstableIterator {
+    fn drop(&mut self) {
+        // Don't let in-flight fetches outlive the scan.
+        for fetch in &self.read_ahead {
+            fetch.handle.abort();
+        }
+    }
+}
+
 impl HummockIterator for BackwardSstableIterator {
     type Direction = Backward;
 
@@ -113,6 +250,12 @@ impl HummockIterator for BackwardSstableIterator {
         HummockValue::from_slice(raw_value).expect("decode error")
     }
 
+    fn value_bytes(&self) -> HummockValue<Bytes> {
+        let raw_value = self.block_iter.as_ref().expect("no block iter").value_bytes();
+
+        HummockValue::from_bytes(raw_value).expect("decode error")
+    }
+
     fn is_valid(&self) -> bool {
         self.block_iter.as_ref().map_or(false, |i| i.is_valid())
     }
@@ -167,9 +310,15 @@ impl SstableIteratorType for BackwardSstableIterator {
     fn create(
         sstable: TableHolder,
         sstable_store: SstableStoreRef,
-        _: Arc<SstableIteratorReadOptions>,
+        options: Arc<SstableIteratorReadOptions>,
     ) -> Self {
-        BackwardSstableIterator::new(sstable, sstable_store)
+        BackwardSstableIterator::new_inner(
+            sstable,
+            sstable_store,
+            options.backward_read_ahead_count,
+            options.limit,
+            options.cache_policy,
+        )
     }
 }
 
@@ -216,6 +365,40 @@ mod tests {
         assert_eq!(cnt, 0);
     }
 
+    #[tokio::test]
+    async fn test_backward_sstable_read_ahead() {
+        let sstable_store = mock_sstable_store();
+        let sstable =
+            gen_default_test_sstable(default_builder_opt_for_test(), 0, sstable_store.clone())
+                .await;
+        assert!(sstable.meta.block_metas.len() > 10);
+        let cache = create_small_table_cache();
+        let handle = cache.insert(0, 0, 1, Box::new(sstable));
+        let mut sstable_iter = BackwardSstableIterator::with_read_ahead(handle, sstable_store, 4);
+        let mut cnt = TEST_KEYS_COUNT;
+        sstable_iter.rewind().await.unwrap();
+
+        while sstable_iter.is_valid() {
+            cnt -= 1;
+            let key = sstable_iter.key();
+            let value = sstable_iter.value();
+            assert_eq!(key, test_key_of(cnt).to_ref());
+            assert_bytes_eq!(value.into_user_value().unwrap(), test_value_of(cnt));
+            sstable_iter.next().await.unwrap();
+        }
+        assert_eq!(cnt, 0);
+
+        // A seek discards the in-flight fetches that are no longer adjacent to the cursor.
+        sstable_iter
+            .seek(test_key_of(TEST_KEYS_COUNT / 2).to_ref())
+            .await
+            .unwrap();
+        assert_eq!(
+            sstable_iter.key(),
+            test_key_of(TEST_KEYS_COUNT / 2).to_ref()
+        );
+    }
+
     #[tokio::test]
     async fn test_backward_sstable_seek() {
         let sstable_store = mock_sstable_store();
@@ -28,6 +28,7 @@ use crate::hummock::{HummockError, HummockResult};
 pub const DEFAULT_BLOCK_SIZE: usize = 4 * 1024;
 pub const DEFAULT_RESTART_INTERVAL: usize = 16;
 pub const DEFAULT_ENTRY_SIZE: usize = 24; // table_id(u64) + primary_key(u64) + epoch(u64)
+pub const DEFAULT_COMPRESSION_LEVEL: u32 = 4;
 
 #[derive(Clone)]
 pub struct Block {
@@ -133,6 +134,11 @@ impl Block {
         &self.data[..self.data_len]
     }
 
+    /// Shared [`Bytes`] version of [`Self::data`].
+    pub fn data_bytes(&self) -> Bytes {
+        self.data.slice(..self.data_len)
+    }
+
     pub fn raw_data(&self) -> &[u8] {
         &self.data[..]
     }
@@ -210,6 +216,8 @@ pub struct BlockBuilderOptions {
     pub capacity: usize,
     /// Compression algorithm.
     pub compression_algorithm: CompressionAlgorithm,
+    /// Compression level of the compression algorithm.
+    pub compression_level: u32,
     /// Restart point interval.
     pub restart_interval: usize,
 }
@@ -219,6 +227,7 @@ impl Default for BlockBuilderOptions {
         Self {
             capacity: DEFAULT_BLOCK_SIZE,
             compression_algorithm: CompressionAlgorithm::None,
+            compression_level: DEFAULT_COMPRESSION_LEVEL,
             restart_interval: DEFAULT_RESTART_INTERVAL,
         }
     }
@@ -238,6 +247,8 @@ pub struct BlockBuilder {
     entry_count: usize,
     /// Compression algorithm.
     compression_algorithm: CompressionAlgorithm,
+    /// Compression level of the compression algorithm.
+    compression_level: u32,
 }
 
 impl BlockBuilder {
@@ -252,6 +263,7 @@ impl BlockBuilder {
             last_key: vec![],
             entry_count: 0,
             compression_algorithm: options.compression_algorithm,
+            compression_level: options.compression_level,
         }
     }
 
@@ -345,7 +357,7 @@ impl BlockBuilder {
             CompressionAlgorithm::None => (),
             CompressionAlgorithm::Lz4 => {
                 let mut encoder = lz4::EncoderBuilder::new()
-                    .level(4)
+                    .level(self.compression_level)
                     .build(BytesMut::with_capacity(self.buf.len()).writer())
                     .map_err(HummockError::encode_error)
                     .unwrap();
@@ -358,10 +370,12 @@ impl BlockBuilder {
                 self.buf = writer.into_inner();
             }
             CompressionAlgorithm::Zstd => {
-                let mut encoder =
-                    zstd::Encoder::new(BytesMut::with_capacity(self.buf.len()).writer(), 4)
-                        .map_err(HummockError::encode_error)
-                        .unwrap();
+                let mut encoder = zstd::Encoder::new(
+                    BytesMut::with_capacity(self.buf.len()).writer(),
+                    self.compression_level as i32,
+                )
+                .map_err(HummockError::encode_error)
+                .unwrap();
                 encoder
                     .write_all(&self.buf[..])
                     .map_err(HummockError::encode_error)
@@ -393,6 +407,22 @@ mod tests {
     use super::*;
     use crate::hummock::{BlockHolder, BlockIterator};
 
+    #[test]
+    fn test_block_checksum_mismatch() {
+        let options = BlockBuilderOptions::default();
+        let mut builder = BlockBuilder::new(options);
+        builder.add(&full_key(b"k1", 1), b"v01");
+        let capacity = builder.uncompressed_block_size();
+        let mut buf = builder.build().to_vec();
+        buf[0] ^= 0xff;
+        let err = Block::decode(buf.into(), capacity)
+            .unwrap_err()
+            .with_block_location(42, 4096);
+        let msg = err.to_string();
+        assert!(msg.contains("SST 42"), "{}", msg);
+        assert!(msg.contains("offset 4096"), "{}", msg);
+    }
+
     #[test]
     fn test_block_enc_dec() {
         let options = BlockBuilderOptions::default();
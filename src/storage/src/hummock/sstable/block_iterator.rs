@@ -15,7 +15,7 @@
 use std::cmp::Ordering;
 use std::ops::Range;
 
-use bytes::BytesMut;
+use bytes::{Bytes, BytesMut};
 use risingwave_hummock_sdk::KeyComparator;
 
 use super::KeyPrefix;
@@ -49,6 +49,20 @@ impl BlockIterator {
         }
     }
 
+    /// Points the iterator to another block, keeping the allocated key scratch buffer. Scans
+    /// visit one block after another, so reusing the iterator avoids re-allocating the scratch
+    /// buffer for every block.
+    ///
+    /// The iterator is invalid afterwards until one of the seek methods is called.
+    pub fn reset(&mut self, block: BlockHolder) {
+        self.block = block;
+        self.offset = usize::MAX;
+        self.restart_point_index = usize::MAX;
+        self.key.clear();
+        self.value_range = 0..0;
+        self.entry_len = 0;
+    }
+
     pub fn next(&mut self) {
         assert!(self.is_valid());
         self.next_inner();
@@ -79,6 +93,12 @@ impl BlockIterator {
         &self.block.data()[self.value_range.clone()]
     }
 
+    /// Shared [`Bytes`] version of [`Self::value`], pointing into the block data without a copy.
+    pub fn value_bytes(&self) -> Bytes {
+        assert!(self.is_valid());
+        self.block.data_bytes().slice(self.value_range.clone())
+    }
+
     pub fn is_valid(&self) -> bool {
         self.offset < self.block.len()
     }
@@ -29,7 +29,7 @@ use risingwave_pb::hummock::SstableInfo;
 use super::utils::CompressionAlgorithm;
 use super::{
     BlockBuilder, BlockBuilderOptions, BlockMeta, SstableMeta, SstableWriter, DEFAULT_BLOCK_SIZE,
-    DEFAULT_ENTRY_SIZE, DEFAULT_RESTART_INTERVAL, VERSION,
+    DEFAULT_COMPRESSION_LEVEL, DEFAULT_ENTRY_SIZE, DEFAULT_RESTART_INTERVAL, VERSION,
 };
 use crate::hummock::sstable::{FilterBuilder, XorFilterBuilder};
 use crate::hummock::value::HummockValue;
@@ -50,17 +50,27 @@ pub struct SstableBuilderOptions {
     pub bloom_false_positive: f64,
     /// Compression algorithm.
     pub compression_algorithm: CompressionAlgorithm,
+    /// Compression level of the compression algorithm.
+    pub compression_level: u32,
 }
 
 impl From<&StorageOpts> for SstableBuilderOptions {
     fn from(options: &StorageOpts) -> SstableBuilderOptions {
         let capacity = (options.sstable_size_mb as usize) * (1 << 20);
+        let compression_algorithm = options
+            .sstable_compression_algorithm
+            .parse()
+            .unwrap_or_else(|e| {
+                tracing::warn!("{}, fall back to no compression", e);
+                CompressionAlgorithm::None
+            });
         SstableBuilderOptions {
             capacity,
             block_capacity: (options.block_size_kb as usize) * (1 << 10),
-            restart_interval: DEFAULT_RESTART_INTERVAL,
+            restart_interval: options.block_restart_interval,
             bloom_false_positive: options.bloom_false_positive,
-            compression_algorithm: CompressionAlgorithm::None,
+            compression_algorithm,
+            compression_level: options.sstable_compression_level,
         }
     }
 }
@@ -73,6 +83,7 @@ impl Default for SstableBuilderOptions {
             restart_interval: DEFAULT_RESTART_INTERVAL,
             bloom_false_positive: DEFAULT_BLOOM_FALSE_POSITIVE,
             compression_algorithm: CompressionAlgorithm::None,
+            compression_level: DEFAULT_COMPRESSION_LEVEL,
         }
     }
 }
@@ -111,6 +122,8 @@ pub struct SstableBuilder<W: SstableWriter, F: FilterBuilder> {
     stale_key_count: u64,
     /// `total_key_count` counts range_tombstones as well.
     total_key_count: u64,
+    /// `tombstone_key_count` counts point deletes and range_tombstones.
+    tombstone_key_count: u64,
     /// Per table stats.
     table_stats: TableStatsMap,
     /// `last_table_stats` accumulates stats for `last_table_id` and finalizes it in `table_stats`
@@ -152,6 +165,7 @@ impl<W: SstableWriter, F: FilterBuilder> SstableBuilder<W, F> {
                 capacity: options.block_capacity,
                 restart_interval: options.restart_interval,
                 compression_algorithm: options.compression_algorithm,
+                compression_level: options.compression_level,
             }),
             filter_builder,
             block_metas: Vec::with_capacity(options.capacity / options.block_capacity + 1),
@@ -166,6 +180,7 @@ impl<W: SstableWriter, F: FilterBuilder> SstableBuilder<W, F> {
             filter_key_extractor,
             stale_key_count: 0,
             total_key_count: 0,
+            tombstone_key_count: 0,
             table_stats: Default::default(),
             last_table_stats: Default::default(),
             min_epoch: u64::MAX,
@@ -237,6 +252,9 @@ impl<W: SstableWriter, F: FilterBuilder> SstableBuilder<W, F> {
         }
         self.total_key_count += 1;
         self.last_table_stats.total_key_count += 1;
+        if value.is_delete() {
+            self.tombstone_key_count += 1;
+        }
 
         if is_new_table && !self.block_builder.is_empty() {
             self.build_block().await?;
@@ -298,7 +316,13 @@ impl<W: SstableWriter, F: FilterBuilder> SstableBuilder<W, F> {
         let mut right_exclusive = false;
         let meta_offset = self.writer.data_len() as u64;
         for tombstone in &self.range_tombstones {
-            assert!(!tombstone.end_user_key.is_empty());
+            // A zero-length end table key is only allowed for a tombstone covering everything up
+            // to the end of the table key space, whose exclusive end is encoded as the
+            // zero-length table key of the next table id.
+            assert!(
+                !tombstone.end_user_key.is_empty()
+                    || tombstone.end_user_key.table_id != tombstone.start_user_key.table_id
+            );
             if largest_key.is_empty()
                 || KeyComparator::encoded_less_than_unencoded(
                     user_key(&largest_key),
@@ -325,6 +349,7 @@ impl<W: SstableWriter, F: FilterBuilder> SstableBuilder<W, F> {
         }
         self.total_key_count += self.range_tombstones.len() as u64;
         self.stale_key_count += self.range_tombstones.len() as u64;
+        self.tombstone_key_count += self.range_tombstones.len() as u64;
         let bloom_filter = if self.options.bloom_false_positive > 0.0 {
             self.filter_builder.finish()
         } else {
@@ -347,6 +372,8 @@ impl<W: SstableWriter, F: FilterBuilder> SstableBuilder<W, F> {
             version: VERSION,
             meta_offset,
             range_tombstone_list: self.range_tombstones,
+            // Recorded by the writer if it encrypts the blocks.
+            encryption_key_id: 0,
         };
         meta.estimated_size = meta.encoded_size() as u32 + meta_offset as u32;
 
@@ -379,6 +406,7 @@ impl<W: SstableWriter, F: FilterBuilder> SstableBuilder<W, F> {
             uncompressed_file_size: uncompressed_file_size + meta.encoded_size() as u64,
             min_epoch: cmp::min(self.min_epoch, tombstone_min_epoch),
             max_epoch: cmp::max(self.max_epoch, tombstone_max_epoch),
+            tombstone_key_count: self.tombstone_key_count,
         };
         tracing::trace!(
             "meta_size {} bloom_filter_size {}  add_key_counts {} stale_key_count {} min_epoch {} max_epoch {}",
@@ -484,6 +512,7 @@ pub(super) mod tests {
             restart_interval: 16,
             bloom_false_positive: 0.001,
             compression_algorithm: CompressionAlgorithm::None,
+            compression_level: DEFAULT_COMPRESSION_LEVEL,
         };
 
         let b = SstableBuilder::for_test(0, mock_sst_writer(&opt), opt);
@@ -499,6 +528,7 @@ pub(super) mod tests {
             restart_interval: 16,
             bloom_false_positive: 0.1,
             compression_algorithm: CompressionAlgorithm::None,
+            compression_level: DEFAULT_COMPRESSION_LEVEL,
         };
         let table_id = TableId::default();
         let mut b = SstableBuilder::for_test(0, mock_sst_writer(&opt), opt);
@@ -558,6 +588,7 @@ pub(super) mod tests {
             restart_interval: 16,
             bloom_false_positive: if with_blooms { 0.01 } else { 0.0 },
             compression_algorithm: CompressionAlgorithm::None,
+            compression_level: DEFAULT_COMPRESSION_LEVEL,
         };
 
         // build remote table
@@ -180,6 +180,33 @@ impl RangeTombstonesCollector {
         }
         delete_ranges
     }
+
+    /// Checks whether the key-value pair of `user_key` written in `epoch` is covered by some
+    /// range tombstone visible below the watermark. Unlike [`DeleteRangeAggregator`], the keys can
+    /// be checked in any order, so it also serves backward iteration.
+    pub fn should_delete(&self, user_key: &UserKey<&[u8]>, epoch: HummockEpoch) -> bool {
+        if epoch > self.watermark {
+            return false;
+        }
+        // Tombstones are sorted by both start and end user key after `build`, so all tombstones
+        // overlapping `user_key` locate in a consecutive range.
+        let mut idx = self
+            .range_tombstone_list
+            .partition_point(|tombstone| tombstone.end_user_key.as_ref().le(user_key));
+        while idx < self.range_tombstone_list.len()
+            && self.range_tombstone_list[idx]
+                .start_user_key
+                .as_ref()
+                .le(user_key)
+        {
+            let sequence = self.range_tombstone_list[idx].sequence;
+            if sequence <= self.watermark && sequence >= epoch {
+                return true;
+            }
+            idx += 1;
+        }
+        false
+    }
 }
 
 pub struct SingleDeleteRangeIterator {
@@ -0,0 +1,200 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Optional at-rest encryption of SST data blocks.
+//!
+//! Blocks are encrypted with AES-256-GCM right before they are uploaded to the object store and
+//! decrypted right after they are read back, so the rest of the read/write path, including the
+//! block cache, always works on plaintext blocks. The id of the key in use is recorded in the SST
+//! meta, which allows keys to be rotated: new SSTs are written with the new key while old SSTs
+//! keep decrypting with the key their meta refers to.
+
+use std::sync::Arc;
+
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use rand::RngCore;
+
+use crate::hummock::{HummockError, HummockResult};
+
+/// Length of an AES-256-GCM key in bytes.
+pub const ENCRYPTION_KEY_LEN: usize = 32;
+/// Length of the random nonce prepended to each encrypted block.
+const NONCE_LEN: usize = 12;
+/// Length of the authentication tag appended to each encrypted block.
+const TAG_LEN: usize = 16;
+
+/// An AES-256-GCM cipher for SST blocks, together with the id under which the key material is
+/// registered in the secrets subsystem.
+pub struct BlockEncryption {
+    key_id: u64,
+    cipher: Aes256Gcm,
+}
+
+impl BlockEncryption {
+    /// Creates a cipher from the raw key material registered under `key_id`. `key_id` must be
+    /// non-zero, as 0 marks unencrypted SSTs in the meta.
+    pub fn new(key_id: u64, key: &[u8; ENCRYPTION_KEY_LEN]) -> Self {
+        assert_ne!(
+            key_id, 0,
+            "encryption key id 0 is reserved for unencrypted SSTs"
+        );
+        Self {
+            key_id,
+            cipher: Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key)),
+        }
+    }
+
+    pub fn key_id(&self) -> u64 {
+        self.key_id
+    }
+
+    /// Encrypts a block with a fresh random nonce.
+    ///
+    /// Format: `| nonce (12B) | ciphertext | tag (16B) |`.
+    pub fn encrypt(&self, block: &[u8]) -> HummockResult<Vec<u8>> {
+        let mut nonce = [0u8; NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        let ciphertext = self
+            .cipher
+            .encrypt(Nonce::from_slice(&nonce), block)
+            .map_err(|_| HummockError::other("block encryption failed"))?;
+        let mut buf = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+        buf.extend_from_slice(&nonce);
+        buf.extend_from_slice(&ciphertext);
+        Ok(buf)
+    }
+
+    /// Decrypts a block encrypted by [`encrypt`](Self::encrypt), verifying the authentication
+    /// tag.
+    pub fn decrypt(&self, data: &[u8]) -> HummockResult<Vec<u8>> {
+        if data.len() < NONCE_LEN + TAG_LEN {
+            return Err(HummockError::corruption(
+                "encrypted block is too short to contain a nonce and a tag",
+            ));
+        }
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        self.cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| {
+                HummockError::corruption("block decryption failed: wrong key or corrupted data")
+            })
+    }
+}
+
+/// Resolves encryption keys by id from the secrets subsystem, so that the read path can decrypt
+/// SSTs written with any still-registered key.
+pub trait EncryptionKeyProvider: Send + Sync + 'static {
+    /// The cipher newly written SSTs are encrypted with, i.e. the newest key.
+    fn write_encryption(&self) -> HummockResult<Arc<BlockEncryption>>;
+
+    fn block_encryption(&self, key_id: u64) -> HummockResult<Arc<BlockEncryption>>;
+}
+
+/// A key provider backed by a local key file, holding one hex-encoded 32-byte key per line. Keys
+/// are numbered in order of appearance starting at 1 and must never be removed or reordered; the
+/// last key is used for newly written SSTs, so appending a line rotates the key.
+pub struct FileEncryptionKeyProvider {
+    encryptions: Vec<Arc<BlockEncryption>>,
+}
+
+impl FileEncryptionKeyProvider {
+    pub fn load(path: &str) -> HummockResult<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            HummockError::other(format!(
+                "failed to read encryption key file {}: {}",
+                path, e
+            ))
+        })?;
+        let mut encryptions = Vec::new();
+        for (idx, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let key: [u8; ENCRYPTION_KEY_LEN] = hex::decode(line)
+                .ok()
+                .and_then(|key| key.try_into().ok())
+                .ok_or_else(|| {
+                    HummockError::other(format!(
+                        "line {} of encryption key file {} is not a hex-encoded {}-byte key",
+                        idx + 1,
+                        path,
+                        ENCRYPTION_KEY_LEN
+                    ))
+                })?;
+            encryptions.push(Arc::new(BlockEncryption::new(
+                encryptions.len() as u64 + 1,
+                &key,
+            )));
+        }
+        if encryptions.is_empty() {
+            return Err(HummockError::other(format!(
+                "encryption key file {} contains no keys",
+                path
+            )));
+        }
+        Ok(Self { encryptions })
+    }
+}
+
+impl EncryptionKeyProvider for FileEncryptionKeyProvider {
+    fn write_encryption(&self) -> HummockResult<Arc<BlockEncryption>> {
+        Ok(self
+            .encryptions
+            .last()
+            .expect("checked non-empty on load")
+            .clone())
+    }
+
+    fn block_encryption(&self, key_id: u64) -> HummockResult<Arc<BlockEncryption>> {
+        key_id
+            .checked_sub(1)
+            .and_then(|idx| self.encryptions.get(idx as usize))
+            .cloned()
+            .ok_or_else(|| {
+                HummockError::other(format!(
+                    "no encryption key with id {} in the key file",
+                    key_id
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_decrypt_roundtrip() {
+        let encryption = BlockEncryption::new(1, &[7; ENCRYPTION_KEY_LEN]);
+        let block = b"some-block-payload".to_vec();
+        let encrypted = encryption.encrypt(&block).unwrap();
+        assert_ne!(encrypted, block);
+        assert_eq!(encryption.decrypt(&encrypted).unwrap(), block);
+        // A fresh nonce is drawn for every block.
+        assert_ne!(encryption.encrypt(&block).unwrap(), encrypted);
+    }
+
+    #[test]
+    fn test_decrypt_failure() {
+        let encryption = BlockEncryption::new(1, &[7; ENCRYPTION_KEY_LEN]);
+        let other = BlockEncryption::new(2, &[8; ENCRYPTION_KEY_LEN]);
+        let encrypted = encryption.encrypt(b"some-block-payload").unwrap();
+        assert!(other.decrypt(&encrypted).is_err());
+        assert!(encryption
+            .decrypt(&encrypted[..NONCE_LEN + TAG_LEN - 1])
+            .is_err());
+    }
+}
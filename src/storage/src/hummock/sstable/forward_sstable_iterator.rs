@@ -16,6 +16,7 @@ use std::cmp::Ordering::{Equal, Less};
 use std::future::Future;
 use std::sync::Arc;
 
+use bytes::Bytes;
 use risingwave_hummock_sdk::key::FullKey;
 use risingwave_hummock_sdk::KeyComparator;
 
@@ -46,13 +47,14 @@ pub struct SstableIterator {
 
     sstable_store: SstableStoreRef,
     stats: StoreLocalStatistic,
+    options: Arc<SstableIteratorReadOptions>,
 }
 
 impl SstableIterator {
     pub fn new(
         sstable: TableHolder,
         sstable_store: SstableStoreRef,
-        _options: Arc<SstableIteratorReadOptions>,
+        options: Arc<SstableIteratorReadOptions>,
     ) -> Self {
         Self {
             block_iter: None,
@@ -60,6 +62,7 @@ impl SstableIterator {
             sst: sstable,
             sstable_store,
             stats: StoreLocalStatistic::default(),
+            options,
         }
     }
 
@@ -85,18 +88,27 @@ impl SstableIterator {
                 .get(
                     self.sst.value(),
                     idx as u64,
-                    crate::hummock::CachePolicy::Fill,
+                    self.options.cache_policy,
                     &mut self.stats,
                 )
                 .await?;
-            let mut block_iter = BlockIterator::new(block);
+            // Reuse the existing block iterator and its key scratch buffer if there is one, to
+            // avoid re-allocating them for every block of the scan.
+            self.stats.block_iter_pool_total_count += 1;
+            let block_iter = match self.block_iter.as_mut() {
+                Some(block_iter) => {
+                    self.stats.block_iter_pool_hit_count += 1;
+                    block_iter.reset(block);
+                    block_iter
+                }
+                None => self.block_iter.insert(BlockIterator::new(block)),
+            };
             if let Some(key) = seek_key {
                 block_iter.seek(key);
             } else {
                 block_iter.seek_to_first();
             }
 
-            self.block_iter = Some(block_iter);
             self.cur_idx = idx;
         }
 
@@ -134,6 +146,12 @@ impl HummockIterator for SstableIterator {
         HummockValue::from_slice(raw_value).expect("decode error")
     }
 
+    fn value_bytes(&self) -> HummockValue<Bytes> {
+        let raw_value = self.block_iter.as_ref().expect("no block iter").value_bytes();
+
+        HummockValue::from_bytes(raw_value).expect("decode error")
+    }
+
     fn is_valid(&self) -> bool {
         self.block_iter.as_ref().map_or(false, |i| i.is_valid())
     }
@@ -344,7 +362,10 @@ mod tests {
                 .await
                 .unwrap(),
             sstable_store,
-            Arc::new(SstableIteratorReadOptions { prefetch: true }),
+            Arc::new(SstableIteratorReadOptions {
+                prefetch: true,
+                ..Default::default()
+            }),
         );
         let mut cnt = 0;
         sstable_iter.rewind().await.unwrap();
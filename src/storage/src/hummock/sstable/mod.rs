@@ -45,6 +45,7 @@ use risingwave_hummock_sdk::{HummockEpoch, HummockSstableId};
 use risingwave_pb::hummock::{KeyRange, SstableInfo};
 
 mod delete_range_aggregator;
+mod encryption;
 mod filter;
 mod sstable_id_manager;
 mod utils;
@@ -53,6 +54,9 @@ pub use delete_range_aggregator::{
     get_delete_range_epoch_from_sstable, DeleteRangeAggregator, DeleteRangeAggregatorBuilder,
     RangeTombstonesCollector, SstableDeleteRangeIterator,
 };
+pub use encryption::{
+    BlockEncryption, EncryptionKeyProvider, FileEncryptionKeyProvider, ENCRYPTION_KEY_LEN,
+};
 pub use filter::FilterBuilder;
 pub use sstable_id_manager::*;
 pub use utils::CompressionAlgorithm;
@@ -60,11 +64,13 @@ use utils::{get_length_prefixed_slice, put_length_prefixed_slice};
 use xxhash_rust::{xxh32, xxh64};
 
 use self::utils::{xxhash64_checksum, xxhash64_verify};
-use super::{HummockError, HummockResult};
+use super::{CachePolicy, HummockError, HummockResult};
 
 const DEFAULT_META_BUFFER_CAPACITY: usize = 4096;
 const MAGIC: u32 = 0x5785ab73;
-const VERSION: u32 = 1;
+const VERSION: u32 = 2;
+/// Format version before the encryption key id was added to the meta.
+const VERSION_V1: u32 = 1;
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 // delete keys located in [start_user_key, end_user_key)
@@ -103,6 +109,24 @@ impl DeleteRangeTombstone {
         }
     }
 
+    /// Creates a tombstone that covers the range from `start_table_key` to the end of the key
+    /// space of `table_id`. Since the exclusive end cannot be represented by a finite table key,
+    /// the zero-length table key of the next table id is used instead.
+    pub fn new_until_table_end(
+        table_id: TableId,
+        start_table_key: Vec<u8>,
+        sequence: HummockEpoch,
+    ) -> Self {
+        Self {
+            start_user_key: UserKey::new(table_id, TableKey(start_table_key)),
+            end_user_key: UserKey::new(
+                TableId::new(table_id.table_id() + 1),
+                TableKey(Vec::new()),
+            ),
+            sequence,
+        }
+    }
+
     pub fn encode(&self, buf: &mut Vec<u8>) {
         self.start_user_key.encode_length_prefixed(buf);
         self.end_user_key.encode_length_prefixed(buf);
@@ -200,6 +224,7 @@ impl Sstable {
             uncompressed_file_size: self.meta.estimated_size as u64,
             min_epoch: 0,
             max_epoch: 0,
+            tombstone_key_count: 0,
         }
     }
 }
@@ -254,6 +279,10 @@ pub struct SstableMeta {
     pub largest_key: Vec<u8>,
     pub meta_offset: u64,
     pub range_tombstone_list: Vec<DeleteRangeTombstone>,
+    /// Id of the encryption key that the data blocks are encrypted with, or 0 if the blocks are
+    /// stored in plaintext. The key itself is resolved from the secrets subsystem via
+    /// [`EncryptionKeyProvider`].
+    pub encryption_key_id: u64,
     /// Format version, for further compatibility.
     pub version: u32,
 }
@@ -269,6 +298,7 @@ impl SstableMeta {
     /// | smallest key len (4B) | smallest key |
     /// | largest key len (4B) | largest key |
     /// | range-tombstone 0 | ... | range-tombstone M-1 |
+    /// | encryption key id (8B) |
     /// | checksum (8B) | version (4B) | magic (4B) |
     /// ```
     pub fn encode_to_bytes(&self) -> Vec<u8> {
@@ -293,6 +323,7 @@ impl SstableMeta {
         for tombstone in &self.range_tombstone_list {
             tombstone.encode(buf);
         }
+        buf.put_u64_le(self.encryption_key_id);
         let checksum = xxhash64_checksum(&buf[start_offset..]);
         buf.put_u64_le(checksum);
         buf.put_u32_le(VERSION);
@@ -310,7 +341,7 @@ impl SstableMeta {
 
         cursor -= 4;
         let version = (&buf[cursor..cursor + 4]).get_u32_le();
-        if version != VERSION {
+        if version != VERSION && version != VERSION_V1 {
             return Err(HummockError::invalid_format_version(version));
         }
 
@@ -336,6 +367,11 @@ impl SstableMeta {
             let tombstone = DeleteRangeTombstone::decode(buf);
             range_tombstone_list.push(tombstone);
         }
+        let encryption_key_id = if version >= VERSION {
+            buf.get_u64_le()
+        } else {
+            0
+        };
 
         Ok(Self {
             block_metas,
@@ -346,6 +382,7 @@ impl SstableMeta {
             largest_key,
             meta_offset,
             range_tombstone_list,
+            encryption_key_id,
             version,
         })
     }
@@ -372,6 +409,7 @@ impl SstableMeta {
             + self.smallest_key.len()
             + 4 // key len
             + self.largest_key.len()
+            + 8 // encryption key id
             + 8 // footer
             + 8 // checksum
             + 4 // version
@@ -382,6 +420,16 @@ impl SstableMeta {
 #[derive(Default)]
 pub struct SstableIteratorReadOptions {
     pub prefetch: bool,
+    /// Number of blocks a backward sstable iterator keeps in flight ahead of its cursor, to hide
+    /// object-store latency of descending scans. 0 disables read-ahead.
+    pub backward_read_ahead_count: usize,
+    /// How the blocks read by the iterator interact with the block cache. See
+    /// [`crate::store::ReadOptions::cache_policy`].
+    pub cache_policy: CachePolicy,
+    /// A hint for the number of keys the caller will consume. Iterators stop read-ahead once that
+    /// many keys have been yielded, but still serve further keys on demand. See
+    /// [`crate::store::ReadOptions::limit`].
+    pub limit: Option<usize>,
 }
 
 #[cfg(test)]
@@ -412,6 +460,7 @@ mod tests {
             largest_key: b"9-largest-key".to_vec(),
             meta_offset: 123,
             range_tombstone_list: vec![],
+            encryption_key_id: 42,
             version: VERSION,
         };
         let sz = meta.encoded_size();
@@ -18,6 +18,7 @@ use std::collections::HashMap;
 use std::ops::DerefMut;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use itertools::Itertools;
 use parking_lot::Mutex;
@@ -31,6 +32,43 @@ use crate::hummock::{HummockError, HummockResult};
 
 pub type SstableIdManagerRef = Arc<SstableIdManager>;
 
+/// Duration for which a locally cached SST id range is considered valid. Expired ids are
+/// discarded instead of being handed out, so that a node that has gone quiet after a burst of
+/// uploads does not keep using arbitrarily old ids, which would interfere with the full GC
+/// watermark heuristics on meta.
+const SST_ID_LEASE_DURATION: Duration = Duration::from_secs(600);
+
+/// A locally cached SST id range, leased until `lease_deadline`.
+struct CachedSstIds {
+    ids: SstIdRange,
+    lease_deadline: Instant,
+}
+
+impl CachedSstIds {
+    fn empty() -> Self {
+        Self {
+            ids: SstIdRange::new(HummockSstableId::MIN, HummockSstableId::MIN),
+            lease_deadline: Instant::now(),
+        }
+    }
+
+    /// Number of unused ids left in the cache.
+    fn remaining(&self) -> u64 {
+        self.ids.end_id.saturating_sub(self.ids.start_id)
+    }
+
+    /// Executes `f` against the cached range, unless the lease has expired.
+    fn map_valid<F, R>(&mut self, f: F) -> Option<R>
+    where
+        F: Fn(&mut SstIdRange) -> Option<R>,
+    {
+        if Instant::now() >= self.lease_deadline {
+            return None;
+        }
+        f(&mut self.ids)
+    }
+}
+
 /// 1. Caches SST ids fetched from meta.
 /// 2. Maintains GC watermark SST id.
 ///
@@ -39,7 +77,7 @@ pub type SstableIdManagerRef = Arc<SstableIdManager>;
 pub struct SstableIdManager {
     // Lock order: `wait_queue` before `available_sst_ids`.
     wait_queue: Mutex<Option<Vec<oneshot::Sender<bool>>>>,
-    available_sst_ids: Mutex<SstIdRange>,
+    available_sst_ids: Mutex<CachedSstIds>,
     remote_fetch_number: u32,
     hummock_meta_client: Arc<dyn HummockMetaClient>,
     sst_id_tracker: SstIdTracker,
@@ -49,10 +87,7 @@ impl SstableIdManager {
     pub fn new(hummock_meta_client: Arc<dyn HummockMetaClient>, remote_fetch_number: u32) -> Self {
         Self {
             wait_queue: Default::default(),
-            available_sst_ids: Mutex::new(SstIdRange::new(
-                HummockSstableId::MIN,
-                HummockSstableId::MIN,
-            )),
+            available_sst_ids: Mutex::new(CachedSstIds::empty()),
             remote_fetch_number,
             hummock_meta_client,
             sst_id_tracker: SstIdTracker::new(),
@@ -62,8 +97,13 @@ impl SstableIdManager {
     /// Returns a new SST id.
     /// The id is guaranteed to be monotonic increasing.
     pub async fn get_new_sst_id(self: &Arc<Self>) -> HummockResult<HummockSstableId> {
-        self.map_next_sst_id(|available_sst_ids| available_sst_ids.get_next_sst_id())
-            .await
+        let new_id = self
+            .map_next_sst_id(|available_sst_ids| available_sst_ids.get_next_sst_id())
+            .await?;
+        // Refill the cache in the background before it drains, so that bursty uploads rarely
+        // have to wait for the meta RPC.
+        self.try_prefetch();
+        Ok(new_id)
     }
 
     /// Executes `f` with next SST id.
@@ -74,13 +114,13 @@ impl SstableIdManager {
     {
         loop {
             // 1. Try to get
-            if let Some(new_id) = f(self.available_sst_ids.lock().deref_mut()) {
+            if let Some(new_id) = self.available_sst_ids.lock().map_valid(&f) {
                 return Ok(new_id);
             }
             // 2. Otherwise either fetch new ids, or wait for previous fetch if any.
             let waiter = {
                 let mut guard = self.wait_queue.lock();
-                if let Some(new_id) = f(self.available_sst_ids.lock().deref_mut()) {
+                if let Some(new_id) = self.available_sst_ids.lock().map_valid(&f) {
                     return Ok(new_id);
                 }
                 let wait_queue = guard.deref_mut();
@@ -102,42 +142,64 @@ impl SstableIdManager {
             // Fetch new ids.
             sync_point!("MAP_NEXT_SST_ID.AS_LEADER");
             sync_point!("MAP_NEXT_SST_ID.BEFORE_FETCH");
-            let this = self.clone();
-            tokio::spawn(async move {
-                let new_sst_ids = match this
-                    .hummock_meta_client
-                    .get_new_sst_ids(this.remote_fetch_number)
-                    .await
-                    .map_err(HummockError::meta_error)
-                {
-                    Ok(new_sst_ids) => new_sst_ids,
-                    Err(err) => {
-                        this.notify_waiters(false);
-                        return Err(err);
-                    }
-                };
-                sync_point!("MAP_NEXT_SST_ID.AFTER_FETCH");
-                sync_point!("MAP_NEXT_SST_ID.BEFORE_FILL_CACHE");
-                // Update local cache.
-                let result = {
-                    let mut guard = this.available_sst_ids.lock();
-                    let available_sst_ids = guard.deref_mut();
-                    if new_sst_ids.start_id < available_sst_ids.end_id {
-                        Err(HummockError::meta_error(format!(
-                            "SST id moves backwards. new {} < old {}",
-                            new_sst_ids.start_id, available_sst_ids.end_id
-                        )))
-                    } else {
-                        *available_sst_ids = new_sst_ids;
-                        Ok(())
-                    }
-                };
-                this.notify_waiters(result.is_ok());
-                result
-            })
-            .await
-            .unwrap()?;
+            self.spawn_fetch().await.unwrap()?;
+        }
+    }
+
+    /// Fetches a new SST id range from meta and fills the local cache. Must only be called
+    /// after the wait queue has been installed, i.e. as the fetch leader. Always notifies
+    /// waiters on completion.
+    fn spawn_fetch(self: &Arc<Self>) -> tokio::task::JoinHandle<HummockResult<()>> {
+        let this = self.clone();
+        tokio::spawn(async move {
+            let new_sst_ids = match this
+                .hummock_meta_client
+                .get_new_sst_ids(this.remote_fetch_number)
+                .await
+                .map_err(HummockError::meta_error)
+            {
+                Ok(new_sst_ids) => new_sst_ids,
+                Err(err) => {
+                    this.notify_waiters(false);
+                    return Err(err);
+                }
+            };
+            sync_point!("MAP_NEXT_SST_ID.AFTER_FETCH");
+            sync_point!("MAP_NEXT_SST_ID.BEFORE_FILL_CACHE");
+            // Update local cache.
+            let result = {
+                let mut guard = this.available_sst_ids.lock();
+                let cache = guard.deref_mut();
+                if new_sst_ids.start_id < cache.ids.end_id {
+                    Err(HummockError::meta_error(format!(
+                        "SST id moves backwards. new {} < old {}",
+                        new_sst_ids.start_id, cache.ids.end_id
+                    )))
+                } else {
+                    cache.ids = new_sst_ids;
+                    cache.lease_deadline = Instant::now() + SST_ID_LEASE_DURATION;
+                    Ok(())
+                }
+            };
+            this.notify_waiters(result.is_ok());
+            result
+        })
+    }
+
+    /// Starts a background fetch if the cached ids are running low and no fetch is in flight.
+    /// Unused ids, if any, are discarded when the fetched range is filled in, which is fine
+    /// because SST ids are not required to be contiguous.
+    fn try_prefetch(self: &Arc<Self>) {
+        let mut guard = self.wait_queue.lock();
+        if guard.is_some() {
+            // A fetch is already in flight.
+            return;
+        }
+        if self.available_sst_ids.lock().remaining() * 2 > self.remote_fetch_number as u64 {
+            return;
         }
+        *guard = Some(vec![]);
+        let _ = self.spawn_fetch();
     }
 
     /// Adds a new watermark SST id using the next unused SST id.
@@ -15,6 +15,7 @@
 // Copyright 2020 TiKV Project Authors. Licensed under Apache-2.0.
 
 use std::ptr;
+use std::str::FromStr;
 
 use risingwave_hummock_sdk::key::MAX_KEY_LEN;
 use xxhash_rust::xxh64;
@@ -112,6 +113,22 @@ impl CompressionAlgorithm {
     }
 }
 
+impl FromStr for CompressionAlgorithm {
+    type Err = HummockError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "none" => Ok(Self::None),
+            "lz4" => Ok(Self::Lz4),
+            "zstd" => Ok(Self::Zstd),
+            _ => Err(HummockError::decode_error(format!(
+                "not a valid compression algorithm: {}",
+                s
+            ))),
+        }
+    }
+}
+
 impl From<CompressionAlgorithm> for u8 {
     fn from(ca: CompressionAlgorithm) -> Self {
         match ca {
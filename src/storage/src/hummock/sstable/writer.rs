@@ -107,6 +107,7 @@ mod tests {
             largest_key: Vec::new(),
             meta_offset: data.len() as u64,
             range_tombstone_list: vec![],
+            encryption_key_id: 0,
             version: VERSION,
         };
 
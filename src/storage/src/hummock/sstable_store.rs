@@ -12,26 +12,35 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use std::clone::Clone;
+use std::path::PathBuf;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_stack_trace::StackTrace;
 use bytes::{Buf, BufMut, Bytes};
 use fail::fail_point;
+use futures::future::try_join_all;
+use futures::stream::{self, StreamExt};
 use itertools::Itertools;
+use parking_lot::RwLock;
 use risingwave_common::cache::LruCacheEventListener;
+use risingwave_hummock_sdk::key::FullKey;
 use risingwave_hummock_sdk::HummockSstableId;
 use risingwave_object_store::object::{
     BlockLocation, MonitoredStreamingReader, ObjectError, ObjectMetadata, ObjectStoreRef,
     ObjectStreamingUploader,
 };
 use risingwave_pb::hummock::SstableInfo;
+use tokio::io::{AsyncReadExt, AsyncWriteExt, BufWriter};
 use tokio::task::JoinHandle;
+use tokio_retry::strategy::{jitter, ExponentialBackoff};
 use zstd::zstd_safe::WriteBuf;
 
 use super::utils::MemoryTracker;
 use super::{
-    Block, BlockCache, BlockMeta, Sstable, SstableMeta, SstableWriter, TieredCache, TieredCacheKey,
+    Block, BlockCache, BlockCachePolicy, BlockCacheUsageTracker, BlockEncryption, BlockMeta,
+    EncryptionKeyProvider, Sstable, SstableMeta, SstableWriter, TieredCache, TieredCacheKey,
     TieredCacheValue,
 };
 use crate::hummock::multi_builder::UploadJoinHandle;
@@ -46,6 +55,49 @@ const MIN_BUFFER_SIZE_PER_SHARD: usize = 256 * 1024 * 1024; // 256MB
 
 pub type TableHolder = CacheableEntry<HummockSstableId, Box<Sstable>>;
 
+/// The table a block belongs to, derived from the smallest key of its block meta.
+fn block_table_id(block_meta: &BlockMeta) -> u32 {
+    FullKey::decode(&block_meta.smallest_key)
+        .user_key
+        .table_id
+        .table_id
+}
+
+/// Maximum number of retries for a transient object store read before the error is propagated.
+const MAX_OBJECT_READ_RETRY: usize = 3;
+
+/// Reads a block of an object from the object store, transparently retrying transient IO errors
+/// with bounded exponential backoff. Non-retryable errors, e.g. corrupted data, are returned
+/// immediately. See [`HummockError::is_retryable`].
+async fn read_object_with_retry(
+    store: &ObjectStoreRef,
+    path: &str,
+    loc: BlockLocation,
+) -> HummockResult<Bytes> {
+    let retry_strategy = ExponentialBackoff::from_millis(10)
+        .max_delay(Duration::from_secs(1))
+        .map(jitter)
+        .take(MAX_OBJECT_READ_RETRY);
+    tokio_retry::RetryIf::spawn(
+        retry_strategy,
+        || async {
+            store
+                .read(path, Some(loc))
+                .await
+                .map_err(HummockError::object_io_error)
+        },
+        |e: &HummockError| {
+            if e.is_retryable() {
+                tracing::warn!("Failed to read object {}, will retry: {:?}", path, e);
+                true
+            } else {
+                false
+            }
+        },
+    )
+    .await
+}
+
 // BEGIN section for tiered cache
 
 impl TieredCacheKey for (HummockSstableId, u64) {
@@ -85,6 +137,7 @@ impl TieredCacheValue for Box<Block> {
 
 pub struct BlockCacheEventListener {
     tiered_cache: TieredCache<(HummockSstableId, u64), Box<Block>>,
+    tracker: Arc<BlockCacheUsageTracker>,
 }
 
 impl LruCacheEventListener for BlockCacheEventListener {
@@ -92,6 +145,7 @@ impl LruCacheEventListener for BlockCacheEventListener {
     type T = Box<Block>;
 
     fn on_release(&self, key: Self::K, value: Self::T) {
+        self.tracker.on_release(key);
         // TODO(MrCroxx): handle error?
         self.tiered_cache.insert(key, value).unwrap();
     }
@@ -99,14 +153,21 @@ impl LruCacheEventListener for BlockCacheEventListener {
 
 // END section for tiered cache
 
-// TODO: Define policy based on use cases (read / compaction / ...).
-#[derive(Clone, Copy, Eq, PartialEq)]
+/// Per-read cache behavior. Carried by `ReadOptions` so that every read can decide how the blocks
+/// it fetches interact with the block cache and the tiered cache.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum CachePolicy {
     /// Disable read cache and not fill the cache afterwards.
     Disable,
     /// Try reading the cache and fill the cache afterwards.
+    #[default]
     Fill,
-    /// Read the cache but not fill the cache afterwards.
+    /// Like [`CachePolicy::Fill`], but bypass the cache admission heuristics (the TinyLFU
+    /// admission filter and the per-table capacity cap) so the blocks are always cached. Intended
+    /// for latency-sensitive streaming state access.
+    HighPriority,
+    /// Read the cache but not fill the cache afterwards, so that large ad-hoc scans do not evict
+    /// the working set of latency-sensitive reads.
     NotFill,
 }
 
@@ -116,6 +177,9 @@ pub struct SstableStore {
     block_cache: BlockCache,
     meta_cache: Arc<LruCache<HummockSstableId, Box<Sstable>>>,
     tiered_cache: TieredCache<(HummockSstableId, u64), Box<Block>>,
+    /// Resolves decryption keys for SSTs whose meta records a non-zero encryption key id. `None`
+    /// until the secrets subsystem registers a provider.
+    encryption_key_provider: RwLock<Option<Arc<dyn EncryptionKeyProvider>>>,
 }
 
 impl SstableStore {
@@ -125,6 +189,26 @@ impl SstableStore {
         block_cache_capacity: usize,
         meta_cache_capacity: usize,
         tiered_cache: TieredCache<(HummockSstableId, u64), Box<Block>>,
+    ) -> Self {
+        Self::new_with_block_cache_policy(
+            store,
+            path,
+            block_cache_capacity,
+            meta_cache_capacity,
+            tiered_cache,
+            BlockCachePolicy::Lru,
+            0,
+        )
+    }
+
+    pub fn new_with_block_cache_policy(
+        store: ObjectStoreRef,
+        path: String,
+        block_cache_capacity: usize,
+        meta_cache_capacity: usize,
+        tiered_cache: TieredCache<(HummockSstableId, u64), Box<Block>>,
+        block_cache_policy: BlockCachePolicy,
+        block_cache_per_table_capacity: usize,
     ) -> Self {
         // TODO: We should validate path early. Otherwise object store won't report invalid path
         // error until first write attempt.
@@ -133,8 +217,10 @@ impl SstableStore {
             shard_bits -= 1;
         }
         let meta_cache = Arc::new(LruCache::new(shard_bits, meta_cache_capacity));
+        let tracker = Arc::new(BlockCacheUsageTracker::new(block_cache_per_table_capacity));
         let listener = Arc::new(BlockCacheEventListener {
             tiered_cache: tiered_cache.clone(),
+            tracker: tracker.clone(),
         });
 
         Self {
@@ -143,10 +229,13 @@ impl SstableStore {
             block_cache: BlockCache::with_event_listener(
                 block_cache_capacity,
                 MAX_CACHE_SHARD_BITS,
+                block_cache_policy,
                 listener,
+                tracker,
             ),
             meta_cache,
             tiered_cache,
+            encryption_key_provider: RwLock::new(None),
         }
     }
 
@@ -163,12 +252,46 @@ impl SstableStore {
         Self {
             path,
             store,
-            block_cache: BlockCache::new(block_cache_capacity, 0),
+            block_cache: BlockCache::new(block_cache_capacity, 0, BlockCachePolicy::Lru),
             meta_cache,
             tiered_cache,
+            encryption_key_provider: RwLock::new(None),
         }
     }
 
+    /// Registers the provider used to resolve block decryption keys. Reads of encrypted SSTs fail
+    /// until a provider is registered.
+    pub fn set_encryption_key_provider(&self, provider: Arc<dyn EncryptionKeyProvider>) {
+        *self.encryption_key_provider.write() = Some(provider);
+    }
+
+    /// The cipher newly written SSTs should be encrypted with, or `None` if encryption at rest is
+    /// not configured.
+    pub fn write_encryption(&self) -> HummockResult<Option<Arc<BlockEncryption>>> {
+        match self.encryption_key_provider.read().clone() {
+            Some(provider) => provider.write_encryption().map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Resolves the cipher of an SST from the encryption key id in its meta. Returns `None` for
+    /// plaintext SSTs.
+    fn block_encryption(
+        &self,
+        encryption_key_id: u64,
+    ) -> HummockResult<Option<Arc<BlockEncryption>>> {
+        if encryption_key_id == 0 {
+            return Ok(None);
+        }
+        let provider = self.encryption_key_provider.read().clone().ok_or_else(|| {
+            HummockError::other(format!(
+                "sst blocks are encrypted with key {} but no encryption key provider is registered",
+                encryption_key_id
+            ))
+        })?;
+        provider.block_encryption(encryption_key_id).map(Some)
+    }
+
     pub async fn delete(&self, sst_id: HummockSstableId) -> HummockResult<()> {
         // Data
         self.store
@@ -225,6 +348,7 @@ impl SstableStore {
                 .get(block_index as usize)
                 .ok_or_else(HummockError::invalid_block)
                 .unwrap(); // FIXME: don't unwrap here.
+            let block_offset = block_meta.offset;
             let block_loc = BlockLocation {
                 offset: block_meta.offset as usize,
                 size: block_meta.len as usize,
@@ -234,6 +358,7 @@ impl SstableStore {
             let sst_id = sst.id;
             let use_tiered_cache = !matches!(policy, CachePolicy::Disable);
             let uncompressed_capacity = block_meta.uncompressed_size as usize;
+            let encryption = self.block_encryption(sst.meta.encryption_key_id);
 
             async move {
                 if use_tiered_cache && let Some(holder) = tiered_cache
@@ -245,8 +370,13 @@ impl SstableStore {
                     return Ok(holder.into_owned());
                 }
 
-                let block_data = store.read(&data_path, Some(block_loc)).await?;
-                let block = Block::decode(block_data, uncompressed_capacity)?;
+                let block_data = read_object_with_retry(&store, &data_path, block_loc).await?;
+                let block_data = match encryption? {
+                    Some(encryption) => Bytes::from(encryption.decrypt(&block_data)?),
+                    None => block_data,
+                };
+                let block = Block::decode(block_data, uncompressed_capacity)
+                    .map_err(|e| e.with_block_location(sst_id, block_offset))?;
                 Ok(Box::new(block))
             }
         };
@@ -263,9 +393,21 @@ impl SstableStore {
         };
 
         match policy {
-            CachePolicy::Fill => {
+            CachePolicy::Fill | CachePolicy::HighPriority => {
+                let table_id = sst
+                    .meta
+                    .block_metas
+                    .get(block_index as usize)
+                    .map(block_table_id)
+                    .unwrap_or_default();
                 self.block_cache
-                    .get_or_insert_with(sst.id, block_index, fetch_block)
+                    .get_or_insert_with(
+                        sst.id,
+                        block_index,
+                        table_id,
+                        policy == CachePolicy::HighPriority,
+                        fetch_block,
+                    )
                     .await
             }
             CachePolicy::NotFill => match self.block_cache.get(sst.id, block_index) {
@@ -284,6 +426,116 @@ impl SstableStore {
         }
     }
 
+    /// Reads the blocks of `sst` at `block_indices`, which must be sorted in ascending order.
+    /// Runs of adjacent blocks that miss the block cache are coalesced into a single ranged read
+    /// of the object store, so that a scan touching many small blocks does not issue one request
+    /// per block. The returned holders are in the same order as `block_indices`.
+    pub async fn read_blocks(
+        &self,
+        sst: &Sstable,
+        block_indices: &[u64],
+        policy: CachePolicy,
+        stats: &mut StoreLocalStatistic,
+    ) -> HummockResult<Vec<BlockHolder>> {
+        debug_assert!(block_indices.windows(2).all(|w| w[0] < w[1]));
+        stats.cache_data_block_total += block_indices.len() as u64;
+
+        let disable_cache: fn() -> bool = || {
+            fail_point!("disable_block_cache", |_| true);
+            false
+        };
+        let policy = if disable_cache() {
+            CachePolicy::Disable
+        } else {
+            policy
+        };
+        let use_cache = !matches!(policy, CachePolicy::Disable);
+
+        // Serve what we can from the block cache and record the positions of the missing blocks.
+        let mut holders: Vec<Option<BlockHolder>> = Vec::with_capacity(block_indices.len());
+        let mut missing = Vec::new();
+        for &block_index in block_indices {
+            let cached = if use_cache {
+                self.block_cache.get(sst.id, block_index)
+            } else {
+                None
+            };
+            match cached {
+                Some(holder) => holders.push(Some(holder)),
+                None => {
+                    missing.push((block_index, holders.len()));
+                    holders.push(None);
+                }
+            }
+        }
+        if missing.is_empty() {
+            return Ok(holders.into_iter().map(|h| h.unwrap()).collect());
+        }
+        stats.cache_data_block_miss += missing.len() as u64;
+
+        // Coalesce runs of adjacent missing blocks into single ranged reads.
+        let mut runs: Vec<Vec<(u64, usize)>> = Vec::new();
+        for (block_index, position) in missing {
+            match runs.last_mut() {
+                Some(run) if run.last().unwrap().0 + 1 == block_index => {
+                    run.push((block_index, position))
+                }
+                _ => runs.push(vec![(block_index, position)]),
+            }
+        }
+
+        let data_path = self.get_sst_data_path(sst.id);
+        let run_data = try_join_all(runs.iter().map(|run| {
+            let data_path = &data_path;
+            async move {
+                let block_meta = |block_index: u64| {
+                    sst.meta
+                        .block_metas
+                        .get(block_index as usize)
+                        .ok_or_else(HummockError::invalid_block)
+                };
+                let first_meta = block_meta(run.first().unwrap().0)?;
+                let last_meta = block_meta(run.last().unwrap().0)?;
+                let run_loc = BlockLocation {
+                    offset: first_meta.offset as usize,
+                    size: (last_meta.offset + last_meta.len - first_meta.offset) as usize,
+                };
+                read_object_with_retry(&self.store, data_path, run_loc).await
+            }
+        }))
+        .await?;
+
+        let encryption = self.block_encryption(sst.meta.encryption_key_id)?;
+        for (run, data) in runs.iter().zip(run_data) {
+            let run_offset = sst.meta.block_metas[run[0].0 as usize].offset as usize;
+            for &(block_index, position) in run {
+                let block_meta = &sst.meta.block_metas[block_index as usize];
+                let start = block_meta.offset as usize - run_offset;
+                let block_data = data.slice(start..start + block_meta.len as usize);
+                let block_data = match &encryption {
+                    Some(encryption) => Bytes::from(encryption.decrypt(&block_data)?),
+                    None => block_data,
+                };
+                let block = Block::decode(block_data, block_meta.uncompressed_size as usize)
+                    .map_err(|e| e.with_block_location(sst.id, block_meta.offset))?;
+                let holder = if matches!(policy, CachePolicy::Fill | CachePolicy::HighPriority) {
+                    self.block_cache.insert(
+                        sst.id,
+                        block_index,
+                        block_table_id(block_meta),
+                        Box::new(block),
+                        policy == CachePolicy::HighPriority,
+                    )
+                } else {
+                    BlockHolder::from_owned_block(Box::new(block))
+                };
+                holders[position] = Some(holder);
+            }
+        }
+
+        Ok(holders.into_iter().map(|h| h.unwrap()).collect())
+    }
+
     pub fn get_sst_data_path(&self, sst_id: HummockSstableId) -> String {
         let obj_prefix = self.store.get_object_prefix(sst_id, true);
         format!("{}/{}{}.data", self.path, obj_prefix, sst_id)
@@ -340,10 +592,7 @@ impl SstableStore {
                 };
                 async move {
                     let now = minstant::Instant::now();
-                    let buf = store
-                        .read(&meta_path, Some(loc))
-                        .await
-                        .map_err(HummockError::object_io_error)?;
+                    let buf = read_object_with_retry(&store, &meta_path, loc).await?;
                     let meta = SstableMeta::decode(&mut &buf[..])?;
                     let sst = Sstable::new(sst_id, meta);
                     let charge = sst.estimate_size();
@@ -370,6 +619,53 @@ impl SstableStore {
         )
     }
 
+    /// Pre-fetches the metadata of `ssts` into the meta cache, then fills the block cache with
+    /// their data blocks in the given order until `max_preload_bytes` of block data has been
+    /// loaded. Callers should order `ssts` from hot to cold so that the budget is spent on the
+    /// blocks that are most likely to be read first. Warming up is best-effort: an SST that
+    /// cannot be read, e.g. because a concurrent compaction has removed it, is skipped.
+    pub async fn warm_up(&self, ssts: Vec<SstableInfo>, max_preload_bytes: usize) {
+        const META_FETCH_CONCURRENCY: usize = 16;
+
+        stream::iter(ssts.iter())
+            .for_each_concurrent(META_FETCH_CONCURRENCY, |sst| async {
+                let mut stats = StoreLocalStatistic::default();
+                if let Err(e) = self.sstable(sst, &mut stats).await {
+                    tracing::warn!("failed to warm up the meta of SST {}: {:?}", sst.id, e);
+                }
+                stats.ignore();
+            })
+            .await;
+
+        let mut stats = StoreLocalStatistic::default();
+        let mut preload_bytes = 0;
+        for sst in &ssts {
+            if preload_bytes >= max_preload_bytes {
+                break;
+            }
+            let holder = match self.sstable(sst, &mut stats).await {
+                Ok(holder) => holder,
+                // Already logged when warming up the meta.
+                Err(_) => continue,
+            };
+            let mut block_indices = Vec::new();
+            for (idx, block_meta) in holder.value().meta.block_metas.iter().enumerate() {
+                if preload_bytes >= max_preload_bytes {
+                    break;
+                }
+                preload_bytes += block_meta.len as usize;
+                block_indices.push(idx as u64);
+            }
+            if let Err(e) = self
+                .read_blocks(holder.value(), &block_indices, CachePolicy::Fill, &mut stats)
+                .await
+            {
+                tracing::warn!("failed to warm up the blocks of SST {}: {:?}", sst.id, e);
+            }
+        }
+        stats.ignore();
+    }
+
     pub async fn list_ssts_from_object_store(&self) -> HummockResult<Vec<ObjectMetadata>> {
         self.store
             .list(&format!("{}/", self.path))
@@ -396,15 +692,23 @@ impl SstableStore {
         &self,
         sst_id: HummockSstableId,
         block_index: u64,
+        table_id: u32,
         block: Box<Block>,
     ) {
-        self.block_cache.insert(sst_id, block_index, block);
+        self.block_cache
+            .insert(sst_id, block_index, table_id, block, false);
     }
 
     pub fn get_meta_memory_usage(&self) -> u64 {
         self.meta_cache.get_memory_usage() as u64
     }
 
+    /// The block cache usage per table. Empty for caches without usage tracking, e.g. the
+    /// compactor's.
+    pub fn block_cache_usage_per_table(&self) -> Vec<(u32, u64)> {
+        self.block_cache.usage_per_table()
+    }
+
     pub async fn get_stream(
         &self,
         sst: &Sstable,
@@ -432,7 +736,9 @@ impl SstableStore {
                 .await
                 .map_err(HummockError::object_io_error)?,
             block_index.unwrap_or(0),
+            sst.id,
             &sst.meta,
+            self.block_encryption(sst.meta.encryption_key_id)?,
         ))
     }
 }
@@ -465,6 +771,10 @@ impl MemoryCollector for HummockMemoryCollector {
     fn get_uploading_memory_usage(&self) -> u64 {
         self.limiter.get_memory_usage()
     }
+
+    fn get_block_cache_usage_per_table(&self) -> Vec<(u32, u64)> {
+        self.sstable_store.block_cache_usage_per_table()
+    }
 }
 
 pub struct SstableWriterOptions {
@@ -472,6 +782,9 @@ pub struct SstableWriterOptions {
     pub capacity_hint: Option<usize>,
     pub tracker: Option<MemoryTracker>,
     pub policy: CachePolicy,
+    /// Encrypt data blocks with this cipher before they are written to the object store. The key
+    /// id is recorded in the SST meta so that readers can resolve the key for decryption.
+    pub encryption: Option<Arc<BlockEncryption>>,
 }
 
 pub trait SstableWriterFactory: Send + Sync {
@@ -519,6 +832,7 @@ pub struct BatchUploadWriter {
     buf: Vec<u8>,
     block_info: Vec<Block>,
     tracker: Option<MemoryTracker>,
+    encryption: Option<Arc<BlockEncryption>>,
 }
 
 impl BatchUploadWriter {
@@ -534,6 +848,7 @@ impl BatchUploadWriter {
             buf: Vec::with_capacity(options.capacity_hint.unwrap_or(0)),
             block_info: Vec::new(),
             tracker: options.tracker,
+            encryption: options.encryption,
         }
     }
 }
@@ -543,18 +858,25 @@ impl SstableWriter for BatchUploadWriter {
     type Output = JoinHandle<HummockResult<()>>;
 
     async fn write_block(&mut self, block: &[u8], meta: &BlockMeta) -> HummockResult<()> {
-        self.buf.extend_from_slice(block);
+        // The cache is filled with plaintext blocks, so decode before encryption.
         if let CachePolicy::Fill = self.policy {
             self.block_info.push(Block::decode(
                 Bytes::from(block.to_vec()),
                 meta.uncompressed_size as usize,
             )?);
         }
+        match &self.encryption {
+            Some(encryption) => self.buf.extend_from_slice(&encryption.encrypt(block)?),
+            None => self.buf.extend_from_slice(block),
+        }
         Ok(())
     }
 
-    async fn finish(mut self, meta: SstableMeta) -> HummockResult<Self::Output> {
+    async fn finish(mut self, mut meta: SstableMeta) -> HummockResult<Self::Output> {
         fail_point!("data_upload_err");
+        if let Some(encryption) = &self.encryption {
+            meta.encryption_key_id = encryption.key_id();
+        }
         let join_handle = tokio::spawn(async move {
             meta.encode_to(&mut self.buf);
             let data = Bytes::from(self.buf);
@@ -571,6 +893,7 @@ impl SstableWriter for BatchUploadWriter {
                 .clone()
                 .put_sst_data(self.sst_id, data)
                 .await?;
+            let table_ids = meta.block_metas.iter().map(block_table_id).collect_vec();
             self.sstable_store.insert_meta_cache(self.sst_id, meta);
 
             // Add block cache.
@@ -581,7 +904,9 @@ impl SstableWriter for BatchUploadWriter {
                     self.sstable_store.block_cache.insert(
                         self.sst_id,
                         block_idx as u64,
+                        table_ids[block_idx],
                         Box::new(block),
+                        false,
                     );
                 }
             }
@@ -605,6 +930,7 @@ pub struct StreamingUploadWriter {
     blocks: Vec<Block>,
     data_len: usize,
     tracker: Option<MemoryTracker>,
+    encryption: Option<Arc<BlockEncryption>>,
 }
 
 impl StreamingUploadWriter {
@@ -622,6 +948,7 @@ impl StreamingUploadWriter {
             blocks: Vec::new(),
             data_len: 0,
             tracker: options.tracker,
+            encryption: options.encryption,
         }
     }
 }
@@ -631,19 +958,27 @@ impl SstableWriter for StreamingUploadWriter {
     type Output = JoinHandle<HummockResult<()>>;
 
     async fn write_block(&mut self, block_data: &[u8], meta: &BlockMeta) -> HummockResult<()> {
-        self.data_len += block_data.len();
         let block_data = Bytes::from(block_data.to_vec());
+        // The cache is filled with plaintext blocks, so decode before encryption.
         if let CachePolicy::Fill = self.policy {
             let block = Block::decode(block_data.clone(), meta.uncompressed_size as usize)?;
             self.blocks.push(block);
         }
+        let block_data = match &self.encryption {
+            Some(encryption) => Bytes::from(encryption.encrypt(&block_data)?),
+            None => block_data,
+        };
+        self.data_len += block_data.len();
         self.object_uploader
             .write_bytes(block_data)
             .await
             .map_err(HummockError::object_io_error)
     }
 
-    async fn finish(mut self, meta: SstableMeta) -> HummockResult<UploadJoinHandle> {
+    async fn finish(mut self, mut meta: SstableMeta) -> HummockResult<UploadJoinHandle> {
+        if let Some(encryption) = &self.encryption {
+            meta.encryption_key_id = encryption.key_id();
+        }
         let meta_data = Bytes::from(meta.encode_to_bytes());
 
         self.object_uploader
@@ -665,6 +1000,7 @@ impl SstableWriter for StreamingUploadWriter {
                 .finish()
                 .await
                 .map_err(HummockError::object_io_error)?;
+            let table_ids = meta.block_metas.iter().map(block_table_id).collect_vec();
             self.sstable_store.insert_meta_cache(self.sst_id, meta);
 
             // Add block cache.
@@ -674,7 +1010,9 @@ impl SstableWriter for StreamingUploadWriter {
                     self.sstable_store.block_cache.insert(
                         self.sst_id,
                         block_idx as u64,
+                        table_ids[block_idx],
                         Box::new(block),
+                        false,
                     );
                 }
             }
@@ -717,6 +1055,257 @@ impl SstableWriterFactory for StreamingSstableWriterFactory {
     }
 }
 
+/// Maximum number of retries for uploading a spilled SST to the object store before giving up
+/// and leaving the spill file on the local disk.
+const MAX_SPILL_UPLOAD_RETRY: usize = 8;
+
+/// Read buffer size for streaming a spill file to the object store.
+const SPILL_UPLOAD_BUFFER_SIZE: usize = 8 * 1024 * 1024;
+
+/// Spill SST data to a file on the local disk and upload it to the object store in the
+/// background, so that a shared buffer flush is bounded by the local disk bandwidth instead of
+/// the object store throughput. The returned `JoinHandle` resolves once the data is durable on
+/// the local disk and the caches are filled, i.e. before the upload completes. Until the upload
+/// finishes, the SST can only be read on this node through the caches; see the caveats of
+/// `storage.shared_buffer_spill_enabled`.
+pub struct SpillUploadWriter {
+    sst_id: HummockSstableId,
+    sstable_store: SstableStoreRef,
+    policy: CachePolicy,
+    spill_path: PathBuf,
+    /// Lazily created on the first write.
+    file: Option<BufWriter<tokio::fs::File>>,
+    data_len: usize,
+    block_info: Vec<Block>,
+    tracker: Option<MemoryTracker>,
+    encryption: Option<Arc<BlockEncryption>>,
+}
+
+impl SpillUploadWriter {
+    pub fn new(
+        sst_id: HummockSstableId,
+        sstable_store: SstableStoreRef,
+        spill_path: PathBuf,
+        options: SstableWriterOptions,
+    ) -> Self {
+        Self {
+            sst_id,
+            sstable_store,
+            policy: options.policy,
+            spill_path,
+            file: None,
+            data_len: 0,
+            block_info: Vec::new(),
+            tracker: options.tracker,
+            encryption: options.encryption,
+        }
+    }
+
+    async fn file(&mut self) -> HummockResult<&mut BufWriter<tokio::fs::File>> {
+        if self.file.is_none() {
+            let file = tokio::fs::File::create(&self.spill_path)
+                .await
+                .map_err(HummockError::other)?;
+            self.file = Some(BufWriter::new(file));
+        }
+        Ok(self.file.as_mut().unwrap())
+    }
+
+    /// Uploads the spill file to the object store, retrying transient failures, and removes it
+    /// once the upload succeeds. On persistent failure the spill file is kept on the disk: the
+    /// SST may already be visible to readers, so the file is the only copy of the data.
+    async fn upload_in_background(
+        sstable_store: SstableStoreRef,
+        sst_id: HummockSstableId,
+        spill_path: PathBuf,
+    ) {
+        let data_path = sstable_store.get_sst_data_path(sst_id);
+        let retry_strategy = ExponentialBackoff::from_millis(100)
+            .max_delay(Duration::from_secs(10))
+            .map(jitter)
+            .take(MAX_SPILL_UPLOAD_RETRY);
+        let upload = tokio_retry::RetryIf::spawn(
+            retry_strategy,
+            || Self::upload_spill_file(&sstable_store, &data_path, &spill_path),
+            |e: &HummockError| {
+                if e.is_retryable() {
+                    tracing::warn!("Failed to upload spilled SST {}, will retry: {:?}", sst_id, e);
+                    true
+                } else {
+                    false
+                }
+            },
+        )
+        .await;
+        match upload {
+            Ok(()) => {
+                if let Err(e) = tokio::fs::remove_file(&spill_path).await {
+                    tracing::warn!(
+                        "Failed to remove uploaded spill file {:?}: {:?}",
+                        spill_path,
+                        e
+                    );
+                }
+            }
+            Err(e) => {
+                tracing::error!(
+                    "Failed to upload spilled SST {}, the only copy of its data is kept at {:?}: {:?}",
+                    sst_id,
+                    spill_path,
+                    e
+                );
+            }
+        }
+    }
+
+    async fn upload_spill_file(
+        sstable_store: &SstableStore,
+        data_path: &str,
+        spill_path: &PathBuf,
+    ) -> HummockResult<()> {
+        let mut file = tokio::fs::File::open(spill_path)
+            .await
+            .map_err(HummockError::other)?;
+        let mut uploader = sstable_store.store.streaming_upload(data_path)?;
+        let mut buf = vec![0; SPILL_UPLOAD_BUFFER_SIZE];
+        loop {
+            let n = file.read(&mut buf).await.map_err(HummockError::other)?;
+            if n == 0 {
+                break;
+            }
+            uploader
+                .write_bytes(Bytes::copy_from_slice(&buf[..n]))
+                .await
+                .map_err(HummockError::object_io_error)?;
+        }
+        uploader
+            .finish()
+            .await
+            .map_err(HummockError::object_io_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl SstableWriter for SpillUploadWriter {
+    type Output = JoinHandle<HummockResult<()>>;
+
+    async fn write_block(&mut self, block: &[u8], meta: &BlockMeta) -> HummockResult<()> {
+        // The cache is filled with plaintext blocks, so decode before encryption.
+        if let CachePolicy::Fill = self.policy {
+            self.block_info.push(Block::decode(
+                Bytes::from(block.to_vec()),
+                meta.uncompressed_size as usize,
+            )?);
+        }
+        match &self.encryption {
+            Some(encryption) => {
+                let encrypted = encryption.encrypt(block)?;
+                self.data_len += encrypted.len();
+                self.file()
+                    .await?
+                    .write_all(&encrypted)
+                    .await
+                    .map_err(HummockError::other)?;
+            }
+            None => {
+                self.data_len += block.len();
+                self.file()
+                    .await?
+                    .write_all(block)
+                    .await
+                    .map_err(HummockError::other)?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn finish(mut self, mut meta: SstableMeta) -> HummockResult<Self::Output> {
+        fail_point!("data_upload_err");
+        if let Some(encryption) = &self.encryption {
+            meta.encryption_key_id = encryption.key_id();
+        }
+        let meta_data = Bytes::from(meta.encode_to_bytes());
+        let file = self.file().await?;
+        file.write_all(&meta_data)
+            .await
+            .map_err(HummockError::other)?;
+        file.flush().await.map_err(HummockError::other)?;
+        file.get_ref()
+            .sync_data()
+            .await
+            .map_err(HummockError::other)?;
+
+        // The data is durable on the local disk now, release the write memory before the
+        // upload even starts.
+        drop(self.tracker.take());
+
+        // The upload must not block the flush, it proceeds in a detached task.
+        tokio::spawn(Self::upload_in_background(
+            self.sstable_store.clone(),
+            self.sst_id,
+            self.spill_path.clone(),
+        ));
+
+        let join_handle = tokio::spawn(async move {
+            // Fill the caches so that the SST is readable on this node before the upload
+            // completes.
+            let table_ids = meta.block_metas.iter().map(block_table_id).collect_vec();
+            self.sstable_store.insert_meta_cache(self.sst_id, meta);
+            if let CachePolicy::Fill = self.policy {
+                for (block_idx, block) in self.block_info.into_iter().enumerate() {
+                    self.sstable_store.block_cache.insert(
+                        self.sst_id,
+                        block_idx as u64,
+                        table_ids[block_idx],
+                        Box::new(block),
+                        false,
+                    );
+                }
+            }
+            Ok(())
+        });
+        Ok(join_handle)
+    }
+
+    fn data_len(&self) -> usize {
+        self.data_len
+    }
+}
+
+pub struct SpillSstableWriterFactory {
+    sstable_store: SstableStoreRef,
+    spill_dir: PathBuf,
+}
+
+impl SpillSstableWriterFactory {
+    /// Creates the factory, creating `spill_dir` if it does not exist.
+    pub fn new(sstable_store: SstableStoreRef, spill_dir: PathBuf) -> HummockResult<Self> {
+        std::fs::create_dir_all(&spill_dir).map_err(HummockError::other)?;
+        Ok(Self {
+            sstable_store,
+            spill_dir,
+        })
+    }
+}
+
+impl SstableWriterFactory for SpillSstableWriterFactory {
+    type Writer = SpillUploadWriter;
+
+    fn create_sst_writer(
+        &self,
+        sst_id: HummockSstableId,
+        options: SstableWriterOptions,
+    ) -> HummockResult<Self::Writer> {
+        let spill_path = self.spill_dir.join(format!("{}.sst", sst_id));
+        Ok(SpillUploadWriter::new(
+            sst_id,
+            self.sstable_store.clone(),
+            spill_path,
+            options,
+        ))
+    }
+}
+
 pub struct CompactorMemoryCollector {
     uploading_memory_limiter: Arc<MemoryLimiter>,
     data_memory_limiter: Arc<MemoryLimiter>,
@@ -768,6 +1357,16 @@ pub struct BlockStream {
     /// streaming starts at block 2 of a given SST, then the list does not contain information
     /// about block 0 and block 1.
     block_size_vec: Vec<(usize, usize)>,
+
+    /// The id of the streamed SST, used to locate corrupted blocks.
+    sst_id: HummockSstableId,
+
+    /// The offsets of the streamed blocks within the SST object, parallel to `block_size_vec`.
+    /// Only used to locate corrupted blocks.
+    block_offset_vec: Vec<u32>,
+
+    /// The cipher to decrypt the streamed blocks with, if the SST is encrypted.
+    encryption: Option<Arc<BlockEncryption>>,
 }
 
 impl BlockStream {
@@ -783,8 +1382,14 @@ impl BlockStream {
         // Index of the SST's block where the stream starts.
         block_index: usize,
 
+        // Id of the SST that is streamed.
+        sst_id: HummockSstableId,
+
         // Meta data of the SST that is streamed.
         sst_meta: &SstableMeta,
+
+        // The cipher to decrypt the streamed blocks with, if the SST is encrypted.
+        encryption: Option<Arc<BlockEncryption>>,
     ) -> Self {
         let metas = &sst_meta.block_metas;
 
@@ -792,16 +1397,21 @@ impl BlockStream {
         let block_index = std::cmp::min(block_index, metas.len());
 
         let mut block_len_vec = Vec::with_capacity(metas.len() - block_index);
+        let mut block_offset_vec = Vec::with_capacity(metas.len() - block_index);
         sst_meta.block_metas[block_index..]
             .iter()
             .for_each(|b_meta| {
-                block_len_vec.push((b_meta.len as usize, b_meta.uncompressed_size as usize))
+                block_len_vec.push((b_meta.len as usize, b_meta.uncompressed_size as usize));
+                block_offset_vec.push(b_meta.offset);
             });
 
         Self {
             byte_stream,
             block_idx: 0,
             block_size_vec: block_len_vec,
+            sst_id,
+            block_offset_vec,
+            encryption,
         }
     }
 
@@ -831,7 +1441,15 @@ impl BlockStream {
             )));
         }
 
-        let boxed_block = Box::new(Block::decode(Bytes::from(buffer), block_full_size)?);
+        let buffer = match &self.encryption {
+            Some(encryption) => encryption.decrypt(&buffer)?,
+            None => buffer,
+        };
+        let block_offset = self.block_offset_vec[self.block_idx];
+        let boxed_block = Box::new(
+            Block::decode(Bytes::from(buffer), block_full_size)
+                .map_err(|e| e.with_block_location(self.sst_id, block_offset))?,
+        );
         self.block_idx += 1;
 
         Ok(Some(boxed_block))
@@ -842,7 +1460,9 @@ impl BlockStream {
 mod tests {
     use std::ops::Range;
     use std::sync::Arc;
+    use std::time::Duration;
 
+    use itertools::Itertools;
     use risingwave_hummock_sdk::HummockSstableId;
     use risingwave_pb::hummock::SstableInfo;
 
@@ -854,7 +1474,11 @@ mod tests {
         default_builder_opt_for_test, gen_test_sstable_data, put_sst,
     };
     use crate::hummock::value::HummockValue;
-    use crate::hummock::{CachePolicy, SstableIterator, SstableMeta};
+    use crate::hummock::{
+        BlockEncryption, CachePolicy, EncryptionKeyProvider, HummockResult,
+        SpillSstableWriterFactory, SstableBuilder, SstableIterator, SstableMeta,
+        SstableWriterFactory, ENCRYPTION_KEY_LEN,
+    };
     use crate::monitor::StoreLocalStatistic;
 
     const SST_ID: HummockSstableId = 1;
@@ -905,6 +1529,7 @@ mod tests {
             capacity_hint: None,
             tracker: None,
             policy: CachePolicy::Disable,
+            encryption: None,
         };
         let info = put_sst(
             SST_ID,
@@ -919,6 +1544,116 @@ mod tests {
         validate_sst(sstable_store, &info, meta, x_range).await;
     }
 
+    #[tokio::test]
+    async fn test_read_blocks() {
+        let sstable_store = mock_sstable_store();
+        let x_range = 0..100;
+        let (data, meta) = gen_test_sstable_data(
+            default_builder_opt_for_test(),
+            x_range
+                .clone()
+                .map(|x| (iterator_test_key_of(x), get_hummock_value(x))),
+        )
+        .await;
+        let writer_opts = SstableWriterOptions {
+            capacity_hint: None,
+            tracker: None,
+            policy: CachePolicy::Disable,
+            encryption: None,
+        };
+        let info = put_sst(
+            SST_ID,
+            data.clone(),
+            meta.clone(),
+            sstable_store.clone(),
+            writer_opts,
+        )
+        .await
+        .unwrap();
+
+        let mut stats = StoreLocalStatistic::default();
+        let holder = sstable_store.sstable(&info, &mut stats).await.unwrap();
+        let sst = holder.value();
+        let block_indices = (0..sst.meta.block_metas.len() as u64).collect_vec();
+        let blocks = sstable_store
+            .read_blocks(sst, &block_indices, CachePolicy::Disable, &mut stats)
+            .await
+            .unwrap();
+        assert_eq!(blocks.len(), block_indices.len());
+        for (&block_index, block) in block_indices.iter().zip(blocks.iter()) {
+            let expected = sstable_store
+                .get(sst, block_index, CachePolicy::Disable, &mut stats)
+                .await
+                .unwrap();
+            assert_eq!(block.raw_data(), expected.raw_data());
+        }
+
+        // Reading a mix of cached and missing blocks with `CachePolicy::Fill` populates the
+        // block cache.
+        let _ = sstable_store
+            .get(sst, 0, CachePolicy::Fill, &mut stats)
+            .await
+            .unwrap();
+        let blocks = sstable_store
+            .read_blocks(sst, &block_indices, CachePolicy::Fill, &mut stats)
+            .await
+            .unwrap();
+        assert_eq!(blocks.len(), block_indices.len());
+        for &block_index in &block_indices {
+            assert!(sstable_store.block_cache.get(SST_ID, block_index).is_some());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_warm_up() {
+        let sstable_store = mock_sstable_store();
+        let x_range = 0..100;
+        let (data, meta) = gen_test_sstable_data(
+            default_builder_opt_for_test(),
+            x_range
+                .clone()
+                .map(|x| (iterator_test_key_of(x), get_hummock_value(x))),
+        )
+        .await;
+        let writer_opts = SstableWriterOptions {
+            capacity_hint: None,
+            tracker: None,
+            policy: CachePolicy::Disable,
+            encryption: None,
+        };
+        let info = put_sst(
+            SST_ID,
+            data.clone(),
+            meta.clone(),
+            sstable_store.clone(),
+            writer_opts,
+        )
+        .await
+        .unwrap();
+        let block_count = meta.block_metas.len() as u64;
+        assert!(block_count > 1);
+
+        // A budget that covers only the first block preloads the meta and that block.
+        sstable_store.clear_meta_cache();
+        sstable_store.clear_block_cache();
+        let budget = meta.block_metas[0].len as usize;
+        sstable_store.warm_up(vec![info.clone()], budget).await;
+        assert!(sstable_store.meta_cache.lookup(SST_ID, &SST_ID).is_some());
+        assert!(sstable_store.block_cache.get(SST_ID, 0).is_some());
+        assert!(sstable_store.block_cache.get(SST_ID, 1).is_none());
+
+        // An unlimited budget preloads all blocks.
+        sstable_store.warm_up(vec![info.clone()], usize::MAX).await;
+        for block_index in 0..block_count {
+            assert!(sstable_store.block_cache.get(SST_ID, block_index).is_some());
+        }
+
+        // An SST that does not exist is skipped without failing the warm-up.
+        let mut missing = info;
+        missing.id = SST_ID + 1;
+        sstable_store.warm_up(vec![missing], usize::MAX).await;
+    }
+
     #[tokio::test]
     async fn test_streaming_upload() {
         // Generate test data.
@@ -935,6 +1670,7 @@ mod tests {
             capacity_hint: None,
             tracker: None,
             policy: CachePolicy::Disable,
+            encryption: None,
         };
         let info = put_sst(
             SST_ID,
@@ -949,6 +1685,133 @@ mod tests {
         validate_sst(sstable_store, &info, meta, x_range).await;
     }
 
+    #[tokio::test]
+    async fn test_spill_upload() {
+        let sstable_store = mock_sstable_store();
+        let spill_dir = tempfile::tempdir().unwrap();
+        let factory =
+            SpillSstableWriterFactory::new(sstable_store.clone(), spill_dir.path().to_path_buf())
+                .unwrap();
+        let x_range = 0..100;
+        let writer_opts = SstableWriterOptions {
+            capacity_hint: None,
+            tracker: None,
+            policy: CachePolicy::Fill,
+            encryption: None,
+        };
+        let writer = factory.create_sst_writer(SST_ID, writer_opts).unwrap();
+        let mut b = SstableBuilder::for_test(SST_ID, writer, default_builder_opt_for_test());
+        for x in x_range.clone() {
+            b.add(
+                &iterator_test_key_of(x).to_ref(),
+                get_hummock_value(x).as_slice(),
+                true,
+            )
+            .await
+            .unwrap();
+        }
+        let output = b.finish().await.unwrap();
+        output.writer_output.await.unwrap().unwrap();
+        let info = output.sst_info.sst_info;
+
+        // The SST is readable through the caches as soon as the writer output resolves, even
+        // though the upload may not have finished yet.
+        assert!(sstable_store.meta_cache.lookup(SST_ID, &SST_ID).is_some());
+        assert!(sstable_store.block_cache.get(SST_ID, 0).is_some());
+
+        // Wait until the background upload finishes and removes the spill file.
+        let spill_path = spill_dir.path().join(format!("{}.sst", SST_ID));
+        for _ in 0..100 {
+            if !spill_path.exists() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(!spill_path.exists());
+
+        // Validate the uploaded SST against the object store.
+        sstable_store.clear_meta_cache();
+        sstable_store.clear_block_cache();
+        let mut stats = StoreLocalStatistic::default();
+        let holder = sstable_store.sstable(&info, &mut stats).await.unwrap();
+        let mut iter = SstableIterator::new(
+            holder,
+            sstable_store,
+            Arc::new(SstableIteratorReadOptions::default()),
+        );
+        iter.rewind().await.unwrap();
+        for i in x_range {
+            assert_eq!(iter.key(), iterator_test_key_of(i).to_ref());
+            assert_eq!(iter.value(), get_hummock_value(i).as_slice());
+            iter.next().await.unwrap();
+        }
+    }
+
+    struct TestKeyProvider(Arc<BlockEncryption>);
+
+    impl EncryptionKeyProvider for TestKeyProvider {
+        fn write_encryption(&self) -> HummockResult<Arc<BlockEncryption>> {
+            Ok(self.0.clone())
+        }
+
+        fn block_encryption(&self, key_id: u64) -> HummockResult<Arc<BlockEncryption>> {
+            assert_eq!(key_id, self.0.key_id());
+            Ok(self.0.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_upload() {
+        let sstable_store = mock_sstable_store();
+        let encryption = Arc::new(BlockEncryption::new(1, &[7; ENCRYPTION_KEY_LEN]));
+        let x_range = 0..100;
+        let writer_opts = SstableWriterOptions {
+            capacity_hint: None,
+            tracker: None,
+            policy: CachePolicy::Disable,
+            encryption: Some(encryption.clone()),
+        };
+        let writer = sstable_store
+            .clone()
+            .create_sst_writer(SST_ID, writer_opts);
+        let mut b = SstableBuilder::for_test(SST_ID, writer, default_builder_opt_for_test());
+        for x in x_range.clone() {
+            b.add(
+                &iterator_test_key_of(x).to_ref(),
+                get_hummock_value(x).as_slice(),
+                true,
+            )
+            .await
+            .unwrap();
+        }
+        let output = b.finish().await.unwrap();
+        output.writer_output.await.unwrap().unwrap();
+        let info = output.sst_info.sst_info;
+
+        let mut stats = StoreLocalStatistic::default();
+        let holder = sstable_store.sstable(&info, &mut stats).await.unwrap();
+        assert_eq!(holder.value().meta.encryption_key_id, encryption.key_id());
+        // Reads of encrypted blocks fail until a key provider is registered.
+        assert!(sstable_store
+            .get(holder.value(), 0, CachePolicy::Disable, &mut stats)
+            .await
+            .is_err());
+
+        sstable_store.set_encryption_key_provider(Arc::new(TestKeyProvider(encryption)));
+        let mut iter = SstableIterator::new(
+            holder,
+            sstable_store,
+            Arc::new(SstableIteratorReadOptions::default()),
+        );
+        iter.rewind().await.unwrap();
+        for i in x_range {
+            assert_eq!(iter.key(), iterator_test_key_of(i).to_ref());
+            assert_eq!(iter.value(), get_hummock_value(i).as_slice());
+            iter.next().await.unwrap();
+        }
+        assert!(!iter.is_valid());
+    }
+
     #[test]
     fn test_basic() {
         let sstable_store = mock_sstable_store();
@@ -73,6 +73,40 @@ impl HummockStorage {
             .await
     }
 
+    /// Gets the values of multiple `keys`, based on the same snapshot corresponding to the given
+    /// `epoch`. The read version is built only once from a key range covering all the keys, and
+    /// the per-key lookups run concurrently.
+    async fn multi_get_inner(
+        &self,
+        keys: &[Vec<u8>],
+        epoch: HummockEpoch,
+        read_options: ReadOptions,
+    ) -> StorageResult<Vec<Option<Bytes>>> {
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+        let key_range = (
+            Bound::Included(TableKey(keys.iter().min().unwrap().clone())),
+            Bound::Included(TableKey(keys.iter().max().unwrap().clone())),
+        );
+
+        let read_version_tuple = if read_options.read_version_from_backup {
+            self.build_read_version_tuple_from_backup(epoch).await?
+        } else {
+            self.build_read_version_tuple(epoch, read_options.table_id, &key_range)?
+        };
+
+        futures::future::try_join_all(keys.iter().map(|key| {
+            self.hummock_version_reader.get(
+                TableKey(key.as_slice()),
+                epoch,
+                read_options.clone(),
+                read_version_tuple.clone(),
+            )
+        }))
+        .await
+    }
+
     async fn iter_inner(
         &self,
         key_range: TableKeyRange,
@@ -158,6 +192,15 @@ impl StateStoreRead for HummockStorage {
         self.get(key, epoch, read_options)
     }
 
+    fn multi_get<'a>(
+        &'a self,
+        keys: &'a [Vec<u8>],
+        epoch: u64,
+        read_options: ReadOptions,
+    ) -> Self::MultiGetFuture<'_> {
+        self.multi_get_inner(keys, epoch, read_options)
+    }
+
     fn iter(
         &self,
         key_range: (Bound<Vec<u8>>, Bound<Vec<u8>>),
@@ -228,6 +271,13 @@ impl StateStore for HummockStorage {
 
     fn sync(&self, epoch: u64) -> Self::SyncFuture<'_> {
         async move {
+            if self.is_read_only() {
+                return Err(HummockError::read_only(format!(
+                    "cannot sync epoch {} in read-only replica mode",
+                    epoch
+                ))
+                .into());
+            }
             if epoch == INVALID_EPOCH {
                 warn!("syncing invalid epoch");
                 return Ok(SyncResult {
@@ -247,6 +297,10 @@ impl StateStore for HummockStorage {
     }
 
     fn seal_epoch(&self, epoch: u64, is_checkpoint: bool) {
+        assert!(
+            !self.is_read_only(),
+            "cannot seal epoch in read-only replica mode"
+        );
         if epoch == INVALID_EPOCH {
             warn!("sealing invalid epoch");
             return;
@@ -18,6 +18,7 @@ use std::sync::Arc;
 
 use async_stack_trace::StackTrace;
 use bytes::Bytes;
+use itertools::Itertools;
 use minitrace::future::FutureExt;
 use parking_lot::RwLock;
 use risingwave_common::catalog::{TableId, TableOption};
@@ -30,8 +31,8 @@ use super::version::{HummockReadVersion, StagingData, VersionUpdate};
 use crate::error::StorageResult;
 use crate::hummock::event_handler::{HummockEvent, LocalInstanceGuard};
 use crate::hummock::iterator::{
-    ConcatIteratorInner, Forward, HummockIteratorUnion, OrderedMergeIteratorInner,
-    UnorderedMergeIteratorInner, UserIterator,
+    Backward, BackwardUserIterator, ConcatIteratorInner, Forward, HummockIteratorUnion,
+    OrderedMergeIteratorInner, UnorderedMergeIteratorInner, UserIterator,
 };
 use crate::hummock::shared_buffer::shared_buffer_batch::{
     SharedBufferBatch, SharedBufferBatchIterator,
@@ -41,7 +42,7 @@ use crate::hummock::utils::{
     do_delete_sanity_check, do_insert_sanity_check, do_update_sanity_check,
     filter_with_delete_range, ENABLE_SANITY_CHECK,
 };
-use crate::hummock::{MemoryLimiter, SstableIterator};
+use crate::hummock::{BackwardSstableIterator, MemoryLimiter, SstableIterator};
 use crate::mem_table::{merge_stream, KeyOp, MemTable};
 use crate::monitor::{HummockStateStoreMetrics, IterLocalMetricsGuard, StoreLocalStatistic};
 use crate::storage_value::StorageValue;
@@ -106,6 +107,40 @@ impl LocalHummockStorage {
             .await
     }
 
+    /// Gets the values of multiple `keys` based on the same read snapshot, which is built only
+    /// once from a key range covering all the keys. The per-key lookups run concurrently.
+    pub async fn multi_get_inner(
+        &self,
+        keys: &[Vec<u8>],
+        epoch: u64,
+        read_options: ReadOptions,
+    ) -> StorageResult<Vec<Option<Bytes>>> {
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+        let table_key_range = (
+            Bound::Included(TableKey(keys.iter().min().unwrap().clone())),
+            Bound::Included(TableKey(keys.iter().max().unwrap().clone())),
+        );
+
+        let read_snapshot = read_filter_for_local(
+            epoch,
+            read_options.table_id,
+            &table_key_range,
+            self.read_version.clone(),
+        )?;
+
+        futures::future::try_join_all(keys.iter().map(|key| {
+            self.hummock_version_reader.get(
+                TableKey(key.as_slice()),
+                epoch,
+                read_options.clone(),
+                read_snapshot.clone(),
+            )
+        }))
+        .await
+    }
+
     pub async fn iter_inner(
         &self,
         table_key_range: TableKeyRange,
@@ -167,6 +202,16 @@ impl StateStoreRead for LocalHummockStorage {
         self.get_inner(TableKey(key), epoch, read_options)
     }
 
+    fn multi_get<'a>(
+        &'a self,
+        keys: &'a [Vec<u8>],
+        epoch: u64,
+        read_options: ReadOptions,
+    ) -> Self::MultiGetFuture<'_> {
+        assert!(epoch <= self.epoch());
+        self.multi_get_inner(keys, epoch, read_options)
+    }
+
     fn iter(
         &self,
         key_range: (Bound<Vec<u8>>, Bound<Vec<u8>>),
@@ -184,6 +229,7 @@ impl LocalStateStore for LocalHummockStorage {
     type GetFuture<'a> = impl GetFutureTrait<'a>;
     type IterFuture<'a> = impl Future<Output = StorageResult<Self::IterStream<'a>>> + Send + 'a;
     type IterStream<'a> = impl StateStoreIterItemStream + 'a;
+    type MultiGetFuture<'a> = impl MultiGetFutureTrait<'a>;
 
     define_local_state_store_associated_type!();
 
@@ -210,6 +256,41 @@ impl LocalStateStore for LocalHummockStorage {
         }
     }
 
+    fn multi_get<'a>(
+        &'a self,
+        keys: &'a [Vec<u8>],
+        read_options: ReadOptions,
+    ) -> Self::MultiGetFuture<'_> {
+        async move {
+            // Resolve as many keys as possible from the mem table and look up the rest in the
+            // read version in a single call.
+            let mut values = vec![None; keys.len()];
+            let mut missing_keys = Vec::new();
+            let mut missing_positions = Vec::new();
+            for (position, key) in keys.iter().enumerate() {
+                match self.mem_table.buffer.get(key.as_slice()) {
+                    None => {
+                        missing_keys.push(key.clone());
+                        missing_positions.push(position);
+                    }
+                    Some(KeyOp::Insert(value)) | Some(KeyOp::Update((_, value))) => {
+                        values[position] = Some(value.clone());
+                    }
+                    Some(KeyOp::Delete(_)) => {}
+                }
+            }
+            if !missing_keys.is_empty() {
+                let missing_values = self
+                    .multi_get_inner(&missing_keys, self.epoch(), read_options)
+                    .await?;
+                for (position, value) in missing_positions.into_iter().zip_eq(missing_values) {
+                    values[position] = value;
+                }
+            }
+            Ok(values)
+        }
+    }
+
     fn iter(
         &self,
         key_range: (Bound<Vec<u8>>, Bound<Vec<u8>>),
@@ -521,3 +602,62 @@ impl Drop for HummockStorageIterator {
             .collect_local_statistic(&mut self.stats_guard.local_stats);
     }
 }
+
+pub type BackwardStagingDataIterator = OrderedMergeIteratorInner<
+    HummockIteratorUnion<Backward, SharedBufferBatchIterator<Backward>, BackwardSstableIterator>,
+>;
+type HummockStorageBackwardIteratorPayload = UnorderedMergeIteratorInner<
+    HummockIteratorUnion<
+        Backward,
+        BackwardStagingDataIterator,
+        OrderedMergeIteratorInner<BackwardSstableIterator>,
+        ConcatIteratorInner<BackwardSstableIterator>,
+    >,
+>;
+
+/// The backward counterpart of [`HummockStorageIterator`].
+pub struct HummockStorageBackwardIterator {
+    inner: BackwardUserIterator<HummockStorageBackwardIteratorPayload>,
+    stats_guard: IterLocalMetricsGuard,
+}
+
+impl StateStoreIter for HummockStorageBackwardIterator {
+    type Item = StateStoreIterItem;
+
+    type NextFuture<'a> = impl StateStoreIterNextFutureTrait<'a>;
+
+    fn next(&mut self) -> Self::NextFuture<'_> {
+        async {
+            let iter = &mut self.inner;
+
+            if iter.is_valid() {
+                let kv = (iter.key().clone(), iter.value().clone());
+                iter.next().await?;
+                Ok(Some(kv))
+            } else {
+                Ok(None)
+            }
+        }
+    }
+}
+
+impl HummockStorageBackwardIterator {
+    pub fn new(
+        inner: BackwardUserIterator<HummockStorageBackwardIteratorPayload>,
+        metrics: Arc<HummockStateStoreMetrics>,
+        table_id: TableId,
+        local_stats: StoreLocalStatistic,
+    ) -> Self {
+        Self {
+            inner,
+            stats_guard: IterLocalMetricsGuard::new(metrics, table_id, local_stats),
+        }
+    }
+}
+
+impl Drop for HummockStorageBackwardIterator {
+    fn drop(&mut self) {
+        self.inner
+            .collect_local_statistic(&mut self.stats_guard.local_stats);
+    }
+}
@@ -33,23 +33,24 @@ use risingwave_pb::hummock::{HummockVersionDelta, LevelType, SstableInfo};
 use sync_point::sync_point;
 
 use super::memtable::{ImmId, ImmutableMemtable};
-use super::state_store::StagingDataIterator;
+use super::state_store::{BackwardStagingDataIterator, StagingDataIterator};
 use crate::error::StorageResult;
 use crate::hummock::iterator::{
-    ConcatIterator, ForwardMergeRangeIterator, HummockIteratorUnion, OrderedMergeIteratorInner,
-    UnorderedMergeIteratorInner, UserIterator,
+    BackwardConcatIterator, BackwardUserIterator, ConcatIterator, ForwardMergeRangeIterator,
+    HummockIteratorUnion, OrderedMergeIteratorInner, UnorderedMergeIteratorInner, UserIterator,
 };
 use crate::hummock::local_version::pinned_version::PinnedVersion;
 use crate::hummock::sstable::SstableIteratorReadOptions;
 use crate::hummock::sstable_store::SstableStoreRef;
-use crate::hummock::store::state_store::HummockStorageIterator;
+use crate::hummock::store::state_store::{HummockStorageBackwardIterator, HummockStorageIterator};
 use crate::hummock::utils::{
-    check_subset_preserve_order, filter_single_sst, prune_nonoverlapping_ssts,
-    prune_overlapping_ssts, range_overlap, search_sst_idx,
+    check_subset_preserve_order, filter_single_sst, filter_single_sst_by_epoch,
+    prune_nonoverlapping_ssts, prune_overlapping_ssts, search_sst_idx,
 };
 use crate::hummock::{
-    get_from_batch, get_from_sstable_info, hit_sstable_bloom_filter, DeleteRangeAggregator,
-    Sstable, SstableDeleteRangeIterator, SstableIterator,
+    get_from_batch, get_from_sstable_info, hit_sstable_bloom_filter, BackwardSstableIterator,
+    DeleteRangeAggregator, DeleteRangeAggregatorBuilder, Sstable, SstableDeleteRangeIterator,
+    SstableIterator, SstableIteratorType,
 };
 use crate::monitor::{
     GetLocalMetricsGuard, HummockStateStoreMetrics, MayExistLocalMetricsGuard, StoreLocalStatistic,
@@ -150,9 +151,8 @@ impl StagingVersion {
     ) {
         let overlapped_imms = self.imm.iter().filter(move |imm| {
             imm.epoch() <= max_epoch_inclusive
-                && imm.table_id == table_id
                 && imm.epoch() > min_epoch_exclusive
-                && range_overlap(table_key_range, imm.start_table_key(), imm.end_table_key())
+                && imm.filter(table_id, table_key_range)
         });
 
         // TODO: Remove duplicate sst based on sst id
@@ -439,6 +439,9 @@ impl HummockVersionReader {
 
         let full_key = FullKey::new(read_options.table_id, table_key, epoch);
         for local_sst in &uncommitted_ssts {
+            if !filter_single_sst_by_epoch(local_sst, min_epoch, epoch) {
+                continue;
+            }
             stats_guard.local_stats.sub_iter_count += 1;
             if let Some(data) = get_from_sstable_info(
                 self.sstable_store.clone(),
@@ -473,6 +476,9 @@ impl HummockVersionReader {
                         &single_table_key_range,
                     );
                     for sstable_info in sstable_infos {
+                        if !filter_single_sst_by_epoch(sstable_info, min_epoch, epoch) {
+                            continue;
+                        }
                         stats_guard.local_stats.sub_iter_count += 1;
                         if let Some(v) = get_from_sstable_info(
                             self.sstable_store.clone(),
@@ -504,6 +510,13 @@ impl HummockVersionReader {
                         sync_point!("HUMMOCK_V2::GET::SKIP_BY_NO_FILE");
                         continue;
                     }
+                    if !filter_single_sst_by_epoch(
+                        &level.table_infos[table_info_idx],
+                        min_epoch,
+                        epoch,
+                    ) {
+                        continue;
+                    }
 
                     stats_guard.local_stats.sub_iter_count += 1;
                     if let Some(v) = get_from_sstable_info(
@@ -535,6 +548,11 @@ impl HummockVersionReader {
         let table_id_string = read_options.table_id.to_string();
         let table_id_label = table_id_string.as_str();
         let (imms, uncommitted_ssts, committed) = read_version_tuple;
+        let sst_read_options = Arc::new(SstableIteratorReadOptions {
+            cache_policy: read_options.cache_policy,
+            limit: read_options.limit,
+            ..Default::default()
+        });
 
         let mut local_stats = StoreLocalStatistic::default();
         let mut staging_iters = Vec::with_capacity(imms.len() + uncommitted_ssts.len());
@@ -576,13 +594,15 @@ impl HummockVersionReader {
             staging_iters.push(HummockIteratorUnion::Second(SstableIterator::new(
                 table_holder,
                 self.sstable_store.clone(),
-                Arc::new(SstableIteratorReadOptions::default()),
+                sst_read_options.clone(),
             )));
         }
         local_stats.staging_sst_iter_count = staging_sst_iter_count;
         let staging_iter: StagingDataIterator = OrderedMergeIteratorInner::new(staging_iters);
 
         // 2. build iterator from committed
+        // the epoch_range left bound for iterator read
+        let min_epoch = gen_min_epoch(epoch, read_options.retention_seconds.as_ref());
         // Because SST meta records encoded key range,
         // the filter key range needs to be encoded as well.
         let user_key_range = bound_table_key_range(read_options.table_id, &table_key_range);
@@ -609,6 +629,7 @@ impl HummockVersionReader {
                             .table_ids
                             .binary_search(&read_options.table_id.table_id)
                             .is_ok()
+                            && filter_single_sst_by_epoch(sstable_info, min_epoch, epoch)
                     })
                     .collect_vec();
                 fetch_meta_reqs.push((level.level_type, fetch_meta_req));
@@ -619,7 +640,12 @@ impl HummockVersionReader {
                     &table_key_range,
                 );
                 // Overlapping
-                let fetch_meta_req = table_infos.rev().collect_vec();
+                let fetch_meta_req = table_infos
+                    .rev()
+                    .filter(|sstable_info| {
+                        filter_single_sst_by_epoch(sstable_info, min_epoch, epoch)
+                    })
+                    .collect_vec();
                 if !fetch_meta_req.is_empty() {
                     fetch_meta_reqs.push((level.level_type, fetch_meta_req));
                 }
@@ -681,7 +707,7 @@ impl HummockVersionReader {
                 non_overlapping_iters.push(ConcatIterator::new_with_prefetch(
                     sstables,
                     self.sstable_store.clone(),
-                    Arc::new(SstableIteratorReadOptions::default()),
+                    sst_read_options.clone(),
                 ));
             } else {
                 let mut iters = Vec::new();
@@ -705,7 +731,7 @@ impl HummockVersionReader {
                     iters.push(SstableIterator::new(
                         sstable,
                         self.sstable_store.clone(),
-                        Arc::new(SstableIteratorReadOptions::default()),
+                        sst_read_options.clone(),
                     ));
                     overlapping_iter_count += 1;
                 }
@@ -730,8 +756,6 @@ impl HummockVersionReader {
                 ),
         );
 
-        // the epoch_range left bound for iterator read
-        let min_epoch = gen_min_epoch(epoch, read_options.retention_seconds.as_ref());
         let mut user_iter = UserIterator::new(
             merge_iter,
             user_key_range,
@@ -759,6 +783,258 @@ impl HummockVersionReader {
         .into_stream())
     }
 
+    /// The backward counterpart of [`iter`](Self::iter). It merges unflushed shared-buffer data
+    /// with SST data under the same epoch visibility rules, so that backward scans observe
+    /// uncommitted epochs consistently with forward scans.
+    pub async fn backward_iter(
+        &self,
+        table_key_range: TableKeyRange,
+        epoch: u64,
+        read_options: ReadOptions,
+        read_version_tuple: (Vec<ImmutableMemtable>, Vec<SstableInfo>, CommittedVersion),
+    ) -> StorageResult<StreamTypeOfIter<HummockStorageBackwardIterator>> {
+        let table_id_string = read_options.table_id.to_string();
+        let table_id_label = table_id_string.as_str();
+        let (imms, uncommitted_ssts, committed) = read_version_tuple;
+        let sst_read_options = Arc::new(SstableIteratorReadOptions {
+            cache_policy: read_options.cache_policy,
+            limit: read_options.limit,
+            ..Default::default()
+        });
+
+        let mut local_stats = StoreLocalStatistic::default();
+        let mut staging_iters = Vec::with_capacity(imms.len() + uncommitted_ssts.len());
+        let mut delete_range_builder = DeleteRangeAggregatorBuilder::default();
+        local_stats.staging_imm_iter_count = imms.len() as u64;
+        for imm in imms {
+            if imm.has_range_tombstone() && !read_options.ignore_range_tombstone {
+                delete_range_builder.add_tombstone(imm.get_delete_range_tombstones());
+            }
+            staging_iters.push(HummockIteratorUnion::First(imm.into_backward_iter()));
+        }
+        let mut staging_sst_iter_count = 0;
+        // encode once
+        let bloom_filter_prefix_hash = read_options
+            .prefix_hint
+            .as_ref()
+            .map(|hint| Sstable::hash_for_bloom_filter(hint, read_options.table_id.table_id()));
+
+        for sstable_info in &uncommitted_ssts {
+            let table_holder = self
+                .sstable_store
+                .sstable(sstable_info, &mut local_stats)
+                .in_span(Span::enter_with_local_parent("get_sstable"))
+                .await?;
+
+            if !table_holder.value().meta.range_tombstone_list.is_empty()
+                && !read_options.ignore_range_tombstone
+            {
+                delete_range_builder
+                    .add_tombstone(table_holder.value().meta.range_tombstone_list.clone());
+            }
+            if let Some(prefix_hash) = bloom_filter_prefix_hash.as_ref() {
+                if !hit_sstable_bloom_filter(table_holder.value(), *prefix_hash, &mut local_stats) {
+                    continue;
+                }
+            }
+
+            staging_sst_iter_count += 1;
+            staging_iters.push(HummockIteratorUnion::Second(BackwardSstableIterator::create(
+                table_holder,
+                self.sstable_store.clone(),
+                sst_read_options.clone(),
+            )));
+        }
+        local_stats.staging_sst_iter_count = staging_sst_iter_count;
+        let staging_iter: BackwardStagingDataIterator =
+            OrderedMergeIteratorInner::new(staging_iters);
+
+        // 2. build iterator from committed
+        // the epoch_range left bound for iterator read
+        let min_epoch = gen_min_epoch(epoch, read_options.retention_seconds.as_ref());
+        // Because SST meta records encoded key range,
+        // the filter key range needs to be encoded as well.
+        let user_key_range = bound_table_key_range(read_options.table_id, &table_key_range);
+        let encoded_user_key_range = (
+            user_key_range.0.as_ref().map(UserKey::encode),
+            user_key_range.1.as_ref().map(UserKey::encode),
+        );
+        let mut non_overlapping_iters = Vec::new();
+        let mut overlapping_iters = Vec::new();
+        let mut overlapping_iter_count = 0;
+        let mut fetch_meta_reqs = vec![];
+        for level in committed.levels(read_options.table_id) {
+            if level.table_infos.is_empty() {
+                continue;
+            }
+
+            if level.level_type == LevelType::Nonoverlapping as i32 {
+                let table_infos =
+                    prune_nonoverlapping_ssts(&level.table_infos, &encoded_user_key_range);
+
+                // The backward concat iterator requires the SSTs to be arranged in descending
+                // order.
+                let fetch_meta_req = table_infos
+                    .rev()
+                    .filter(|sstable_info| {
+                        sstable_info
+                            .table_ids
+                            .binary_search(&read_options.table_id.table_id)
+                            .is_ok()
+                            && filter_single_sst_by_epoch(sstable_info, min_epoch, epoch)
+                    })
+                    .collect_vec();
+                fetch_meta_reqs.push((level.level_type, fetch_meta_req));
+            } else {
+                let table_infos = prune_overlapping_ssts(
+                    &level.table_infos,
+                    read_options.table_id,
+                    &table_key_range,
+                );
+                // Overlapping
+                let fetch_meta_req = table_infos
+                    .rev()
+                    .filter(|sstable_info| {
+                        filter_single_sst_by_epoch(sstable_info, min_epoch, epoch)
+                    })
+                    .collect_vec();
+                if !fetch_meta_req.is_empty() {
+                    fetch_meta_reqs.push((level.level_type, fetch_meta_req));
+                }
+            }
+        }
+        let mut flatten_reqs = vec![];
+        let mut req_count = 0;
+        for (_, fetch_meta_req) in &fetch_meta_reqs {
+            for sstable_info in fetch_meta_req {
+                let inner_req_count = req_count;
+                let capture_ref = async {
+                    self.sstable_store
+                        .sstable_syncable(sstable_info, &local_stats)
+                        .in_span(Span::enter_with_local_parent("get_sstable"))
+                        .await
+                };
+                // use `buffer_unordered` to simulate `try_join_all` by assigning an index
+                flatten_reqs
+                    .push(async move { capture_ref.await.map(|result| (inner_req_count, result)) });
+                req_count += 1;
+            }
+        }
+        let timer = self
+            .state_store_metrics
+            .iter_fetch_meta_duration
+            .with_label_values(&[table_id_label])
+            .start_timer();
+        let mut flatten_resps = vec![None; req_count];
+        let mut buffered = stream::iter(flatten_reqs).buffer_unordered(10);
+        while let Some(result) = buffered.next().await {
+            let (req_index, resp) = result?;
+            flatten_resps[req_count - req_index - 1] = Some(resp);
+        }
+        drop(buffered);
+        timer.observe_duration();
+
+        for (level_type, fetch_meta_req) in fetch_meta_reqs {
+            if level_type == LevelType::Nonoverlapping as i32 {
+                let mut sstables = vec![];
+                for sstable_info in fetch_meta_req {
+                    let (sstable, local_cache_meta_block_miss) =
+                        flatten_resps.pop().unwrap().unwrap();
+                    assert_eq!(sstable_info.id, sstable.value().id);
+                    local_stats.apply_meta_fetch(local_cache_meta_block_miss);
+                    if !sstable.value().meta.range_tombstone_list.is_empty()
+                        && !read_options.ignore_range_tombstone
+                    {
+                        delete_range_builder
+                            .add_tombstone(sstable.value().meta.range_tombstone_list.clone());
+                    }
+                    if let Some(key_hash) = bloom_filter_prefix_hash.as_ref() {
+                        if !hit_sstable_bloom_filter(sstable.value(), *key_hash, &mut local_stats) {
+                            continue;
+                        }
+                    }
+                    sstables.push(sstable);
+                }
+
+                non_overlapping_iters.push(BackwardConcatIterator::new_with_prefetch(
+                    sstables,
+                    self.sstable_store.clone(),
+                    sst_read_options.clone(),
+                ));
+            } else {
+                let mut iters = Vec::new();
+                for sstable_info in fetch_meta_req {
+                    let (sstable, local_cache_meta_block_miss) =
+                        flatten_resps.pop().unwrap().unwrap();
+                    assert_eq!(sstable_info.id, sstable.value().id);
+                    local_stats.apply_meta_fetch(local_cache_meta_block_miss);
+                    if !sstable.value().meta.range_tombstone_list.is_empty()
+                        && !read_options.ignore_range_tombstone
+                    {
+                        delete_range_builder
+                            .add_tombstone(sstable.value().meta.range_tombstone_list.clone());
+                    }
+                    if let Some(dist_hash) = bloom_filter_prefix_hash.as_ref() {
+                        if !hit_sstable_bloom_filter(sstable.value(), *dist_hash, &mut local_stats)
+                        {
+                            continue;
+                        }
+                    }
+                    iters.push(BackwardSstableIterator::create(
+                        sstable,
+                        self.sstable_store.clone(),
+                        sst_read_options.clone(),
+                    ));
+                    overlapping_iter_count += 1;
+                }
+                overlapping_iters.push(OrderedMergeIteratorInner::new(iters));
+            }
+        }
+        local_stats.overlapping_iter_count = overlapping_iter_count;
+        local_stats.non_overlapping_iter_count = non_overlapping_iters.len() as u64;
+
+        // 3. build user_iterator
+        let merge_iter = UnorderedMergeIteratorInner::new(
+            once(HummockIteratorUnion::First(staging_iter))
+                .chain(
+                    overlapping_iters
+                        .into_iter()
+                        .map(HummockIteratorUnion::Second),
+                )
+                .chain(
+                    non_overlapping_iters
+                        .into_iter()
+                        .map(HummockIteratorUnion::Third),
+                ),
+        );
+
+        let mut user_iter = BackwardUserIterator::new(
+            merge_iter,
+            user_key_range,
+            epoch,
+            min_epoch,
+            Some(committed),
+            delete_range_builder.build(epoch, false),
+        );
+        user_iter
+            .rewind()
+            .in_span(Span::enter_with_local_parent("rewind"))
+            .await?;
+        local_stats.found_key = user_iter.is_valid();
+        local_stats.sub_iter_count = local_stats.staging_imm_iter_count
+            + local_stats.staging_sst_iter_count
+            + local_stats.overlapping_iter_count
+            + local_stats.non_overlapping_iter_count;
+
+        Ok(HummockStorageBackwardIterator::new(
+            user_iter,
+            self.state_store_metrics.clone(),
+            read_options.table_id,
+            local_stats,
+        )
+        .into_stream())
+    }
+
     // Note: this method will not check the kv tomestones and delete range tomestones
     pub async fn may_exist(
         &self,
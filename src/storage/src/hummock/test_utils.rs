@@ -25,7 +25,7 @@ use risingwave_pb::hummock::{KeyRange, SstableInfo};
 use super::iterator::test_utils::iterator_test_table_key_of;
 use super::{
     CompressionAlgorithm, HummockResult, InMemWriter, SstableMeta, SstableWriterOptions,
-    DEFAULT_RESTART_INTERVAL,
+    DEFAULT_COMPRESSION_LEVEL, DEFAULT_RESTART_INTERVAL,
 };
 use crate::error::StorageResult;
 use crate::hummock::shared_buffer::shared_buffer_batch::SharedBufferBatch;
@@ -113,6 +113,7 @@ pub fn gen_dummy_sst_info(
         uncompressed_file_size: file_size,
         min_epoch: 0,
         max_epoch: 0,
+        tombstone_key_count: 0,
     }
 }
 
@@ -126,6 +127,7 @@ pub fn default_builder_opt_for_test() -> SstableBuilderOptions {
         restart_interval: DEFAULT_RESTART_INTERVAL,
         bloom_false_positive: 0.1,
         compression_algorithm: CompressionAlgorithm::None,
+        compression_level: DEFAULT_COMPRESSION_LEVEL,
     }
 }
 
@@ -134,6 +136,7 @@ pub fn default_writer_opt_for_test() -> SstableWriterOptions {
         capacity_hint: None,
         tracker: None,
         policy: CachePolicy::Disable,
+        encryption: None,
     }
 }
 
@@ -188,6 +191,7 @@ pub async fn put_sst(
         uncompressed_file_size: meta.estimated_size as u64,
         min_epoch: 0,
         max_epoch: 0,
+        tombstone_key_count: 0,
     };
     let writer_output = writer.finish(meta).await?;
     writer_output.await.unwrap()?;
@@ -207,6 +211,7 @@ pub async fn gen_test_sstable_inner<B: AsRef<[u8]>>(
         capacity_hint: None,
         tracker: None,
         policy,
+        encryption: None,
     };
     let writer = sstable_store.clone().create_sst_writer(sst_id, writer_opts);
     let mut b = SstableBuilder::for_test(sst_id, writer, opts);
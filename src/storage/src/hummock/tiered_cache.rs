@@ -106,12 +106,12 @@ where
 unsafe impl<K: TieredCacheKey, V: TieredCacheValue> Send for TieredCacheEntryHolder<K, V> {}
 unsafe impl<K: TieredCacheKey, V: TieredCacheValue> Sync for TieredCacheEntryHolder<K, V> {}
 
-#[cfg(target_os = "linux")]
+#[cfg(unix)]
 pub use super::file_cache;
 
 #[derive(thiserror::Error, Debug)]
 pub enum TieredCacheError {
-    #[cfg(target_os = "linux")]
+    #[cfg(unix)]
     #[error("file cache error: {0}")]
     FileCache(#[from] file_cache::error::Error),
 }
@@ -129,7 +129,7 @@ impl TieredCacheMetricsBuilder {
         Self(None)
     }
 
-    #[cfg(target_os = "linux")]
+    #[cfg(unix)]
     pub fn file(self) -> file_cache::metrics::FileCacheMetrics {
         file_cache::metrics::FileCacheMetrics::new(self.0.unwrap())
     }
@@ -141,7 +141,7 @@ where
     V: TieredCacheValue,
 {
     NoneCache(PhantomData<(K, V)>),
-    #[cfg(target_os = "linux")]
+    #[cfg(unix)]
     FileCache(file_cache::cache::FileCache<K, V>),
 }
 
@@ -153,7 +153,7 @@ where
     fn clone(&self) -> Self {
         match self {
             TieredCache::NoneCache(_) => TieredCache::NoneCache(PhantomData::default()),
-            #[cfg(target_os = "linux")]
+            #[cfg(unix)]
             TieredCache::FileCache(file_cache) => TieredCache::FileCache(file_cache.clone()),
         }
     }
@@ -168,7 +168,7 @@ where
         Self::NoneCache(PhantomData::default())
     }
 
-    #[cfg(target_os = "linux")]
+    #[cfg(unix)]
     pub async fn file(
         options: file_cache::cache::FileCacheOptions,
         metrics: file_cache::metrics::FileCacheMetricsRef,
@@ -181,7 +181,7 @@ where
     pub fn insert(&self, key: K, value: V) -> Result<()> {
         match self {
             TieredCache::NoneCache(_) => Ok(()),
-            #[cfg(target_os = "linux")]
+            #[cfg(unix)]
             TieredCache::FileCache(file_cache) => {
                 file_cache.insert(key, value)?;
                 Ok(())
@@ -193,7 +193,7 @@ where
     pub fn erase(&self, key: &K) -> Result<()> {
         match self {
             TieredCache::NoneCache(_) => Ok(()),
-            #[cfg(target_os = "linux")]
+            #[cfg(unix)]
             TieredCache::FileCache(file_cache) => {
                 file_cache.erase(key)?;
                 Ok(())
@@ -205,7 +205,7 @@ where
     pub async fn get(&self, key: &K) -> Result<Option<TieredCacheEntryHolder<K, V>>> {
         match self {
             TieredCache::NoneCache(_) => Ok(None),
-            #[cfg(target_os = "linux")]
+            #[cfg(unix)]
             TieredCache::FileCache(file_cache) => {
                 let holder = file_cache.get(key).await?;
                 Ok(holder)
@@ -21,7 +21,7 @@ use std::sync::Arc;
 
 use bytes::Bytes;
 use risingwave_common::catalog::{TableId, TableOption};
-use risingwave_hummock_sdk::can_concat;
+use risingwave_hummock_sdk::{can_concat, HummockEpoch};
 use risingwave_hummock_sdk::key::{bound_table_key_range, user_key, TableKey, UserKey};
 use risingwave_pb::hummock::{HummockVersion, SstableInfo};
 use tokio::sync::Notify;
@@ -29,7 +29,7 @@ use tokio::sync::Notify;
 use super::{HummockError, HummockResult};
 use crate::error::StorageResult;
 use crate::mem_table::{KeyOp, MemTableError};
-use crate::store::{ReadOptions, StateStoreRead};
+use crate::store::{CachePolicy, ReadOptions, StateStoreRead};
 
 pub fn range_overlap<R, B>(
     search_key_range: &R,
@@ -108,6 +108,18 @@ where
             .is_ok()
 }
 
+/// Whether the SST may contain keys visible within the epoch range
+/// `(min_epoch_exclusive, max_epoch_inclusive]`. SSTs built before the epoch range was recorded in
+/// the meta have `max_epoch == 0` and are never filtered out.
+pub fn filter_single_sst_by_epoch(
+    info: &SstableInfo,
+    min_epoch_exclusive: HummockEpoch,
+    max_epoch_inclusive: HummockEpoch,
+) -> bool {
+    info.max_epoch == 0
+        || (info.min_epoch <= max_epoch_inclusive && info.max_epoch > min_epoch_exclusive)
+}
+
 /// Search the SST containing the specified key within a level, using binary search.
 pub(crate) fn search_sst_idx<B>(ssts: &[SstableInfo], key: &B) -> usize
 where
@@ -350,7 +362,9 @@ pub(crate) async fn do_insert_sanity_check(
         retention_seconds: table_option.retention_seconds,
         table_id,
         ignore_range_tombstone: false,
+        limit: None,
         read_version_from_backup: false,
+        cache_policy: CachePolicy::Fill,
     };
     let stored_value = inner.get(key, epoch, read_options).await?;
 
@@ -379,7 +393,9 @@ pub(crate) async fn do_delete_sanity_check(
         retention_seconds: table_option.retention_seconds,
         table_id,
         ignore_range_tombstone: false,
+        limit: None,
         read_version_from_backup: false,
+        cache_policy: CachePolicy::Fill,
     };
     match inner.get(key, epoch, read_options).await? {
         None => Err(Box::new(MemTableError::InconsistentOperation {
@@ -416,9 +432,11 @@ pub(crate) async fn do_update_sanity_check(
     let read_options = ReadOptions {
         prefix_hint: None,
         ignore_range_tombstone: false,
+        limit: None,
         retention_seconds: table_option.retention_seconds,
         table_id,
         read_version_from_backup: false,
+        cache_policy: CachePolicy::Fill,
     };
 
     match inner.get(key, epoch, read_options).await? {
@@ -449,40 +467,45 @@ pub(crate) async fn do_update_sanity_check(
     }
 }
 
+/// Whether `key` falls in the delete range `[range_start, range_end)`, where an empty
+/// `range_end` means the range is unbounded on the right.
+fn covered_by_delete_range(key: &Bytes, range_start: &Bytes, range_end: &Bytes) -> bool {
+    key >= range_start && (range_end.is_empty() || key < range_end)
+}
+
+fn assert_delete_range_valid(range_start: &Bytes, range_end: &Bytes) {
+    assert!(
+        range_end.is_empty() || range_start <= range_end,
+        "range_end {:?} smaller than range_start {:?}",
+        range_start,
+        range_end
+    );
+}
+
 pub(crate) fn filter_with_delete_range<'a>(
     kv_iter: impl Iterator<Item = (Bytes, KeyOp)> + 'a,
     mut delete_ranges_iter: impl Iterator<Item = &'a (Bytes, Bytes)> + 'a,
 ) -> impl Iterator<Item = (Bytes, KeyOp)> + 'a {
     let mut range = delete_ranges_iter.next();
     if let Some((range_start, range_end)) = range {
-        assert!(
-            range_start <= range_end,
-            "range_end {:?} smaller than range_start {:?}",
-            range_start,
-            range_end
-        );
+        assert_delete_range_valid(range_start, range_end);
     }
     kv_iter.filter(move |(ref key, _)| {
         if let Some((range_start, range_end)) = range {
             if key < range_start {
                 true
-            } else if key < range_end {
+            } else if covered_by_delete_range(key, range_start, range_end) {
                 false
             } else {
                 // Key has exceeded the current key range. Advance to the next range.
                 loop {
                     range = delete_ranges_iter.next();
                     if let Some((range_start, range_end)) = range {
-                        assert!(
-                            range_start <= range_end,
-                            "range_end {:?} smaller than range_start {:?}",
-                            range_start,
-                            range_end
-                        );
+                        assert_delete_range_valid(range_start, range_end);
                         if key < range_start {
                             // Not fall in the next delete range
                             break true;
-                        } else if key < range_end {
+                        } else if covered_by_delete_range(key, range_start, range_end) {
                             // Fall in the next delete range
                             break false;
                         } else {
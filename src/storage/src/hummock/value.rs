@@ -138,6 +138,18 @@ impl<'a> HummockValue<&'a [u8]> {
 }
 
 impl HummockValue<Bytes> {
+    /// Decodes the object from shared [`Bytes`], sharing instead of copying the user value.
+    pub fn from_bytes(mut buffer: Bytes) -> HummockResult<Self> {
+        if buffer.remaining() == 0 {
+            return Err(HummockError::decode_error("empty value"));
+        }
+        match buffer.get_u8() {
+            VALUE_PUT => Ok(Self::Put(buffer)),
+            VALUE_DELETE => Ok(Self::Delete),
+            _ => Err(HummockError::decode_error("non-empty but format error")),
+        }
+    }
+
     pub fn to_vec(&self) -> HummockValue<Vec<u8>> {
         match self {
             HummockValue::Put(data) => HummockValue::Put(data.to_vec()),
@@ -21,8 +21,9 @@ use std::ops::{Bound, RangeBounds};
 use bytes::Bytes;
 use futures::{pin_mut, StreamExt};
 use futures_async_stream::try_stream;
+use itertools::Itertools;
 use risingwave_common::catalog::{TableId, TableOption};
-use risingwave_common::row::RowDeserializer;
+use risingwave_common::util::value_encoding::ValueRowDeserializer;
 use risingwave_hummock_sdk::key::{FullKey, TableKey};
 use thiserror::Error;
 
@@ -204,7 +205,7 @@ impl KeyOp {
     /// # Panics
     ///
     /// The function will panic if it failed to decode the bytes with provided data types.
-    pub fn debug_fmt(&self, row_deserializer: &RowDeserializer) -> String {
+    pub fn debug_fmt(&self, row_deserializer: &impl ValueRowDeserializer) -> String {
         match self {
             Self::Insert(after) => {
                 let after = row_deserializer.deserialize(after.as_ref());
@@ -342,6 +343,7 @@ impl<S: StateStoreWrite + StateStoreRead> LocalStateStore for MemtableLocalState
     type GetFuture<'a> = impl GetFutureTrait<'a>;
     type IterFuture<'a> = impl Future<Output = StorageResult<Self::IterStream<'a>>> + Send + 'a;
     type IterStream<'a> = impl StateStoreIterItemStream + 'a;
+    type MultiGetFuture<'a> = impl MultiGetFutureTrait<'a>;
 
     define_local_state_store_associated_type!();
 
@@ -365,6 +367,42 @@ impl<S: StateStoreWrite + StateStoreRead> LocalStateStore for MemtableLocalState
         }
     }
 
+    fn multi_get<'a>(
+        &'a self,
+        keys: &'a [Vec<u8>],
+        read_options: ReadOptions,
+    ) -> Self::MultiGetFuture<'_> {
+        async move {
+            // Resolve as many keys as possible from the memtable and forward the rest to the
+            // underlying store in a single call.
+            let mut values = vec![None; keys.len()];
+            let mut missing_keys = Vec::new();
+            let mut missing_positions = Vec::new();
+            for (position, key) in keys.iter().enumerate() {
+                match self.mem_table.buffer.get(key.as_slice()) {
+                    None => {
+                        missing_keys.push(key.clone());
+                        missing_positions.push(position);
+                    }
+                    Some(KeyOp::Insert(value)) | Some(KeyOp::Update((_, value))) => {
+                        values[position] = Some(value.clone());
+                    }
+                    Some(KeyOp::Delete(_)) => {}
+                }
+            }
+            if !missing_keys.is_empty() {
+                let missing_values = self
+                    .inner
+                    .multi_get(&missing_keys, self.epoch(), read_options)
+                    .await?;
+                for (position, value) in missing_positions.into_iter().zip_eq(missing_values) {
+                    values[position] = value;
+                }
+            }
+            Ok(values)
+        }
+    }
+
     fn iter(
         &self,
         key_range: (Bound<Vec<u8>>, Bound<Vec<u8>>),
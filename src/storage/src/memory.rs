@@ -114,6 +114,21 @@ pub mod sled {
                 .into_path();
             Self::new(path)
         }
+
+        /// Like [`Self::new_temp`], but bounds the in-memory cache of the store.
+        pub fn new_temp_with_cache_capacity(cache_capacity: u64) -> Self {
+            create_dir_all("./.risingwave/sled").expect("should create");
+            let path = tempfile::TempDir::new_in("./.risingwave/sled")
+                .expect("find temp dir")
+                .into_path();
+            SledRangeKv {
+                inner: sled::Config::new()
+                    .path(path)
+                    .cache_capacity(cache_capacity)
+                    .open()
+                    .expect("open"),
+            }
+        }
     }
 
     const EMPTY: u8 = 1;
@@ -194,6 +209,13 @@ pub mod sled {
                 inner: SledRangeKv::new_temp(),
             }
         }
+
+        /// Like [`Self::new_temp`], but bounds the in-memory cache of the store.
+        pub fn new_temp_with_cache_capacity(cache_capacity: u64) -> Self {
+            RangeKvStateStore {
+                inner: SledRangeKv::new_temp_with_cache_capacity(cache_capacity),
+            }
+        }
     }
 
     #[cfg(test)]
@@ -280,6 +302,122 @@ pub mod sled {
     }
 }
 
+/// An embedded RocksDB state store for durable single-node deployments without an object store.
+/// Like [`sled`], the epoch is encoded in reverse into the key, so that the records of one key are
+/// ordered from the latest epoch to the oldest.
+#[cfg(feature = "rocksdb-backend")]
+pub mod rocksdb {
+    use std::ops::Bound::{Excluded, Included, Unbounded};
+    use std::ops::RangeBounds;
+    use std::sync::Arc;
+
+    use bytes::Bytes;
+    use risingwave_hummock_sdk::key::FullKey;
+    use rocksdb::{Direction, IteratorMode, WriteBatch, DB};
+
+    use crate::error::StorageResult;
+    use crate::memory::{BytesFullKey, BytesFullKeyRange, RangeKv, RangeKvStateStore};
+
+    #[derive(Clone)]
+    pub struct RocksdbRangeKv {
+        inner: Arc<DB>,
+    }
+
+    impl RocksdbRangeKv {
+        pub fn new(path: impl AsRef<std::path::Path>) -> Self {
+            let mut options = rocksdb::Options::default();
+            options.create_if_missing(true);
+            RocksdbRangeKv {
+                inner: Arc::new(DB::open(&options, path).expect("open")),
+            }
+        }
+    }
+
+    const EMPTY: u8 = 1;
+    const NON_EMPTY: u8 = 0;
+
+    impl RangeKv for RocksdbRangeKv {
+        fn range(
+            &self,
+            range: BytesFullKeyRange,
+            limit: Option<usize>,
+        ) -> StorageResult<Vec<(BytesFullKey, Option<Bytes>)>> {
+            let (left, right) = range;
+            let full_key_ref_bound = (
+                left.as_ref().map(FullKey::to_ref),
+                right.as_ref().map(FullKey::to_ref),
+            );
+            let left_encoded = left.as_ref().map(|key| key.to_ref().encode_reverse_epoch());
+            let right_encoded = right
+                .as_ref()
+                .map(|key| key.to_ref().encode_reverse_epoch());
+            let mode = match &left_encoded {
+                Included(key) | Excluded(key) => IteratorMode::From(key, Direction::Forward),
+                Unbounded => IteratorMode::Start,
+            };
+            let limit = limit.unwrap_or(usize::MAX);
+            let mut ret = vec![];
+            for result in self.inner.iterator(mode).take(limit) {
+                let (key, value) = result?;
+                let past_right_bound = match &right_encoded {
+                    Included(bound) => key.as_ref() > bound.as_slice(),
+                    Excluded(bound) => key.as_ref() >= bound.as_slice(),
+                    Unbounded => false,
+                };
+                if past_right_bound {
+                    break;
+                }
+                let full_key = FullKey::decode_reverse_epoch(key.as_ref()).copy_into();
+                if !full_key_ref_bound.contains(&full_key.to_ref()) {
+                    continue;
+                }
+                let value = match value.as_ref() {
+                    [EMPTY] => None,
+                    [NON_EMPTY, rest @ ..] => Some(Bytes::from(Vec::from(rest))),
+                    _ => unreachable!("malformed value: {:?}", value),
+                };
+                ret.push((full_key, value))
+            }
+            Ok(ret)
+        }
+
+        fn ingest_batch(
+            &self,
+            kv_pairs: impl Iterator<Item = (BytesFullKey, Option<Bytes>)>,
+        ) -> StorageResult<()> {
+            let mut batch = WriteBatch::default();
+            for (key, value) in kv_pairs {
+                let encoded_key = key.encode_reverse_epoch();
+                let mut buffer =
+                    Vec::with_capacity(value.as_ref().map(|v| v.len()).unwrap_or_default() + 1);
+                if let Some(value) = value {
+                    buffer.push(NON_EMPTY);
+                    buffer.extend_from_slice(value.as_ref());
+                } else {
+                    buffer.push(EMPTY);
+                }
+                batch.put(encoded_key, buffer);
+            }
+            self.inner.write(batch)?;
+            Ok(())
+        }
+
+        fn flush(&self) -> StorageResult<()> {
+            Ok(self.inner.flush()?)
+        }
+    }
+
+    pub type RocksdbStateStore = RangeKvStateStore<RocksdbRangeKv>;
+
+    impl RocksdbStateStore {
+        pub fn new(path: impl AsRef<std::path::Path>) -> Self {
+            RangeKvStateStore {
+                inner: RocksdbRangeKv::new(path),
+            }
+        }
+    }
+}
+
 mod batched_iter {
     use itertools::Itertools;
 
@@ -561,6 +699,21 @@ impl<R: RangeKv> StateStoreRead for RangeKvStateStore<R> {
         }
     }
 
+    fn multi_get<'a>(
+        &'a self,
+        keys: &'a [Vec<u8>],
+        epoch: u64,
+        read_options: ReadOptions,
+    ) -> Self::MultiGetFuture<'_> {
+        async move {
+            let mut values = Vec::with_capacity(keys.len());
+            for key in keys {
+                values.push(self.get(key, epoch, read_options.clone()).await?);
+            }
+            Ok(values)
+        }
+    }
+
     fn iter(
         &self,
         key_range: (Bound<Vec<u8>>, Bound<Vec<u8>>),
@@ -17,7 +17,7 @@ use std::sync::Arc;
 use prometheus::core::{AtomicU64, Collector, Desc, GenericCounterVec};
 use prometheus::{
     exponential_buckets, histogram_opts, proto, register_histogram_vec_with_registry,
-    register_int_counter_vec_with_registry, HistogramVec, IntGauge, Opts, Registry,
+    register_int_counter_vec_with_registry, HistogramVec, IntGauge, IntGaugeVec, Opts, Registry,
 };
 
 /// [`HummockStateStoreMetrics`] stores the performance and IO metrics of `XXXStore` such as
@@ -190,12 +190,17 @@ pub trait MemoryCollector: Sync + Send {
     fn get_meta_memory_usage(&self) -> u64;
     fn get_data_memory_usage(&self) -> u64;
     fn get_uploading_memory_usage(&self) -> u64;
+    /// The block cache usage per table. Empty for collectors without per-table tracking.
+    fn get_block_cache_usage_per_table(&self) -> Vec<(u32, u64)> {
+        vec![]
+    }
 }
 
 struct StateStoreCollector {
     memory_collector: Arc<dyn MemoryCollector>,
     descs: Vec<Desc>,
     block_cache_size: IntGauge,
+    block_cache_usage_per_table: IntGaugeVec,
     meta_cache_size: IntGauge,
     limit_memory_size: IntGauge,
 }
@@ -211,6 +216,16 @@ impl StateStoreCollector {
         .unwrap();
         descs.extend(block_cache_size.desc().into_iter().cloned());
 
+        let block_cache_usage_per_table = IntGaugeVec::new(
+            Opts::new(
+                "state_store_block_cache_usage_per_table",
+                "the size of cached data blocks in the block cache per table",
+            ),
+            &["table_id"],
+        )
+        .unwrap();
+        descs.extend(block_cache_usage_per_table.desc().into_iter().cloned());
+
         let meta_cache_size = IntGauge::with_opts(Opts::new(
             "state_store_meta_cache_size",
             "the size of cache for meta file cache",
@@ -228,6 +243,7 @@ impl StateStoreCollector {
             memory_collector,
             descs,
             block_cache_size,
+            block_cache_usage_per_table,
             meta_cache_size,
             limit_memory_size,
         }
@@ -246,10 +262,18 @@ impl Collector for StateStoreCollector {
             .set(self.memory_collector.get_meta_memory_usage() as i64);
         self.limit_memory_size
             .set(self.memory_collector.get_uploading_memory_usage() as i64);
+        // Reset, so that tables whose blocks have all been evicted disappear from the report.
+        self.block_cache_usage_per_table.reset();
+        for (table_id, usage) in self.memory_collector.get_block_cache_usage_per_table() {
+            self.block_cache_usage_per_table
+                .with_label_values(&[&table_id.to_string()])
+                .set(usage as i64);
+        }
 
         // collect MetricFamilies.
-        let mut mfs = Vec::with_capacity(3);
+        let mut mfs = Vec::with_capacity(4);
         mfs.extend(self.block_cache_size.collect());
+        mfs.extend(self.block_cache_usage_per_table.collect());
         mfs.extend(self.meta_cache_size.collect());
         mfs.extend(self.limit_memory_size.collect());
         mfs
@@ -34,6 +34,8 @@ pub struct StoreLocalStatistic {
     pub cache_data_block_total: u64,
     pub cache_meta_block_miss: u64,
     pub cache_meta_block_total: u64,
+    pub block_iter_pool_hit_count: u64,
+    pub block_iter_pool_total_count: u64,
 
     // include multiple versions of one key.
     pub total_key_count: u64,
@@ -209,6 +211,8 @@ struct LocalStoreMetrics {
     cache_data_block_miss: GenericLocalCounter<prometheus::core::AtomicU64>,
     cache_meta_block_total: GenericLocalCounter<prometheus::core::AtomicU64>,
     cache_meta_block_miss: GenericLocalCounter<prometheus::core::AtomicU64>,
+    block_iter_pool_hit_count: GenericLocalCounter<prometheus::core::AtomicU64>,
+    block_iter_pool_total_count: GenericLocalCounter<prometheus::core::AtomicU64>,
     remote_io_time: LocalHistogram,
     processed_key_count: GenericLocalCounter<prometheus::core::AtomicU64>,
     skip_multi_version_key_count: GenericLocalCounter<prometheus::core::AtomicU64>,
@@ -251,6 +255,16 @@ impl LocalStoreMetrics {
             .with_label_values(&[table_id_label, "meta_miss"])
             .local();
 
+        let block_iter_pool_hit_count = metrics
+            .sst_store_block_request_counts
+            .with_label_values(&[table_id_label, "block_iter_pool_hit"])
+            .local();
+
+        let block_iter_pool_total_count = metrics
+            .sst_store_block_request_counts
+            .with_label_values(&[table_id_label, "block_iter_pool_total"])
+            .local();
+
         let remote_io_time = metrics
             .remote_read_time
             .with_label_values(&[table_id_label])
@@ -314,6 +328,8 @@ impl LocalStoreMetrics {
             cache_data_block_miss,
             cache_meta_block_total,
             cache_meta_block_miss,
+            block_iter_pool_hit_count,
+            block_iter_pool_total_count,
             remote_io_time,
             processed_key_count,
             skip_multi_version_key_count,
@@ -408,6 +424,8 @@ add_local_metrics_count!(
     cache_data_block_miss,
     cache_meta_block_total,
     cache_meta_block_miss,
+    block_iter_pool_hit_count,
+    block_iter_pool_total_count,
     skip_multi_version_key_count,
     skip_delete_key_count,
     get_shared_buffer_hit_counts,
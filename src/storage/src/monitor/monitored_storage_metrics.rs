@@ -33,6 +33,7 @@ pub struct MonitoredStorageMetrics {
     pub may_exist_duration: HistogramVec,
 
     pub iter_in_process_counts: GenericCounterVec<AtomicU64>,
+    pub read_vnode_counts: GenericCounterVec<AtomicU64>,
 
     pub sync_duration: Histogram,
     pub sync_size: Histogram,
@@ -112,6 +113,14 @@ impl MonitoredStorageMetrics {
         )
         .unwrap();
 
+        let read_vnode_counts = register_int_counter_vec_with_registry!(
+            "state_store_read_vnode_counts",
+            "Total number of keys read from state store per table and virtual node, a heatmap for detecting vnode-level skew in state access",
+            &["table_id", "vnode"],
+            registry
+        )
+        .unwrap();
+
         let opts = histogram_opts!(
             "state_store_may_exist_duration",
             "Histogram of may exist time that have been issued to state store",
@@ -144,6 +153,7 @@ impl MonitoredStorageMetrics {
             iter_scan_duration,
             may_exist_duration,
             iter_in_process_counts,
+            read_vnode_counts,
             sync_duration,
             sync_size,
         }
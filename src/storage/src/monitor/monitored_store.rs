@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::ops::Bound;
 use std::sync::Arc;
 
@@ -20,6 +21,7 @@ use bytes::Bytes;
 use futures::{Future, TryFutureExt, TryStreamExt};
 use futures_async_stream::try_stream;
 use risingwave_common::catalog::TableId;
+use risingwave_common::hash::VirtualNode;
 use risingwave_hummock_sdk::HummockReadEpoch;
 use tracing::error;
 
@@ -55,6 +57,16 @@ fn identity(input: impl StateStoreIterItemStream) -> impl StateStoreIterItemStre
     input
 }
 
+/// Extracts the vnode prefix of a table key for the read heatmap. Keys of tables that are not
+/// distributed by vnode are attributed to vnode 0.
+fn vnode_of(key: &[u8]) -> VirtualNode {
+    if key.len() < VirtualNode::SIZE {
+        VirtualNode::ZERO
+    } else {
+        VirtualNode::from_be_bytes(key[..VirtualNode::SIZE].try_into().unwrap())
+    }
+}
+
 pub type MonitoredStateStoreIterStream<'s, S: StateStoreIterItemStream + 's> =
     impl StateStoreIterItemStream + 's;
 
@@ -95,6 +107,7 @@ impl<S> MonitoredStateStore<S> {
                 total_items: 0,
                 total_size: 0,
                 scan_time: minstant::Instant::now(),
+                vnode_counts: HashMap::new(),
                 storage_metrics: self.storage_metrics.clone(),
                 table_id,
             },
@@ -111,6 +124,7 @@ impl<S> MonitoredStateStore<S> {
         get_future: impl Future<Output = StorageResult<Option<Bytes>>>,
         table_id: TableId,
         key_len: usize,
+        vnode: VirtualNode,
     ) -> StorageResult<Option<Bytes>> {
         let table_id_label = table_id.to_string();
         let timer = self
@@ -134,9 +148,57 @@ impl<S> MonitoredStateStore<S> {
                 .with_label_values(&[table_id_label.as_str()])
                 .observe(value.len() as _);
         }
+        self.storage_metrics
+            .read_vnode_counts
+            .with_label_values(&[
+                table_id_label.as_str(),
+                vnode.to_index().to_string().as_str(),
+            ])
+            .inc();
 
         Ok(value)
     }
+
+    async fn monitored_multi_get(
+        &self,
+        multi_get_future: impl Future<Output = StorageResult<Vec<Option<Bytes>>>>,
+        table_id: TableId,
+        keys: &[Vec<u8>],
+    ) -> StorageResult<Vec<Option<Bytes>>> {
+        let table_id_label = table_id.to_string();
+        let timer = self
+            .storage_metrics
+            .get_duration
+            .with_label_values(&[table_id_label.as_str()])
+            .start_timer();
+        let values = multi_get_future
+            .verbose_stack_trace("store_multi_get")
+            .await
+            .inspect_err(|e| error!("Failed in multi_get: {:?}", e))?;
+        timer.observe_duration();
+
+        for key in keys {
+            self.storage_metrics
+                .get_key_size
+                .with_label_values(&[table_id_label.as_str()])
+                .observe(key.len() as _);
+            self.storage_metrics
+                .read_vnode_counts
+                .with_label_values(&[
+                    table_id_label.as_str(),
+                    vnode_of(key).to_index().to_string().as_str(),
+                ])
+                .inc();
+        }
+        for value in values.iter().flatten() {
+            self.storage_metrics
+                .get_value_size
+                .with_label_values(&[table_id_label.as_str()])
+                .observe(value.len() as _);
+        }
+
+        Ok(values)
+    }
 }
 
 impl<S: StateStoreRead> StateStoreRead for MonitoredStateStore<S> {
@@ -152,7 +214,27 @@ impl<S: StateStoreRead> StateStoreRead for MonitoredStateStore<S> {
     ) -> Self::GetFuture<'_> {
         let table_id = read_options.table_id;
         let key_len = key.len();
-        self.monitored_get(self.inner.get(key, epoch, read_options), table_id, key_len)
+        let vnode = vnode_of(key);
+        self.monitored_get(
+            self.inner.get(key, epoch, read_options),
+            table_id,
+            key_len,
+            vnode,
+        )
+    }
+
+    fn multi_get<'a>(
+        &'a self,
+        keys: &'a [Vec<u8>],
+        epoch: u64,
+        read_options: ReadOptions,
+    ) -> Self::MultiGetFuture<'_> {
+        let table_id = read_options.table_id;
+        self.monitored_multi_get(
+            self.inner.multi_get(keys, epoch, read_options),
+            table_id,
+            keys,
+        )
     }
 
     fn iter(
@@ -174,6 +256,7 @@ impl<S: LocalStateStore> LocalStateStore for MonitoredStateStore<S> {
     type GetFuture<'a> = impl GetFutureTrait<'a>;
     type IterFuture<'a> = impl Future<Output = StorageResult<Self::IterStream<'a>>> + Send + 'a;
     type IterStream<'a> = impl StateStoreIterItemStream + 'a;
+    type MultiGetFuture<'a> = impl MultiGetFutureTrait<'a>;
 
     // TODO: include the rest future to macro
     define_local_state_store_associated_type!();
@@ -199,8 +282,19 @@ impl<S: LocalStateStore> LocalStateStore for MonitoredStateStore<S> {
     fn get<'a>(&'a self, key: &'a [u8], read_options: ReadOptions) -> Self::GetFuture<'_> {
         let table_id = read_options.table_id;
         let key_len = key.len();
+        let vnode = vnode_of(key);
         // TODO: may collect the metrics as local
-        self.monitored_get(self.inner.get(key, read_options), table_id, key_len)
+        self.monitored_get(self.inner.get(key, read_options), table_id, key_len, vnode)
+    }
+
+    fn multi_get<'a>(
+        &'a self,
+        keys: &'a [Vec<u8>],
+        read_options: ReadOptions,
+    ) -> Self::MultiGetFuture<'_> {
+        let table_id = read_options.table_id;
+        // TODO: may collect the metrics as local
+        self.monitored_multi_get(self.inner.multi_get(keys, read_options), table_id, keys)
     }
 
     fn iter(
@@ -341,6 +435,9 @@ struct MonitoredStateStoreIterStats {
     total_items: usize,
     total_size: usize,
     scan_time: minstant::Instant,
+    /// Number of keys yielded per vnode, aggregated locally and flushed to the read heatmap when
+    /// the iterator is dropped.
+    vnode_counts: HashMap<VirtualNode, u64>,
     storage_metrics: Arc<MonitoredStorageMetrics>,
 
     table_id: TableId,
@@ -358,6 +455,11 @@ impl<S: StateStoreIterItemStream> MonitoredStateStoreIter<S> {
         {
             self.stats.total_items += 1;
             self.stats.total_size += key.encoded_len() + value.len();
+            *self
+                .stats
+                .vnode_counts
+                .entry(vnode_of(&key.user_key.table_key))
+                .or_default() += 1;
             yield (key, value);
         }
     }
@@ -383,5 +485,14 @@ impl Drop for MonitoredStateStoreIterStats {
             .iter_size
             .with_label_values(&[table_id_label.as_str()])
             .observe(self.total_size as f64);
+        for (vnode, count) in &self.vnode_counts {
+            self.storage_metrics
+                .read_vnode_counts
+                .with_label_values(&[
+                    table_id_label.as_str(),
+                    vnode.to_index().to_string().as_str(),
+                ])
+                .inc_by(*count);
+        }
     }
 }
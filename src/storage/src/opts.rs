@@ -38,6 +38,8 @@ pub struct StorageOpts {
     pub write_conflict_detection_enabled: bool,
     /// Capacity of sstable block cache.
     pub block_cache_capacity_mb: usize,
+    /// Maximum share of the block cache a single table may occupy. `0` disables the cap.
+    pub block_cache_per_table_capacity_mb: usize,
     /// Capacity of sstable meta cache.
     pub meta_cache_capacity_mb: usize,
     pub disable_remote_compactor: bool,
@@ -46,6 +48,15 @@ pub struct StorageOpts {
     pub local_object_store: String,
     /// Number of tasks shared buffer can upload in parallel.
     pub share_buffer_upload_concurrency: usize,
+    /// Number of upload tasks that may run in parallel for a single table. `0` disables the
+    /// per-table limit.
+    pub share_buffer_upload_per_table_concurrency: usize,
+    /// Target size of a single shared buffer upload task in bytes. Data of different tables is
+    /// packed into upload tasks of roughly this size and uploaded in parallel.
+    pub share_buffer_upload_task_size: usize,
+    /// Whether to spill shared buffer flushes to the local disk under the file cache directory
+    /// and upload the resulting SSTs in the background.
+    pub shared_buffer_spill_enabled: bool,
     /// Capacity of sstable meta cache.
     pub compactor_memory_limit_mb: usize,
     /// Number of SST ids fetched from meta per RPC
@@ -55,6 +66,49 @@ pub struct StorageOpts {
     /// Max sub compaction task numbers
     pub max_sub_compaction: u32,
     pub max_concurrent_compaction_task_number: u64,
+    /// Ratio of compaction tasks whose results are verified against their inputs before being
+    /// reported as successful. 0.0 disables verification and 1.0 verifies every task.
+    pub compaction_result_verification_ratio: f64,
+    /// Ratio of state store reads that are verified against a shadow state store. 0.0 disables
+    /// verification and 1.0 verifies every read. Only effective in debug builds.
+    pub state_store_verify_sample_ratio: f64,
+    /// In-memory cache capacity of the shadow state store used for verification.
+    pub state_store_verify_cache_capacity_mb: usize,
+    /// Run the state store in read-only replica mode: local writes, epoch syncs and sealing are
+    /// rejected, while `get` and `iter` are served from pinned versions.
+    pub read_only: bool,
+    /// Compression algorithm for blocks of newly written SSTs: "none", "lz4" or "zstd".
+    pub sstable_compression_algorithm: String,
+    /// Compression level of the chosen compression algorithm.
+    pub sstable_compression_level: u32,
+    /// Number of entries between restart points inside an SST block.
+    pub block_restart_interval: usize,
+    /// Number of attempts for a remote object store operation before the error is returned to the
+    /// caller. `1` disables retrying.
+    pub object_store_retry_attempts: usize,
+    /// The backoff before the first retry of a remote object store operation in milliseconds.
+    pub object_store_retry_initial_backoff_ms: u64,
+    /// The maximum backoff between two attempts of a remote object store operation in
+    /// milliseconds.
+    pub object_store_retry_max_backoff_ms: u64,
+    /// Admission policy of the block cache: "lru" or "tinylfu".
+    pub block_cache_policy: String,
+    /// Bandwidth quota for object store requests issued by compaction in MB/s. `0` disables the
+    /// quota.
+    pub object_store_compaction_bandwidth_mb_per_sec: u64,
+    /// Bandwidth quota for object store writes flushing the shared buffer in MB/s. `0` disables
+    /// the quota.
+    pub object_store_flush_bandwidth_mb_per_sec: u64,
+    /// Bandwidth quota for object store reads serving `get` and `iter` in MB/s. `0` disables the
+    /// quota.
+    pub object_store_read_bandwidth_mb_per_sec: u64,
+    /// Bandwidth quota shared by all object store writes, i.e. checkpoint uploads and compaction
+    /// output, in MB/s. Checkpoint uploads take priority over compaction output. `0` disables the
+    /// quota.
+    pub object_store_write_bandwidth_mb_per_sec: u64,
+    /// Path to a local file holding the AES-256-GCM keys used to encrypt SST blocks at rest, one
+    /// hex-encoded 32-byte key per line. Empty disables encryption.
+    pub sstable_encryption_key_file: String,
 
     pub file_cache_dir: String,
     pub file_cache_capacity_mb: usize,
@@ -101,16 +155,46 @@ impl From<(&RwConfig, &SystemParamsReader)> for StorageOpts {
             data_directory: p.data_directory().to_string(),
             write_conflict_detection_enabled: c.storage.write_conflict_detection_enabled,
             block_cache_capacity_mb: c.storage.block_cache_capacity_mb,
+            block_cache_per_table_capacity_mb: c.storage.block_cache_per_table_capacity_mb,
             meta_cache_capacity_mb: c.storage.meta_cache_capacity_mb,
             disable_remote_compactor: c.storage.disable_remote_compactor,
             enable_local_spill: c.storage.enable_local_spill,
             local_object_store: c.storage.local_object_store.to_string(),
             share_buffer_upload_concurrency: c.storage.share_buffer_upload_concurrency,
+            share_buffer_upload_per_table_concurrency: c
+                .storage
+                .share_buffer_upload_per_table_concurrency,
+            share_buffer_upload_task_size: c.storage.share_buffer_upload_task_size_mb * (1 << 20),
+            shared_buffer_spill_enabled: c.storage.shared_buffer_spill_enabled,
             compactor_memory_limit_mb: c.storage.compactor_memory_limit_mb,
             sstable_id_remote_fetch_number: c.storage.sstable_id_remote_fetch_number,
             min_sst_size_for_streaming_upload: c.storage.min_sst_size_for_streaming_upload,
             max_sub_compaction: c.storage.max_sub_compaction,
             max_concurrent_compaction_task_number: c.storage.max_concurrent_compaction_task_number,
+            compaction_result_verification_ratio: c.storage.compaction_result_verification_ratio,
+            state_store_verify_sample_ratio: c.storage.state_store_verify_sample_ratio,
+            state_store_verify_cache_capacity_mb: c.storage.state_store_verify_cache_capacity_mb,
+            read_only: c.storage.read_only,
+            sstable_compression_algorithm: c.storage.sstable_compression_algorithm.clone(),
+            sstable_compression_level: c.storage.sstable_compression_level,
+            block_restart_interval: c.storage.block_restart_interval,
+            object_store_retry_attempts: c.storage.object_store_retry_attempts,
+            object_store_retry_initial_backoff_ms: c.storage.object_store_retry_initial_backoff_ms,
+            object_store_retry_max_backoff_ms: c.storage.object_store_retry_max_backoff_ms,
+            block_cache_policy: c.storage.block_cache_policy.clone(),
+            object_store_compaction_bandwidth_mb_per_sec: c
+                .storage
+                .object_store_compaction_bandwidth_mb_per_sec,
+            object_store_flush_bandwidth_mb_per_sec: c
+                .storage
+                .object_store_flush_bandwidth_mb_per_sec,
+            object_store_read_bandwidth_mb_per_sec: c
+                .storage
+                .object_store_read_bandwidth_mb_per_sec,
+            object_store_write_bandwidth_mb_per_sec: c
+                .storage
+                .object_store_write_bandwidth_mb_per_sec,
+            sstable_encryption_key_file: c.storage.sstable_encryption_key_file.clone(),
             file_cache_dir: c.storage.file_cache.dir.clone(),
             file_cache_capacity_mb: c.storage.file_cache.capacity_mb,
             file_cache_total_buffer_capacity_mb: c.storage.file_cache.total_buffer_capacity_mb,
@@ -50,6 +50,17 @@ impl StateStoreRead for PanicStateStore {
         }
     }
 
+    fn multi_get<'a>(
+        &'a self,
+        _keys: &'a [Vec<u8>],
+        _epoch: u64,
+        _read_options: ReadOptions,
+    ) -> Self::MultiGetFuture<'_> {
+        async move {
+            panic!("should not read from the state store!");
+        }
+    }
+
     fn iter(
         &self,
         _key_range: (Bound<Vec<u8>>, Bound<Vec<u8>>),
@@ -83,6 +94,7 @@ impl LocalStateStore for PanicStateStore {
     type FlushFuture<'a> = impl Future<Output = StorageResult<usize>> + 'a;
     type GetFuture<'a> = impl GetFutureTrait<'a>;
     type IterFuture<'a> = impl Future<Output = StorageResult<Self::IterStream<'a>>> + Send + 'a;
+    type MultiGetFuture<'a> = impl MultiGetFutureTrait<'a>;
 
     define_local_state_store_associated_type!();
 
@@ -102,6 +114,16 @@ impl LocalStateStore for PanicStateStore {
         }
     }
 
+    fn multi_get<'a>(
+        &'a self,
+        _keys: &'a [Vec<u8>],
+        _read_options: ReadOptions,
+    ) -> Self::MultiGetFuture<'_> {
+        async move {
+            panic!("should not operate on the panic state store!");
+        }
+    }
+
     fn iter(
         &self,
         _key_range: (Bound<Vec<u8>>, Bound<Vec<u8>>),
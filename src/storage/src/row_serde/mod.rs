@@ -16,6 +16,7 @@ use risingwave_common::catalog::{ColumnDesc, ColumnId};
 use risingwave_common::row::{OwnedRow, Project, RowExt};
 
 pub mod row_serde_util;
+pub mod value_serde;
 
 /// Find out the [`ColumnDesc`] by a list of [`ColumnId`].
 ///
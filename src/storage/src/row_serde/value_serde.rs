@@ -0,0 +1,184 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Value encoding serdes for the storage value (row) of a table.
+
+use std::sync::Arc;
+
+use risingwave_common::catalog::ColumnId;
+use risingwave_common::row::Row;
+use risingwave_common::types::{DataType, Datum};
+use risingwave_common::util::value_encoding::{
+    column_aware_row_encoding, BasicSerde, Result, ValueRowDeserializer, ValueRowSerializer,
+};
+
+/// Serde of the column-aware value encoding. Unlike [`BasicSerde`], the encoded bytes are
+/// self-describing with column ids, so they remain decodable after schema changes and support
+/// projection pushdown on the decoding side.
+#[derive(Clone)]
+pub struct ColumnAwareSerde {
+    pub serializer: column_aware_row_encoding::Serializer,
+    pub deserializer: column_aware_row_encoding::Deserializer,
+}
+
+impl ColumnAwareSerde {
+    pub fn new(column_ids: &[ColumnId], schema: Arc<[DataType]>) -> Self {
+        let serializer = column_aware_row_encoding::Serializer::new(column_ids);
+        let deserializer = column_aware_row_encoding::Deserializer::new(column_ids, schema);
+        Self {
+            serializer,
+            deserializer,
+        }
+    }
+
+    /// Create a `ColumnAwareSerde` that only decodes the columns at `projection`. Refer to
+    /// [`column_aware_row_encoding::Deserializer::with_projection`] for details.
+    pub fn new_with_projection(
+        column_ids: &[ColumnId],
+        schema: Arc<[DataType]>,
+        projection: &[usize],
+    ) -> Self {
+        let serializer = column_aware_row_encoding::Serializer::new(column_ids);
+        let deserializer = column_aware_row_encoding::Deserializer::with_projection(
+            column_ids, schema, projection,
+        );
+        Self {
+            serializer,
+            deserializer,
+        }
+    }
+
+    /// Create a `ColumnAwareSerde` that serializes the same columns but only decodes the columns
+    /// at `projection`, out of the columns this serde decodes.
+    pub fn project(&self, projection: &[usize]) -> Self {
+        Self {
+            serializer: self.serializer.clone(),
+            deserializer: self.deserializer.project(projection),
+        }
+    }
+}
+
+impl ValueRowSerializer for ColumnAwareSerde {
+    fn serialize(&self, row: impl Row) -> Vec<u8> {
+        self.serializer.serialize(row)
+    }
+}
+
+impl ValueRowDeserializer for ColumnAwareSerde {
+    fn deserialize(&self, encoded_bytes: &[u8]) -> Result<Vec<Datum>> {
+        self.deserializer.deserialize(encoded_bytes)
+    }
+}
+
+/// Row serde that dispatches to the basic or the column-aware implementation according to
+/// whether the table is versioned.
+#[derive(Clone)]
+pub enum EitherSerde {
+    Basic(BasicSerde),
+    ColumnAware(ColumnAwareSerde),
+}
+
+impl EitherSerde {
+    /// Returns a serde that serializes the same columns but only decodes the columns at
+    /// `projection`, leaving the other columns `None`. The basic encoding does not support
+    /// projected decoding, so it falls back to decoding all columns.
+    pub fn project(&self, projection: &[usize]) -> Self {
+        match self {
+            Self::Basic(serde) => Self::Basic(serde.clone()),
+            Self::ColumnAware(serde) => Self::ColumnAware(serde.project(projection)),
+        }
+    }
+}
+
+impl From<BasicSerde> for EitherSerde {
+    fn from(serde: BasicSerde) -> Self {
+        Self::Basic(serde)
+    }
+}
+
+impl From<ColumnAwareSerde> for EitherSerde {
+    fn from(serde: ColumnAwareSerde) -> Self {
+        Self::ColumnAware(serde)
+    }
+}
+
+impl ValueRowSerializer for EitherSerde {
+    fn serialize(&self, row: impl Row) -> Vec<u8> {
+        match self {
+            Self::Basic(serde) => serde.serialize(row),
+            Self::ColumnAware(serde) => serde.serialize(row),
+        }
+    }
+}
+
+impl ValueRowDeserializer for EitherSerde {
+    fn deserialize(&self, encoded_bytes: &[u8]) -> Result<Vec<Datum>> {
+        match self {
+            Self::Basic(serde) => serde.deserialize(encoded_bytes),
+            Self::ColumnAware(serde) => serde.deserialize(encoded_bytes),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::row::OwnedRow;
+    use risingwave_common::types::ScalarImpl;
+
+    use super::*;
+
+    #[test]
+    fn test_either_serde_roundtrip() {
+        let column_ids = vec![ColumnId::new(0), ColumnId::new(1)];
+        let data_types = vec![DataType::Int16, DataType::Varchar];
+        let row = OwnedRow::new(vec![
+            Some(ScalarImpl::Int16(5)),
+            Some(ScalarImpl::Utf8("abc".into())),
+        ]);
+
+        let basic: EitherSerde = BasicSerde::new(data_types.clone()).into();
+        let decoded = basic.deserialize(&basic.serialize(&row)).unwrap();
+        assert_eq!(decoded, row.clone().into_inner());
+
+        let column_aware: EitherSerde =
+            ColumnAwareSerde::new(&column_ids, data_types.clone().into()).into();
+        let decoded = column_aware
+            .deserialize(&column_aware.serialize(&row))
+            .unwrap();
+        assert_eq!(decoded, row.into_inner());
+    }
+
+    #[test]
+    fn test_either_serde_project() {
+        let column_ids = vec![ColumnId::new(0), ColumnId::new(1)];
+        let data_types = vec![DataType::Int16, DataType::Varchar];
+        let row = OwnedRow::new(vec![
+            Some(ScalarImpl::Int16(5)),
+            Some(ScalarImpl::Utf8("abc".into())),
+        ]);
+
+        // The basic encoding cannot skip decoding columns, all columns are decoded.
+        let basic: EitherSerde = BasicSerde::new(data_types.clone()).into();
+        let projected = basic.project(&[1]);
+        let decoded = projected.deserialize(&projected.serialize(&row)).unwrap();
+        assert_eq!(decoded, row.clone().into_inner());
+
+        // The column-aware encoding skips the columns out of the projection.
+        let column_aware: EitherSerde =
+            ColumnAwareSerde::new(&column_ids, data_types.into()).into();
+        let projected = column_aware.project(&[1]);
+        let decoded = projected.deserialize(&projected.serialize(&row)).unwrap();
+        assert_eq!(decoded, vec![None, Some(ScalarImpl::Utf8("abc".into()))]);
+    }
+}
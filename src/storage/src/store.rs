@@ -25,6 +25,7 @@ use risingwave_hummock_sdk::key::FullKey;
 use risingwave_hummock_sdk::{HummockReadEpoch, LocalSstableInfo};
 
 use crate::error::{StorageError, StorageResult};
+pub use crate::hummock::CachePolicy;
 use crate::monitor::{MonitoredStateStore, MonitoredStorageMetrics};
 use crate::storage_value::StorageValue;
 use crate::write_batch::WriteBatch;
@@ -66,11 +67,13 @@ impl<I: StateStoreIter> StateStoreIterExt for I {
 macro_rules! define_state_store_read_associated_type {
     () => {
         type GetFuture<'a> = impl GetFutureTrait<'a>;
+        type MultiGetFuture<'a> = impl MultiGetFutureTrait<'a>;
         type IterFuture<'a> = impl IterFutureTrait<'a, Self::IterStream>;
     };
 }
 
 pub trait GetFutureTrait<'a> = Future<Output = StorageResult<Option<Bytes>>> + Send + 'a;
+pub trait MultiGetFutureTrait<'a> = Future<Output = StorageResult<Vec<Option<Bytes>>>> + Send + 'a;
 pub type StateStoreIterItem = (FullKey<Bytes>, Bytes);
 pub trait StateStoreIterNextFutureTrait<'a> = NextFutureTrait<'a, StateStoreIterItem>;
 pub trait StateStoreIterItemStream = Stream<Item = StorageResult<StateStoreIterItem>> + Send;
@@ -82,6 +85,7 @@ pub trait StateStoreRead: StaticSendSync {
     type IterStream: StateStoreReadIterStream;
 
     type GetFuture<'a>: GetFutureTrait<'a>;
+    type MultiGetFuture<'a>: MultiGetFutureTrait<'a>;
     type IterFuture<'a>: IterFutureTrait<'a, Self::IterStream>;
 
     /// Point gets a value from the state store.
@@ -93,6 +97,16 @@ pub trait StateStoreRead: StaticSendSync {
         read_options: ReadOptions,
     ) -> Self::GetFuture<'_>;
 
+    /// Point gets the values of multiple keys from the state store in one call, returning the
+    /// values in the order of `keys`. Compared to one `get` per key, the implementation may share
+    /// work across the keys, e.g. take the read snapshot only once.
+    fn multi_get<'a>(
+        &'a self,
+        keys: &'a [Vec<u8>],
+        epoch: u64,
+        read_options: ReadOptions,
+    ) -> Self::MultiGetFuture<'_>;
+
     /// Opens and returns an iterator for given `prefix_hint` and `full_key_range`
     /// Internally, `prefix_hint` will be used to for checking `bloom_filter` and
     /// `full_key_range` used for iter. (if the `prefix_hint` not None, it should be be included
@@ -137,6 +151,7 @@ impl<S: StateStoreRead> StateStoreReadExt for S {
         limit: Option<usize>,
         read_options: ReadOptions,
     ) -> Self::ScanFuture<'_> {
+        let read_options = ReadOptions { limit, ..read_options };
         let limit = limit.unwrap_or(usize::MAX);
         async move {
             self.iter(key_range, epoch, read_options)
@@ -173,6 +188,10 @@ pub trait StateStoreWrite: StaticSendSync {
     /// - A version of a kv pair. kv pair associated with larger `Epoch` is guaranteed to be newer
     ///   then kv pair with smaller `Epoch`. Currently this version is only used to derive the
     ///   per-key modification history (e.g. in compaction), not across different keys.
+    ///
+    /// `delete_ranges` are sorted key ranges `[begin, end)` to be covered by range-delete
+    /// tombstones. An empty `end` key means the range is unbounded on the right, i.e. covers
+    /// everything from `begin` to the end of the table key space.
     fn ingest_batch(
         &self,
         kv_pairs: Vec<(Bytes, StorageValue)>,
@@ -262,6 +281,7 @@ pub trait LocalStateStore: StaticSendSync {
 
     type MayExistFuture<'a>: MayExistTrait<'a>;
     type GetFuture<'a>: GetFutureTrait<'a>;
+    type MultiGetFuture<'a>: MultiGetFutureTrait<'a>;
     type IterFuture<'a>: Future<Output = StorageResult<Self::IterStream<'a>>> + Send + 'a;
     type FlushFuture<'a>: Future<Output = StorageResult<usize>> + Send + 'a;
 
@@ -269,6 +289,14 @@ pub trait LocalStateStore: StaticSendSync {
     /// The result is based on the latest written snapshot.
     fn get<'a>(&'a self, key: &'a [u8], read_options: ReadOptions) -> Self::GetFuture<'_>;
 
+    /// Point gets the values of multiple keys from the state store in one call, returning the
+    /// values in the order of `keys`. See [`StateStoreRead::multi_get`].
+    fn multi_get<'a>(
+        &'a self,
+        keys: &'a [Vec<u8>],
+        read_options: ReadOptions,
+    ) -> Self::MultiGetFuture<'_>;
+
     /// Opens and returns an iterator for given `prefix_hint` and `full_key_range`
     /// Internally, `prefix_hint` will be used to for checking `bloom_filter` and
     /// `full_key_range` used for iter. (if the `prefix_hint` not None, it should be be included
@@ -323,12 +351,22 @@ pub struct ReadOptions {
     /// `key` or `key_range` in the read API.
     pub prefix_hint: Option<Bytes>,
     pub ignore_range_tombstone: bool,
+    /// A hint for the number of keys the caller will consume from the iterator, e.g. the `LIMIT`
+    /// plus the `OFFSET` of the query. Iterators use it to stop block read-ahead once that many
+    /// keys are covered; it does NOT truncate the results, so a caller consuming beyond the hint
+    /// still sees them, only without read-ahead.
+    pub limit: Option<usize>,
 
     pub retention_seconds: Option<u32>,
     pub table_id: TableId,
     /// Read from historical hummock version of meta snapshot backup.
     /// It should only be used by `StorageTable` for batch query.
     pub read_version_from_backup: bool,
+    /// How the blocks fetched for this read interact with the block cache and the tiered cache.
+    /// Large ad-hoc batch scans should use [`CachePolicy::NotFill`] so that they do not evict the
+    /// working set of latency-sensitive streaming state, which in turn reads with
+    /// [`CachePolicy::HighPriority`].
+    pub cache_policy: CachePolicy,
 }
 
 pub fn gen_min_epoch(base_epoch: u64, retention_seconds: Option<&u32>) -> u64 {
@@ -336,7 +374,7 @@ pub fn gen_min_epoch(base_epoch: u64, retention_seconds: Option<&u32>) -> u64 {
     match retention_seconds {
         Some(retention_seconds_u32) => {
             base_epoch
-                .subtract_ms((retention_seconds_u32 * 1000) as u64)
+                .subtract_ms(*retention_seconds_u32 as u64 * 1000)
                 .0
         }
         None => 0,
@@ -14,12 +14,14 @@
 
 use std::fmt::Debug;
 use std::sync::Arc;
+use std::time::Duration;
 
 use enum_as_inner::EnumAsInner;
 use risingwave_common_service::observer_manager::RpcNotificationClient;
 use risingwave_hummock_sdk::filter_key_extractor::FilterKeyExtractorManagerRef;
 use risingwave_object_store::object::{
-    parse_local_object_store, parse_remote_object_store, ObjectStoreImpl,
+    parse_local_object_store, parse_remote_object_store, IoClass, IoQuota, IoScheduledObjectStore,
+    IoScheduler, ObjectStoreImpl, RetryConfig, RetryObjectStore,
 };
 
 use crate::error::StorageResult;
@@ -27,9 +29,11 @@ use crate::hummock::backup_reader::{parse_meta_snapshot_storage, BackupReader};
 use crate::hummock::hummock_meta_client::MonitoredHummockMetaClient;
 use crate::hummock::sstable_store::SstableStoreRef;
 use crate::hummock::{
-    HummockStorage, MemoryLimiter, SstableIdManagerRef, SstableStore, TieredCache,
-    TieredCacheMetricsBuilder,
+    BlockCachePolicy, FileEncryptionKeyProvider, HummockStorage, MemoryLimiter,
+    SstableIdManagerRef, SstableStore, TieredCache, TieredCacheMetricsBuilder,
 };
+#[cfg(feature = "rocksdb-backend")]
+use crate::memory::rocksdb::RocksdbStateStore;
 use crate::memory::sled::SledStateStore;
 use crate::memory::MemoryStateStore;
 use crate::monitor::{
@@ -42,6 +46,8 @@ use crate::StateStore;
 pub type HummockStorageType = impl StateStore + AsHummockTrait;
 pub type MemoryStateStoreType = impl StateStore + AsHummockTrait;
 pub type SledStateStoreType = impl StateStore + AsHummockTrait;
+#[cfg(feature = "rocksdb-backend")]
+pub type RocksdbStateStoreType = impl StateStore + AsHummockTrait;
 
 /// The type erased [`StateStore`].
 #[derive(Clone, EnumAsInner)]
@@ -61,7 +67,15 @@ pub enum StateStoreImpl {
     /// store misses some critical implementation to ensure the correctness of persisting streaming
     /// state. (e.g., no read_epoch support, no async checkpoint)
     MemoryStateStore(Monitored<MemoryStateStoreType>),
+    /// Durable pure-Rust local state store over sled, selectable as `sled://<dir>` or
+    /// `local://<dir>`. Should only be used by integration tests and CI to exercise recovery
+    /// semantics (e.g. epoch sealing and sync) that the in-memory state store does not support.
     SledStateStore(Monitored<SledStateStoreType>),
+    /// Embedded RocksDB state store, for durable single-node deployments without an object store.
+    /// URLs beginning with `rocksdb://` will be recognized as this state store. Scaling and
+    /// recovery are not supported.
+    #[cfg(feature = "rocksdb-backend")]
+    RocksdbStateStore(Monitored<RocksdbStateStoreType>),
 }
 
 fn may_dynamic_dispatch(
@@ -78,21 +92,30 @@ fn may_dynamic_dispatch(
     }
 }
 
-fn may_verify(state_store: impl StateStore + AsHummockTrait) -> impl StateStore + AsHummockTrait {
+fn may_verify(
+    state_store: impl StateStore + AsHummockTrait,
+    opts: &StorageOpts,
+) -> impl StateStore + AsHummockTrait {
     #[cfg(not(debug_assertions))]
     {
+        let _ = opts;
         state_store
     }
     #[cfg(debug_assertions)]
     {
-        use risingwave_common::util::env_var::env_var_is_true;
         use tracing::info;
 
         use crate::store_impl::verify::VerifyStateStore;
 
-        let expected = if env_var_is_true("ENABLE_STATE_STORE_VERIFY") {
-            info!("enable verify state store");
-            Some(SledStateStore::new_temp())
+        let sample_ratio = opts.state_store_verify_sample_ratio;
+        let expected = if sample_ratio > 0.0 {
+            info!(
+                "enable verify state store, verifying a ratio of {} of the reads",
+                sample_ratio
+            );
+            Some(SledStateStore::new_temp_with_cache_capacity(
+                (opts.state_store_verify_cache_capacity_mb * (1 << 20)) as u64,
+            ))
         } else {
             info!("verify state store is not enabled");
             None
@@ -100,6 +123,7 @@ fn may_verify(state_store: impl StateStore + AsHummockTrait) -> impl StateStore
         VerifyStateStore {
             actual: state_store,
             expected,
+            sample_ratio,
         }
     }
 }
@@ -116,10 +140,11 @@ impl StateStoreImpl {
     pub fn hummock(
         state_store: HummockStorage,
         storage_metrics: Arc<MonitoredStorageMetrics>,
+        opts: &StorageOpts,
     ) -> Self {
         // The specific type of HummockStateStoreType in deducted here.
         Self::HummockStateStore(
-            may_dynamic_dispatch(may_verify(state_store)).monitored(storage_metrics),
+            may_dynamic_dispatch(may_verify(state_store, opts)).monitored(storage_metrics),
         )
     }
 
@@ -130,6 +155,14 @@ impl StateStoreImpl {
         Self::SledStateStore(may_dynamic_dispatch(state_store).monitored(storage_metrics))
     }
 
+    #[cfg(feature = "rocksdb-backend")]
+    pub fn rocksdb(
+        state_store: RocksdbStateStore,
+        storage_metrics: Arc<MonitoredStorageMetrics>,
+    ) -> Self {
+        Self::RocksdbStateStore(may_dynamic_dispatch(state_store).monitored(storage_metrics))
+    }
+
     pub fn shared_in_memory_store(storage_metrics: Arc<MonitoredStorageMetrics>) -> Self {
         Self::in_memory(MemoryStateStore::shared(), storage_metrics)
     }
@@ -176,6 +209,8 @@ impl Debug for StateStoreImpl {
             StateStoreImpl::HummockStateStore(_) => write!(f, "HummockStateStore"),
             StateStoreImpl::MemoryStateStore(_) => write!(f, "MemoryStateStore"),
             StateStoreImpl::SledStateStore(_) => write!(f, "SledStateStore"),
+            #[cfg(feature = "rocksdb-backend")]
+            StateStoreImpl::RocksdbStateStore(_) => write!(f, "RocksdbStateStore"),
         }
     }
 }
@@ -214,6 +249,9 @@ macro_rules! dispatch_state_store {
                 }
             }
 
+            #[cfg(feature = "rocksdb-backend")]
+            StateStoreImpl::RocksdbStateStore($store) => $body,
+
             StateStoreImpl::HummockStateStore($store) => $body,
         }
     }};
@@ -228,8 +266,9 @@ pub mod verify {
     use bytes::Bytes;
     use futures::{pin_mut, TryStreamExt};
     use futures_async_stream::try_stream;
+    use rand::Rng;
     use risingwave_hummock_sdk::HummockReadEpoch;
-    use tracing::log::warn;
+    use tracing::error;
 
     use crate::error::{StorageError, StorageResult};
     use crate::storage_value::StorageValue;
@@ -237,21 +276,44 @@ pub mod verify {
     use crate::store_impl::{AsHummockTrait, HummockTrait};
     use crate::StateStore;
 
-    fn assert_result_eq<Item: PartialEq + Debug, E>(
-        first: &std::result::Result<Item, E>,
-        second: &std::result::Result<Item, E>,
+    /// Logs a structured report of a divergence between the actual and the expected state store.
+    /// Divergences are reported instead of panicked on, so that verification keeps the node
+    /// alive and every divergence surfaces in the logs.
+    fn report_divergence(
+        op: &str,
+        key: &dyn Debug,
+        epoch: Option<u64>,
+        actual: &dyn Debug,
+        expected: &dyn Debug,
+    ) {
+        error!(
+            "state store divergence in {}: key: {:?}, epoch: {:?}, actual: {:?}, expected: {:?}",
+            op, key, epoch, actual, expected
+        );
+    }
+
+    fn check_result_eq<Item: PartialEq + Debug, E>(
+        op: &str,
+        key: &dyn Debug,
+        epoch: Option<u64>,
+        actual: &std::result::Result<Item, E>,
+        expected: &std::result::Result<Item, E>,
     ) {
-        match (first, second) {
-            (Ok(first), Ok(second)) => {
-                if first != second {
-                    warn!("result different: {:?} {:?}", first, second);
+        match (actual, expected) {
+            (Ok(actual), Ok(expected)) => {
+                if actual != expected {
+                    report_divergence(op, key, epoch, actual, expected);
                 }
-                assert_eq!(first, second);
             }
             (Err(_), Err(_)) => {}
-            _ => {
-                warn!("one success and one failed");
-                panic!("result not equal");
+            (actual, expected) => {
+                report_divergence(
+                    op,
+                    key,
+                    epoch,
+                    &actual.as_ref().map_err(|_| "error"),
+                    &expected.as_ref().map_err(|_| "error"),
+                );
             }
         }
     }
@@ -259,6 +321,18 @@ pub mod verify {
     pub struct VerifyStateStore<A, E> {
         pub actual: A,
         pub expected: Option<E>,
+        /// Ratio of reads that are verified against `expected`. Writes are always mirrored so
+        /// that the expected store stays consistent with the actual one.
+        pub sample_ratio: f64,
+    }
+
+    impl<A, E> VerifyStateStore<A, E> {
+        /// The expected store when this read is sampled for verification.
+        fn sample(&self) -> Option<&E> {
+            self.expected
+                .as_ref()
+                .filter(|_| rand::thread_rng().gen::<f64>() < self.sample_ratio)
+        }
     }
 
     impl<A: AsHummockTrait, E> AsHummockTrait for VerifyStateStore<A, E> {
@@ -280,9 +354,25 @@ pub mod verify {
         ) -> Self::GetFuture<'_> {
             async move {
                 let actual = self.actual.get(key, epoch, read_options.clone()).await;
-                if let Some(expected) = &self.expected {
+                if let Some(expected) = self.sample() {
                     let expected = expected.get(key, epoch, read_options).await;
-                    assert_result_eq(&actual, &expected);
+                    check_result_eq("get", &key, Some(epoch), &actual, &expected);
+                }
+                actual
+            }
+        }
+
+        fn multi_get<'a>(
+            &'a self,
+            keys: &'a [Vec<u8>],
+            epoch: u64,
+            read_options: ReadOptions,
+        ) -> Self::MultiGetFuture<'_> {
+            async move {
+                let actual = self.actual.multi_get(keys, epoch, read_options.clone()).await;
+                if let Some(expected) = self.sample() {
+                    let expected = expected.multi_get(keys, epoch, read_options).await;
+                    check_result_eq("multi_get", &keys, Some(epoch), &actual, &expected);
                 }
                 actual
             }
@@ -299,7 +389,7 @@ pub mod verify {
                     .actual
                     .iter(key_range.clone(), epoch, read_options.clone())
                     .await?;
-                let expected = if let Some(expected) = &self.expected {
+                let expected = if let Some(expected) = self.sample() {
                     Some(expected.iter(key_range, epoch, read_options).await?)
                 } else {
                     None
@@ -318,12 +408,22 @@ pub mod verify {
         pin_mut!(actual);
         pin_mut!(expected);
         let mut expected = expected.as_pin_mut();
+        let mut diverged = false;
 
         loop {
             let actual = actual.try_next().await?;
-            if let Some(expected) = expected.as_mut() {
+            if !diverged && let Some(expected) = expected.as_mut() {
                 let expected = expected.try_next().await?;
-                assert_eq!(actual, expected);
+                if actual != expected {
+                    // The key and epoch of the divergent entries are part of the items. The
+                    // streams are misaligned from here on, comparing further would only repeat
+                    // the report.
+                    error!(
+                        "state store divergence in iter: actual item: {:?}, expected item: {:?}",
+                        actual, expected
+                    );
+                    diverged = true;
+                }
             }
             if let Some(actual) = actual {
                 yield actual;
@@ -343,6 +443,7 @@ pub mod verify {
             write_options: WriteOptions,
         ) -> Self::IngestBatchFuture<'_> {
             async move {
+                let epoch = write_options.epoch;
                 let actual = self
                     .actual
                     .ingest_batch(
@@ -355,7 +456,15 @@ pub mod verify {
                     let expected = expected
                         .ingest_batch(kv_pairs, delete_ranges, write_options)
                         .await;
-                    assert_eq!(actual.is_err(), expected.is_err());
+                    if actual.is_err() != expected.is_err() {
+                        report_divergence(
+                            "ingest_batch",
+                            &"<batch>",
+                            Some(epoch),
+                            &actual,
+                            &expected,
+                        );
+                    }
                 }
                 actual
             }
@@ -367,6 +476,7 @@ pub mod verify {
             Self {
                 actual: self.actual.clone(),
                 expected: self.expected.clone(),
+                sample_ratio: self.sample_ratio,
             }
         }
     }
@@ -376,6 +486,7 @@ pub mod verify {
         type GetFuture<'a> = impl GetFutureTrait<'a>;
         type IterFuture<'a> = impl Future<Output = StorageResult<Self::IterStream<'a>>> + Send + 'a;
         type IterStream<'a> = impl StateStoreIterItemStream + 'a;
+        type MultiGetFuture<'a> = impl MultiGetFutureTrait<'a>;
 
         define_local_state_store_associated_type!();
 
@@ -393,9 +504,24 @@ pub mod verify {
         fn get<'a>(&'a self, key: &'a [u8], read_options: ReadOptions) -> Self::GetFuture<'_> {
             async move {
                 let actual = self.actual.get(key, read_options.clone()).await;
-                if let Some(expected) = &self.expected {
+                if let Some(expected) = self.sample() {
                     let expected = expected.get(key, read_options).await;
-                    assert_result_eq(&actual, &expected);
+                    check_result_eq("local get", &key, None, &actual, &expected);
+                }
+                actual
+            }
+        }
+
+        fn multi_get<'a>(
+            &'a self,
+            keys: &'a [Vec<u8>],
+            read_options: ReadOptions,
+        ) -> Self::MultiGetFuture<'_> {
+            async move {
+                let actual = self.actual.multi_get(keys, read_options.clone()).await;
+                if let Some(expected) = self.sample() {
+                    let expected = expected.multi_get(keys, read_options).await;
+                    check_result_eq("local multi_get", &keys, None, &actual, &expected);
                 }
                 actual
             }
@@ -411,7 +537,7 @@ pub mod verify {
                     .actual
                     .iter(key_range.clone(), read_options.clone())
                     .await?;
-                let expected = if let Some(expected) = &self.expected {
+                let expected = if let Some(expected) = self.sample() {
                     Some(expected.iter(key_range, read_options).await?)
                 } else {
                     None
@@ -521,6 +647,7 @@ pub mod verify {
                 VerifyStateStore {
                     actual: self.actual.new_local(option).await,
                     expected,
+                    sample_ratio: self.sample_ratio,
                 }
             }
         }
@@ -540,7 +667,7 @@ pub mod verify {
 }
 
 impl StateStoreImpl {
-    #[cfg_attr(not(target_os = "linux"), expect(unused_variables))]
+    #[cfg_attr(not(unix), expect(unused_variables))]
     #[allow(clippy::too_many_arguments)]
     pub async fn new(
         s: &str,
@@ -553,10 +680,10 @@ impl StateStoreImpl {
         storage_metrics: Arc<MonitoredStorageMetrics>,
         compactor_metrics: Arc<CompactorMetrics>,
     ) -> StorageResult<Self> {
-        #[cfg(not(target_os = "linux"))]
+        #[cfg(not(unix))]
         let tiered_cache = TieredCache::none();
 
-        #[cfg(target_os = "linux")]
+        #[cfg(unix)]
         let tiered_cache = if opts.file_cache_dir.is_empty() {
             TieredCache::none()
         } else {
@@ -586,6 +713,43 @@ impl StateStoreImpl {
                     "Hummock",
                 )
                 .await;
+                // Charge flush and serving-read traffic against their bandwidth quotas, so that
+                // neither can starve the other on shared bandwidth.
+                let io_quota = IoQuota {
+                    compaction_bytes_per_sec: opts.object_store_compaction_bandwidth_mb_per_sec
+                        << 20,
+                    flush_bytes_per_sec: opts.object_store_flush_bandwidth_mb_per_sec << 20,
+                    read_bytes_per_sec: opts.object_store_read_bandwidth_mb_per_sec << 20,
+                    write_bytes_per_sec: opts.object_store_write_bandwidth_mb_per_sec << 20,
+                };
+                let remote_object_store = if !io_quota.is_unlimited() {
+                    ObjectStoreImpl::Scheduled(IoScheduledObjectStore::new(
+                        remote_object_store,
+                        Arc::new(IoScheduler::new(io_quota, object_store_metrics.clone())),
+                        IoClass::Flush,
+                        IoClass::Read,
+                    ))
+                } else {
+                    remote_object_store
+                };
+                // Retry transient failures of the remote object store with exponential backoff,
+                // so that they do not bubble up as query failures or compactor panics.
+                let remote_object_store = if opts.object_store_retry_attempts > 1 {
+                    ObjectStoreImpl::Retry(RetryObjectStore::new(
+                        remote_object_store,
+                        RetryConfig {
+                            max_attempts: opts.object_store_retry_attempts,
+                            initial_backoff: Duration::from_millis(
+                                opts.object_store_retry_initial_backoff_ms,
+                            ),
+                            max_backoff: Duration::from_millis(
+                                opts.object_store_retry_max_backoff_ms,
+                            ),
+                        },
+                    ))
+                } else {
+                    remote_object_store
+                };
                 let object_store = if opts.enable_local_spill {
                     let local_object_store = parse_local_object_store(
                         opts.local_object_store.as_str(),
@@ -596,13 +760,24 @@ impl StateStoreImpl {
                     remote_object_store
                 };
 
-                let sstable_store = Arc::new(SstableStore::new(
+                let block_cache_policy = opts.block_cache_policy.parse().unwrap_or_else(|e| {
+                    tracing::warn!("{}, fall back to lru", e);
+                    BlockCachePolicy::Lru
+                });
+                let sstable_store = Arc::new(SstableStore::new_with_block_cache_policy(
                     Arc::new(object_store),
                     opts.data_directory.to_string(),
                     opts.block_cache_capacity_mb * (1 << 20),
                     opts.meta_cache_capacity_mb * (1 << 20),
                     tiered_cache,
+                    block_cache_policy,
+                    opts.block_cache_per_table_capacity_mb * (1 << 20),
                 ));
+                if !opts.sstable_encryption_key_file.is_empty() {
+                    sstable_store.set_encryption_key_provider(Arc::new(
+                        FileEncryptionKeyProvider::load(&opts.sstable_encryption_key_file)?,
+                    ));
+                }
                 let notification_client =
                     RpcNotificationClient::new(hummock_meta_client.get_inner().clone());
 
@@ -612,6 +787,12 @@ impl StateStoreImpl {
                 )
                 .await?;
                 let backup_reader = BackupReader::new(backup_store);
+                if opts.read_only {
+                    tracing::info!(
+                        "Hummock state store runs in read-only replica mode: local writes and \
+                         epoch syncs are rejected, reads are served from pinned versions."
+                    );
+                }
                 let inner = HummockStorage::new(
                     opts.clone(),
                     sstable_store,
@@ -624,7 +805,7 @@ impl StateStoreImpl {
                 )
                 .await?;
 
-                StateStoreImpl::hummock(inner, storage_metrics)
+                StateStoreImpl::hummock(inner, storage_metrics, &opts)
             }
 
             "in_memory" | "in-memory" => {
@@ -632,12 +813,22 @@ impl StateStoreImpl {
                 StateStoreImpl::shared_in_memory_store(storage_metrics.clone())
             }
 
-            sled if sled.starts_with("sled://") => {
+            sled if sled.starts_with("sled://") || sled.starts_with("local://") => {
                 tracing::warn!("sled state store should never be used in end-to-end benchmarks or production environment. Scaling and recovery are not supported.");
-                let path = sled.strip_prefix("sled://").unwrap();
+                let path = sled
+                    .strip_prefix("sled://")
+                    .or_else(|| sled.strip_prefix("local://"))
+                    .unwrap();
                 StateStoreImpl::sled(SledStateStore::new(path), storage_metrics.clone())
             }
 
+            #[cfg(feature = "rocksdb-backend")]
+            rocksdb if rocksdb.starts_with("rocksdb://") => {
+                tracing::warn!("rocksdb state store only supports single-node deployments. Scaling and recovery are not supported.");
+                let path = rocksdb.strip_prefix("rocksdb://").unwrap();
+                StateStoreImpl::rocksdb(RocksdbStateStore::new(path), storage_metrics.clone())
+            }
+
             other => unimplemented!("{} state store is not supported", other),
         };
 
@@ -698,6 +889,13 @@ impl AsHummockTrait for SledStateStore {
     }
 }
 
+#[cfg(feature = "rocksdb-backend")]
+impl AsHummockTrait for RocksdbStateStore {
+    fn as_hummock_trait(&self) -> Option<&dyn HummockTrait> {
+        None
+    }
+}
+
 #[cfg(debug_assertions)]
 pub mod boxed_state_store {
     use std::future::Future;
@@ -726,6 +924,13 @@ pub mod boxed_state_store {
             read_options: ReadOptions,
         ) -> StorageResult<Option<Bytes>>;
 
+        async fn multi_get<'a>(
+            &'a self,
+            keys: &'a [Vec<u8>],
+            epoch: u64,
+            read_options: ReadOptions,
+        ) -> StorageResult<Vec<Option<Bytes>>>;
+
         async fn iter(
             &self,
             key_range: (Bound<Vec<u8>>, Bound<Vec<u8>>),
@@ -745,6 +950,15 @@ pub mod boxed_state_store {
             self.get(key, epoch, read_options).await
         }
 
+        async fn multi_get<'a>(
+            &'a self,
+            keys: &'a [Vec<u8>],
+            epoch: u64,
+            read_options: ReadOptions,
+        ) -> StorageResult<Vec<Option<Bytes>>> {
+            self.multi_get(keys, epoch, read_options).await
+        }
+
         async fn iter(
             &self,
             key_range: (Bound<Vec<u8>>, Bound<Vec<u8>>),
@@ -771,6 +985,12 @@ pub mod boxed_state_store {
             read_options: ReadOptions,
         ) -> StorageResult<Option<Bytes>>;
 
+        async fn multi_get<'a>(
+            &'a self,
+            keys: &'a [Vec<u8>],
+            read_options: ReadOptions,
+        ) -> StorageResult<Vec<Option<Bytes>>>;
+
         async fn iter(
             &self,
             key_range: (Bound<Vec<u8>>, Bound<Vec<u8>>),
@@ -815,6 +1035,14 @@ pub mod boxed_state_store {
             self.get(key, read_options).await
         }
 
+        async fn multi_get<'a>(
+            &'a self,
+            keys: &'a [Vec<u8>],
+            read_options: ReadOptions,
+        ) -> StorageResult<Vec<Option<Bytes>>> {
+            self.multi_get(keys, read_options).await
+        }
+
         async fn iter(
             &self,
             key_range: (Bound<Vec<u8>>, Bound<Vec<u8>>),
@@ -865,6 +1093,7 @@ pub mod boxed_state_store {
         type FlushFuture<'a> = impl Future<Output = StorageResult<usize>> + 'a;
         type GetFuture<'a> = impl GetFutureTrait<'a>;
         type IterFuture<'a> = impl Future<Output = StorageResult<Self::IterStream<'a>>> + Send + 'a;
+        type MultiGetFuture<'a> = impl MultiGetFutureTrait<'a>;
 
         define_local_state_store_associated_type!();
 
@@ -880,6 +1109,14 @@ pub mod boxed_state_store {
             self.deref().get(key, read_options)
         }
 
+        fn multi_get<'a>(
+            &'a self,
+            keys: &'a [Vec<u8>],
+            read_options: ReadOptions,
+        ) -> Self::MultiGetFuture<'_> {
+            self.deref().multi_get(keys, read_options)
+        }
+
         fn iter(
             &self,
             key_range: (Bound<Vec<u8>>, Bound<Vec<u8>>),
@@ -982,6 +1219,15 @@ pub mod boxed_state_store {
             self.deref().get(key, epoch, read_options)
         }
 
+        fn multi_get<'a>(
+            &'a self,
+            keys: &'a [Vec<u8>],
+            epoch: u64,
+            read_options: ReadOptions,
+        ) -> Self::MultiGetFuture<'_> {
+            self.deref().multi_get(keys, epoch, read_options)
+        }
+
         fn iter(
             &self,
             key_range: (Bound<Vec<u8>>, Bound<Vec<u8>>),
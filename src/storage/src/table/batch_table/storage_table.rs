@@ -29,9 +29,10 @@ use risingwave_common::catalog::{
     get_dist_key_in_pk_indices, ColumnDesc, ColumnId, Schema, TableId, TableOption,
 };
 use risingwave_common::hash::{VirtualNode, VnodeBitmapExt};
-use risingwave_common::row::{self, OwnedRow, Row, RowDeserializer, RowExt};
+use risingwave_common::row::{self, OwnedRow, Row, RowExt};
 use risingwave_common::util::ordered::*;
 use risingwave_common::util::sort_util::OrderType;
+use risingwave_common::util::value_encoding::{BasicSerde, ValueRowDeserializer};
 use risingwave_hummock_sdk::key::{end_bound_of_prefix, next_key, prefixed_range};
 use risingwave_hummock_sdk::HummockReadEpoch;
 use tracing::trace;
@@ -41,8 +42,9 @@ use crate::error::{StorageError, StorageResult};
 use crate::row_serde::row_serde_util::{
     parse_raw_key_to_vnode_and_key, serialize_pk, serialize_pk_with_vnode,
 };
+use crate::row_serde::value_serde::{ColumnAwareSerde, EitherSerde};
 use crate::row_serde::{find_columns_by_ids, ColumnMapping};
-use crate::store::ReadOptions;
+use crate::store::{CachePolicy, ReadOptions};
 use crate::table::{compute_vnode, Distribution, TableIter, DEFAULT_VNODE};
 use crate::StateStore;
 
@@ -78,7 +80,7 @@ pub struct StorageTable<S: StateStore> {
     mapping: Arc<ColumnMapping>,
 
     /// Row deserializer to deserialize the whole value in storage to a row.
-    row_deserializer: Arc<RowDeserializer>,
+    row_serde: Arc<EitherSerde>,
 
     /// Indices of primary key.
     /// Note that the index is based on the all columns of the table, instead of the output ones.
@@ -118,6 +120,9 @@ impl<S: StateStore> StorageTable<S> {
     /// Create a  [`StorageTable`] given a complete set of `columns` and a partial
     /// set of `column_ids`. The output will only contains columns with the given ids in the same
     /// order.
+    ///
+    /// If the table is `versioned`, the value is decoded with the column-aware row encoding, and
+    /// only the output value columns are decoded.
     #[allow(clippy::too_many_arguments)]
     pub fn new_partial(
         store: S,
@@ -130,6 +135,7 @@ impl<S: StateStore> StorageTable<S> {
         table_options: TableOption,
         value_indices: Vec<usize>,
         read_prefix_len_hint: usize,
+        versioned: bool,
     ) -> Self {
         Self::new_inner(
             store,
@@ -142,6 +148,7 @@ impl<S: StateStore> StorageTable<S> {
             table_options,
             value_indices,
             read_prefix_len_hint,
+            versioned,
         )
     }
 
@@ -165,6 +172,7 @@ impl<S: StateStore> StorageTable<S> {
             Default::default(),
             value_indices,
             0,
+            false,
         )
     }
 
@@ -189,6 +197,7 @@ impl<S: StateStore> StorageTable<S> {
         table_option: TableOption,
         value_indices: Vec<usize>,
         read_prefix_len_hint: usize,
+        versioned: bool,
     ) -> Self {
         assert_eq!(order_types.len(), pk_indices.len());
 
@@ -214,8 +223,6 @@ impl<S: StateStore> StorageTable<S> {
             .collect_vec();
         let schema = Schema::new(output_columns.iter().map(Into::into).collect());
 
-        let mapping = ColumnMapping::new(output_row_in_value_indices);
-
         let pk_data_types = pk_indices
             .iter()
             .map(|i| table_columns[*i].data_type.clone())
@@ -229,7 +236,24 @@ impl<S: StateStore> StorageTable<S> {
             .map(|idx| all_data_types[*idx].clone())
             .collect_vec();
         let pk_serializer = OrderedRowSerde::new(pk_data_types, order_types);
-        let row_deserializer = RowDeserializer::new(data_types);
+        let row_serde: EitherSerde = if versioned {
+            let value_column_ids = value_indices
+                .iter()
+                .map(|idx| table_columns[*idx].column_id)
+                .collect_vec();
+            // Only the output value columns are needed by this table instance, so the
+            // deserializer can skip decoding the other columns of the row.
+            ColumnAwareSerde::new_with_projection(
+                &value_column_ids,
+                data_types.into(),
+                &output_row_in_value_indices,
+            )
+            .into()
+        } else {
+            BasicSerde::new(data_types).into()
+        };
+
+        let mapping = ColumnMapping::new(output_row_in_value_indices);
 
         let dist_key_in_pk_indices = get_dist_key_in_pk_indices(&dist_key_indices, &pk_indices);
         let key_output_indices = match key_output_indices.is_empty() {
@@ -246,7 +270,7 @@ impl<S: StateStore> StorageTable<S> {
             value_output_indices,
             output_row_in_key_indices,
             mapping: Arc::new(mapping),
-            row_deserializer: Arc::new(row_deserializer),
+            row_serde: Arc::new(row_serde),
             pk_indices,
             dist_key_indices,
             dist_key_in_pk_indices,
@@ -263,12 +287,16 @@ impl<S: StateStore> StorageTable<S> {
     pub fn pk_indices(&self) -> &[usize] {
         &self.pk_indices
     }
+
+    pub fn vnodes(&self) -> &Bitmap {
+        &self.vnodes
+    }
 }
 
 /// Point get
 impl<S: StateStore> StorageTable<S> {
     /// Get vnode value with given primary key.
-    fn compute_vnode_by_pk(&self, pk: impl Row) -> VirtualNode {
+    pub fn compute_vnode_by_pk(&self, pk: impl Row) -> VirtualNode {
         compute_vnode(pk, &self.dist_key_in_pk_indices, &self.vnodes)
     }
 
@@ -305,13 +333,15 @@ impl<S: StateStore> StorageTable<S> {
             prefix_hint,
             retention_seconds: self.table_option.retention_seconds,
             ignore_range_tombstone: false,
+            limit: None,
             table_id: self.table_id,
             read_version_from_backup: read_backup,
+            cache_policy: CachePolicy::Fill,
         };
         if let Some(value) = self.store.get(&serialized_pk, epoch, read_options).await? {
             // Refer to [`StorageTableIterInner::new`] for necessity of `validate_read_epoch`.
             self.store.validate_read_epoch(wait_epoch)?;
-            let full_row = self.row_deserializer.deserialize(value)?;
+            let full_row = OwnedRow::new(self.row_serde.deserialize(&value)?);
             let result_row_in_value = self.mapping.project(full_row).into_owned_row();
             match &self.key_output_indices {
                 Some(key_output_indices) => {
@@ -419,9 +449,13 @@ impl<S: StateStore> StorageTable<S> {
                 let read_options = ReadOptions {
                     prefix_hint,
                     ignore_range_tombstone: false,
+                    limit: None,
                     retention_seconds: self.table_option.retention_seconds,
                     table_id: self.table_id,
                     read_version_from_backup: read_backup,
+                    // Batch scans can be arbitrarily large, so do not let them evict the block
+                    // cache working set of streaming state access.
+                    cache_policy: CachePolicy::NotFill,
                 };
                 let pk_serializer = match self.output_row_in_key_indices.is_empty() {
                     true => None,
@@ -435,7 +469,7 @@ impl<S: StateStore> StorageTable<S> {
                     self.key_output_indices.clone(),
                     self.value_output_indices.clone(),
                     self.output_row_in_key_indices.clone(),
-                    self.row_deserializer.clone(),
+                    self.row_serde.clone(),
                     raw_key_range,
                     read_options,
                     wait_epoch,
@@ -462,12 +496,15 @@ impl<S: StateStore> StorageTable<S> {
     }
 
     /// Iterates on the table with the given prefix of the pk in `pk_prefix` and the range bounds.
+    /// If `vnode_hint` is set, only the rows of this single vnode are accessed. Otherwise, it's
+    /// derived from `pk_prefix` if possible.
     async fn iter_with_pk_bounds(
         &self,
         epoch: HummockReadEpoch,
         pk_prefix: impl Row,
         range_bounds: impl RangeBounds<OwnedRow>,
         ordered: bool,
+        vnode_hint: Option<VirtualNode>,
     ) -> StorageResult<StorageTableIter<S>> {
         // TODO: directly use `prefixed_range`.
         fn serialize_pk_bound(
@@ -575,7 +612,7 @@ impl<S: StateStore> StorageTable<S> {
             prefix_hint,
             (start_key, end_key),
             epoch,
-            self.try_compute_vnode_by_pk_prefix(pk_prefix),
+            vnode_hint.or_else(|| self.try_compute_vnode_by_pk_prefix(pk_prefix)),
             ordered,
         )
         .await
@@ -590,7 +627,22 @@ impl<S: StateStore> StorageTable<S> {
         range_bounds: impl RangeBounds<OwnedRow>,
         ordered: bool,
     ) -> StorageResult<StorageTableIter<S>> {
-        self.iter_with_pk_bounds(epoch, pk_prefix, range_bounds, ordered)
+        self.iter_with_pk_bounds(epoch, pk_prefix, range_bounds, ordered, None)
+            .await
+    }
+
+    /// Construct a [`StorageTableIter`] for batch executors that only accesses the rows of the
+    /// given `vnode` of this table. The `vnode` must be set in [`Self::vnodes`].
+    pub async fn batch_iter_vnode_with_pk_bounds(
+        &self,
+        epoch: HummockReadEpoch,
+        vnode: VirtualNode,
+        range_bounds: impl RangeBounds<OwnedRow>,
+    ) -> StorageResult<StorageTableIter<S>> {
+        assert!(self.vnodes.is_set(vnode.to_index()));
+        // The rows of a single vnode are ordered by the primary key, so an ordered iterator comes
+        // for free.
+        self.iter_with_pk_bounds(epoch, row::empty(), range_bounds, true, Some(vnode))
             .await
     }
 
@@ -612,7 +664,7 @@ struct StorageTableIterInner<S: StateStore> {
 
     mapping: Arc<ColumnMapping>,
 
-    row_deserializer: Arc<RowDeserializer>,
+    row_serde: Arc<EitherSerde>,
 
     /// Used for serializing and deserializing the primary key.
     pk_serializer: Option<Arc<OrderedRowSerde>>,
@@ -640,7 +692,7 @@ impl<S: StateStore> StorageTableIterInner<S> {
         key_output_indices: Option<Vec<usize>>,
         value_output_indices: Vec<usize>,
         output_row_in_key_indices: Vec<usize>,
-        row_deserializer: Arc<RowDeserializer>,
+        row_serde: Arc<EitherSerde>,
         raw_key_range: R,
         read_options: ReadOptions,
         epoch: HummockReadEpoch,
@@ -664,7 +716,7 @@ impl<S: StateStore> StorageTableIterInner<S> {
         let iter = Self {
             iter,
             mapping,
-            row_deserializer,
+            row_serde,
             pk_serializer,
             output_indices,
             key_output_indices,
@@ -689,7 +741,7 @@ impl<S: StateStore> StorageTableIterInner<S> {
         {
             let (_, key) = parse_raw_key_to_vnode_and_key(&raw_key);
 
-            let full_row = self.row_deserializer.deserialize(value)?;
+            let full_row = OwnedRow::new(self.row_serde.deserialize(&value)?);
             let result_row_in_value = self.mapping.project(full_row).into_owned_row();
             match &self.key_output_indices {
                 Some(key_output_indices) => {
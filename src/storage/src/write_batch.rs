@@ -16,7 +16,6 @@ use bytes::Bytes;
 use risingwave_hummock_sdk::key::next_key;
 
 use crate::error::StorageResult;
-use crate::hummock::HummockError;
 use crate::storage_value::StorageValue;
 use crate::store::{StateStoreWrite, WriteOptions};
 
@@ -87,17 +86,22 @@ impl<'a, S: StateStoreWrite> WriteBatch<'a, S> {
         self.batch.len()
     }
 
-    /// Preprocesses the batch to make it sorted. It returns `false` if duplicate keys are found.
-    fn preprocess(&mut self) -> StorageResult<()> {
-        let original_length = self.batch.len();
+    /// Preprocesses the batch to make it sorted and deduplicated. If a key is written multiple
+    /// times, only the last write takes effect, as if the entries were applied one by one. This
+    /// avoids uploading stale versions of high-churn keys to the shared buffer.
+    fn preprocess(&mut self) {
+        // The sort is stable, so the last write of a key remains the last one in its run.
         self.batch.sort_by(|(k1, _), (k2, _)| k1.cmp(k2));
-        self.batch.dedup_by(|(k1, _), (k2, _)| k1 == k2);
-
-        if original_length == self.batch.len() {
-            Ok(())
-        } else {
-            Err(HummockError::invalid_write_batch().into())
-        }
+        self.batch.dedup_by(|(k1, v1), (k2, v2)| {
+            if k1 == k2 {
+                // `dedup_by` keeps the first element of a run, so move the value of the last
+                // write into it.
+                std::mem::swap(v1, v2);
+                true
+            } else {
+                false
+            }
+        });
     }
 
     /// Returns `true` if the batch contains no key-value pairs.
@@ -108,7 +112,7 @@ impl<'a, S: StateStoreWrite> WriteBatch<'a, S> {
     /// Ingests this batch into the associated state store.
     pub async fn ingest(mut self) -> StorageResult<()> {
         if !self.is_empty() {
-            self.preprocess()?;
+            self.preprocess();
             self.store
                 .ingest_batch(self.batch, self.delete_ranges, self.write_options)
                 .await?;
@@ -129,10 +133,10 @@ mod tests {
 
     use crate::memory::MemoryStateStore;
     use crate::storage_value::StorageValue;
-    use crate::store::{StateStoreWrite, WriteOptions};
+    use crate::store::{ReadOptions, StateStoreRead, StateStoreWrite, WriteOptions};
 
     #[tokio::test]
-    async fn test_invalid_write_batch() {
+    async fn test_write_batch_dedup() {
         let state_store = MemoryStateStore::new();
         let mut batch = state_store.start_write_batch(WriteOptions {
             epoch: 1,
@@ -142,11 +146,25 @@ mod tests {
         batch.put(Bytes::from("aa"), StorageValue::new_put("444"));
         batch.put(Bytes::from("cc"), StorageValue::new_put("444"));
         batch.put(Bytes::from("bb"), StorageValue::new_put("444"));
+        batch.put(Bytes::from("bb"), StorageValue::new_put("555"));
         batch.delete(Bytes::from("aa"));
 
-        batch
-            .ingest()
-            .await
-            .expect_err("Should panic here because of duplicate key.");
+        batch.ingest().await.unwrap();
+
+        // Only the last write of each key takes effect.
+        assert_eq!(
+            state_store
+                .get(b"aa", 1, ReadOptions::default())
+                .await
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            state_store
+                .get(b"bb", 1, ReadOptions::default())
+                .await
+                .unwrap(),
+            Some(Bytes::from("555"))
+        );
     }
 }
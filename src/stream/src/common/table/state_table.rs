@@ -23,14 +23,17 @@ use risingwave_common::array::{Op, StreamChunk, Vis};
 use risingwave_common::buffer::Bitmap;
 use risingwave_common::catalog::{get_dist_key_in_pk_indices, ColumnDesc, TableId, TableOption};
 use risingwave_common::hash::{VirtualNode, VnodeBitmapExt};
-use risingwave_common::row::{self, CompactedRow, OwnedRow, Row, RowDeserializer, RowExt};
-use risingwave_common::types::ScalarImpl;
+use risingwave_common::row::{self, CompactedRow, OwnedRow, Row, RowExt};
+use risingwave_common::types::{DataType, ScalarImpl};
 use risingwave_common::util::epoch::EpochPair;
 use risingwave_common::util::iter_util::{ZipEqDebug, ZipEqFast};
 use risingwave_common::util::ordered::OrderedRowSerde;
 use risingwave_common::util::sort_util::OrderType;
+use risingwave_common::util::value_encoding::{
+    BasicSerde, ValueRowDeserializer, ValueRowSerializer,
+};
 use risingwave_hummock_sdk::key::{
-    end_bound_of_prefix, prefixed_range, range_of_prefix, start_bound_of_excluded_prefix,
+    end_bound_of_prefix, next_key, prefixed_range, range_of_prefix, start_bound_of_excluded_prefix,
 };
 use risingwave_pb::catalog::Table;
 use risingwave_storage::error::StorageError;
@@ -38,8 +41,9 @@ use risingwave_storage::mem_table::MemTableError;
 use risingwave_storage::row_serde::row_serde_util::{
     deserialize_pk_with_vnode, serialize_pk, serialize_pk_with_vnode,
 };
+use risingwave_storage::row_serde::value_serde::{ColumnAwareSerde, EitherSerde};
 use risingwave_storage::store::{
-    LocalStateStore, NewLocalOptions, ReadOptions, StateStoreIterItemStream,
+    CachePolicy, LocalStateStore, NewLocalOptions, ReadOptions, StateStoreIterItemStream,
 };
 use risingwave_storage::table::{compute_chunk_vnode, compute_vnode, Distribution};
 use risingwave_storage::StateStore;
@@ -67,8 +71,8 @@ pub struct StateTable<
     /// Used for serializing and deserializing the primary key.
     pk_serde: OrderedRowSerde,
 
-    /// Row deserializer with value encoding
-    row_deserializer: RowDeserializer,
+    /// Row serde for serializing and deserializing the value with value encoding.
+    row_serde: EitherSerde,
 
     /// Indices of primary key.
     /// Note that the index is based on the all columns of the table, instead of the output ones.
@@ -202,10 +206,22 @@ impl<S: StateStore, W: WatermarkBufferStrategy> StateTable<S, W> {
             .map(|val| *val as usize)
             .collect_vec();
 
-        let data_types = input_value_indices
+        let data_types: Vec<DataType> = input_value_indices
             .iter()
             .map(|idx| table_columns[*idx].data_type.clone())
             .collect();
+        // The table will be versioned iff it's created by `CREATE TABLE`, in which case it may
+        // be subject to schema changes and the value must be encoded with the column-aware
+        // encoding to be compatible.
+        let row_serde: EitherSerde = if table_catalog.version.is_some() {
+            let value_column_ids = input_value_indices
+                .iter()
+                .map(|idx| table_columns[*idx].column_id)
+                .collect_vec();
+            ColumnAwareSerde::new(&value_column_ids, data_types.into()).into()
+        } else {
+            BasicSerde::new(data_types).into()
+        };
 
         let no_shuffle_value_indices = (0..table_columns.len()).collect_vec();
 
@@ -221,7 +237,7 @@ impl<S: StateStore, W: WatermarkBufferStrategy> StateTable<S, W> {
             table_id,
             local_store: local_state_store,
             pk_serde,
-            row_deserializer: RowDeserializer::new(data_types),
+            row_serde,
             pk_indices: pk_indices.to_vec(),
             dist_key_indices,
             dist_key_in_pk_indices,
@@ -411,7 +427,7 @@ impl<S: StateStore, W: WatermarkBufferStrategy> StateTable<S, W> {
             table_id,
             local_store: local_state_store,
             pk_serde,
-            row_deserializer: RowDeserializer::new(data_types),
+            row_serde: BasicSerde::new(data_types).into(),
             pk_indices,
             dist_key_indices,
             dist_key_in_pk_indices,
@@ -477,6 +493,10 @@ impl<S: StateStore, W: WatermarkBufferStrategy> StateTable<S, W> {
         &self.pk_serde
     }
 
+    pub(crate) fn row_serde(&self) -> &EitherSerde {
+        &self.row_serde
+    }
+
     pub fn dist_key_indices(&self) -> &[usize] {
         &self.dist_key_indices
     }
@@ -505,10 +525,8 @@ impl<S: StateStore> StateTable<S> {
         let compacted_row: Option<CompactedRow> = self.get_compacted_row(pk).await?;
         match compacted_row {
             Some(compacted_row) => {
-                let row = self
-                    .row_deserializer
-                    .deserialize(compacted_row.row.as_ref())?;
-                Ok(Some(row))
+                let row = self.row_serde.deserialize(compacted_row.row.as_ref())?;
+                Ok(Some(OwnedRow::new(row)))
             }
             None => Ok(None),
         }
@@ -539,7 +557,9 @@ impl<S: StateStore> StateTable<S> {
             retention_seconds: self.table_option.retention_seconds,
             table_id: self.table_id,
             ignore_range_tombstone: false,
+            limit: None,
             read_version_from_backup: false,
+            cache_policy: CachePolicy::HighPriority,
         };
         if let Some(storage_row_bytes) = self.local_store.get(&serialized_pk, read_options).await? {
             Ok(Some(CompactedRow {
@@ -550,6 +570,78 @@ impl<S: StateStore> StateTable<S> {
         }
     }
 
+    /// Get the compacted rows of multiple `pks` from the state table with a single vectored get
+    /// on the state store. The returned rows are in the order of `pks`.
+    pub async fn multi_get_compacted_rows(
+        &self,
+        pks: impl IntoIterator<Item = impl Row>,
+    ) -> StreamExecutorResult<Vec<Option<CompactedRow>>> {
+        let serialized_pks = pks
+            .into_iter()
+            .map(|pk| {
+                assert!(pk.len() <= self.pk_indices.len());
+                serialize_pk_with_vnode(&pk, &self.pk_serde, self.compute_prefix_vnode(&pk))
+                    .to_vec()
+            })
+            .collect_vec();
+
+        // A vectored get cannot carry a per-key prefix hint, so none is provided.
+        let read_options = ReadOptions {
+            prefix_hint: None,
+            retention_seconds: self.table_option.retention_seconds,
+            table_id: self.table_id,
+            ignore_range_tombstone: false,
+            limit: None,
+            read_version_from_backup: false,
+            cache_policy: CachePolicy::HighPriority,
+        };
+        Ok(self
+            .local_store
+            .multi_get(&serialized_pks, read_options)
+            .await?
+            .into_iter()
+            .map(|value| value.map(|row| CompactedRow { row }))
+            .collect())
+    }
+
+    /// Returns whether the given `pk` may exist in this state table, by only checking the bloom
+    /// filter with the serialized key instead of reading the value.
+    ///
+    /// Returns:
+    /// - false: the key is guaranteed to be absent, so the caller can skip a storage get.
+    /// - true: the key may or may not exist.
+    pub async fn may_exist(&self, pk: impl Row) -> StreamExecutorResult<bool> {
+        assert!(pk.len() <= self.pk_indices.len());
+
+        if self.prefix_hint_len != 0 {
+            debug_assert_eq!(self.prefix_hint_len, pk.len());
+        }
+
+        let serialized_pk =
+            serialize_pk_with_vnode(&pk, &self.pk_serde, self.compute_prefix_vnode(&pk));
+
+        let prefix_hint = if self.prefix_hint_len != 0 && self.prefix_hint_len == pk.len() {
+            Some(serialized_pk.slice(VirtualNode::SIZE..))
+        } else {
+            None
+        };
+
+        let read_options = ReadOptions {
+            prefix_hint,
+            retention_seconds: self.table_option.retention_seconds,
+            table_id: self.table_id,
+            ignore_range_tombstone: false,
+            limit: None,
+            read_version_from_backup: false,
+            cache_policy: CachePolicy::HighPriority,
+        };
+        let key_range = (
+            Included(serialized_pk.to_vec()),
+            Included(serialized_pk.to_vec()),
+        );
+        Ok(self.local_store.may_exist(key_range, read_options).await?)
+    }
+
     /// Update the vnode bitmap of the state table, returns the previous vnode bitmap.
     #[must_use = "the executor should decide whether to manipulate the cache based on the previous vnode bitmap"]
     pub fn update_vnode_bitmap(&mut self, new_vnodes: Arc<Bitmap>) -> Arc<Bitmap> {
@@ -586,8 +678,8 @@ impl<S: StateStore> StateTable<S> {
                     self.table_id(),
                     vnode,
                     &key,
-                    prev.debug_fmt(&self.row_deserializer),
-                    new.debug_fmt(&self.row_deserializer),
+                    prev.debug_fmt(&self.row_serde),
+                    new.debug_fmt(&self.row_serde),
                 )
             }
         }
@@ -595,9 +687,11 @@ impl<S: StateStore> StateTable<S> {
 
     fn serialize_value(&self, value: impl Row) -> Bytes {
         if let Some(value_indices) = self.value_indices.as_ref() {
-            value.project(value_indices).value_serialize_bytes()
+            self.row_serde
+                .serialize(value.project(value_indices))
+                .into()
         } else {
-            value.value_serialize_bytes()
+            self.row_serde.serialize(value).into()
         }
     }
 
@@ -669,7 +763,7 @@ impl<S: StateStore> StateTable<S> {
         } else {
             chunk.clone()
         };
-        let values = value_chunk.serialize();
+        let values = value_chunk.serialize_with(&self.row_serde);
 
         let key_chunk = chunk.reorder_columns(self.pk_indices());
         let vnode_and_pks = key_chunk
@@ -725,6 +819,33 @@ impl<S: StateStore> StateTable<S> {
         self.seal_current_epoch(new_epoch.curr).await
     }
 
+    /// Commit the current epoch as [`Self::commit`] does, and additionally issue range-delete
+    /// tombstones covering the whole key space of this table. This is called on the cleanup
+    /// barrier when the streaming job owning this table is dropped, so that the space occupied
+    /// by the state is reclaimed by the next compaction instead of lingering until full GC.
+    pub async fn commit_and_cleanup(&mut self, new_epoch: EpochPair) -> StreamExecutorResult<()> {
+        assert_eq!(self.epoch(), new_epoch.prev);
+        trace!(
+            table_id = %self.table_id,
+            epoch = ?self.epoch(),
+            "commit state table and clean up state"
+        );
+        // Any pending watermark-based state cleaning is covered by the ranges below.
+        self.cur_watermark = None;
+
+        let mut delete_ranges = Vec::new();
+        for vnode in self.vnodes.iter_vnodes() {
+            let range_begin = vnode.to_be_bytes().to_vec();
+            // An empty `range_end` means the range is unbounded on the right, which only
+            // happens for the maximum vnode.
+            let range_end = next_key(&range_begin);
+            delete_ranges.push((Bytes::from(range_begin), Bytes::from(range_end)));
+        }
+        self.local_store.flush(delete_ranges).await?;
+        self.local_store.seal_current_epoch(new_epoch.curr);
+        Ok(())
+    }
+
     // TODO(st1page): maybe we should extract a pub struct to do it
     /// just specially used by those state table read-only and after the call the data
     /// in the epoch will be visible
@@ -808,6 +929,40 @@ impl<S: StateStore, W: WatermarkBufferStrategy> StateTable<S, W> {
         Ok(self.iter_key_and_val(pk_prefix).await?.map(get_second))
     }
 
+    /// Like [`Self::iter_with_pk_prefix`], but hints the storage layer that at most `limit` rows
+    /// will be consumed, so that it can stop block read-ahead once those are covered. The stream
+    /// itself is NOT truncated: consuming it beyond `limit` rows is still correct, only without
+    /// read-ahead.
+    pub async fn iter_with_pk_prefix_and_limit(
+        &self,
+        pk_prefix: impl Row,
+        limit: usize,
+    ) -> StreamExecutorResult<RowStream<'_, S>> {
+        Ok(deserialize_row_stream(
+            self.iter_with_pk_prefix_inner(pk_prefix, Some(limit)).await?,
+            self.row_serde.clone(),
+        )
+        .map(get_second))
+    }
+
+    /// Like [`Self::iter_with_pk_prefix`], but only decodes the value columns at `projection`,
+    /// leaving the other columns of the yielded rows `None`. Tables with the column-aware
+    /// encoding (i.e. versioned tables) skip decoding the other columns entirely; the basic
+    /// encoding falls back to decoding all columns.
+    /// `limit` is the same hint as in [`Self::iter_with_pk_prefix_and_limit`].
+    pub async fn iter_with_pk_prefix_projected(
+        &self,
+        pk_prefix: impl Row,
+        projection: &[usize],
+        limit: Option<usize>,
+    ) -> StreamExecutorResult<RowStream<'_, S>> {
+        Ok(deserialize_row_stream(
+            self.iter_with_pk_prefix_inner(pk_prefix, limit).await?,
+            self.row_serde.project(projection),
+        )
+        .map(get_second))
+    }
+
     /// This function scans rows from the relational table with specific `pk_prefix`.
     async fn iter_with_pk_range_inner(
         &self,
@@ -823,7 +978,7 @@ impl<S: StateStore, W: WatermarkBufferStrategy> StateTable<S, W> {
             prefixed_range(memcomparable_range, &vnode.to_be_bytes());
 
         // TODO: provide a trace of useful params.
-        self.iter_inner(memcomparable_range_with_vnode, None)
+        self.iter_inner(memcomparable_range_with_vnode, None, None)
             .await
             .map_err(StreamExecutorError::from)
     }
@@ -852,7 +1007,7 @@ impl<S: StateStore, W: WatermarkBufferStrategy> StateTable<S, W> {
     ) -> StreamExecutorResult<RowStreamWithPk<'_, S>> {
         Ok(deserialize_row_stream(
             self.iter_with_pk_range_inner(pk_range, vnode).await?,
-            self.row_deserializer.clone(),
+            self.row_serde.clone(),
         ))
     }
 
@@ -863,14 +1018,15 @@ impl<S: StateStore, W: WatermarkBufferStrategy> StateTable<S, W> {
         pk_prefix: impl Row,
     ) -> StreamExecutorResult<RowStreamWithPk<'_, S>> {
         Ok(deserialize_row_stream(
-            self.iter_with_pk_prefix_inner(pk_prefix).await?,
-            self.row_deserializer.clone(),
+            self.iter_with_pk_prefix_inner(pk_prefix, None).await?,
+            self.row_serde.clone(),
         ))
     }
 
     async fn iter_with_pk_prefix_inner(
         &self,
         pk_prefix: impl Row,
+        limit: Option<usize>,
     ) -> StreamExecutorResult<<S::Local as LocalStateStore>::IterStream<'_>> {
         let prefix_serializer = self.pk_serde.prefix(pk_prefix.len());
         let encoded_prefix = serialize_pk(&pk_prefix, &prefix_serializer);
@@ -906,7 +1062,7 @@ impl<S: StateStore, W: WatermarkBufferStrategy> StateTable<S, W> {
             "storage_iter_with_prefix"
         );
 
-        self.iter_inner(encoded_key_range_with_vnode, prefix_hint)
+        self.iter_inner(encoded_key_range_with_vnode, prefix_hint, limit)
             .await
     }
 
@@ -914,13 +1070,16 @@ impl<S: StateStore, W: WatermarkBufferStrategy> StateTable<S, W> {
         &self,
         key_range: (Bound<Vec<u8>>, Bound<Vec<u8>>),
         prefix_hint: Option<Bytes>,
+        limit: Option<usize>,
     ) -> StreamExecutorResult<<S::Local as LocalStateStore>::IterStream<'_>> {
         let read_options = ReadOptions {
             prefix_hint,
             ignore_range_tombstone: false,
+            limit,
             retention_seconds: self.table_option.retention_seconds,
             table_id: self.table_id,
             read_version_from_backup: false,
+            cache_policy: CachePolicy::HighPriority,
         };
 
         Ok(self.local_store.iter(key_range, read_options).await?)
@@ -930,10 +1089,13 @@ impl<S: StateStore, W: WatermarkBufferStrategy> StateTable<S, W> {
         self.vnodes.clone()
     }
 
+    /// Returns whether the given `pk_prefix` may exist in this state table, by consulting only the
+    /// bloom filters and key ranges of the SSTs without reading any block.
+    ///
     /// Returns:
     /// false: the provided pk prefix is absent in state store.
     /// true: the provided pk prefix may or may not be present in state store.
-    pub async fn may_exist(&self, pk_prefix: impl Row) -> StreamExecutorResult<bool> {
+    pub async fn prefix_may_exist(&self, pk_prefix: impl Row) -> StreamExecutorResult<bool> {
         let prefix_serializer = self.pk_serde.prefix(pk_prefix.len());
         let encoded_prefix = serialize_pk(&pk_prefix, &prefix_serializer);
         let encoded_key_range = range_of_prefix(&encoded_prefix);
@@ -963,9 +1125,11 @@ impl<S: StateStore, W: WatermarkBufferStrategy> StateTable<S, W> {
         let read_options = ReadOptions {
             prefix_hint,
             ignore_range_tombstone: false,
+            limit: None,
             retention_seconds: None,
             table_id: self.table_id,
             read_version_from_backup: false,
+            cache_policy: CachePolicy::HighPriority,
         };
 
         self.local_store
@@ -981,15 +1145,15 @@ pub type RowStreamWithPk<'a, S: StateStore> =
 
 fn deserialize_row_stream(
     stream: impl StateStoreIterItemStream,
-    deserializer: RowDeserializer,
+    deserializer: EitherSerde,
 ) -> impl Stream<Item = StreamExecutorResult<(Bytes, OwnedRow)>> {
     stream.map(move |result| {
         result
             .map_err(StreamExecutorError::from)
             .and_then(|(key, value)| {
                 Ok(deserializer
-                    .deserialize(value)
-                    .map(move |row| (key.user_key.table_key.0, row))?)
+                    .deserialize(&value)
+                    .map(move |row| (key.user_key.table_key.0, OwnedRow::new(row)))?)
             })
     })
 }
@@ -1258,11 +1258,11 @@ async fn check_may_exist<S>(
 {
     for prefix in existent_prefix {
         let pk_prefix = OwnedRow::new(vec![Some(prefix.into())]);
-        assert!(state_table.may_exist(&pk_prefix).await.unwrap());
+        assert!(state_table.prefix_may_exist(&pk_prefix).await.unwrap());
     }
     for prefix in non_existent_prefix {
         let pk_prefix = OwnedRow::new(vec![Some(prefix.into())]);
-        assert!(!state_table.may_exist(&pk_prefix).await.unwrap());
+        assert!(!state_table.prefix_may_exist(&pk_prefix).await.unwrap());
     }
 }
 
@@ -56,6 +56,10 @@ pub struct MaterializedInputState<S: StateStore> {
     /// The columns to order by in state table, group key skipped.
     state_table_order_col_indices: Vec<usize>,
 
+    /// All state table columns read by this state, i.e. the union of the argument and order
+    /// columns. Decoding of the other columns is skipped when syncing the cache.
+    state_table_used_col_indices: Vec<usize>,
+
     /// Cache of state table.
     cache: Box<dyn StateCache>,
 
@@ -117,6 +121,14 @@ impl<S: StateStore> MaterializedInputState<S> {
             })
             .collect_vec();
 
+        let state_table_used_col_indices = state_table_order_col_indices
+            .iter()
+            .chain(state_table_arg_col_indices.iter())
+            .copied()
+            .sorted()
+            .dedup()
+            .collect_vec();
+
         let cache_key_data_types = order_col_indices
             .iter()
             .map(|i| input_schema[*i].data_type())
@@ -140,6 +152,7 @@ impl<S: StateStore> MaterializedInputState<S> {
             state_table_arg_col_indices,
             order_col_indices,
             state_table_order_col_indices,
+            state_table_used_col_indices,
             cache,
             cache_key_serializer,
             _phantom_data: PhantomData,
@@ -171,10 +184,20 @@ impl<S: StateStore> MaterializedInputState<S> {
         group_key: Option<&OwnedRow>,
     ) -> StreamExecutorResult<Datum> {
         if !self.cache.is_synced() {
-            let all_data_iter = state_table.iter_with_pk_prefix(&group_key).await?;
-            pin_mut!(all_data_iter);
-
             let mut cache_filler = self.cache.begin_syncing();
+            // An unlimited cache consumes the whole range, so there is no limit to hint.
+            let limit = match cache_filler.capacity() {
+                usize::MAX => None,
+                capacity => Some(capacity),
+            };
+            let all_data_iter = state_table
+                .iter_with_pk_prefix_projected(
+                    &group_key,
+                    &self.state_table_used_col_indices,
+                    limit,
+                )
+                .await?;
+            pin_mut!(all_data_iter);
             #[for_await]
             for state_row in all_data_iter.take(cache_filler.capacity()) {
                 let state_row: OwnedRow = state_row?;
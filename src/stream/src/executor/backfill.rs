@@ -13,17 +13,19 @@
 // limitations under the License.
 
 use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::ops::Bound;
 
 use async_stack_trace::StackTrace;
 use either::Either;
 use futures::stream::select_with_strategy;
-use futures::{pin_mut, stream, StreamExt, TryStreamExt};
+use futures::{pin_mut, stream, StreamExt};
 use futures_async_stream::try_stream;
 use risingwave_common::array::{Op, StreamChunk};
 use risingwave_common::buffer::BitmapBuilder;
 use risingwave_common::catalog::Schema;
-use risingwave_common::row::{self, OwnedRow, Row, RowExt};
+use risingwave_common::hash::VirtualNode;
+use risingwave_common::row::{OwnedRow, Row, RowExt};
 use risingwave_common::util::iter_util::ZipEqFast;
 use risingwave_common::util::sort_util::OrderType;
 use risingwave_hummock_sdk::HummockReadEpoch;
@@ -45,12 +47,12 @@ use crate::task::{ActorId, CreateMviewProgress};
 /// It uses the latest epoch to read the snapshot of the upstream mv during two barriers and all the
 /// `StreamChunk` of the snapshot read will forward to the downstream.
 ///
-/// It uses `current_pos` to record the progress of the backfill (the pk of the upstream mv) and
-/// `current_pos` is initiated as an empty `Row`.
+/// It keeps a backfill position (the pk of the upstream mv) for each vnode owned by this executor,
+/// and reads the snapshots of at most [`SNAPSHOT_READ_PARALLELISM`] vnodes concurrently.
 ///
 /// All upstream messages during the two barriers interval will be buffered and decide to forward or
-/// ignore based on the `current_pos` at the end of the later barrier. Once `current_pos` reaches
-/// the end of the upstream mv pk, the backfill would finish.
+/// ignore based on the position of the vnode each row belongs to, at the end of the later barrier.
+/// Once the snapshots of all vnodes are exhausted, the backfill would finish.
 ///
 /// Notice:
 /// The pk we are talking about here refers to the storage primary key.
@@ -75,6 +77,20 @@ pub struct BackfillExecutor<S: StateStore> {
 
 const CHUNK_SIZE: usize = 1024;
 
+/// The maximum number of vnode snapshots to be read concurrently.
+const SNAPSHOT_READ_PARALLELISM: usize = 4;
+
+/// Backfill progress of one vnode owned by this executor.
+#[derive(Clone, Debug)]
+enum VnodeBackfillState {
+    /// No row of this vnode has been read from the snapshot yet.
+    NotStarted,
+    /// The rows with pk no greater than the position have been forwarded to the downstream.
+    InProgress(OwnedRow),
+    /// The snapshot of this vnode has been exhausted.
+    Completed,
+}
+
 impl<S> BackfillExecutor<S>
 where
     S: StateStore,
@@ -119,10 +135,15 @@ where
         // directly.
         let to_create_mv = first_barrier.is_add_dispatcher(self.actor_id);
         // If the snapshot is empty, we don't need to backfill.
+        // We use uncommitted read here, because we have already scheduled the `BackfillExecutor`
+        // together with the upstream mv.
         let is_snapshot_empty: bool = {
-            let snapshot = Self::snapshot_read(&self.table, init_epoch, None, false);
-            pin_mut!(snapshot);
-            snapshot.try_next().await?.unwrap().is_none()
+            let iter = self
+                .table
+                .batch_iter(HummockReadEpoch::NoWait(init_epoch), false)
+                .await?;
+            pin_mut!(iter);
+            iter.next_row().await?.is_none()
         };
         let to_backfill = to_create_mv && !is_snapshot_empty;
 
@@ -149,9 +170,14 @@ where
         // The epoch used to snapshot read upstream mv.
         let mut snapshot_read_epoch = init_epoch;
 
-        // Current position of the table storage primary key.
-        // `None` means it starts from the beginning.
-        let mut current_pos: Option<OwnedRow> = None;
+        // Current backfill position of each vnode owned by this executor, on the table storage
+        // primary key.
+        let mut states: BTreeMap<VirtualNode, VnodeBackfillState> = self
+            .table
+            .vnodes()
+            .iter_vnodes()
+            .map(|vnode| (vnode, VnodeBackfillState::NotStarted))
+            .collect();
 
         // Keep track of rows from the upstream and snapshot.
         let mut processed_rows: u64 = 0;
@@ -163,19 +189,21 @@ where
         // upstream       snapshot
         //
         // We construct a backfill stream with upstream as its left input and mv snapshot read
-        // stream as its right input. When a chunk comes from upstream, we will buffer it.
+        // stream as its right input. The snapshot is read separately for each owned vnode, with
+        // at most `SNAPSHOT_READ_PARALLELISM` vnodes scanned concurrently. When a chunk comes
+        // from upstream, we will buffer it.
         //
         // When a barrier comes from upstream:
         //  - Update the `snapshot_read_epoch`.
-        //  - For each row of the upstream chunk buffer, forward it to downstream if its pk <=
-        //    `current_pos`, otherwise ignore it.
-        //  - reconstruct the whole backfill stream with upstream and new mv snapshot read stream
+        //  - For each row of the upstream chunk buffer, forward it to downstream if its pk <= the
+        //    position of its vnode, otherwise ignore it.
+        //  - reconstruct the whole backfill stream with upstream and new mv snapshot read streams
         //    with the `snapshot_read_epoch`.
         //
-        // When a chunk comes from snapshot, we forward it to the downstream and raise
-        // `current_pos`.
+        // When a chunk comes from snapshot, we forward it to the downstream and raise the
+        // position of the vnode it belongs to.
         //
-        // When we reach the end of the snapshot read stream, it means backfill has been
+        // When the snapshot read streams of all vnodes reach the end, it means backfill has been
         // finished.
         //
         // Once the backfill loop ends, we forward the upstream directly to the downstream.
@@ -184,8 +212,24 @@ where
 
             let left_upstream = upstream.by_ref().map(Either::Left);
 
+            // Construct a snapshot read stream for each unfinished vnode, resuming from its
+            // current position.
+            let vnode_snapshots = states
+                .iter()
+                .filter(|(_, state)| !matches!(state, VnodeBackfillState::Completed))
+                .map(|(&vnode, state)| {
+                    let current_pos = match state {
+                        VnodeBackfillState::NotStarted => None,
+                        VnodeBackfillState::InProgress(current_pos) => Some(current_pos.clone()),
+                        VnodeBackfillState::Completed => unreachable!(),
+                    };
+                    Self::snapshot_read(&self.table, snapshot_read_epoch, vnode, current_pos)
+                        .boxed()
+                })
+                .collect::<Vec<_>>();
             let right_snapshot = Box::pin(
-                Self::snapshot_read(&self.table, snapshot_read_epoch, current_pos.clone(), true)
+                stream::iter(vnode_snapshots)
+                    .flatten_unordered(SNAPSHOT_READ_PARALLELISM)
                     .map(Either::Right),
             );
 
@@ -207,17 +251,10 @@ where
 
                                 // Consume upstream buffer chunk
                                 for chunk in upstream_chunk_buffer.drain(..) {
-                                    if let Some(current_pos) = &current_pos {
-                                        yield Message::Chunk(Self::mapping_chunk(
-                                            Self::mark_chunk(
-                                                chunk,
-                                                current_pos,
-                                                table_pk_indices,
-                                                pk_order,
-                                            ),
-                                            &upstream_indices,
-                                        ));
-                                    }
+                                    yield Message::Chunk(Self::mapping_chunk(
+                                        Self::mark_chunk(chunk, &self.table, &states, pk_order),
+                                        &upstream_indices,
+                                    ));
                                 }
 
                                 // Update snapshot read epoch.
@@ -243,37 +280,44 @@ where
                         }
                     }
                     Either::Right(msg) => {
-                        match msg? {
+                        let (vnode, chunk) = msg?;
+                        match chunk {
                             None => {
-                                // End of the snapshot read stream.
-                                // We need to set current_pos to the maximum value or do not
-                                // mark the chunk anymore, otherwise, we will ignore some rows
-                                // in the buffer. Here we choose to never mark the chunk.
-                                // Consume with the renaming stream buffer chunk without mark.
-                                for chunk in upstream_chunk_buffer.drain(..) {
-                                    processed_rows += chunk.cardinality() as u64;
-                                    yield Message::Chunk(Self::mapping_chunk(
-                                        chunk,
-                                        &upstream_indices,
-                                    ));
-                                }
+                                // End of the snapshot read stream of this vnode. Note that the
+                                // rows of a completed vnode are always forwarded when marking the
+                                // buffer, so there's no position to maintain anymore.
+                                states.insert(vnode, VnodeBackfillState::Completed);
+
+                                if states
+                                    .values()
+                                    .all(|state| matches!(state, VnodeBackfillState::Completed))
+                                {
+                                    // All vnodes have been exhausted.
+                                    // Consume the remaining stream buffer chunk without mark.
+                                    for chunk in upstream_chunk_buffer.drain(..) {
+                                        processed_rows += chunk.cardinality() as u64;
+                                        yield Message::Chunk(Self::mapping_chunk(
+                                            chunk,
+                                            &upstream_indices,
+                                        ));
+                                    }
 
-                                // Finish backfill.
-                                break 'backfill_loop;
+                                    // Finish backfill.
+                                    break 'backfill_loop;
+                                }
                             }
                             Some(chunk) => {
-                                // Raise the current position.
+                                // Raise the current position of the vnode.
                                 // As snapshot read streams are ordered by pk, so we can
-                                // just use the last row to update `current_pos`.
-                                current_pos = Some(
-                                    chunk
-                                        .rows()
-                                        .last()
-                                        .unwrap()
-                                        .1
-                                        .project(table_pk_indices)
-                                        .into_owned_row(),
-                                );
+                                // just use the last row to update the position.
+                                let current_pos = chunk
+                                    .rows()
+                                    .last()
+                                    .unwrap()
+                                    .1
+                                    .project(table_pk_indices)
+                                    .into_owned_row();
+                                states.insert(vnode, VnodeBackfillState::InProgress(current_pos));
                                 processed_rows += chunk.cardinality() as u64;
                                 yield Message::Chunk(Self::mapping_chunk(chunk, &upstream_indices));
                             }
@@ -301,13 +345,16 @@ where
         }
     }
 
+    /// Read the snapshot of the given `vnode` of the upstream table, resuming from (exclusive)
+    /// `current_pos` if set. The vnode is yielded along with each chunk, and a `None` chunk
+    /// indicates that the snapshot of this vnode has been exhausted.
     #[expect(clippy::needless_lifetimes, reason = "code generated by try_stream")]
-    #[try_stream(ok = Option<StreamChunk>, error = StreamExecutorError)]
+    #[try_stream(ok = (VirtualNode, Option<StreamChunk>), error = StreamExecutorError)]
     async fn snapshot_read(
         table: &StorageTable<S>,
         epoch: u64,
+        vnode: VirtualNode,
         current_pos: Option<OwnedRow>,
-        ordered: bool,
     ) {
         // `current_pos` is None means it needs to scan from the beginning, so we use Unbounded to
         // scan. Otherwise, use Excluded.
@@ -317,7 +364,7 @@ where
             // `Excluded(empty_row)` range bound, so we can simply return `None`.
             if current_pos.is_empty() {
                 assert!(table.pk_indices().is_empty());
-                yield None;
+                yield (vnode, None);
                 return Ok(());
             }
 
@@ -328,12 +375,7 @@ where
         // We use uncommitted read here, because we have already scheduled the `BackfillExecutor`
         // together with the upstream mv.
         let iter = table
-            .batch_iter_with_pk_bounds(
-                HummockReadEpoch::NoWait(epoch),
-                row::empty(),
-                range_bounds,
-                ordered,
-            )
+            .batch_iter_vnode_with_pk_bounds(HummockReadEpoch::NoWait(epoch), vnode, range_bounds)
             .await?;
 
         pin_mut!(iter);
@@ -346,20 +388,21 @@ where
             if data_chunk.cardinality() != 0 {
                 let ops = vec![Op::Insert; data_chunk.capacity()];
                 let stream_chunk = StreamChunk::from_parts(ops, data_chunk);
-                yield Some(stream_chunk);
+                yield (vnode, Some(stream_chunk));
             }
         }
 
-        yield None;
+        yield (vnode, None);
     }
 
     /// Mark chunk:
-    /// For each row of the chunk, forward it to downstream if its pk <= `current_pos`, otherwise
-    /// ignore it. We implement it by changing the visibility bitmap.
+    /// For each row of the chunk, forward it to downstream if its pk <= the backfill position of
+    /// the vnode it belongs to, otherwise ignore it. We implement it by changing the visibility
+    /// bitmap.
     fn mark_chunk(
         chunk: StreamChunk,
-        current_pos: &OwnedRow,
-        table_pk_indices: PkIndicesRef<'_>,
+        table: &StorageTable<S>,
+        states: &BTreeMap<VirtualNode, VnodeBackfillState>,
         pk_order: &[OrderType],
     ) -> StreamChunk {
         let chunk = chunk.compact();
@@ -367,16 +410,20 @@ where
         let mut new_visibility = BitmapBuilder::with_capacity(ops.len());
         // Use project to avoid allocation.
         for v in data.rows().map(|row| {
-            match row
-                .project(table_pk_indices)
-                .iter()
-                .zip_eq_fast(pk_order.iter())
-                .cmp_by(current_pos.iter(), |(x, order), y| match order {
-                    OrderType::Ascending => x.cmp(&y),
-                    OrderType::Descending => y.cmp(&x),
-                }) {
-                Ordering::Less | Ordering::Equal => true,
-                Ordering::Greater => false,
+            let pk = row.project(table.pk_indices());
+            match &states[&table.compute_vnode_by_pk(&pk)] {
+                VnodeBackfillState::NotStarted => false,
+                VnodeBackfillState::InProgress(current_pos) => match pk
+                    .iter()
+                    .zip_eq_fast(pk_order.iter())
+                    .cmp_by(current_pos.iter(), |(x, order), y| match order {
+                        OrderType::Ascending => x.cmp(&y),
+                        OrderType::Descending => y.cmp(&x),
+                    }) {
+                    Ordering::Less | Ordering::Equal => true,
+                    Ordering::Greater => false,
+                },
+                VnodeBackfillState::Completed => true,
             }
         }) {
             new_visibility.append(v);
@@ -426,7 +473,7 @@ where
         &self.info.schema
     }
 
-    fn pk_indices(&self) -> super::PkIndicesRef<'_> {
+    fn pk_indices(&self) -> PkIndicesRef<'_> {
         &self.info.pk_indices
     }
 
@@ -267,7 +267,7 @@ impl StreamConsumer for MockConsumer {
             while let Some(item) = input.next().await {
                 match item? {
                     Message::Watermark(_) => {
-                        todo!("https://github.com/risingwavelabs/risingwave/issues/6042")
+                        // Watermarks are not checked by this consumer.
                     }
                     Message::Chunk(chunk) => data.lock().unwrap().push(chunk),
                     Message::Barrier(barrier) => yield barrier,
@@ -98,7 +98,8 @@ pub async fn poll_until_barrier(stream: impl MessageStream, expected_barrier: Ba
     for item in stream {
         match item? {
             Message::Watermark(_) => {
-                todo!("https://github.com/risingwavelabs/risingwave/issues/6042")
+                // Lookup provides no watermark guarantees on its output, so watermarks from
+                // either side are simply dropped, which is always sound.
             }
             c @ Message::Chunk(_) => yield c,
             Message::Barrier(b) => {
@@ -152,11 +153,9 @@ pub async fn align_barrier(left: impl MessageStream, right: impl MessageStream)
                     yield Either::Right(Message::Barrier(b.clone()));
                     break 'inner (SideStatus::RightBarrier, b);
                 }
-                Some(Either::Right(Ok(Message::Watermark(_)))) => {
-                    todo!("https://github.com/risingwavelabs/risingwave/issues/6042")
-                }
-                Some(Either::Left(Ok(Message::Watermark(_)))) => {
-                    todo!("https://github.com/risingwavelabs/risingwave/issues/6042")
+                Some(Either::Right(Ok(Message::Watermark(_))))
+                | Some(Either::Left(Ok(Message::Watermark(_)))) => {
+                    // Watermarks are dropped, see `poll_until_barrier`.
                 }
                 Some(Either::Left(Err(e))) | Some(Either::Right(Err(e))) => return Err(e),
                 None => {
@@ -234,11 +233,8 @@ pub async fn stream_lookup_arrange_prev_epoch(
                         break;
                     }
                 }
-                Either::Left(Message::Watermark(_)) => {
-                    todo!("https://github.com/risingwavelabs/risingwave/issues/6042")
-                }
-                Either::Right(Message::Watermark(_)) => {
-                    todo!("https://github.com/risingwavelabs/risingwave/issues/6042")
+                Either::Left(Message::Watermark(_)) | Either::Right(Message::Watermark(_)) => {
+                    // Watermarks are dropped, see `poll_until_barrier`.
                 }
             }
         }
@@ -250,7 +246,7 @@ pub async fn stream_lookup_arrange_prev_epoch(
                 .expect("unexpected close of barrier aligner")?
             {
                 Either::Left(Message::Watermark(_)) => {
-                    todo!("https://github.com/risingwavelabs/risingwave/issues/6042")
+                    // Watermarks are dropped, see `poll_until_barrier`.
                 }
                 Either::Left(Message::Chunk(msg)) => yield ArrangeMessage::Stream(msg),
                 Either::Left(Message::Barrier(b)) => {
@@ -318,11 +314,8 @@ pub async fn stream_lookup_arrange_this_epoch(
                     }
                     break 'inner Status::ArrangeReady;
                 }
-                Either::Left(Message::Watermark(_)) => {
-                    todo!("https://github.com/risingwavelabs/risingwave/issues/6042")
-                }
-                Either::Right(Message::Watermark(_)) => {
-                    todo!("https://github.com/risingwavelabs/risingwave/issues/6042")
+                Either::Left(Message::Watermark(_)) | Either::Right(Message::Watermark(_)) => {
+                    // Watermarks are dropped, see `poll_until_barrier`.
                 }
             }
         };
@@ -340,11 +333,9 @@ pub async fn stream_lookup_arrange_this_epoch(
                         yield ArrangeMessage::Barrier(b);
                         break;
                     }
-                    Either::Left(Message::Watermark(_)) => {
-                        todo!("https://github.com/risingwavelabs/risingwave/issues/6042")
-                    }
-                    Either::Right(Message::Watermark(_)) => {
-                        todo!("https://github.com/risingwavelabs/risingwave/issues/6042")
+                    Either::Left(Message::Watermark(_))
+                    | Either::Right(Message::Watermark(_)) => {
+                        // Watermarks are dropped, see `poll_until_barrier`.
                     }
                     Either::Right(_) => unreachable!(),
                 }
@@ -373,7 +364,7 @@ pub async fn stream_lookup_arrange_this_epoch(
                         break;
                     }
                     Either::Right(Message::Watermark(_)) => {
-                        todo!("https://github.com/risingwavelabs/risingwave/issues/6042")
+                        // Watermarks are dropped, see `poll_until_barrier`.
                     }
                 }
             },
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use async_trait::async_trait;
 use futures::channel::mpsc;
 use futures::future::{join_all, select, Either};
@@ -21,6 +23,7 @@ use itertools::Itertools;
 use risingwave_common::catalog::Schema;
 
 use super::error::StreamExecutorError;
+use super::watermark::BufferedWatermarks;
 use super::*;
 use crate::executor::{BoxedMessageStream, ExecutorInfo};
 
@@ -99,11 +102,15 @@ impl LookupUnionExecutor {
         }
         // This future is used to drive all inputs.
         let mut drive_inputs = join_all(futures).fuse();
+        // Watermarks of each column are combined into the minimum of all inputs, like in the
+        // `UnionExecutor`.
+        let input_num = rxs.len();
+        let mut buffered_watermarks: BTreeMap<usize, BufferedWatermarks<usize>> = BTreeMap::new();
         let mut end = false;
         while !end {
             end = true; // no message on this turn?
             let mut this_barrier: Option<Barrier> = None;
-            for rx in &mut rxs {
+            for (input_id, rx) in rxs.iter_mut().enumerate() {
                 loop {
                     let msg = match select(rx.next(), &mut drive_inputs).await {
                         Either::Left((Some(msg), _)) => msg?,
@@ -112,8 +119,14 @@ impl LookupUnionExecutor {
                     };
                     end = false;
                     match msg {
-                        Message::Watermark(_) => {
-                            todo!("https://github.com/risingwavelabs/risingwave/issues/6042")
+                        Message::Watermark(watermark) => {
+                            let buffers = buffered_watermarks
+                                .entry(watermark.col_idx)
+                                .or_insert_with(|| BufferedWatermarks::with_ids(0..input_num));
+                            if let Some(watermark) = buffers.handle_watermark(input_id, watermark)
+                            {
+                                yield Message::Watermark(watermark);
+                            }
                         }
 
                         msg @ Message::Chunk(_) => yield msg,
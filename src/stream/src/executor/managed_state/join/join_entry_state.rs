@@ -69,6 +69,10 @@ impl JoinEntryState {
     pub fn len(&self) -> usize {
         self.cached.len()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.cached.is_empty()
+    }
 }
 
 #[cfg(test)]
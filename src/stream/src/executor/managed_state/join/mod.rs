@@ -161,6 +161,9 @@ pub struct JoinHashMapMetrics {
     /// How many times have we miss the cache when insert row
     insert_cache_miss_count: usize,
     may_exist_true_count: usize,
+    /// How many times a positive `prefix_may_exist` was verified to be a false positive by the
+    /// following probe
+    may_exist_false_positive_count: usize,
 }
 
 impl JoinHashMapMetrics {
@@ -173,6 +176,7 @@ impl JoinHashMapMetrics {
             total_lookup_count: 0,
             insert_cache_miss_count: 0,
             may_exist_true_count: 0,
+            may_exist_false_positive_count: 0,
         }
     }
 
@@ -193,10 +197,15 @@ impl JoinHashMapMetrics {
             .join_may_exist_true_count
             .with_label_values(&[&self.actor_id, self.side])
             .inc_by(self.may_exist_true_count as u64);
+        self.metrics
+            .join_may_exist_false_positive_count
+            .with_label_values(&[&self.actor_id, self.side])
+            .inc_by(self.may_exist_false_positive_count as u64);
         self.total_lookup_count = 0;
         self.lookup_miss_count = 0;
         self.insert_cache_miss_count = 0;
         self.may_exist_true_count = 0;
+        self.may_exist_false_positive_count = 0;
     }
 }
 
@@ -358,11 +367,17 @@ impl<K: HashKey, S: StateStore> JoinHashMap<K, S> {
 
     /// Fetch cache from the state store. Should only be called if the key does not exist in memory.
     /// Will return a empty `JoinEntryState` even when state does not exist in remote.
-    async fn fetch_cached_state(&self, key: &K) -> StreamExecutorResult<JoinEntryState> {
+    async fn fetch_cached_state(&mut self, key: &K) -> StreamExecutorResult<JoinEntryState> {
         let key = key.deserialize(&self.join_key_data_types)?;
 
         let mut entry_state = JoinEntryState::default();
 
+        // Check the bloom filters and SST key ranges first, so that join keys guaranteed to be
+        // absent in storage do not pay for a real probe.
+        if !self.state.table.prefix_may_exist(&key).await? {
+            return Ok(entry_state);
+        }
+
         if self.need_degree_table {
             let table_iter_fut = self.state.table.iter_key_and_val(&key);
             let degree_table_iter_fut = self.degree_state.table.iter_key_and_val(&key);
@@ -404,6 +419,12 @@ impl<K: HashKey, S: StateStore> JoinHashMap<K, S> {
             }
         };
 
+        if entry_state.is_empty() {
+            // `prefix_may_exist` returned true but the probe found nothing, i.e. a bloom filter
+            // false positive.
+            self.metrics.may_exist_false_positive_count += 1;
+        }
+
         Ok(entry_state)
     }
 
@@ -433,7 +454,7 @@ impl<K: HashKey, S: StateStore> JoinHashMap<K, S> {
             let prefix = key.deserialize(&self.join_key_data_types)?;
             self.metrics.insert_cache_miss_count += 1;
             // Refill cache when the join key exists in neither cache or storage.
-            if !self.state.table.may_exist(&prefix).await? {
+            if !self.state.table.prefix_may_exist(&prefix).await? {
                 let mut state = JoinEntryState::default();
                 state.insert(pk, value.encode());
                 self.update_state(key, state.into());
@@ -469,7 +490,7 @@ impl<K: HashKey, S: StateStore> JoinHashMap<K, S> {
             let prefix = key.deserialize(&self.join_key_data_types)?;
             self.metrics.insert_cache_miss_count += 1;
             // Refill cache when the join key exists in neither cache or storage.
-            if !self.state.table.may_exist(&prefix).await? {
+            if !self.state.table.prefix_may_exist(&prefix).await? {
                 let mut state = JoinEntryState::default();
                 state.insert(pk, join_row.encode());
                 self.update_state(key, state.into());
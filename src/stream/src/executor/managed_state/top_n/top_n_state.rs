@@ -155,7 +155,17 @@ impl<S: StateStore> ManagedTopNState<S> {
         assert!(topn_cache.middle.is_empty());
         assert!(topn_cache.high.is_empty());
 
-        let state_table_iter = self.state_table.iter_with_pk_prefix(&group_key).await?;
+        // The cache caps how many rows this scan consumes, so hint the storage layer not to read
+        // ahead any further. `WITH TIES` may look at a few more rows, which are then fetched on
+        // demand.
+        let limit = topn_cache
+            .offset
+            .saturating_add(topn_cache.limit)
+            .saturating_add(topn_cache.high_capacity);
+        let state_table_iter = self
+            .state_table
+            .iter_with_pk_prefix_and_limit(&group_key, limit)
+            .await?;
         pin_mut!(state_table_iter);
         if topn_cache.offset > 0 {
             while let Some(item) = state_table_iter.next().await {
@@ -87,6 +87,7 @@ mod sort_buffer;
 pub mod source;
 mod stream_reader;
 pub mod subtask;
+mod table_sink;
 mod top_n;
 mod union;
 mod watermark;
@@ -128,6 +129,7 @@ use simple::{SimpleExecutor, SimpleExecutorWrapper};
 pub use sink::SinkExecutor;
 pub use sort::SortExecutor;
 pub use source::*;
+pub use table_sink::TableSinkExecutor;
 pub use top_n::{
     AppendOnlyGroupTopNExecutor, AppendOnlyTopNExecutor, GroupTopNExecutor, TopNExecutor,
 };
@@ -280,6 +282,19 @@ impl Barrier {
             .map_or(false, |actors| actors.contains(&actor_id))
     }
 
+    /// Whether this barrier is to stop the actor with `actor_id` because the streaming job it
+    /// belongs to is dropped or cancelled, i.e. its state will never be accessed again.
+    ///
+    /// Note that this is different from [`Self::is_stop_or_update_drop_actor`]: actors dropped by
+    /// an `Update` mutation are rescheduled and their state will be read by the actors created
+    /// elsewhere, so it must not be cleaned up.
+    pub fn is_drop_job_actor(&self, actor_id: ActorId) -> bool {
+        matches!(
+            self.mutation.as_deref(),
+            Some(Mutation::Stop(actors)) if actors.contains(&actor_id)
+        )
+    }
+
     /// Get all actors that to be stopped (dropped) by this barrier.
     pub fn all_stop_actors(&self) -> Option<&HashSet<ActorId>> {
         match self.mutation.as_deref() {
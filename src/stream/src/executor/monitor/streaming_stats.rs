@@ -54,6 +54,7 @@ pub struct StreamingMetrics {
     pub join_total_lookup_count: GenericCounterVec<AtomicU64>,
     pub join_insert_cache_miss_count: GenericCounterVec<AtomicU64>,
     pub join_may_exist_true_count: GenericCounterVec<AtomicU64>,
+    pub join_may_exist_false_positive_count: GenericCounterVec<AtomicU64>,
     pub join_actor_input_waiting_duration_ns: GenericCounterVec<AtomicU64>,
     pub join_match_duration_ns: GenericCounterVec<AtomicU64>,
     pub join_barrier_align_duration: HistogramVec,
@@ -68,6 +69,15 @@ pub struct StreamingMetrics {
     pub agg_chunk_lookup_miss_count: GenericCounterVec<AtomicU64>,
     pub agg_chunk_total_lookup_count: GenericCounterVec<AtomicU64>,
 
+    /// Rows dropped by watermark filters because they arrived after the current watermark.
+    pub watermark_filter_late_row_count: GenericCounterVec<AtomicU64>,
+
+    // Materialize
+    /// The lag in milliseconds between the wall-clock time and the time covered by the epoch of
+    /// the last barrier processed by each materialize executor, i.e. how fresh the materialized
+    /// view is.
+    pub materialize_freshness_lag_ms: GenericGaugeVec<AtomicI64>,
+
     /// The duration from receipt of barrier to all actors collection.
     /// And the max of all node `barrier_inflight_latency` is the latency for a barrier
     /// to flow through the graph.
@@ -305,6 +315,15 @@ impl StreamingMetrics {
         )
         .unwrap();
 
+        let join_may_exist_false_positive_count = register_int_counter_vec_with_registry!(
+            "stream_join_may_exist_false_positive_count",
+            "Count of prefix_may_exist's true returns that are verified to be false positives by \
+             the following probe in join executor",
+            &["actor_id", "side"],
+            registry
+        )
+        .unwrap();
+
         let join_actor_input_waiting_duration_ns = register_int_counter_vec_with_registry!(
             "stream_join_actor_input_waiting_duration_ns",
             "Total waiting duration (ns) of input buffer of join actor",
@@ -394,6 +413,22 @@ impl StreamingMetrics {
         )
         .unwrap();
 
+        let watermark_filter_late_row_count = register_int_counter_vec_with_registry!(
+            "stream_watermark_filter_late_row_count",
+            "Total number of rows dropped by watermark filters for arriving after the current watermark",
+            &["actor_id"],
+            registry
+        )
+        .unwrap();
+
+        let materialize_freshness_lag_ms = register_int_gauge_vec_with_registry!(
+            "stream_materialize_freshness_lag_ms",
+            "The lag in milliseconds between the wall-clock time and the time covered by the epoch of the last barrier processed by the materialize executor",
+            &["table_id", "actor_id"],
+            registry
+        )
+        .unwrap();
+
         let opts = histogram_opts!(
             "stream_barrier_inflight_duration_seconds",
             "barrier_inflight_latency",
@@ -487,6 +522,7 @@ impl StreamingMetrics {
             join_total_lookup_count,
             join_insert_cache_miss_count,
             join_may_exist_true_count,
+            join_may_exist_false_positive_count,
             join_actor_input_waiting_duration_ns,
             join_match_duration_ns,
             join_barrier_align_duration,
@@ -498,6 +534,8 @@ impl StreamingMetrics {
             agg_cached_keys,
             agg_chunk_lookup_miss_count,
             agg_chunk_total_lookup_count,
+            watermark_filter_late_row_count,
+            materialize_freshness_lag_ms,
             barrier_inflight_latency,
             barrier_sync_latency,
             sink_commit_duration,
@@ -0,0 +1,547 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use futures::StreamExt;
+use futures_async_stream::try_stream;
+use itertools::Itertools;
+use risingwave_common::buffer::Bitmap;
+use risingwave_common::catalog::{ColumnDesc, ColumnId, ConflictBehavior, Schema, TableId};
+use risingwave_common::util::iter_util::ZipEqFast;
+use risingwave_common::util::sort_util::OrderPair;
+use risingwave_pb::catalog::Table;
+use risingwave_storage::StateStore;
+
+use super::materialize::{generate_output, MaterializeBuffer, MaterializeCache};
+use crate::common::table::state_table::StateTable;
+use crate::executor::error::StreamExecutorError;
+use crate::executor::{
+    expect_first_barrier, ActorContext, ActorContextRef, BoxedExecutor, BoxedMessageStream,
+    Executor, ExecutorInfo, Message, PkIndicesRef,
+};
+use crate::task::AtomicU64Ref;
+
+/// `ConflictResolveExecutor` turns a stream that may violate primary-key uniqueness (e.g. from an
+/// upsert source) into a clean changelog: conflicting inserts become updates against the previous
+/// value, or are dropped, depending on the conflict behavior.
+///
+/// It maintains its own state table of the latest value per key to look up the previous values,
+/// so that the downstream `MaterializeExecutor` can run with `ConflictBehavior::NoCheck` and skip
+/// the cache and storage lookups entirely. The planner only places this executor when the input
+/// may actually conflict; clean append-only or PK-preserving pipelines don't pay for it.
+pub struct ConflictResolveExecutor<S: StateStore> {
+    input: BoxedExecutor,
+
+    state_table: StateTable<S>,
+
+    /// Columns of arrange keys (including pk, group keys, join keys, etc.)
+    arrange_columns: Vec<usize>,
+
+    actor_context: ActorContextRef,
+
+    info: ExecutorInfo,
+
+    cache: MaterializeCache,
+    conflict_behavior: ConflictBehavior,
+}
+
+impl<S: StateStore> ConflictResolveExecutor<S> {
+    /// Create a new `ConflictResolveExecutor` with distribution specified with
+    /// `distribution_keys` and `vnodes`. For singleton distribution, `distribution_keys` should
+    /// be empty and `vnodes` should be `None`.
+    ///
+    /// `conflict_behavior` must not be `NoCheck` -- the planner should not have placed this
+    /// executor then.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn new(
+        input: BoxedExecutor,
+        store: S,
+        key: Vec<OrderPair>,
+        executor_id: u64,
+        actor_context: ActorContextRef,
+        vnodes: Option<Arc<Bitmap>>,
+        table_catalog: &Table,
+        watermark_epoch: AtomicU64Ref,
+        conflict_behavior: ConflictBehavior,
+    ) -> Self {
+        assert_ne!(conflict_behavior, ConflictBehavior::NoCheck);
+
+        let arrange_columns: Vec<usize> = key.iter().map(|k| k.column_idx).collect();
+
+        let schema = input.schema().clone();
+
+        let state_table = StateTable::from_table_catalog(table_catalog, store, vnodes).await;
+
+        Self {
+            input,
+            state_table,
+            arrange_columns: arrange_columns.clone(),
+            actor_context,
+            info: ExecutorInfo {
+                schema,
+                pk_indices: arrange_columns,
+                identity: format!("ConflictResolveExecutor {:X}", executor_id),
+            },
+            cache: MaterializeCache::new(watermark_epoch),
+            conflict_behavior,
+        }
+    }
+
+    /// Create a new `ConflictResolveExecutor` without distribution info for test purpose.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn for_test(
+        input: BoxedExecutor,
+        store: S,
+        table_id: TableId,
+        keys: Vec<OrderPair>,
+        column_ids: Vec<ColumnId>,
+        executor_id: u64,
+        watermark_epoch: AtomicU64Ref,
+        conflict_behavior: ConflictBehavior,
+    ) -> Self {
+        assert_ne!(conflict_behavior, ConflictBehavior::NoCheck);
+
+        let arrange_columns: Vec<usize> = keys.iter().map(|k| k.column_idx).collect();
+        let arrange_order_types = keys.iter().map(|k| k.order_type).collect();
+        let schema = input.schema().clone();
+        let columns = column_ids
+            .into_iter()
+            .zip_eq_fast(schema.fields.iter())
+            .map(|(column_id, field)| ColumnDesc::unnamed(column_id, field.data_type()))
+            .collect_vec();
+
+        let state_table = StateTable::new_without_distribution(
+            store,
+            table_id,
+            columns,
+            arrange_order_types,
+            arrange_columns.clone(),
+        )
+        .await;
+
+        Self {
+            input,
+            state_table,
+            arrange_columns: arrange_columns.clone(),
+            actor_context: ActorContext::create(0),
+            info: ExecutorInfo {
+                schema,
+                pk_indices: arrange_columns,
+                identity: format!("ConflictResolveExecutor {:X}", executor_id),
+            },
+            cache: MaterializeCache::new(watermark_epoch),
+            conflict_behavior,
+        }
+    }
+
+    #[try_stream(ok = Message, error = StreamExecutorError)]
+    async fn execute_inner(mut self) {
+        let data_types = self.schema().data_types().clone();
+        let mut input = self.input.execute();
+
+        let barrier = expect_first_barrier(&mut input).await?;
+        self.state_table.init_epoch(barrier.epoch);
+
+        // The first barrier message should be propagated.
+        yield Message::Barrier(barrier);
+
+        #[for_await]
+        for msg in input {
+            let msg = msg?;
+            yield match msg {
+                Message::Watermark(w) => Message::Watermark(w),
+                Message::Chunk(chunk) => {
+                    let buffer = MaterializeBuffer::fill_buffer_from_chunk(
+                        chunk,
+                        self.state_table.value_indices(),
+                        self.state_table.pk_indices(),
+                        self.state_table.pk_serde(),
+                        self.state_table.row_serde(),
+                    );
+
+                    if buffer.is_empty() {
+                        // empty chunk
+                        continue;
+                    }
+
+                    let fixed_changes = self
+                        .cache
+                        .handlle_conflict(buffer, &self.state_table, &self.conflict_behavior)
+                        .await?;
+
+                    if self.state_table.value_indices().is_some() {
+                        panic!(
+                            "conflict resolve executor can not handle only materialize partial columns"
+                        )
+                    }
+
+                    match generate_output(
+                        fixed_changes,
+                        data_types.clone(),
+                        self.state_table.row_serde(),
+                    )? {
+                        Some(output_chunk) => {
+                            self.state_table.write_chunk(output_chunk.clone());
+                            Message::Chunk(output_chunk)
+                        }
+                        None => continue,
+                    }
+                }
+                Message::Barrier(b) => {
+                    if b.is_drop_job_actor(self.actor_context.id) {
+                        // The job is dropped and the state will never be accessed again. Issue
+                        // range-delete tombstones along with the last commit so that the space is
+                        // reclaimed by the next compaction.
+                        self.state_table.commit_and_cleanup(b.epoch).await?;
+                    } else {
+                        self.state_table.commit(b.epoch).await?;
+                    }
+
+                    // Update the vnode bitmap for the state table if asked.
+                    if let Some(vnode_bitmap) = b.as_update_vnode_bitmap(self.actor_context.id) {
+                        let _ = self.state_table.update_vnode_bitmap(vnode_bitmap);
+                    }
+                    self.cache.evict();
+                    Message::Barrier(b)
+                }
+            }
+        }
+    }
+}
+
+impl<S: StateStore> Executor for ConflictResolveExecutor<S> {
+    fn execute(self: Box<Self>) -> BoxedMessageStream {
+        self.execute_inner().boxed()
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.info.schema
+    }
+
+    fn pk_indices(&self) -> PkIndicesRef<'_> {
+        &self.info.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        self.info.identity.as_str()
+    }
+
+    fn info(&self) -> ExecutorInfo {
+        self.info.clone()
+    }
+}
+
+impl<S: StateStore> std::fmt::Debug for ConflictResolveExecutor<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConflictResolveExecutor")
+            .field("input info", &self.info())
+            .field("arrange_columns", &self.arrange_columns)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::sync::atomic::AtomicU64;
+    use std::sync::Arc;
+
+    use futures::stream::StreamExt;
+    use risingwave_common::array::stream_chunk::StreamChunkTestExt;
+    use risingwave_common::array::{Op, StreamChunk};
+    use risingwave_common::catalog::{ColumnDesc, ConflictBehavior, Field, Schema, TableId};
+    use risingwave_common::row::OwnedRow;
+    use risingwave_common::types::DataType;
+    use risingwave_common::util::sort_util::{OrderPair, OrderType};
+    use risingwave_hummock_sdk::HummockReadEpoch;
+    use risingwave_storage::memory::MemoryStateStore;
+    use risingwave_storage::table::batch_table::storage_table::StorageTable;
+
+    use super::*;
+    use crate::executor::test_utils::*;
+    use crate::executor::*;
+
+    #[tokio::test]
+    async fn test_resolve_insert_conflict() {
+        // Prepare storage and memtable.
+        let memory_state_store = MemoryStateStore::new();
+        let table_id = TableId::new(1);
+        // Two columns of int32 type, the first column is PK.
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+        ]);
+        let column_ids = vec![0.into(), 1.into()];
+
+        // Double insert one pk, the latter needs to override the former.
+        let chunk1 = StreamChunk::from_pretty(
+            " i i
+            + 1 3
+            + 1 4
+            + 2 5",
+        );
+        let chunk2 = StreamChunk::from_pretty(
+            " i i
+            + 1 6
+            - 2 5",
+        );
+
+        // Prepare stream executors.
+        let source = MockSource::with_messages(
+            schema.clone(),
+            PkIndices::new(),
+            vec![
+                Message::Barrier(Barrier::new_test_barrier(1)),
+                Message::Chunk(chunk1),
+                Message::Barrier(Barrier::new_test_barrier(2)),
+                Message::Chunk(chunk2),
+                Message::Barrier(Barrier::new_test_barrier(3)),
+            ],
+        );
+
+        let order_types = vec![OrderType::Ascending];
+        let column_descs = vec![
+            ColumnDesc::unnamed(column_ids[0], DataType::Int32),
+            ColumnDesc::unnamed(column_ids[1], DataType::Int32),
+        ];
+
+        let table = StorageTable::for_test(
+            memory_state_store.clone(),
+            table_id,
+            column_descs,
+            order_types,
+            vec![0],
+            vec![0, 1],
+        );
+
+        let mut conflict_resolve_executor = Box::new(
+            ConflictResolveExecutor::for_test(
+                Box::new(source),
+                memory_state_store,
+                table_id,
+                vec![OrderPair::new(0, OrderType::Ascending)],
+                column_ids,
+                1,
+                Arc::new(AtomicU64::new(0)),
+                ConflictBehavior::OverWrite,
+            )
+            .await,
+        )
+        .execute();
+        conflict_resolve_executor
+            .next()
+            .await
+            .transpose()
+            .unwrap();
+
+        // The emitted chunk must be a clean changelog: one insert per key.
+        match conflict_resolve_executor
+            .next()
+            .await
+            .transpose()
+            .unwrap()
+        {
+            Some(Message::Chunk(c)) => {
+                let ops = c.ops();
+                assert_eq!(ops.len(), 2);
+                assert!(ops.iter().all(|op| *op == Op::Insert));
+            }
+            _ => unreachable!(),
+        }
+
+        // First barrier. The state table keeps the resolved values.
+        match conflict_resolve_executor
+            .next()
+            .await
+            .transpose()
+            .unwrap()
+        {
+            Some(Message::Barrier(_)) => {
+                let row = table
+                    .get_row(
+                        &OwnedRow::new(vec![Some(1_i32.into())]),
+                        HummockReadEpoch::NoWait(u64::MAX),
+                    )
+                    .await
+                    .unwrap();
+                assert_eq!(
+                    row,
+                    Some(OwnedRow::new(vec![Some(1_i32.into()), Some(4_i32.into())]))
+                );
+            }
+            _ => unreachable!(),
+        }
+
+        // Second stream chunk. The insert on the existing pk 1 is rewritten into an update
+        // against the previous value.
+        match conflict_resolve_executor
+            .next()
+            .await
+            .transpose()
+            .unwrap()
+        {
+            Some(Message::Chunk(c)) => {
+                let ops = c.ops();
+                assert!(ops.contains(&Op::UpdateDelete));
+                assert!(ops.contains(&Op::UpdateInsert));
+                assert!(ops.contains(&Op::Delete));
+            }
+            _ => unreachable!(),
+        }
+
+        match conflict_resolve_executor
+            .next()
+            .await
+            .transpose()
+            .unwrap()
+        {
+            Some(Message::Barrier(_)) => {
+                let row = table
+                    .get_row(
+                        &OwnedRow::new(vec![Some(1_i32.into())]),
+                        HummockReadEpoch::NoWait(u64::MAX),
+                    )
+                    .await
+                    .unwrap();
+                assert_eq!(
+                    row,
+                    Some(OwnedRow::new(vec![Some(1_i32.into()), Some(6_i32.into())]))
+                );
+                let row = table
+                    .get_row(
+                        &OwnedRow::new(vec![Some(2_i32.into())]),
+                        HummockReadEpoch::NoWait(u64::MAX),
+                    )
+                    .await
+                    .unwrap();
+                assert_eq!(row, None);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_ignore_conflict() {
+        // Prepare storage and memtable.
+        let memory_state_store = MemoryStateStore::new();
+        let table_id = TableId::new(1);
+        // Two columns of int32 type, the first column is PK.
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+        ]);
+        let column_ids = vec![0.into(), 1.into()];
+
+        // Insert the same pk twice, the latter should be ignored.
+        let chunk1 = StreamChunk::from_pretty(
+            " i i
+            + 1 3",
+        );
+        let chunk2 = StreamChunk::from_pretty(
+            " i i
+            + 1 4",
+        );
+
+        let source = MockSource::with_messages(
+            schema.clone(),
+            PkIndices::new(),
+            vec![
+                Message::Barrier(Barrier::new_test_barrier(1)),
+                Message::Chunk(chunk1),
+                Message::Barrier(Barrier::new_test_barrier(2)),
+                Message::Chunk(chunk2),
+                Message::Barrier(Barrier::new_test_barrier(3)),
+            ],
+        );
+
+        let order_types = vec![OrderType::Ascending];
+        let column_descs = vec![
+            ColumnDesc::unnamed(column_ids[0], DataType::Int32),
+            ColumnDesc::unnamed(column_ids[1], DataType::Int32),
+        ];
+
+        let table = StorageTable::for_test(
+            memory_state_store.clone(),
+            table_id,
+            column_descs,
+            order_types,
+            vec![0],
+            vec![0, 1],
+        );
+
+        let mut conflict_resolve_executor = Box::new(
+            ConflictResolveExecutor::for_test(
+                Box::new(source),
+                memory_state_store,
+                table_id,
+                vec![OrderPair::new(0, OrderType::Ascending)],
+                column_ids,
+                1,
+                Arc::new(AtomicU64::new(0)),
+                ConflictBehavior::IgnoreConflict,
+            )
+            .await,
+        )
+        .execute();
+        conflict_resolve_executor
+            .next()
+            .await
+            .transpose()
+            .unwrap();
+
+        // The first insert passes through.
+        match conflict_resolve_executor
+            .next()
+            .await
+            .transpose()
+            .unwrap()
+        {
+            Some(Message::Chunk(c)) => {
+                assert_eq!(c.ops(), vec![Op::Insert]);
+            }
+            _ => unreachable!(),
+        }
+
+        conflict_resolve_executor
+            .next()
+            .await
+            .transpose()
+            .unwrap();
+
+        // The conflicting insert is dropped entirely, so the next message is the barrier and the
+        // state table still holds the first value.
+        match conflict_resolve_executor
+            .next()
+            .await
+            .transpose()
+            .unwrap()
+        {
+            Some(Message::Barrier(_)) => {
+                let row = table
+                    .get_row(
+                        &OwnedRow::new(vec![Some(1_i32.into())]),
+                        HummockReadEpoch::NoWait(u64::MAX),
+                    )
+                    .await
+                    .unwrap();
+                assert_eq!(
+                    row,
+                    Some(OwnedRow::new(vec![Some(1_i32.into()), Some(3_i32.into())]))
+                );
+            }
+            _ => unreachable!(),
+        }
+    }
+}
@@ -16,26 +16,32 @@ use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use anyhow::anyhow;
 use bytes::Bytes;
-use futures::{stream, StreamExt};
+use futures::StreamExt;
+use prometheus::core::{AtomicI64, GenericGauge};
 use futures_async_stream::try_stream;
 use itertools::{izip, Itertools};
 use risingwave_common::array::{Op, StreamChunk, Vis};
 use risingwave_common::buffer::Bitmap;
 use risingwave_common::catalog::{ColumnDesc, ColumnId, ConflictBehavior, Schema, TableId};
-use risingwave_common::row::{CompactedRow, RowDeserializer};
+use risingwave_common::row::{CompactedRow, OwnedRow};
 use risingwave_common::types::DataType;
 use risingwave_common::util::chunk_coalesce::DataChunkBuilder;
+use risingwave_common::util::epoch::Epoch;
 use risingwave_common::util::iter_util::{ZipEqDebug, ZipEqFast};
 use risingwave_common::util::ordered::OrderedRowSerde;
 use risingwave_common::util::sort_util::OrderPair;
+use risingwave_common::util::value_encoding::{ValueRowDeserializer, ValueRowSerializer};
 use risingwave_pb::catalog::Table;
 use risingwave_storage::mem_table::KeyOp;
+use risingwave_storage::row_serde::value_serde::EitherSerde;
 use risingwave_storage::StateStore;
 
 use crate::cache::{new_unbounded, ExecutorCache};
 use crate::common::table::state_table::StateTable;
 use crate::executor::error::StreamExecutorError;
+use crate::executor::monitor::StreamingMetrics;
 use crate::executor::{
     expect_first_barrier, ActorContext, ActorContextRef, BoxedExecutor, BoxedMessageStream,
     Executor, ExecutorInfo, Message, PkIndicesRef, StreamExecutorResult,
@@ -57,6 +63,14 @@ pub struct MaterializeExecutor<S: StateStore> {
 
     materialize_cache: MaterializeCache,
     conflict_behavior: ConflictBehavior,
+
+    /// The freshness lag gauge of this materialized view, labeled with the table id and the actor
+    /// id.
+    freshness_lag_ms: GenericGauge<AtomicI64>,
+
+    /// The freshness SLO threshold in milliseconds. An event is reported through the log when the
+    /// freshness lag of this materialized view exceeds the threshold.
+    freshness_slo_threshold_ms: Option<u64>,
 }
 
 impl<S: StateStore> MaterializeExecutor<S> {
@@ -74,6 +88,8 @@ impl<S: StateStore> MaterializeExecutor<S> {
         table_catalog: &Table,
         watermark_epoch: AtomicU64Ref,
         conflict_behavior: ConflictBehavior,
+        metrics: Arc<StreamingMetrics>,
+        freshness_slo_threshold_ms: Option<u64>,
     ) -> Self {
         let arrange_columns: Vec<usize> = key.iter().map(|k| k.column_idx).collect();
 
@@ -81,6 +97,11 @@ impl<S: StateStore> MaterializeExecutor<S> {
 
         let state_table = StateTable::from_table_catalog(table_catalog, store, vnodes).await;
 
+        let freshness_lag_ms = metrics.materialize_freshness_lag_ms.with_label_values(&[
+            &table_catalog.id.to_string(),
+            &actor_context.id.to_string(),
+        ]);
+
         Self {
             input,
             state_table,
@@ -93,6 +114,8 @@ impl<S: StateStore> MaterializeExecutor<S> {
             },
             materialize_cache: MaterializeCache::new(watermark_epoch),
             conflict_behavior,
+            freshness_lag_ms,
+            freshness_slo_threshold_ms,
         }
     }
 
@@ -138,6 +161,10 @@ impl<S: StateStore> MaterializeExecutor<S> {
             },
             materialize_cache: MaterializeCache::new(watermark_epoch),
             conflict_behavior,
+            freshness_lag_ms: StreamingMetrics::unused()
+                .materialize_freshness_lag_ms
+                .with_label_values(&[&table_id.table_id().to_string(), "0"]),
+            freshness_slo_threshold_ms: None,
         }
     }
 
@@ -159,13 +186,17 @@ impl<S: StateStore> MaterializeExecutor<S> {
                 Message::Watermark(w) => Message::Watermark(w),
                 Message::Chunk(chunk) => {
                     match self.conflict_behavior {
-                        ConflictBehavior::OverWrite | ConflictBehavior::IgnoreConflict => {
+                        ConflictBehavior::OverWrite
+                        | ConflictBehavior::IgnoreConflict
+                        | ConflictBehavior::ReportError
+                        | ConflictBehavior::DoUpdateIfNotNull => {
                             // create MaterializeBuffer from chunk
                             let buffer = MaterializeBuffer::fill_buffer_from_chunk(
                                 chunk,
                                 self.state_table.value_indices(),
                                 self.state_table.pk_indices(),
                                 self.state_table.pk_serde(),
+                                self.state_table.row_serde(),
                             );
 
                             if buffer.is_empty() {
@@ -188,7 +219,11 @@ impl<S: StateStore> MaterializeExecutor<S> {
                                 panic!("materialize executor with data check can not handle only materialize partial columns")
                             }
 
-                            match generate_output(fixed_changes, data_types.clone())? {
+                            match generate_output(
+                                fixed_changes,
+                                data_types.clone(),
+                                self.state_table.row_serde(),
+                            )? {
                                 Some(output_chunk) => {
                                     self.state_table.write_chunk(output_chunk.clone());
                                     Message::Chunk(output_chunk)
@@ -204,7 +239,28 @@ impl<S: StateStore> MaterializeExecutor<S> {
                     }
                 }
                 Message::Barrier(b) => {
-                    self.state_table.commit(b.epoch).await?;
+                    if b.is_drop_job_actor(self.actor_context.id) {
+                        // The job is dropped and the state will never be accessed again. Issue
+                        // range-delete tombstones along with the last commit so that the space is
+                        // reclaimed by the next compaction.
+                        self.state_table.commit_and_cleanup(b.epoch).await?;
+                    } else {
+                        self.state_table.commit(b.epoch).await?;
+                    }
+
+                    // Report the freshness of this materialized view, i.e. the lag between the
+                    // wall-clock time and the time covered by the epoch just committed.
+                    let lag_ms = Epoch::physical_now()
+                        .saturating_sub(Epoch(b.epoch.curr).physical_time());
+                    self.freshness_lag_ms.set(lag_ms as i64);
+                    if let Some(threshold) = self.freshness_slo_threshold_ms && lag_ms > threshold {
+                        tracing::warn!(
+                            "{} violates the freshness SLO: lag {}ms exceeds the threshold {}ms",
+                            self.info.identity,
+                            lag_ms,
+                            threshold
+                        );
+                    }
 
                     // Update the vnode bitmap for the state table if asked.
                     if let Some(vnode_bitmap) = b.as_update_vnode_bitmap(self.actor_context.id) {
@@ -219,15 +275,15 @@ impl<S: StateStore> MaterializeExecutor<S> {
 }
 
 /// Construct output `StreamChunk` from given buffer.
-fn generate_output(
+pub(crate) fn generate_output(
     changes: Vec<(Vec<u8>, KeyOp)>,
     data_types: Vec<DataType>,
+    row_serde: &EitherSerde,
 ) -> StreamExecutorResult<Option<StreamChunk>> {
     // construct output chunk
     // TODO(st1page): when materialize partial columns(), we should construct some columns in the pk
     let mut new_ops: Vec<Op> = vec![];
     let mut new_rows: Vec<Bytes> = vec![];
-    let row_deserializer = RowDeserializer::new(data_types.clone());
     for (_, row_op) in changes {
         match row_op {
             KeyOp::Insert(value) => {
@@ -249,8 +305,8 @@ fn generate_output(
     let mut data_chunk_builder = DataChunkBuilder::new(data_types, new_rows.len() + 1);
 
     for row_bytes in new_rows {
-        let res =
-            data_chunk_builder.append_one_row(row_deserializer.deserialize(row_bytes.as_ref())?);
+        let res = data_chunk_builder
+            .append_one_row(OwnedRow::new(row_serde.deserialize(row_bytes.as_ref())?));
         debug_assert!(res.is_none());
     }
 
@@ -262,6 +318,23 @@ fn generate_output(
     }
 }
 
+/// Merge `new_row` into `old_row` for the `DoUpdateIfNotNull` conflict behavior: the non-null
+/// columns of `new_row` take effect, while its null columns keep the old values.
+fn merge_row_if_not_null(
+    old_row: &Bytes,
+    new_row: &Bytes,
+    row_serde: &EitherSerde,
+) -> StreamExecutorResult<Bytes> {
+    let old_values = row_serde.deserialize(old_row.as_ref())?;
+    let mut new_values = row_serde.deserialize(new_row.as_ref())?;
+    for (new, old) in new_values.iter_mut().zip_eq_fast(old_values) {
+        if new.is_none() {
+            *new = old;
+        }
+    }
+    Ok(row_serde.serialize(OwnedRow::new(new_values)).into())
+}
+
 /// `MaterializeBuffer` is a buffer to handle chunk into `KeyOp`.
 pub struct MaterializeBuffer {
     buffer: HashMap<Vec<u8>, KeyOp>,
@@ -275,11 +348,12 @@ impl MaterializeBuffer {
     }
 
     #[allow(clippy::disallowed_methods)]
-    fn fill_buffer_from_chunk(
+    pub(crate) fn fill_buffer_from_chunk(
         stream_chunk: StreamChunk,
         value_indices: &Option<Vec<usize>>,
         pk_indices: &[usize],
         pk_serde: &OrderedRowSerde,
+        row_serde: &EitherSerde,
     ) -> Self {
         let (data_chunk, ops) = stream_chunk.into_parts();
 
@@ -288,7 +362,7 @@ impl MaterializeBuffer {
         } else {
             data_chunk.clone()
         };
-        let values = value_chunk.serialize();
+        let values = value_chunk.serialize_with(row_serde);
 
         let mut pks = vec![vec![]; data_chunk.capacity()];
         let key_chunk = data_chunk.reorder_columns(pk_indices);
@@ -362,7 +436,7 @@ impl MaterializeBuffer {
         }
     }
 
-    fn is_empty(&self) -> bool {
+    pub(crate) fn is_empty(&self) -> bool {
         self.buffer.is_empty()
     }
 
@@ -430,7 +504,7 @@ impl MaterializeCache {
         for (key, row_op) in buffer.into_parts() {
             let mut update_cache = false;
             match row_op {
-                KeyOp::Insert(new_row) => {
+                KeyOp::Insert(mut new_row) => {
                     match conflict_behavior {
                         ConflictBehavior::OverWrite => {
                             match self.force_get(&key) {
@@ -453,7 +527,38 @@ impl MaterializeCache {
                                 }
                             };
                         }
-                        _ => unreachable!(),
+                        ConflictBehavior::ReportError => {
+                            match self.force_get(&key) {
+                                Some(_) => {
+                                    return Err(anyhow!(
+                                        "primary key conflict detected on insert"
+                                    )
+                                    .into())
+                                }
+                                None => fixed_changes
+                                    .push((key.clone(), KeyOp::Insert(new_row.clone()))),
+                            };
+                            update_cache = true;
+                        }
+                        ConflictBehavior::DoUpdateIfNotNull => {
+                            match self.force_get(&key) {
+                                Some(old_row) => {
+                                    new_row = merge_row_if_not_null(
+                                        &old_row.row,
+                                        &new_row,
+                                        table.row_serde(),
+                                    )?;
+                                    fixed_changes.push((
+                                        key.clone(),
+                                        KeyOp::Update((old_row.row.clone(), new_row.clone())),
+                                    ));
+                                }
+                                None => fixed_changes
+                                    .push((key.clone(), KeyOp::Insert(new_row.clone()))),
+                            };
+                            update_cache = true;
+                        }
+                        ConflictBehavior::NoCheck => unreachable!(),
                     };
 
                     if update_cache {
@@ -462,7 +567,9 @@ impl MaterializeCache {
                 }
                 KeyOp::Delete(_) => {
                     match conflict_behavior {
-                        ConflictBehavior::OverWrite => {
+                        ConflictBehavior::OverWrite
+                        | ConflictBehavior::ReportError
+                        | ConflictBehavior::DoUpdateIfNotNull => {
                             match self.force_get(&key) {
                                 Some(old_row) => {
                                     fixed_changes
@@ -473,16 +580,16 @@ impl MaterializeCache {
                             update_cache = true;
                         }
                         ConflictBehavior::IgnoreConflict => (),
-                        _ => unreachable!(),
+                        ConflictBehavior::NoCheck => unreachable!(),
                     };
 
                     if update_cache {
                         self.put(key, None);
                     }
                 }
-                KeyOp::Update((_, new_row)) => {
+                KeyOp::Update((_, mut new_row)) => {
                     match conflict_behavior {
-                        ConflictBehavior::OverWrite => {
+                        ConflictBehavior::OverWrite | ConflictBehavior::ReportError => {
                             match self.force_get(&key) {
                                 Some(old_row) => fixed_changes.push((
                                     key.clone(),
@@ -503,7 +610,25 @@ impl MaterializeCache {
                                 }
                             };
                         }
-                        _ => unreachable!(),
+                        ConflictBehavior::DoUpdateIfNotNull => {
+                            match self.force_get(&key) {
+                                Some(old_row) => {
+                                    new_row = merge_row_if_not_null(
+                                        &old_row.row,
+                                        &new_row,
+                                        table.row_serde(),
+                                    )?;
+                                    fixed_changes.push((
+                                        key.clone(),
+                                        KeyOp::Update((old_row.row.clone(), new_row.clone())),
+                                    ));
+                                }
+                                None => fixed_changes
+                                    .push((key.clone(), KeyOp::Insert(new_row.clone()))),
+                            };
+                            update_cache = true;
+                        }
+                        ConflictBehavior::NoCheck => unreachable!(),
                     };
 
                     if update_cache {
@@ -520,22 +645,29 @@ impl MaterializeCache {
         keys: impl Iterator<Item = &'a [u8]>,
         table: &StateTable<S>,
     ) -> StreamExecutorResult<()> {
-        let mut futures = vec![];
+        // Check the bloom filter first, so that keys that are guaranteed to be absent do not
+        // reach storage at all, and fetch the remaining keys with a single vectored get.
+        let mut fetch_keys = vec![];
+        let mut fetch_key_rows = vec![];
         for key in keys {
             if self.data.contains(key) {
                 continue;
             }
-
-            futures.push(async {
-                let key_row = table.pk_serde().deserialize(key).unwrap();
-                (key.to_vec(), table.get_compacted_row(&key_row).await)
-            });
+            let key_row = table.pk_serde().deserialize(key).unwrap();
+            if table.may_exist(&key_row).await? {
+                fetch_keys.push(key.to_vec());
+                fetch_key_rows.push(key_row);
+            } else {
+                self.data.push(key.to_vec(), None);
+            }
         }
 
-        let mut buffered = stream::iter(futures).buffer_unordered(10).fuse();
-        while let Some(result) = buffered.next().await {
-            let (key, value) = result;
-            self.data.push(key, value?);
+        if fetch_keys.is_empty() {
+            return Ok(());
+        }
+        let values = table.multi_get_compacted_rows(&fetch_key_rows).await?;
+        for (key, value) in fetch_keys.into_iter().zip_eq(values) {
+            self.data.push(key, value);
         }
 
         Ok(())
@@ -554,7 +686,7 @@ impl MaterializeCache {
         self.data.push(key, value);
     }
 
-    fn evict(&mut self) {
+    pub(crate) fn evict(&mut self) {
         self.data.evict()
     }
 }
@@ -1320,4 +1452,161 @@ mod tests {
             _ => unreachable!(),
         }
     }
+
+    #[tokio::test]
+    async fn test_do_update_if_not_null_conflict() {
+        // Prepare storage and memtable.
+        let memory_state_store = MemoryStateStore::new();
+        let table_id = TableId::new(1);
+        // Two columns of int32 type, the first column is PK.
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+        ]);
+        let column_ids = vec![0.into(), 1.into()];
+
+        // test insert after insert, the null columns of the latter keep the old values.
+        let chunk1 = StreamChunk::from_pretty(
+            " i i
+            + 1 4
+            + 2 .
+            + 3 6",
+        );
+
+        let chunk2 = StreamChunk::from_pretty(
+            " i i
+            + 1 .
+            + 2 5
+            + 3 8",
+        );
+
+        // test update with a null column, delete
+        let chunk3 = StreamChunk::from_pretty(
+            " i i
+            U- 1 4
+            U+ 1 .
+            - 3 8",
+        );
+
+        // Prepare stream executors.
+        let source = MockSource::with_messages(
+            schema.clone(),
+            PkIndices::new(),
+            vec![
+                Message::Barrier(Barrier::new_test_barrier(1)),
+                Message::Chunk(chunk1),
+                Message::Chunk(chunk2),
+                Message::Barrier(Barrier::new_test_barrier(2)),
+                Message::Chunk(chunk3),
+                Message::Barrier(Barrier::new_test_barrier(3)),
+            ],
+        );
+
+        let order_types = vec![OrderType::Ascending];
+        let column_descs = vec![
+            ColumnDesc::unnamed(column_ids[0], DataType::Int32),
+            ColumnDesc::unnamed(column_ids[1], DataType::Int32),
+        ];
+
+        let table = StorageTable::for_test(
+            memory_state_store.clone(),
+            table_id,
+            column_descs,
+            order_types,
+            vec![0],
+            vec![0, 1],
+        );
+
+        let mut materialize_executor = Box::new(
+            MaterializeExecutor::for_test(
+                Box::new(source),
+                memory_state_store,
+                table_id,
+                vec![OrderPair::new(0, OrderType::Ascending)],
+                column_ids,
+                1,
+                Arc::new(AtomicU64::new(0)),
+                ConflictBehavior::DoUpdateIfNotNull,
+            )
+            .await,
+        )
+        .execute();
+        materialize_executor.next().await.transpose().unwrap();
+
+        materialize_executor.next().await.transpose().unwrap();
+        materialize_executor.next().await.transpose().unwrap();
+
+        match materialize_executor.next().await.transpose().unwrap() {
+            Some(Message::Barrier(_)) => {
+                // (1, .) keeps the old value 4
+                let row = table
+                    .get_row(
+                        &OwnedRow::new(vec![Some(1_i32.into())]),
+                        HummockReadEpoch::NoWait(u64::MAX),
+                    )
+                    .await
+                    .unwrap();
+                assert_eq!(
+                    row,
+                    Some(OwnedRow::new(vec![Some(1_i32.into()), Some(4_i32.into())]))
+                );
+
+                // (2, 5) overwrites the old null value
+                let row = table
+                    .get_row(
+                        &OwnedRow::new(vec![Some(2_i32.into())]),
+                        HummockReadEpoch::NoWait(u64::MAX),
+                    )
+                    .await
+                    .unwrap();
+                assert_eq!(
+                    row,
+                    Some(OwnedRow::new(vec![Some(2_i32.into()), Some(5_i32.into())]))
+                );
+
+                // (3, 8) overwrites the old value as usual
+                let row = table
+                    .get_row(
+                        &OwnedRow::new(vec![Some(3_i32.into())]),
+                        HummockReadEpoch::NoWait(u64::MAX),
+                    )
+                    .await
+                    .unwrap();
+                assert_eq!(
+                    row,
+                    Some(OwnedRow::new(vec![Some(3_i32.into()), Some(8_i32.into())]))
+                );
+            }
+            _ => unreachable!(),
+        }
+        materialize_executor.next().await.transpose().unwrap();
+
+        match materialize_executor.next().await.transpose().unwrap() {
+            Some(Message::Barrier(_)) => {
+                // the null column of the update also keeps the old value
+                let row = table
+                    .get_row(
+                        &OwnedRow::new(vec![Some(1_i32.into())]),
+                        HummockReadEpoch::NoWait(u64::MAX),
+                    )
+                    .await
+                    .unwrap();
+                assert_eq!(
+                    row,
+                    Some(OwnedRow::new(vec![Some(1_i32.into()), Some(4_i32.into())]))
+                );
+
+                // check delete
+                let row = table
+                    .get_row(
+                        &OwnedRow::new(vec![Some(3_i32.into())]),
+                        HummockReadEpoch::NoWait(u64::MAX),
+                    )
+                    .await
+                    .unwrap();
+                assert_eq!(row, None);
+            }
+            _ => unreachable!(),
+        }
+    }
 }
@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod conflict_resolve;
 mod materialize;
 
 #[cfg(test)]
 pub(crate) mod test_utils;
 
+pub use conflict_resolve::*;
 pub use materialize::*;
@@ -18,6 +18,7 @@ use either::{for_both, Either};
 use futures::StreamExt;
 use futures_async_stream::try_stream;
 use itertools::Itertools;
+use multimap::MultiMap;
 use risingwave_common::array::column::Column;
 use risingwave_common::array::{ArrayBuilder, DataChunk, I64ArrayBuilder, Op, StreamChunk};
 use risingwave_common::catalog::{Field, Schema};
@@ -26,15 +27,20 @@ use risingwave_common::util::iter_util::ZipEqFast;
 use risingwave_expr::table_function::ProjectSetSelectItem;
 
 use super::error::StreamExecutorError;
-use super::{BoxedExecutor, Executor, ExecutorInfo, Message, PkIndices, PkIndicesRef};
+use super::{
+    ActorContextRef, BoxedExecutor, Executor, ExecutorInfo, Message, PkIndices, PkIndicesRef,
+};
 
 impl ProjectSetExecutor {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
+        ctx: ActorContextRef,
         input: Box<dyn Executor>,
         pk_indices: PkIndices,
         select_list: Vec<ProjectSetSelectItem>,
         executor_id: u64,
         chunk_size: usize,
+        watermark_derivations: MultiMap<usize, usize>,
     ) -> Self {
         let mut fields = vec![Field::with_name(DataType::Int64, "projected_row_id")];
         fields.extend(
@@ -49,10 +55,12 @@ impl ProjectSetExecutor {
             identity: format!("ProjectSet {:X}", executor_id),
         };
         Self {
+            ctx,
             input,
             info,
             select_list,
             chunk_size,
+            watermark_derivations,
         }
     }
 }
@@ -61,11 +69,16 @@ impl ProjectSetExecutor {
 /// and returns a new data chunk. And then, `ProjectSetExecutor` will insert, delete
 /// or update element into next operator according to the result of the expression.
 pub struct ProjectSetExecutor {
+    ctx: ActorContextRef,
     input: BoxedExecutor,
     info: ExecutorInfo,
     /// Expressions of the current project_section.
     select_list: Vec<ProjectSetSelectItem>,
     chunk_size: usize,
+    /// All the watermark derivations, (input column index, expr idx). A column can be derived by
+    /// multiple expressions, but the derivation only works for select items that are plain
+    /// expressions, not table functions.
+    watermark_derivations: MultiMap<usize, usize>,
 }
 
 impl Debug for ProjectSetExecutor {
@@ -108,8 +121,45 @@ impl ProjectSetExecutor {
         for msg in input {
             let msg = msg?;
             match msg {
-                Message::Watermark(_) => {
-                    todo!("https://github.com/risingwavelabs/risingwave/issues/6042")
+                Message::Watermark(watermark) => {
+                    // A watermark is derived for an output column iff the select item of that
+                    // column is a watermark-deriving expression over the watermark column. Table
+                    // functions never derive watermarks.
+                    let out_expr_indices =
+                        match self.watermark_derivations.get_vec(&watermark.col_idx) {
+                            Some(v) => v,
+                            None => continue,
+                        };
+                    for expr_idx in out_expr_indices {
+                        let expr_idx = *expr_idx;
+                        let expr = match &self.select_list[expr_idx] {
+                            ProjectSetSelectItem::Expr(expr) => expr,
+                            ProjectSetSelectItem::TableFunction(_) => continue,
+                        };
+                        let derived_watermark = watermark.clone().transform_with_expr(
+                            expr,
+                            // The first column of `ProjectSet` is `projected_row_id`.
+                            expr_idx + 1,
+                            |err| {
+                                self.ctx.on_compute_error(
+                                    err,
+                                    &(self.info.identity.to_string()
+                                        + "(when computing watermark)"),
+                                )
+                            },
+                        );
+                        match derived_watermark {
+                            Some(derived_watermark) => {
+                                yield Message::Watermark(derived_watermark)
+                            }
+                            None => {
+                                warn!(
+                                    "{} derive a NULL watermark with the expression {}!",
+                                    self.info.identity, expr_idx
+                                );
+                            }
+                        }
+                    }
                 }
 
                 Message::Chunk(chunk) => {
@@ -208,7 +258,7 @@ mod tests {
     use risingwave_common::array::stream_chunk::StreamChunkTestExt;
     use risingwave_common::array::StreamChunk;
     use risingwave_common::catalog::{Field, Schema};
-    use risingwave_common::types::DataType;
+    use risingwave_common::types::{DataType, ScalarImpl};
     use risingwave_expr::expr::{
         new_binary_expr, Expression, InputRefExpression, LiteralExpression,
     };
@@ -261,11 +311,13 @@ mod tests {
         );
 
         let project_set = Box::new(ProjectSetExecutor::new(
+            ActorContext::create(123),
             Box::new(source),
             vec![],
             vec![test_expr.into(), tf1.into(), tf2.into()],
             1,
             CHUNK_SIZE,
+            MultiMap::new(),
         ));
 
         let expected = vec![
@@ -296,4 +348,69 @@ mod tests {
         }
         assert!(project_set.next().await.unwrap().unwrap().is_stop());
     }
+
+    #[tokio::test]
+    async fn test_project_set_watermark() {
+        let schema = Schema {
+            fields: vec![Field::unnamed(DataType::Int64)],
+        };
+        let (mut tx, source) = MockSource::channel(schema, PkIndices::new());
+
+        let a_left_expr = InputRefExpression::new(DataType::Int64, 0);
+        let a_right_expr = LiteralExpression::new(DataType::Int64, Some(ScalarImpl::Int64(1)));
+        let a_expr = new_binary_expr(
+            Type::Add,
+            DataType::Int64,
+            Box::new(a_left_expr),
+            Box::new(a_right_expr),
+        )
+        .unwrap();
+
+        let tf = repeat_tf(
+            LiteralExpression::new(DataType::Int32, Some(1_i32.into())).boxed(),
+            1,
+        );
+
+        let project_set = Box::new(ProjectSetExecutor::new(
+            ActorContext::create(123),
+            Box::new(source),
+            vec![],
+            vec![a_expr.into(), tf.into()],
+            1,
+            CHUNK_SIZE,
+            MultiMap::from_iter(vec![(0, 0)].into_iter()),
+        ));
+        let mut project_set = project_set.execute();
+
+        tx.push_int64_watermark(0, 100);
+
+        // The watermark is derived for the expression select item, shifted by the
+        // `projected_row_id` column.
+        let w = project_set.next().await.unwrap().unwrap();
+        let w = w.as_watermark().unwrap();
+        assert_eq!(
+            w,
+            &Watermark {
+                col_idx: 1,
+                data_type: DataType::Int64,
+                val: ScalarImpl::Int64(101)
+            }
+        );
+
+        // Watermarks are derived one by one.
+        tx.push_int64_watermark(0, 103);
+        let w = project_set.next().await.unwrap().unwrap();
+        let w = w.as_watermark().unwrap();
+        assert_eq!(
+            w,
+            &Watermark {
+                col_idx: 1,
+                data_type: DataType::Int64,
+                val: ScalarImpl::Int64(104)
+            }
+        );
+
+        tx.push_barrier(1, true);
+        assert!(project_set.next().await.unwrap().unwrap().is_stop());
+    }
 }
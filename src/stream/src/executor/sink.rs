@@ -29,7 +29,7 @@ use risingwave_connector::ConnectorParams;
 use super::error::{StreamExecutorError, StreamExecutorResult};
 use super::{BoxedExecutor, Executor, Message};
 use crate::executor::monitor::StreamingMetrics;
-use crate::executor::PkIndices;
+use crate::executor::{ActorContextRef, PkIndices};
 
 pub struct SinkExecutor {
     input: BoxedExecutor,
@@ -40,6 +40,7 @@ pub struct SinkExecutor {
     schema: Schema,
     pk_indices: Vec<usize>,
     sink_type: SinkType,
+    actor_context: ActorContextRef,
 }
 
 async fn build_sink(
@@ -55,7 +56,10 @@ async fn build_sink(
 }
 
 // Drop all the DELETE messages in this chunk and convert UPDATE INSERT into INSERT.
-fn force_append_only(chunk: StreamChunk, data_types: Vec<DataType>) -> Option<StreamChunk> {
+pub(super) fn force_append_only(
+    chunk: StreamChunk,
+    data_types: Vec<DataType>,
+) -> Option<StreamChunk> {
     let mut builder = DataChunkBuilder::new(data_types, chunk.cardinality() + 1);
     for (op, row_ref) in chunk.rows() {
         if op == Op::Insert || op == Op::UpdateInsert {
@@ -80,6 +84,7 @@ impl SinkExecutor {
         schema: Schema,
         pk_indices: Vec<usize>,
         sink_type: SinkType,
+        actor_context: ActorContextRef,
     ) -> Self {
         Self {
             input: materialize_executor,
@@ -90,6 +95,7 @@ impl SinkExecutor {
             schema,
             connector_params,
             sink_type,
+            actor_context,
         }
     }
 
@@ -171,6 +177,18 @@ impl SinkExecutor {
                         in_transaction = false;
                         empty_checkpoint_flag = true;
                     }
+                    if barrier.is_drop_job_actor(self.actor_context.id) && in_transaction {
+                        // The sink is dropped on a non-checkpoint barrier while a transaction is
+                        // in flight. Abort it so that no uncommitted data is left in the external
+                        // system after the actor exits.
+                        sink.abort().await?;
+                        tracing::debug!(
+                            "transaction abort due to sink dropped, epoch: {:?}",
+                            epoch
+                        );
+                        in_transaction = false;
+                        empty_checkpoint_flag = true;
+                    }
                     epoch = barrier.epoch.curr;
                     yield Message::Barrier(barrier);
                 }
@@ -210,7 +228,7 @@ mod test {
         use risingwave_common::catalog::Field;
         use risingwave_common::types::DataType;
 
-        use crate::executor::Barrier;
+        use crate::executor::{ActorContext, Barrier};
 
         let properties = maplit::hashmap! {
         "connector".into() => "mysql".into(),
@@ -253,6 +271,7 @@ mod test {
             schema.clone(),
             pk.clone(),
             SinkType::AppendOnly,
+            ActorContext::create(0),
         );
 
         let mut executor = SinkExecutor::execute(Box::new(sink_executor));
@@ -270,7 +289,7 @@ mod test {
         use risingwave_common::catalog::Field;
         use risingwave_common::types::DataType;
 
-        use crate::executor::Barrier;
+        use crate::executor::{ActorContext, Barrier};
 
         let properties = maplit::hashmap! {
             "connector".into() => "console".into(),
@@ -315,6 +334,7 @@ mod test {
             schema.clone(),
             pk.clone(),
             SinkType::ForceAppendOnly,
+            ActorContext::create(0),
         );
 
         let mut executor = SinkExecutor::execute(Box::new(sink_executor));
@@ -0,0 +1,137 @@
+// Copyright 2023 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::iter::repeat;
+
+use anyhow::anyhow;
+use futures::StreamExt;
+use futures_async_stream::try_stream;
+use risingwave_common::array::{I64Array, StreamChunk};
+use risingwave_common::catalog::{Schema, TableId, TableVersionId};
+use risingwave_connector::sink::catalog::SinkType;
+use risingwave_source::dml_manager::DmlManagerRef;
+
+use super::error::StreamExecutorError;
+use super::sink::force_append_only;
+use super::{BoxedExecutor, Executor, Message, PkIndices};
+
+/// [`TableSinkExecutor`] implements `CREATE SINK ... INTO table` by writing the sink changes
+/// into another table in the same cluster through the DML path, i.e. the batch channels read by
+/// the [`super::dml::DmlExecutor`]s of the target table. The downstream DML plan of the table
+/// takes care of row id generation and shuffling by the primary key, just like ad-hoc DML
+/// statements, so other jobs and DML can touch the target table concurrently.
+pub struct TableSinkExecutor {
+    input: BoxedExecutor,
+    identity: String,
+    dml_manager: DmlManagerRef,
+    table_id: TableId,
+    table_version_id: TableVersionId,
+    /// The index to insert a null row id column, if the target table has no user-defined primary
+    /// key. Such sinks are guaranteed to be append-only by the frontend.
+    row_id_index: Option<usize>,
+    sink_type: SinkType,
+    pk_indices: PkIndices,
+}
+
+impl TableSinkExecutor {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        input: BoxedExecutor,
+        executor_id: u64,
+        dml_manager: DmlManagerRef,
+        table_id: TableId,
+        table_version_id: TableVersionId,
+        row_id_index: Option<usize>,
+        sink_type: SinkType,
+        pk_indices: PkIndices,
+    ) -> Self {
+        Self {
+            input,
+            identity: format!("TableSinkExecutor {:X}", executor_id),
+            dml_manager,
+            table_id,
+            table_version_id,
+            row_id_index,
+            sink_type,
+            pk_indices,
+        }
+    }
+
+    #[try_stream(ok = Message, error = StreamExecutorError)]
+    async fn execute_inner(self: Box<Self>) {
+        let data_types = self.input.schema().data_types();
+        let input = self.input.execute();
+
+        #[for_await]
+        for msg in input {
+            match msg? {
+                Message::Watermark(w) => yield Message::Watermark(w),
+                Message::Chunk(chunk) => {
+                    let visible_chunk = if self.sink_type == SinkType::ForceAppendOnly {
+                        force_append_only(chunk, data_types.clone())
+                    } else {
+                        Some(chunk.compact())
+                    };
+
+                    if let Some(chunk) = visible_chunk {
+                        let write_chunk = if let Some(row_id_index) = self.row_id_index {
+                            // The target table generates its own row ids, so pad the chunk with
+                            // a null row id column like `InsertExecutor` does.
+                            let (data_chunk, ops) = chunk.clone().into_parts();
+                            let cap = data_chunk.capacity();
+                            let (mut columns, vis) = data_chunk.into_parts();
+                            let row_id_col = I64Array::from_iter(repeat(None).take(cap));
+                            columns.insert(row_id_index, row_id_col.into());
+                            StreamChunk::new(ops, columns, vis.into_visibility())
+                        } else {
+                            chunk.clone()
+                        };
+
+                        let notifier = self
+                            .dml_manager
+                            .write_chunk(self.table_id, self.table_version_id, write_chunk)
+                            .await
+                            .map_err(StreamExecutorError::connector_error)?;
+                        // Wait for the chunk to be taken by a `DmlExecutor` of the target table,
+                        // so that the barrier below is not propagated until then.
+                        notifier.await.map_err(|_| {
+                            anyhow!("failed to write chunk to table `{:?}`", self.table_id)
+                        })?;
+
+                        yield Message::Chunk(chunk);
+                    }
+                }
+                Message::Barrier(barrier) => yield Message::Barrier(barrier),
+            }
+        }
+    }
+}
+
+impl Executor for TableSinkExecutor {
+    fn execute(self: Box<Self>) -> super::BoxedMessageStream {
+        self.execute_inner().boxed()
+    }
+
+    fn schema(&self) -> &Schema {
+        self.input.schema()
+    }
+
+    fn pk_indices(&self) -> super::PkIndicesRef<'_> {
+        &self.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+}
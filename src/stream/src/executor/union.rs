@@ -12,13 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use futures::StreamExt;
 use futures_async_stream::try_stream;
+use parking_lot::Mutex;
 use risingwave_common::catalog::Schema;
 use risingwave_common::util::select_all;
 
+use super::watermark::BufferedWatermarks;
 use super::*;
 use crate::executor::{BoxedMessageStream, ExecutorInfo};
 
@@ -75,17 +78,33 @@ impl Executor for UnionExecutor {
 
 /// Merges input streams and aligns with barriers.
 pub fn merge(inputs: Vec<BoxedMessageStream>) -> BoxedMessageStream {
-    let barrier = Arc::new(tokio::sync::Barrier::new(inputs.len()));
+    let input_num = inputs.len();
+    let barrier = Arc::new(tokio::sync::Barrier::new(input_num));
+    // Watermarks of each column are combined into the minimum of all inputs, i.e. a watermark is
+    // only emitted after all inputs have reached it, like what the `MergeExecutor` does across
+    // upstream actors.
+    let buffered_watermarks: Arc<Mutex<BTreeMap<usize, BufferedWatermarks<usize>>>> =
+        Arc::new(Mutex::new(BTreeMap::new()));
     let mut streams = vec![];
-    for input in inputs {
+    for (input_id, input) in inputs.into_iter().enumerate() {
         let barrier = barrier.clone();
+        let buffered_watermarks = buffered_watermarks.clone();
         let stream = #[try_stream]
         async move {
             #[for_await]
             for item in input {
                 match item? {
-                    Message::Watermark(_) => {
-                        todo!("https://github.com/risingwavelabs/risingwave/issues/6042")
+                    Message::Watermark(watermark) => {
+                        let watermark = {
+                            let mut buffers = buffered_watermarks.lock();
+                            let buffers = buffers
+                                .entry(watermark.col_idx)
+                                .or_insert_with(|| BufferedWatermarks::with_ids(0..input_num));
+                            buffers.handle_watermark(input_id, watermark)
+                        };
+                        if let Some(watermark) = watermark {
+                            yield Message::Watermark(watermark);
+                        }
                     }
                     msg @ Message::Chunk(_) => yield msg,
                     msg @ Message::Barrier(_) => {
@@ -108,6 +127,7 @@ mod tests {
     use futures::TryStreamExt;
     use risingwave_common::array::stream_chunk::StreamChunkTestExt;
     use risingwave_common::array::StreamChunk;
+    use risingwave_common::types::{DataType, ScalarImpl};
 
     use super::*;
 
@@ -142,4 +162,37 @@ mod tests {
             ]
         );
     }
+
+    #[tokio::test]
+    async fn union_watermark() {
+        let watermark = |val: i64| Watermark::new(0, DataType::Int64, ScalarImpl::Int64(val));
+        let streams = vec![
+            try_stream! {
+                yield Message::Watermark(watermark(1));
+                yield Message::Barrier(Barrier::new_test_barrier(1));
+                yield Message::Watermark(watermark(3));
+                yield Message::Barrier(Barrier::new_test_barrier(2));
+            }
+            .boxed(),
+            try_stream! {
+                yield Message::Watermark(watermark(2));
+                yield Message::Barrier(Barrier::new_test_barrier(1));
+                yield Message::Watermark(watermark(4));
+                yield Message::Barrier(Barrier::new_test_barrier(2));
+            }
+            .boxed(),
+        ];
+        let output: Vec<_> = merge(streams).try_collect().await.unwrap();
+        // A watermark is only emitted after all inputs have reached it.
+        assert_eq!(
+            output,
+            vec![
+                Message::Watermark(watermark(1)),
+                Message::Barrier(Barrier::new_test_barrier(1)),
+                Message::Watermark(watermark(2)),
+                Message::Watermark(watermark(3)),
+                Message::Barrier(Barrier::new_test_barrier(2)),
+            ]
+        );
+    }
 }
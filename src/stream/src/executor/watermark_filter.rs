@@ -13,11 +13,13 @@
 // limitations under the License.
 
 use std::cmp;
+use std::sync::Arc;
 
 use futures::future::join_all;
 use futures::StreamExt;
 use futures_async_stream::try_stream;
 use itertools::Itertools;
+use risingwave_common::array::Op;
 use risingwave_common::hash::{VirtualNode, VnodeBitmapExt};
 use risingwave_common::row::{OwnedRow, Row};
 use risingwave_common::types::{DataType, ScalarImpl};
@@ -36,6 +38,7 @@ use super::{
 };
 use crate::common::table::state_table::StateTable;
 use crate::common::InfallibleExpression;
+use crate::executor::monitor::StreamingMetrics;
 use crate::executor::{expect_first_barrier, Watermark};
 
 /// The executor will generate a `Watermark` after each chunk.
@@ -50,6 +53,10 @@ pub struct WatermarkFilterExecutor<S: StateStore> {
     ctx: ActorContextRef,
     info: ExecutorInfo,
     table: StateTable<S>,
+    /// If set, late rows are routed to this side-output table instead of being silently dropped,
+    /// so users can audit and reprocess late events.
+    late_rows_table: Option<StateTable<S>>,
+    metrics: Arc<StreamingMetrics>,
 }
 
 impl<S: StateStore> WatermarkFilterExecutor<S> {
@@ -59,6 +66,8 @@ impl<S: StateStore> WatermarkFilterExecutor<S> {
         event_time_col_idx: usize,
         ctx: ActorContextRef,
         table: StateTable<S>,
+        late_rows_table: Option<StateTable<S>>,
+        metrics: Arc<StreamingMetrics>,
     ) -> Self {
         let info = input.info();
 
@@ -69,6 +78,8 @@ impl<S: StateStore> WatermarkFilterExecutor<S> {
             ctx,
             info,
             table,
+            late_rows_table,
+            metrics,
         }
     }
 }
@@ -105,7 +116,10 @@ impl<S: StateStore> WatermarkFilterExecutor<S> {
             ctx,
             info,
             mut table,
+            mut late_rows_table,
+            metrics,
         } = *self;
+        let actor_id_str = ctx.id.to_string();
 
         let watermark_type = watermark_expr.return_type();
         assert_eq!(
@@ -116,6 +130,9 @@ impl<S: StateStore> WatermarkFilterExecutor<S> {
 
         let first_barrier = expect_first_barrier(&mut input).await?;
         table.init_epoch(first_barrier.epoch);
+        if let Some(late_rows_table) = &mut late_rows_table {
+            late_rows_table.init_epoch(first_barrier.epoch);
+        }
         // The first barrier message should be propagated.
         yield Message::Barrier(first_barrier);
 
@@ -169,6 +186,31 @@ impl<S: StateStore> WatermarkFilterExecutor<S> {
                             ctx.on_compute_error(err, &info.identity)
                         });
 
+                    // Rows with an event time less than the watermark are late. Count them and, if
+                    // a side-output table is configured, persist them for auditing.
+                    let late_row_count = pred_output
+                        .as_bool()
+                        .iter()
+                        .filter(|pred| *pred == Some(false))
+                        .count();
+                    if late_row_count > 0 {
+                        metrics
+                            .watermark_filter_late_row_count
+                            .with_label_values(&[&actor_id_str])
+                            .inc_by(late_row_count as u64);
+                        if let Some(late_rows_table) = &mut late_rows_table {
+                            for ((op, row), pred) in
+                                chunk.rows().zip_eq(pred_output.as_bool().iter())
+                            {
+                                if pred == Some(false)
+                                    && matches!(op, Op::Insert | Op::UpdateInsert)
+                                {
+                                    late_rows_table.insert(row);
+                                }
+                            }
+                        }
+                    }
+
                     if let Some(output_chunk) = SimpleFilterExecutor::filter(chunk, pred_output)? {
                         yield Message::Chunk(output_chunk);
                     };
@@ -223,6 +265,10 @@ impl<S: StateStore> WatermarkFilterExecutor<S> {
                         table.commit_no_data_expected(barrier.epoch);
                     }
 
+                    if let Some(late_rows_table) = &mut late_rows_table {
+                        late_rows_table.commit(barrier.epoch).await?;
+                    }
+
                     yield Message::Barrier(barrier);
                 }
             }
@@ -327,6 +373,8 @@ mod tests {
 
     async fn create_watermark_filter_executor(
         mem_state: MemoryStateStore,
+        with_late_rows_table: bool,
+        metrics: Arc<StreamingMetrics>,
     ) -> (BoxedExecutor, MessageSender) {
         let interval_type = DataType::Interval;
 
@@ -350,7 +398,7 @@ mod tests {
         .unwrap();
 
         let table = create_in_memory_state_table(
-            mem_state,
+            mem_state.clone(),
             &[DataType::Int16, WATERMARK_TYPE],
             &[OrderType::Ascending],
             &[0],
@@ -359,6 +407,22 @@ mod tests {
         )
         .await;
 
+        let late_rows_table = if with_late_rows_table {
+            Some(
+                create_in_memory_state_table(
+                    mem_state,
+                    &[DataType::Int16, WATERMARK_TYPE],
+                    &[OrderType::Ascending],
+                    &[0],
+                    &[0, 1],
+                    1,
+                )
+                .await,
+            )
+        } else {
+            None
+        };
+
         let (tx, source) = MockSource::channel(schema, vec![0]);
 
         (
@@ -368,6 +432,8 @@ mod tests {
                 1,
                 ActorContext::create(123),
                 table,
+                late_rows_table,
+                metrics,
             )
             .boxed(),
             tx,
@@ -397,7 +463,12 @@ mod tests {
 
         let mem_state = MemoryStateStore::new();
 
-        let (executor, mut tx) = create_watermark_filter_executor(mem_state.clone()).await;
+        let (executor, mut tx) = create_watermark_filter_executor(
+            mem_state.clone(),
+            false,
+            Arc::new(StreamingMetrics::unused()),
+        )
+        .await;
         let mut executor = executor.execute();
 
         // push the init barrier
@@ -468,7 +539,12 @@ mod tests {
         drop(executor);
 
         // Build new executor
-        let (executor, mut tx) = create_watermark_filter_executor(mem_state.clone()).await;
+        let (executor, mut tx) = create_watermark_filter_executor(
+            mem_state.clone(),
+            false,
+            Arc::new(StreamingMetrics::unused()),
+        )
+        .await;
         let mut executor = executor.execute();
 
         // push the 1st barrier after failover
@@ -503,4 +579,68 @@ mod tests {
             ))
         );
     }
+
+    #[tokio::test]
+    async fn test_watermark_filter_late_row_side_output() {
+        let chunk1 = StreamChunk::from_pretty(
+            "  I TS
+             + 1 2022-11-07T00:00:00
+             + 2 2022-11-08T00:00:00",
+        );
+        let chunk2 = StreamChunk::from_pretty(
+            "  I TS
+             + 4 2022-11-07T00:00:00
+             + 5 2022-11-06T00:00:00
+             + 6 2022-11-10T00:00:00",
+        );
+
+        let mem_state = MemoryStateStore::new();
+        let metrics = Arc::new(StreamingMetrics::unused());
+
+        let (executor, mut tx) =
+            create_watermark_filter_executor(mem_state, true, metrics.clone()).await;
+        let mut executor = executor.execute();
+
+        // push the init barrier
+        tx.push_barrier(1, false);
+        executor.next().await.unwrap().unwrap();
+        // Init watermark
+        executor.next().await.unwrap().unwrap();
+
+        // The 1st chunk sets the watermark to `2022-11-07`, no rows are late yet.
+        tx.push_chunk(chunk1);
+        executor.next().await.unwrap().unwrap();
+        executor.next().await.unwrap().unwrap();
+        assert_eq!(
+            metrics
+                .watermark_filter_late_row_count
+                .with_label_values(&["123"])
+                .get(),
+            0
+        );
+
+        // Row 5 of the 2nd chunk arrives after the watermark and goes to the side output.
+        tx.push_chunk(chunk2);
+        let chunk = executor.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap().compact(),
+            StreamChunk::from_pretty(
+                "  I TS
+                 + 4 2022-11-07T00:00:00
+                 + 6 2022-11-10T00:00:00",
+            )
+        );
+        executor.next().await.unwrap().unwrap();
+        assert_eq!(
+            metrics
+                .watermark_filter_late_row_count
+                .with_label_values(&["123"])
+                .get(),
+            1
+        );
+
+        // The late row is committed to the side-output table on checkpoint.
+        tx.push_barrier(2, false);
+        executor.next().await.unwrap().unwrap();
+    }
 }
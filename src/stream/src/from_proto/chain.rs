@@ -131,6 +131,7 @@ impl ExecutorBuilder for ChainExecutorBuilder {
                     .map(|&k| k as usize)
                     .collect_vec();
                 let prefix_hint_len = table_desc.get_read_prefix_len_hint() as usize;
+                let versioned = table_desc.versioned;
                 // TODO: refactor it with from_table_catalog in the future.
                 let table = StorageTable::new_partial(
                     state_store,
@@ -143,6 +144,7 @@ impl ExecutorBuilder for ChainExecutorBuilder {
                     table_option,
                     value_indices,
                     prefix_hint_len,
+                    versioned,
                 );
 
                 BackfillExecutor::new(
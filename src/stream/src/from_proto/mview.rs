@@ -53,8 +53,13 @@ impl ExecutorBuilder for MaterializeExecutorBuilder {
             risingwave_pb::catalog::HandleConflictBehavior::Ignore => {
                 ConflictBehavior::IgnoreConflict
             }
+            risingwave_pb::catalog::HandleConflictBehavior::Error => ConflictBehavior::ReportError,
+            risingwave_pb::catalog::HandleConflictBehavior::DoUpdateIfNotNull => {
+                ConflictBehavior::DoUpdateIfNotNull
+            }
         };
 
+        let freshness_slo_threshold_ms = params.env.config().mview_freshness_slo_threshold_ms;
         let executor = MaterializeExecutor::new(
             input,
             store,
@@ -65,6 +70,8 @@ impl ExecutorBuilder for MaterializeExecutorBuilder {
             table,
             stream.get_watermark_epoch(),
             conflict_behavior,
+            params.executor_stats,
+            freshness_slo_threshold_ms,
         )
         .await;
 
@@ -108,7 +115,12 @@ impl ExecutorBuilder for ArrangeExecutorBuilder {
             risingwave_pb::catalog::HandleConflictBehavior::Ignore => {
                 ConflictBehavior::IgnoreConflict
             }
+            risingwave_pb::catalog::HandleConflictBehavior::Error => ConflictBehavior::ReportError,
+            risingwave_pb::catalog::HandleConflictBehavior::DoUpdateIfNotNull => {
+                ConflictBehavior::DoUpdateIfNotNull
+            }
         };
+        let freshness_slo_threshold_ms = params.env.config().mview_freshness_slo_threshold_ms;
         let executor = MaterializeExecutor::new(
             input,
             store,
@@ -119,6 +131,8 @@ impl ExecutorBuilder for ArrangeExecutorBuilder {
             table,
             stream.get_watermark_epoch(),
             conflict_behavior,
+            params.executor_stats,
+            freshness_slo_threshold_ms,
         )
         .await;
 
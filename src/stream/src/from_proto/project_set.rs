@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use multimap::MultiMap;
+use risingwave_common::util::iter_util::ZipEqFast;
 use risingwave_expr::table_function::ProjectSetSelectItem;
 use risingwave_pb::stream_plan::ProjectSetNode;
 
@@ -42,12 +44,26 @@ impl ExecutorBuilder for ProjectSetExecutorBuilder {
             })
             .try_collect()?;
         let chunk_size = params.env.config().developer.stream_chunk_size;
+
+        let watermark_derivations = MultiMap::from_iter(
+            node.get_watermark_input_key()
+                .iter()
+                .map(|key| *key as usize)
+                .zip_eq_fast(
+                    node.get_watermark_output_key()
+                        .iter()
+                        .map(|key| *key as usize),
+                ),
+        );
+
         Ok(ProjectSetExecutor::new(
+            params.actor_context,
             input,
             params.pk_indices,
             select_list,
             params.executor_id,
             chunk_size,
+            watermark_derivations,
         )
         .boxed())
     }
@@ -12,12 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use risingwave_common::catalog::TableId;
 use risingwave_connector::sink::catalog::SinkType;
-use risingwave_connector::sink::SinkConfig;
+use risingwave_connector::sink::{
+    SinkConfig, TABLE_SINK, TABLE_SINK_ID_OPTION, TABLE_SINK_ROW_ID_INDEX_OPTION,
+    TABLE_SINK_VERSION_ID_OPTION,
+};
 use risingwave_pb::stream_plan::SinkNode;
 
 use super::*;
-use crate::executor::{SinkExecutor, StreamExecutorError};
+use crate::executor::{SinkExecutor, StreamExecutorError, TableSinkExecutor};
 
 pub struct SinkExecutorBuilder;
 
@@ -42,6 +46,39 @@ impl ExecutorBuilder for SinkExecutorBuilder {
             .map(|pk| pk.index as usize)
             .collect::<Vec<_>>();
         let schema = sink_desc.columns.iter().map(Into::into).collect();
+
+        // A `table` sink writes into another table through the DML path instead of an external
+        // system, so it is handled by a dedicated executor. All the `table.*` options below are
+        // injected by the frontend when planning `CREATE SINK ... INTO table`.
+        if properties.get("connector").map(|s| s.as_str()) == Some(TABLE_SINK) {
+            let table_id = TableId::new(
+                properties
+                    .get(TABLE_SINK_ID_OPTION)
+                    .expect("table id not provided for table sink")
+                    .parse()
+                    .unwrap(),
+            );
+            let table_version_id = properties
+                .get(TABLE_SINK_VERSION_ID_OPTION)
+                .expect("table version id not provided for table sink")
+                .parse()
+                .unwrap();
+            let row_id_index = properties
+                .get(TABLE_SINK_ROW_ID_INDEX_OPTION)
+                .map(|i| i.parse().unwrap());
+
+            return Ok(Box::new(TableSinkExecutor::new(
+                materialize_executor,
+                params.executor_id,
+                params.env.dml_manager_ref(),
+                table_id,
+                table_version_id,
+                row_id_index,
+                sink_type,
+                pk_indices,
+            )));
+        }
+
         // This field can be used to distinguish a specific actor in parallelism to prevent
         // transaction execution errors
         properties.insert(
@@ -59,6 +96,7 @@ impl ExecutorBuilder for SinkExecutorBuilder {
             schema,
             pk_indices,
             sink_type,
+            params.actor_context,
         )))
     }
 }
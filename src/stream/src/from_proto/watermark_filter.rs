@@ -45,9 +45,21 @@ impl ExecutorBuilder for WatermarkFilterBuilder {
         );
 
         // TODO: may use consistent op for watermark filter after we have upsert.
-        let [table]: [_; 1] = node.get_tables().clone().try_into().unwrap();
-        let table =
-            StateTable::from_table_catalog_inconsistent_op(&table, store, Some(vnodes)).await;
+        // The optional second table is the side output for late rows.
+        let mut tables = node.get_tables().clone().into_iter();
+        let table = tables.next().expect("watermark table not set");
+        let table = StateTable::from_table_catalog_inconsistent_op(
+            &table,
+            store.clone(),
+            Some(vnodes.clone()),
+        )
+        .await;
+        let late_rows_table = match tables.next() {
+            Some(table) => Some(
+                StateTable::from_table_catalog_inconsistent_op(&table, store, Some(vnodes)).await,
+            ),
+            None => None,
+        };
 
         Ok(WatermarkFilterExecutor::new(
             input,
@@ -55,6 +67,8 @@ impl ExecutorBuilder for WatermarkFilterBuilder {
             event_time_col_idx,
             params.actor_context,
             table,
+            late_rows_table,
+            params.executor_stats,
         )
         .boxed())
     }
@@ -39,7 +39,7 @@ use risingwave_storage::monitor::{
     MonitoredStorageMetrics, ObjectStoreMetrics,
 };
 use risingwave_storage::opts::StorageOpts;
-use risingwave_storage::store::{ReadOptions, StateStoreRead};
+use risingwave_storage::store::{CachePolicy, ReadOptions, StateStoreRead};
 use risingwave_storage::{StateStore, StateStoreImpl};
 
 const SST_ID_SHIFT_COUNT: u32 = 1000000;
@@ -621,7 +621,9 @@ async fn open_hummock_iters(
                     table_id: TableId { table_id },
                     retention_seconds: None,
                     ignore_range_tombstone: false,
+                    limit: None,
                     read_version_from_backup: false,
+                    cache_policy: CachePolicy::Fill,
                 },
             )
             .await?;
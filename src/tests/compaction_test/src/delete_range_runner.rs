@@ -49,7 +49,7 @@ use risingwave_storage::hummock::{
 };
 use risingwave_storage::monitor::{CompactorMetrics, HummockStateStoreMetrics};
 use risingwave_storage::opts::StorageOpts;
-use risingwave_storage::store::{LocalStateStore, NewLocalOptions, ReadOptions};
+use risingwave_storage::store::{CachePolicy, LocalStateStore, NewLocalOptions, ReadOptions};
 use risingwave_storage::StateStore;
 
 use crate::CompactionTestOpts;
@@ -389,9 +389,11 @@ impl NormalState {
                 ReadOptions {
                     prefix_hint: None,
                     ignore_range_tombstone,
+                    limit: None,
                     retention_seconds: None,
                     table_id: self.table_id,
                     read_version_from_backup: false,
+                    cache_policy: CachePolicy::Fill,
                 },
             )
             .await
@@ -414,9 +416,11 @@ impl NormalState {
                     ReadOptions {
                         prefix_hint: None,
                         ignore_range_tombstone,
+                        limit: None,
                         retention_seconds: None,
                         table_id: self.table_id,
                         read_version_from_backup: false,
+                        cache_policy: CachePolicy::Fill,
                     },
                 )
                 .await
@@ -445,9 +449,11 @@ impl CheckState for NormalState {
                     ReadOptions {
                         prefix_hint: None,
                         ignore_range_tombstone: true,
+                        limit: None,
                         retention_seconds: None,
                         table_id: self.table_id,
                         read_version_from_backup: false,
+                        cache_policy: CachePolicy::Fill,
                     },
                 )
                 .await
@@ -13,7 +13,7 @@
 // limitations under the License.
 //
 #![cfg(madsim)]
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::time::Duration;
 
 use anyhow::Result;
@@ -32,8 +32,11 @@ const APPEND_ONLY_SINK_CREATE: &str = "create sink s1 from t with (connector='ka
 const MV_CREATE: &str = "create materialized view m as select count(*) from t;";
 const DEBEZIUM_SINK_CREATE: &str = "create sink s2 from m with (connector='kafka', properties.bootstrap.server='192.168.11.1:29092', topic='t_sink_debezium', format='debezium');";
 
+const BOOTSTRAP_SINK_CREATE: &str = "create sink s3 from t with (connector='kafka', properties.bootstrap.server='192.168.11.1:29092', topic='t_sink_bootstrap', format='append_only');";
+
 const APPEND_ONLY_TOPIC: &str = "t_sink_append_only";
 const DEBEZIUM_TOPIC: &str = "t_sink_debezium";
+const BOOTSTRAP_TOPIC: &str = "t_sink_bootstrap";
 
 use serde_derive::{Deserialize, Serialize};
 
@@ -202,9 +205,69 @@ async fn test_sink_debezium() -> Result<()> {
     Ok(())
 }
 
+/// A sink created on a relation with existing contents should bootstrap from a snapshot of the
+/// full dataset (via a backfill-style scan coordinated with barriers) before switching to
+/// incremental changes, so the external system does not miss the history.
+#[madsim::test]
+async fn test_sink_bootstrap() -> Result<()> {
+    let mut cluster = Cluster::start(Configuration::for_scale()).await?;
+
+    let mut topics = HashMap::new();
+    topics.insert(BOOTSTRAP_TOPIC.to_string(), 1);
+    cluster.create_kafka_topics(topics);
+
+    time::sleep(Duration::from_secs(10)).await;
+
+    cluster.run(ROOT_TABLE_CREATE).await?;
+
+    // Write some rows and make them visible before the sink exists.
+    for i in 1..=3 {
+        cluster
+            .run(&format!("insert into t values ({});", i))
+            .await?;
+    }
+    cluster.run("flush;").await?;
+
+    cluster.run(BOOTSTRAP_SINK_CREATE).await?;
+
+    let consumer: StreamConsumer<_> = cluster
+        .run_on_client(async move {
+            let consumer = ClientConfig::new()
+                .set("bootstrap.servers", "192.168.11.1:29092")
+                .set("group.id", "id")
+                .create::<StreamConsumer<_>>()
+                .await
+                .expect("failed to create kafka consumer client");
+
+            let mut tpl = TopicPartitionList::new();
+            tpl.add_partition(BOOTSTRAP_TOPIC, 0);
+
+            consumer.assign(&tpl).unwrap();
+            consumer
+        })
+        .await;
+
+    let mut stream = consumer.stream();
+
+    // The rows written before the sink was created should be emitted first.
+    let mut bootstrapped = HashSet::new();
+    for _ in 1..=3 {
+        let msg = stream.next().await.unwrap().unwrap();
+        let payload = msg.payload().unwrap();
+        let data: AppendOnlyPayload = serde_json::from_slice(payload).unwrap();
+        bootstrapped.insert(data.v1);
+    }
+    assert_eq!(bootstrapped, HashSet::from_iter(1..=3));
+
+    // Then the sink continues with incremental changes.
+    check_kafka_after_insert(&mut cluster, &mut stream, &[4, 5, 6]).await?;
+
+    Ok(())
+}
+
 fn check_payload(msg: &BorrowedMessage, payload: &[u8], i: i64) {
     match msg.topic() {
-        APPEND_ONLY_TOPIC => {
+        APPEND_ONLY_TOPIC | BOOTSTRAP_TOPIC => {
             let data: AppendOnlyPayload = serde_json::from_slice(payload).unwrap();
             assert_eq!(data.v1, i);
         }
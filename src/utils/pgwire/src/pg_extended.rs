@@ -289,6 +289,18 @@ impl PreparedStatement {
     pub fn parse_statement(
         raw_statement: String,
         provided_param_oid: Vec<i32>,
+    ) -> PsqlResult<Self> {
+        Self::parse_statement_inferred(raw_statement, provided_param_oid, &[])
+    }
+
+    /// Same as [`Self::parse_statement`], but additionally takes the param types inferred by the
+    /// session from the statement (e.g. from the target column types of a DML statement). Inferred
+    /// types have a lower priority than both the explicit `::type` casts and the types provided in
+    /// the `Parse` message, and are used before falling back to `VARCHAR`.
+    pub fn parse_statement_inferred(
+        raw_statement: String,
+        provided_param_oid: Vec<i32>,
+        inferred_param_types: &[Option<DataType>],
     ) -> PsqlResult<Self> {
         let provided_param_types = provided_param_oid
             .iter()
@@ -354,6 +366,8 @@ impl PreparedStatement {
             }
             if idx < provided_param_types.len() {
                 *param_record = Some(provided_param_types[idx].clone());
+            } else if let Some(Some(inferred_type)) = inferred_param_types.get(idx) {
+                *param_record = Some(inferred_type.clone());
             } else {
                 // If the type information isn't provided implicitly or explicitly, we just assign
                 // it as VARCHAR.
@@ -397,9 +411,23 @@ impl PreparedStatement {
             .zip_eq_fast(format_iter)
         {
             let str = match type_oid {
-                DataType::Varchar | DataType::Bytea => {
+                DataType::Varchar => {
                     format!("'{}'", cstr_to_str(raw_param).unwrap().replace('\'', "''"))
                 }
+                DataType::Bytea => match param_format {
+                    // The binary format is the raw bytes, which may not be valid utf8, so
+                    // re-encode them as a hex bytea literal.
+                    Format::Binary => {
+                        use risingwave_common::types::to_text::ToText as _;
+                        format!(
+                            "'{}'::BYTEA",
+                            raw_param.as_ref().to_text_with_type(&DataType::Bytea)
+                        )
+                    }
+                    Format::Text => {
+                        format!("'{}'", cstr_to_str(raw_param).unwrap().replace('\'', "''"))
+                    }
+                },
                 DataType::Boolean => match param_format {
                     Format::Binary => bool::from_sql(&place_hodler, raw_param)
                         .unwrap()
@@ -621,6 +649,7 @@ impl PreparedStatement {
 
 #[cfg(test)]
 mod tests {
+    use bytes::Bytes;
     use chrono::{DateTime, Utc};
     use pg_interval::Interval;
     // Note this useful idiom: importing names from outer (for mod tests) scope.
@@ -852,6 +881,14 @@ mod tests {
                 .unwrap();
         assert_eq!(params, vec!["'A'", "'B'", "'C'"]);
 
+        // Test BYTEA type. The binary format is the raw bytes, which need not be valid utf8.
+        let raw_params = vec![Bytes::from_static(&[0x00, 0xde, 0xad, 0xbe, 0xef])];
+        let type_description = vec![DataType::Bytea];
+        let params =
+            PreparedStatement::parse_params(&type_description, &raw_params, &[Format::Binary])
+                .unwrap();
+        assert_eq!(params, vec!["'\\x00deadbeef'::BYTEA"]);
+
         // Test BOOLEAN type.
         let mut raw_params = vec![BytesMut::new(); 2];
         false.to_sql(&place_hodler, &mut raw_params[0]).unwrap();
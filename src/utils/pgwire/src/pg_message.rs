@@ -362,7 +362,7 @@ pub enum BeMessage<'a> {
     NoData,
     DataRow(&'a Row),
     ParameterStatus(BeParameterStatusMessage<'a>),
-    ReadyForQuery,
+    ReadyForQuery(TransactionStatus),
     RowDescription(&'a [PgFieldDescriptor]),
     ErrorResponse(BoxedError),
     CloseComplete,
@@ -371,6 +371,28 @@ pub enum BeMessage<'a> {
     BackendKeyData((i32, i32)),
 }
 
+/// The transaction status reported in the `ReadyForQuery` message. Clients like pg_dump and
+/// migration tools inspect it to decide whether their `BEGIN`/`COMMIT` round trips succeeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionStatus {
+    /// Not in a transaction block.
+    Idle,
+    /// In a transaction block.
+    InTransaction,
+    /// In a failed transaction block; statements are rejected until the block is ended.
+    InFailedTransaction,
+}
+
+impl TransactionStatus {
+    fn as_byte(self) -> u8 {
+        match self {
+            TransactionStatus::Idle => b'I',
+            TransactionStatus::InTransaction => b'T',
+            TransactionStatus::InFailedTransaction => b'E',
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum BeParameterStatusMessage<'a> {
     ClientEncoding(&'a str),
@@ -563,11 +585,10 @@ impl<'a> BeMessage<'a> {
             // +-----+----------+---------------------------+
             // | 'Z' | int32(5) | byte1(transaction status) |
             // +-----+----------+---------------------------+
-            BeMessage::ReadyForQuery => {
+            BeMessage::ReadyForQuery(status) => {
                 buf.put_u8(b'Z');
                 buf.put_i32(5);
-                // TODO: add transaction status
-                buf.put_u8(b'I');
+                buf.put_u8(status.as_byte());
             }
 
             BeMessage::ParseComplete => {
@@ -24,6 +24,7 @@ use bytes::{Bytes, BytesMut};
 use futures::stream::StreamExt;
 use futures::Stream;
 use openssl::ssl::{SslAcceptor, SslContext, SslContextRef, SslMethod};
+use risingwave_common::PG_VERSION;
 use risingwave_sqlparser::parser::Parser;
 use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
 use tokio_openssl::SslStream;
@@ -36,7 +37,7 @@ use crate::pg_field_descriptor::PgFieldDescriptor;
 use crate::pg_message::{
     BeCommandCompleteMessage, BeMessage, BeParameterStatusMessage, FeBindMessage, FeCancelMessage,
     FeCloseMessage, FeDescribeMessage, FeExecuteMessage, FeMessage, FeParseMessage,
-    FePasswordMessage, FeStartupMessage,
+    FePasswordMessage, FeStartupMessage, TransactionStatus,
 };
 use crate::pg_response::RowSetResult;
 use crate::pg_server::{Session, SessionManager, UserAuthenticator};
@@ -190,7 +191,7 @@ where
                             .write_no_flush(&BeMessage::ErrorResponse(Box::new(e)))
                             .unwrap();
                         self.stream
-                            .write_no_flush(&BeMessage::ReadyForQuery)
+                            .write_no_flush(&BeMessage::ReadyForQuery(TransactionStatus::Idle))
                             .unwrap();
                     }
 
@@ -222,7 +223,9 @@ where
             FeMessage::Bind(m) => self.process_bind_msg(m)?,
             FeMessage::Execute(m) => self.process_execute_msg(m).await?,
             FeMessage::Describe(m) => self.process_describe_msg(m)?,
-            FeMessage::Sync => self.stream.write_no_flush(&BeMessage::ReadyForQuery)?,
+            FeMessage::Sync => self
+                .stream
+                .write_no_flush(&BeMessage::ReadyForQuery(TransactionStatus::Idle))?,
             FeMessage::Close(m) => self.process_close_msg(m)?,
             FeMessage::Flush => self.stream.flush().await?,
         }
@@ -278,7 +281,7 @@ where
                     .write_no_flush(&BeMessage::BackendKeyData(session.id()))?;
 
                 self.stream.write_parameter_status_msg_no_flush()?;
-                self.stream.write_no_flush(&BeMessage::ReadyForQuery)?;
+                self.stream.write_no_flush(&BeMessage::ReadyForQuery(TransactionStatus::Idle))?;
             }
             UserAuthenticator::ClearText(_) => {
                 self.stream
@@ -304,7 +307,7 @@ where
         }
         self.stream.write_no_flush(&BeMessage::AuthenticationOk)?;
         self.stream.write_parameter_status_msg_no_flush()?;
-        self.stream.write_no_flush(&BeMessage::ReadyForQuery)?;
+        self.stream.write_no_flush(&BeMessage::ReadyForQuery(TransactionStatus::Idle))?;
         self.state = PgProtocolState::Regular;
         Ok(())
     }
@@ -384,7 +387,7 @@ where
         }
         // Put this line inside the for loop above will lead to unfinished/stuck regress test...Not
         // sure the reason.
-        self.stream.write_no_flush(&BeMessage::ReadyForQuery)?;
+        self.stream.write_no_flush(&BeMessage::ReadyForQuery(TransactionStatus::Idle))?;
         Ok(())
     }
 
@@ -408,7 +411,21 @@ where
                 || lower_sql.starts_with("explain")
         };
 
-        let prepared_statement = PreparedStatement::parse_statement(sql.to_string(), msg.type_ids)?;
+        // For DML statements, ask the session to derive the param types from the target column
+        // types, so that `Describe` answers an accurate `ParameterDescription` instead of falling
+        // back to `VARCHAR`. This is best-effort: on failure we keep the old behavior.
+        let inferred_param_types = if is_query_sql {
+            vec![]
+        } else {
+            let session = self.session.clone().unwrap();
+            session.infer_param_types(sql).await.unwrap_or_default()
+        };
+
+        let prepared_statement = PreparedStatement::parse_statement_inferred(
+            sql.to_string(),
+            msg.type_ids,
+            &inferred_param_types,
+        )?;
 
         // 2. Create the row description.
         let fields: Vec<PgFieldDescriptor> = if is_query_sql {
@@ -620,8 +637,10 @@ where
         self.write_no_flush(&BeMessage::ParameterStatus(
             BeParameterStatusMessage::StandardConformingString("on"),
         ))?;
+        // pg_dump refuses to talk to servers it believes to be older than 9.2, and migration
+        // tools choose their catalog queries based on this value.
         self.write_no_flush(&BeMessage::ParameterStatus(
-            BeParameterStatusMessage::ServerVersion("9.5.0"),
+            BeParameterStatusMessage::ServerVersion(PG_VERSION),
         ))?;
         Ok(())
     }
@@ -63,6 +63,7 @@ pub enum StatementType {
     DROP_DATABASE,
     DROP_USER,
     ALTER_TABLE,
+    ALTER_MATERIALIZED_VIEW,
     ALTER_SYSTEM,
     REVOKE_PRIVILEGE,
     // Introduce ORDER_BY statement type cuz Calcite unvalidated AST has SqlKind.ORDER_BY. Note
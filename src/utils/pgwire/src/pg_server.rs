@@ -18,6 +18,7 @@ use std::result::Result;
 use std::sync::Arc;
 
 use futures::Stream;
+use risingwave_common::types::DataType;
 use risingwave_sqlparser::ast::Statement;
 use tokio::io::{AsyncRead, AsyncWrite};
 use tokio::net::TcpListener;
@@ -72,6 +73,16 @@ where
         self: Arc<Self>,
         sql: &str,
     ) -> Result<Vec<PgFieldDescriptor>, BoxedError>;
+
+    /// Infer the types of the parameters (`$1`, `$2`, ...) in `sql` from the types of the columns
+    /// they are bound to, so that `Describe` can answer an accurate `ParameterDescription` for
+    /// prepared DML statements. The returned vector is indexed by parameter index (0-based), and
+    /// an entry is `None` if the type of that parameter cannot be derived.
+    async fn infer_param_types(
+        self: Arc<Self>,
+        sql: &str,
+    ) -> Result<Vec<Option<DataType>>, BoxedError>;
+
     fn user_authenticator(&self) -> &UserAuthenticator;
 
     fn id(&self) -> SessionId;
@@ -287,6 +298,13 @@ mod tests {
             ])
         }
 
+        async fn infer_param_types(
+            self: Arc<Self>,
+            _sql: &str,
+        ) -> Result<Vec<Option<risingwave_common::types::DataType>>, super::BoxedError> {
+            Ok(vec![])
+        }
+
         fn id(&self) -> SessionId {
             (0, 0)
         }